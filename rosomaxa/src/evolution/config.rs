@@ -239,13 +239,13 @@ where
         min_cv: Option<(String, usize, f64, bool, K)>,
         target_proximity: Option<(Vec<f64>, f64)>,
     ) -> Result<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>, GenericError> {
-        let terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>> = match (
+        // NOTE target proximity alone does not guarantee that search stops, so default limits are still applied
+        let mut terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>> = match (
             max_generations,
             max_time,
             &min_cv,
-            &target_proximity,
         ) {
-            (None, None, None, None) => {
+            (None, None, None) => {
                 (logger)("configured to use default max-generations (3000) and max-time (300secs)");
                 vec![Box::new(MaxGeneration::new(3000)), Box::new(MaxTime::new(300.))]
             }
@@ -284,20 +284,20 @@ where
                     terminations.push(variation)
                 }
 
-                if let Some((target_fitness, distance_threshold)) = target_proximity.clone() {
-                    (logger)(
-                            format!(
-                                "configured to use target fitness: {target_fitness:?}, distance threshold: {distance_threshold}",
-                            )
-                            .as_str(),
-                        );
-                    terminations.push(Box::new(TargetProximity::new(target_fitness, distance_threshold)));
-                }
-
                 terminations
             }
         };
 
+        if let Some((target_fitness, distance_threshold)) = target_proximity {
+            (logger)(
+                format!(
+                    "configured to use target fitness: {target_fitness:?}, distance threshold: {distance_threshold}"
+                )
+                .as_str(),
+            );
+            terminations.push(Box::new(TargetProximity::new(target_fitness, distance_threshold)));
+        }
+
         Ok(Box::new(CompositeTermination::new(terminations)))
     }
 
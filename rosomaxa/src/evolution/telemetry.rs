@@ -22,6 +22,8 @@ pub struct TelemetryMetrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<TelemetryGeneration>,
+    /// A seed used to initialize random generator, if it was specified.
+    pub seed: Option<u64>,
 }
 
 /// Represents information about generation.
@@ -48,6 +50,8 @@ pub struct TelemetryIndividual {
     pub difference: f64,
     /// Objectives fitness values.
     pub fitness: Vec<f64>,
+    /// Solution gap (in percent) from target fitness, if it was specified.
+    pub gap: Option<f64>,
 }
 
 /// Holds population state.
@@ -105,6 +109,7 @@ where
     improvement_tracker: ImprovementTracker,
     speed_tracker: SpeedTracker,
     next_generation: Option<usize>,
+    target_fitness: Option<Vec<f64>>,
     _marker: (PhantomData<O>, PhantomData<S>),
 }
 
@@ -117,16 +122,29 @@ where
     pub fn new(mode: TelemetryMode) -> Self {
         Self {
             time: Timer::start(),
-            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
+            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![], seed: None },
             mode,
             statistics: Default::default(),
             improvement_tracker: ImprovementTracker::new(1000),
             speed_tracker: SpeedTracker::default(),
             next_generation: None,
+            target_fitness: None,
             _marker: Default::default(),
         }
     }
 
+    /// Reports a seed used to initialize random generator, so the run can be replayed.
+    pub fn on_seed(&mut self, seed: u64) {
+        self.log(format!("random seed: {seed}").as_str());
+        self.metrics.seed = Some(seed);
+    }
+
+    /// Sets target fitness, e.g. of the best known solution, used to report a gap of individuals.
+    pub fn set_target_fitness(&mut self, target_fitness: Vec<f64>) {
+        self.log(format!("target fitness: ({})", format_fitness(target_fitness.iter().cloned())).as_str());
+        self.target_fitness = Some(target_fitness);
+    }
+
     /// Reports initial solution statistics.
     pub fn on_initial(&mut self, solution: &S, item_time: Timer) {
         match &self.mode {
@@ -320,24 +338,31 @@ where
         let fitness = solution.fitness().collect::<Vec<_>>();
 
         let difference = get_fitness_change(objective, population, solution);
+        let gap = self
+            .target_fitness
+            .as_ref()
+            .map(|target_fitness| relative_distance(fitness.iter().cloned(), target_fitness.iter().cloned()) * 100.);
 
-        TelemetryIndividual { rank, difference, fitness }
+        TelemetryIndividual { rank, difference, fitness, gap }
     }
 
     fn log_individual(&self, metrics: &TelemetryIndividual, gen_info: Option<(usize, Timer)>) {
         let fitness = format_fitness(metrics.fitness.iter().cloned());
 
+        let gap = metrics.gap.map(|gap| format!(", gap: {gap:.3}%")).unwrap_or_default();
+
         let value = if let Some((gen, gen_time)) = gen_info {
             format!(
-                "[{}s] generation {} took {}ms, median: {}ms fitness: ({})",
+                "[{}s] generation {} took {}ms, median: {}ms fitness: ({}){}",
                 self.time.elapsed_secs(),
                 gen,
                 gen_time.elapsed_millis(),
                 self.speed_tracker.median.approx_median().unwrap_or(0),
-                fitness
+                fitness,
+                gap
             )
         } else {
-            format!("\trank: {}, fitness: ({}), difference: {:.3}%", metrics.rank, fitness, metrics.difference)
+            format!("\trank: {}, fitness: ({}), difference: {:.3}%{}", metrics.rank, fitness, metrics.difference, gap)
         };
 
         self.log(value.as_str());
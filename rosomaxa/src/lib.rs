@@ -175,7 +175,11 @@ where
         telemetry_mode: TelemetryMode,
         environment: Arc<Environment>,
     ) -> Self {
-        let telemetry = Telemetry::new(telemetry_mode);
+        let mut telemetry = Telemetry::new(telemetry_mode);
+        if let Some(seed) = environment.random.get_seed() {
+            telemetry.on_seed(seed);
+        }
+
         Self { objective, population, telemetry, environment }
     }
 
@@ -183,6 +187,11 @@ where
     pub fn add_solution(&mut self, solution: S) {
         self.population.add(solution);
     }
+
+    /// Sets target fitness used to report a gap of individuals in telemetry.
+    pub fn set_target_fitness(&mut self, target_fitness: Vec<f64>) {
+        self.telemetry.set_target_fitness(target_fitness);
+    }
 }
 
 impl<O, S> HeuristicContext for TelemetryHeuristicContext<O, S>
@@ -100,7 +100,7 @@ impl Default for Parallelism {
 impl Parallelism {
     /// Creates an instance of `Parallelism`.
     pub fn new(num_thread_pools: usize, threads_per_pool: usize) -> Self {
-        let thread_pools = (0..num_thread_pools).map(|_| ThreadPool::new(threads_per_pool)).collect();
+        let thread_pools = (0..num_thread_pools).map(|idx| ThreadPool::new_with_index(idx, threads_per_pool)).collect();
         Self { available_cpus: get_cpus(), thread_pools: Some(Arc::new(thread_pools)) }
     }
 
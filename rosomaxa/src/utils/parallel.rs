@@ -12,6 +12,7 @@ pub use self::actual::ThreadPool;
 mod actual {
     extern crate rayon;
     use self::rayon::{ThreadPool as RayonThreadPool, ThreadPoolBuilder};
+    use crate::utils::set_random_stream;
     use rayon::prelude::*;
 
     /// Represents a thread pool wrapper.
@@ -27,6 +28,18 @@ mod actual {
             }
         }
 
+        /// Creates a new instance of `ThreadPool` which is identified by given index. The index is
+        /// used to derive per thread random streams when a seeded random generator is used.
+        pub fn new_with_index(pool_idx: usize, num_threads: usize) -> Self {
+            Self {
+                inner: ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .start_handler(move |thread_idx| set_random_stream(pool_idx, thread_idx))
+                    .build()
+                    .expect("cannot build a thread pool"),
+            }
+        }
+
         /// Executes given operation on thread pool.
         pub fn execute<OP, R>(&self, op: OP) -> R
         where
@@ -91,6 +104,11 @@ mod actual {
             Self {}
         }
 
+        /// Creates a new instance of `ThreadPool` identified by index.
+        pub fn new_with_index(_pool_idx: usize, _num_threads: usize) -> Self {
+            Self {}
+        }
+
         /// Executes given operation on thread pool (dummy).
         pub fn execute<OP, R>(&self, op: OP) -> R
         where
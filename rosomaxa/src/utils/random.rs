@@ -5,9 +5,9 @@ mod random_test;
 use rand::prelude::*;
 use rand::Error;
 use rand_distr::{Gamma, Normal};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::cmp::Ordering;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 /// Provides the way to sample from different distributions.
 pub trait DistributionSampler {
@@ -39,6 +39,11 @@ pub trait Random {
 
     /// Returns RNG.
     fn get_rng(&self) -> RandomGen;
+
+    /// Returns a seed used to initialize random generator, if it was specified explicitly.
+    fn get_seed(&self) -> Option<u64> {
+        None
+    }
 }
 
 /// Provides way to sample from different distributions.
@@ -69,13 +74,20 @@ impl DistributionSampler for DefaultDistributionSampler {
 /// A default random implementation.
 #[derive(Default)]
 pub struct DefaultRandom {
-    use_repeatable: bool,
+    mode: RandomMode,
 }
 
 impl DefaultRandom {
     /// Creates an instance of `DefaultRandom` with repeatable (predictable) random generation.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { mode: RandomMode::Repeatable }
+    }
+
+    /// Creates an instance of `DefaultRandom` which uses random generators initialized from given seed.
+    /// Each thread gets its own random stream which is derived deterministically from the seed and
+    /// the thread identity (thread pool and thread index), so the same seed produces the same streams.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { mode: RandomMode::Seeded(Arc::new(RandomSeed(seed))) }
     }
 }
 
@@ -117,68 +129,133 @@ impl Random for DefaultRandom {
     }
 
     fn get_rng(&self) -> RandomGen {
-        RandomGen { use_repeatable: self.use_repeatable }
+        RandomGen { mode: self.mode.clone() }
     }
+
+    fn get_seed(&self) -> Option<u64> {
+        match &self.mode {
+            RandomMode::Seeded(seed) => Some(seed.0),
+            _ => None,
+        }
+    }
+}
+
+/// Specifies how random generators are initialized.
+#[derive(Clone, Debug, Default)]
+enum RandomMode {
+    /// Generators are seeded from thread_rng.
+    #[default]
+    Randomized,
+    /// Generators are seeded with 0.
+    Repeatable,
+    /// Generators are seeded from the user provided seed.
+    Seeded(Arc<RandomSeed>),
 }
 
+/// Keeps a user provided seed. Its address identifies random streams which belong to the same
+/// `DefaultRandom` instance.
+#[derive(Debug)]
+struct RandomSeed(u64);
+
 thread_local! {
     /// Random generator seeded from thread_rng to make runs non-repeatable.
     static RANDOMIZED_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_rng(thread_rng()).expect("cannot get RNG from thread rng"));
 
     /// Random generator seeded with 0 SmallRng to make runs repeatable.
     static REPEATABLE_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(0));
+
+    /// Random generators seeded from user provided seeds, one per living `DefaultRandom` instance.
+    static SEEDED_RNGS: RefCell<Vec<(Weak<RandomSeed>, SmallRng)>> = RefCell::new(Vec::default());
+
+    /// An identity of the thread used to derive its random stream from the seed.
+    static RANDOM_STREAM: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Sets an identity of the random stream for the current thread. It is expected to be called
+/// once when a thread pool starts its threads.
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+pub(crate) fn set_random_stream(pool_idx: usize, thread_idx: usize) {
+    RANDOM_STREAM.with(|stream| stream.set(Some(((pool_idx as u64 + 1) << 32) | thread_idx as u64)));
+}
+
+/// Returns an identity of the random stream for the current thread.
+fn get_random_stream() -> u64 {
+    RANDOM_STREAM.with(|stream| stream.get()).unwrap_or_else(|| {
+        // NOTE threads of the global thread pool are identified by their index, other threads share the same stream
+        rayon::current_thread_index().map_or(0, |thread_idx| (u32::MAX as u64) << 32 | thread_idx as u64)
+    })
+}
+
+/// Runs given action using random generator which belongs to the seed and the current thread.
+fn with_seeded_rng<R>(seed: &Arc<RandomSeed>, action: impl FnOnce(&mut SmallRng) -> R) -> R {
+    SEEDED_RNGS.with(|rngs| {
+        let mut rngs = rngs.borrow_mut();
+
+        let idx =
+            rngs.iter().position(|(owner, _)| std::ptr::eq(owner.as_ptr(), Arc::as_ptr(seed))).unwrap_or_else(|| {
+                // NOTE remove generators of dropped instances to keep the list short
+                rngs.retain(|(owner, _)| owner.strong_count() > 0);
+                let stream_seed = split_mix(split_mix(seed.0) ^ get_random_stream());
+                rngs.push((Arc::downgrade(seed), SmallRng::seed_from_u64(stream_seed)));
+
+                rngs.len() - 1
+            });
+
+        action(&mut rngs[idx].1)
+    })
+}
+
+/// A SplitMix64 mixing function used to derive well distributed stream seeds.
+fn split_mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
 }
 
 /// Provides underlying random generator API.
 #[derive(Clone, Debug)]
 pub struct RandomGen {
-    use_repeatable: bool,
+    mode: RandomMode,
 }
 
 impl RandomGen {
     /// Creates an instance of `RandomGen` using random generator with fixed seed.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { mode: RandomMode::Repeatable }
     }
 
     /// Creates an instance of `RandomGen` using random generator with randomized seed.
     pub fn new_randomized() -> Self {
-        Self { use_repeatable: false }
+        Self { mode: RandomMode::Randomized }
+    }
+
+    fn with_rng<R>(&self, action: impl FnOnce(&mut SmallRng) -> R) -> R {
+        // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
+        match &self.mode {
+            RandomMode::Randomized => RANDOMIZED_RNG.with(|t| action(&mut t.borrow_mut())),
+            RandomMode::Repeatable => REPEATABLE_RNG.with(|t| action(&mut t.borrow_mut())),
+            RandomMode::Seeded(seed) => with_seeded_rng(seed, action),
+        }
     }
 }
 
 impl RngCore for RandomGen {
     fn next_u32(&mut self) -> u32 {
-        // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u32())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u32())
-        }
+        self.with_rng(|rng| rng.next_u32())
     }
 
     fn next_u64(&mut self) -> u64 {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u64())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u64())
-        }
+        self.with_rng(|rng| rng.next_u64())
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.fill_bytes(dest))
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.try_fill_bytes(dest))
     }
 }
 
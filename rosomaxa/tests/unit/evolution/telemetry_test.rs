@@ -41,3 +41,21 @@ fn can_update_statistic() {
     telemetry.on_generation(objective, population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
 }
+
+#[test]
+fn can_report_gap_to_target_fitness() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let mut population = get_default_population(objective.clone(), environment, selection_size);
+    population.add(VectorSolution::new(vec![2., 2.], objective.clone()));
+    let mut telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 1 });
+    telemetry.set_target_fitness(vec![802.]);
+
+    telemetry.on_generation(objective.as_ref(), population.as_ref(), 0., Timer::start(), true);
+
+    let metrics = telemetry.take_metrics().expect("no metrics");
+    let individuals = &metrics.evolution.first().expect("no generation").population.individuals;
+    assert_eq!(individuals.len(), 1);
+    assert_eq!(individuals[0].gap, Some(50.));
+}
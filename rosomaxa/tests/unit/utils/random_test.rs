@@ -1,4 +1,5 @@
 use super::*;
+use crate::utils::ThreadPool;
 
 #[test]
 fn can_return_weights() {
@@ -20,3 +21,45 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+fn get_samples(random: &DefaultRandom) -> Vec<i32> {
+    (0..10).map(|_| random.uniform_int(0, 1000)).collect()
+}
+
+#[test]
+fn can_produce_same_values_with_same_seed() {
+    let first = DefaultRandom::new_with_seed(42);
+    let second = DefaultRandom::new_with_seed(42);
+
+    assert_eq!(get_samples(&first), get_samples(&second));
+    assert_eq!(first.get_seed(), Some(42));
+}
+
+#[test]
+fn can_produce_different_values_with_different_seeds() {
+    let first = DefaultRandom::new_with_seed(42);
+    let second = DefaultRandom::new_with_seed(7);
+
+    assert_ne!(get_samples(&first), get_samples(&second));
+}
+
+#[test]
+fn can_return_no_seed_when_it_is_not_specified() {
+    assert_eq!(DefaultRandom::default().get_seed(), None);
+    assert_eq!(DefaultRandom::new_repeatable().get_seed(), None);
+}
+
+#[test]
+fn can_derive_different_streams_for_thread_pools() {
+    let get_pool_samples = |pool_idx: usize| {
+        let random = Arc::new(DefaultRandom::new_with_seed(42));
+        ThreadPool::new_with_index(pool_idx, 1).execute(move || get_samples(random.as_ref()))
+    };
+
+    let first = get_pool_samples(0);
+    let second = get_pool_samples(1);
+
+    assert_ne!(first, second);
+    assert_eq!(first, get_pool_samples(0));
+    assert_ne!(first, get_samples(&DefaultRandom::new_with_seed(42)));
+}
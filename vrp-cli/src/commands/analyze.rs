@@ -3,7 +3,7 @@
 mod analyze_test;
 
 use super::*;
-use vrp_cli::extensions::analyze::get_clusters;
+use vrp_cli::extensions::analyze::{get_clusters, get_solution_diff};
 use vrp_core::utils::GenericError;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
@@ -12,50 +12,75 @@ const MATRIX_ARG_NAME: &str = "matrix";
 const MIN_POINTS_ARG_NAME: &str = "min-points";
 const EPSILON_ARG_NAME: &str = "epsilon";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const LEFT_SOLUTION_ARG_NAME: &str = "LEFT_SOLUTION";
+const RIGHT_SOLUTION_ARG_NAME: &str = "RIGHT_SOLUTION";
+const OUT_GEOJSON_ARG_NAME: &str = "out-geojson";
 
 pub fn get_analyze_app() -> Command {
-    Command::new("analyze").about("Provides helper functionality to analyze problem or solution").subcommand(
-        Command::new("clusters")
-            .about("Analyzes job clusters")
-            .arg(
-                Arg::new(FORMAT_ARG_NAME)
-                    .help("Specifies input type")
-                    .required(true)
-                    .value_parser(["pragmatic"])
-                    .index(1),
-            )
-            .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
-            .arg(
-                Arg::new(MIN_POINTS_ARG_NAME)
-                    .help("Minimum cluster size")
-                    .short('c')
-                    .default_value("3")
-                    .long(MIN_POINTS_ARG_NAME)
-                    .required(false),
-            )
-            .arg(
-                Arg::new(EPSILON_ARG_NAME)
-                    .help("Epsilon parameter in DBSCAN")
-                    .short('e')
-                    .long(EPSILON_ARG_NAME)
-                    .required(false),
-            )
-            .arg(
-                Arg::new(MATRIX_ARG_NAME)
-                    .help("Specifies path to file with routing matrix")
-                    .short('m')
-                    .long(MATRIX_ARG_NAME)
-                    .num_args(1..)
-                    .required(false),
-            )
-            .arg(
-                Arg::new(OUT_RESULT_ARG_NAME)
-                    .help("Specifies path to the file for result output")
-                    .short('o')
-                    .long(OUT_RESULT_ARG_NAME)
-                    .required(true),
-            ),
-    )
+    Command::new("analyze")
+        .about("Provides helper functionality to analyze problem or solution")
+        .subcommand(get_clusters_app())
+        .subcommand(get_diff_app())
+}
+
+fn get_clusters_app() -> Command {
+    Command::new("clusters")
+        .about("Analyzes job clusters")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(
+            Arg::new(MIN_POINTS_ARG_NAME)
+                .help("Minimum cluster size")
+                .short('c')
+                .default_value("3")
+                .long(MIN_POINTS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(EPSILON_ARG_NAME)
+                .help("Epsilon parameter in DBSCAN")
+                .short('e')
+                .long(EPSILON_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .num_args(1..)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(true),
+        )
+}
+
+fn get_diff_app() -> Command {
+    Command::new("diff")
+        .about("Compares two solutions of the same problem")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(Arg::new(LEFT_SOLUTION_ARG_NAME).help("Sets the original solution file").required(true).index(3))
+        .arg(Arg::new(RIGHT_SOLUTION_ARG_NAME).help("Sets the solution file to compare with").required(true).index(4))
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_GEOJSON_ARG_NAME)
+                .help("Specifies path to the file for geojson overlay of both solutions")
+                .short('g')
+                .long(OUT_GEOJSON_ARG_NAME)
+                .required(false),
+        )
 }
 
 pub fn run_analyze(
@@ -89,6 +114,37 @@ pub fn run_analyze(
 
             geo_writer.write_all(clusters.as_bytes()).map_err(|err| format!("cannot write result: '{err}'").into())
         }
+        Some(("diff", diff_matches)) => {
+            let problem_format = diff_matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
+
+            if problem_format != "pragmatic" {
+                return Err(format!("unknown problem format: '{problem_format}'").into());
+            }
+
+            let get_reader = |arg_name: &str, file_type: &str| {
+                BufReader::new(open_file(diff_matches.get_one::<String>(arg_name).unwrap(), file_type))
+            };
+            let out_geojson_path = diff_matches.get_one::<String>(OUT_GEOJSON_ARG_NAME);
+
+            let (diff, geojson) = get_solution_diff(
+                get_reader(PROBLEM_ARG_NAME, "problem"),
+                get_reader(LEFT_SOLUTION_ARG_NAME, "left solution"),
+                get_reader(RIGHT_SOLUTION_ARG_NAME, "right solution"),
+                out_geojson_path.is_some(),
+            )
+            .map_err(|err| GenericError::from(format!("cannot get solution diff: '{err}'")))?;
+
+            if let Some((path, geojson)) = out_geojson_path.zip(geojson) {
+                let mut geo_writer = out_writer_func(Some(create_file(path, "out geojson")));
+                geo_writer.write_all(geojson.as_bytes()).map_err(|err| format!("cannot write geojson: '{err}'"))?;
+            }
+
+            let out_result =
+                diff_matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_writer = out_writer_func(out_result);
+
+            out_writer.write_all(diff.as_bytes()).map_err(|err| format!("cannot write result: '{err}'").into())
+        }
         _ => Err("no argument with analyze subcommand was used. Use -h to print help information".into()),
     }
 }
@@ -4,7 +4,7 @@ mod import_test;
 
 use super::*;
 use std::io::BufReader;
-use vrp_cli::extensions::import::import_problem;
+use vrp_cli::extensions::import::{import_matrix, import_problem};
 use vrp_core::prelude::GenericError;
 use vrp_pragmatic::format::problem::serialize_problem;
 
@@ -14,8 +14,14 @@ pub const OUT_RESULT_ARG_NAME: &str = "out-result";
 
 pub fn get_import_app() -> Command {
     Command::new("import")
-        .about("Provides the way to import problem from various formats")
-        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["csv"]).index(1))
+        .about("Provides the way to import problem or routing matrix from various formats")
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .value_parser(["csv", "matrix"])
+                .index(1),
+        )
         .arg(
             Arg::new(INPUT_ARG_NAME)
                 .help("Sets input files which contains a VRP definition")
@@ -39,6 +45,10 @@ pub fn run_import(matches: &ArgMatches) -> Result<(), GenericError> {
         .get_many::<String>(INPUT_ARG_NAME)
         .map(|paths| paths.map(|path| BufReader::new(open_file(path, "input"))).collect::<Vec<_>>());
 
+    if input_format == "matrix" {
+        return run_import_matrix(matches, input_files);
+    }
+
     match import_problem(input_format, input_files) {
         Ok(problem) => {
             let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
@@ -49,3 +59,15 @@ pub fn run_import(matches: &ArgMatches) -> Result<(), GenericError> {
         Err(err) => Err(format!("cannot import problem: '{err}'").into()),
     }
 }
+
+fn run_import_matrix(matches: &ArgMatches, input_files: Option<Vec<BufReader<File>>>) -> Result<(), GenericError> {
+    match input_files {
+        Some(mut input_files) if input_files.len() == 1 => {
+            let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_buffer = create_write_buffer(out_result);
+            import_matrix(input_files.swap_remove(0), &mut out_buffer)
+                .map_err(|err| format!("cannot import matrix: '{err}'").into())
+        }
+        _ => Err("matrix format expects one file with routing matrix in json format as an input".into()),
+    }
+}
@@ -0,0 +1,583 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/serve_test.rs"]
+mod serve_test;
+
+use super::solve::create_cancellable_quota;
+use super::*;
+use clap::ArgAction;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::io::{BufRead, Read};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+use vrp_cli::extensions::solve::config::{create_builder_from_config_with_environment, Config, TelemetryConfig};
+use vrp_core::prelude::*;
+use vrp_core::utils::{InfoLogger, Timer};
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem as ApiProblem};
+use vrp_pragmatic::format::solution::{create_best_solution_callback, write_pragmatic, PragmaticOutputType};
+
+const ADDRESS_ARG_NAME: &str = "address";
+const ALLOW_REMOTE_ARG_NAME: &str = "allow-remote";
+const MAX_REQUEST_SIZE_ARG_NAME: &str = "max-request-size";
+const JOB_TTL_ARG_NAME: &str = "job-ttl";
+const MAX_CONNECTIONS_ARG_NAME: &str = "max-connections";
+const MAX_JOBS_ARG_NAME: &str = "max-jobs";
+const MAX_QUEUED_JOBS_ARG_NAME: &str = "max-queued-jobs";
+
+const MAX_HEADER_LINE_SIZE: usize = 8 * 1024;
+const MAX_HEADER_COUNT: usize = 100;
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_PURGE_INTERVAL: Duration = Duration::from_secs(60);
+
+pub fn get_serve_app() -> Command {
+    Command::new("serve")
+        .about("Runs a local server which exposes the solver via HTTP/JSON job API")
+        .arg(
+            Arg::new(ADDRESS_ARG_NAME)
+                .help("Specifies address to listen on")
+                .short('a')
+                .long(ADDRESS_ARG_NAME)
+                .required(false)
+                .default_value("127.0.0.1:8080"),
+        )
+        .arg(
+            Arg::new(ALLOW_REMOTE_ARG_NAME)
+                .help("Allows to listen on non-loopback address. The server has no authentication, use with care")
+                .long(ALLOW_REMOTE_ARG_NAME)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(MAX_REQUEST_SIZE_ARG_NAME)
+                .help("Specifies max size of request body in megabytes")
+                .long(MAX_REQUEST_SIZE_ARG_NAME)
+                .required(false)
+                .default_value("100"),
+        )
+        .arg(
+            Arg::new(JOB_TTL_ARG_NAME)
+                .help("Specifies time in seconds for which completed job is kept before it is removed")
+                .long(JOB_TTL_ARG_NAME)
+                .required(false)
+                .default_value("3600"),
+        )
+        .arg(
+            Arg::new(MAX_CONNECTIONS_ARG_NAME)
+                .help("Specifies max amount of connections handled concurrently, extra connections are rejected")
+                .long(MAX_CONNECTIONS_ARG_NAME)
+                .required(false)
+                .default_value("16"),
+        )
+        .arg(
+            Arg::new(MAX_JOBS_ARG_NAME)
+                .help("Specifies max amount of jobs solved concurrently")
+                .long(MAX_JOBS_ARG_NAME)
+                .required(false)
+                .default_value("1"),
+        )
+        .arg(
+            Arg::new(MAX_QUEUED_JOBS_ARG_NAME)
+                .help("Specifies max amount of jobs waiting to be solved, extra jobs are rejected")
+                .long(MAX_QUEUED_JOBS_ARG_NAME)
+                .required(false)
+                .default_value("16"),
+        )
+}
+
+pub fn run_serve(matches: &ArgMatches) -> Result<(), GenericError> {
+    let address = get_listen_address(matches)?;
+    let max_request_size =
+        parse_int_value::<usize>(matches, MAX_REQUEST_SIZE_ARG_NAME, "max request size")?.unwrap_or_default();
+    let job_ttl = parse_int_value::<u64>(matches, JOB_TTL_ARG_NAME, "job ttl")?.unwrap_or_default();
+    let max_connections =
+        parse_int_value::<usize>(matches, MAX_CONNECTIONS_ARG_NAME, "max connections")?.unwrap_or_default();
+    let max_jobs = parse_int_value::<usize>(matches, MAX_JOBS_ARG_NAME, "max jobs")?.unwrap_or_default();
+    let max_queued_jobs =
+        parse_int_value::<usize>(matches, MAX_QUEUED_JOBS_ARG_NAME, "max queued jobs")?.unwrap_or_default();
+
+    if max_connections == 0 || max_jobs == 0 {
+        return Err("max connections and max jobs should be greater than zero".into());
+    }
+
+    let listener =
+        TcpListener::bind(address.as_str()).map_err(|err| format!("cannot listen on '{address}': '{err}'"))?;
+    let local_address = listener.local_addr().map_err(|err| format!("cannot get local address: '{err}'"))?;
+
+    let mut out_buffer = create_write_buffer(None);
+    writeln!(out_buffer, "listening on {local_address}")
+        .and_then(|_| out_buffer.flush())
+        .map_err(|err| format!("cannot write to output: '{err}'"))?;
+
+    let limits = ServeLimits {
+        max_body_size: max_request_size.saturating_mul(1024 * 1024),
+        max_connections,
+        max_jobs,
+        max_queued_jobs,
+        job_ttl: Duration::from_secs(job_ttl),
+    };
+
+    serve(listener, limits);
+
+    Ok(())
+}
+
+/// Returns the address to listen on. Non-loopback address is accepted only when it is explicitly
+/// allowed as the server exposes solver to anyone who can reach it.
+fn get_listen_address(matches: &ArgMatches) -> Result<String, GenericError> {
+    let address = matches.get_one::<String>(ADDRESS_ARG_NAME).unwrap();
+    let is_loopback = address
+        .to_socket_addrs()
+        .map_err(|err| format!("cannot resolve address '{address}': '{err}'"))?
+        .all(|socket_addr| socket_addr.ip().is_loopback());
+
+    if !is_loopback {
+        if !matches.get_flag(ALLOW_REMOTE_ARG_NAME) {
+            return Err(format!(
+                "address '{address}' is not a loopback one, use --{ALLOW_REMOTE_ARG_NAME} to listen on it"
+            )
+            .into());
+        }
+
+        eprintln!("warning: listening on non-loopback address '{address}', the server has no authentication");
+    }
+
+    Ok(address.clone())
+}
+
+/// Specifies limits of the server.
+struct ServeLimits {
+    max_body_size: usize,
+    max_connections: usize,
+    max_jobs: usize,
+    max_queued_jobs: usize,
+    job_ttl: Duration,
+}
+
+/// Accepts incoming connections and passes them to a fixed amount of connection handlers through
+/// a bounded queue. Connections which do not fit into the queue are rejected.
+fn serve(listener: TcpListener, limits: ServeLimits) {
+    let registry =
+        Arc::new(JobRegistry::new(limits.job_ttl, start_job_workers(limits.max_jobs, limits.max_queued_jobs)));
+    start_purge_timer(registry.clone());
+
+    let (sender, receiver) = sync_channel::<TcpStream>(limits.max_connections);
+    let receiver = Arc::new(Mutex::new(receiver));
+    let max_body_size = limits.max_body_size;
+
+    (0..limits.max_connections).for_each(|_| {
+        let (registry, receiver) = (registry.clone(), receiver.clone());
+        thread::spawn(move || {
+            // NOTE lock is released right after the stream is received
+            while let Ok(stream) = { receiver.lock().unwrap().recv() } {
+                handle_connection(stream, registry.as_ref(), max_body_size);
+            }
+        });
+    });
+
+    listener.incoming().filter_map(|stream| stream.ok()).for_each(|stream| {
+        if let Err(TrySendError::Full(mut stream) | TrySendError::Disconnected(mut stream)) = sender.try_send(stream) {
+            let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+            let _ = HttpResponse::error(503, "too many connections").write(&mut stream);
+        }
+    });
+}
+
+/// Starts a fixed amount of solver workers which take submitted jobs from a bounded queue.
+fn start_job_workers(max_jobs: usize, max_queued_jobs: usize) -> SyncSender<PendingJob> {
+    let (sender, receiver) = sync_channel::<PendingJob>(max_queued_jobs);
+    let receiver = Arc::new(Mutex::new(receiver));
+
+    (0..max_jobs).for_each(|_| {
+        let receiver = receiver.clone();
+        thread::spawn(move || run_job_worker(receiver));
+    });
+
+    sender
+}
+
+/// Periodically removes expired jobs, so they are not kept in memory when nobody requests them.
+fn start_purge_timer(registry: Arc<JobRegistry>) {
+    let interval = (registry.ttl / 2).clamp(Duration::from_millis(100), MAX_PURGE_INTERVAL);
+
+    thread::spawn(move || loop {
+        thread::sleep(interval);
+        registry.purge();
+    });
+}
+
+/// A request to solve a pragmatic problem.
+#[derive(Deserialize)]
+struct JobRequest {
+    problem: ApiProblem,
+    matrices: Option<Vec<Matrix>>,
+    config: Option<Config>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum JobStatus {
+    Queued,
+    Running,
+    Finished,
+    Cancelled,
+    Failed,
+}
+
+struct Job {
+    status: JobStatus,
+    timer: Timer,
+    progress: Option<String>,
+    solution: Option<String>,
+    error: Option<String>,
+    should_interrupt: Arc<AtomicBool>,
+    completed: Option<Instant>,
+}
+
+/// A job status info returned to the client.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JobInfo {
+    id: String,
+    status: JobStatus,
+    elapsed: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    progress: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// A submitted job which waits in the queue to be solved.
+struct PendingJob {
+    problem: Arc<Problem>,
+    config: Config,
+    job: Arc<Mutex<Job>>,
+}
+
+/// Keeps submitted jobs and passes them to the solver queue. Completed jobs are removed when their
+/// time to live is elapsed.
+struct JobRegistry {
+    jobs: Mutex<HashMap<String, Arc<Mutex<Job>>>>,
+    queue: SyncSender<PendingJob>,
+    next_id: AtomicUsize,
+    ttl: Duration,
+}
+
+impl JobRegistry {
+    fn new(ttl: Duration, queue: SyncSender<PendingJob>) -> Self {
+        Self { jobs: Default::default(), queue, next_id: Default::default(), ttl }
+    }
+
+    /// Adds a job to the queue. Returns None if the queue is full.
+    fn add(&self, pending: PendingJob) -> Option<String> {
+        let job = pending.job.clone();
+        self.queue.try_send(pending).ok()?;
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed).to_string();
+        self.jobs.lock().unwrap().insert(id.clone(), job);
+
+        Some(id)
+    }
+
+    fn get(&self, id: &str) -> Option<Arc<Mutex<Job>>> {
+        let mut jobs = self.jobs.lock().unwrap();
+        self.remove_expired(&mut jobs);
+
+        jobs.get(id).cloned()
+    }
+
+    fn purge(&self) {
+        self.remove_expired(&mut self.jobs.lock().unwrap());
+    }
+
+    fn remove_expired(&self, jobs: &mut HashMap<String, Arc<Mutex<Job>>>) {
+        jobs.retain(|_, job| lock_job(job).completed.is_none_or(|completed| completed.elapsed() < self.ttl));
+    }
+}
+
+struct HttpRequest {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+struct HttpResponse {
+    status: u16,
+    body: String,
+}
+
+impl HttpResponse {
+    fn new(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::new(status, json!({ "error": message }).to_string())
+    }
+
+    fn write(&self, stream: &mut TcpStream) -> std::io::Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            202 => "Accepted",
+            400 => "Bad Request",
+            404 => "Not Found",
+            409 => "Conflict",
+            413 => "Payload Too Large",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        };
+
+        write!(
+            stream,
+            "HTTP/1.1 {} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.body.len(),
+            self.body
+        )?;
+
+        stream.flush()
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, registry: &JobRegistry, max_body_size: usize) {
+    // NOTE slow or stalled clients should not occupy connection handler forever
+    if stream.set_read_timeout(Some(IO_TIMEOUT)).and_then(|_| stream.set_write_timeout(Some(IO_TIMEOUT))).is_err() {
+        return;
+    }
+
+    let response = match read_request(&mut BufReader::new(&mut stream), max_body_size) {
+        Ok(request) => handle_request(&request, registry),
+        Err(response) => response,
+    };
+
+    // NOTE client might disconnect before response is sent, there is nothing to do in that case
+    let _ = response.write(&mut stream);
+}
+
+fn read_request<R: BufRead>(reader: &mut R, max_body_size: usize) -> Result<HttpRequest, HttpResponse> {
+    let bad_request = |msg: String| HttpResponse::error(400, &format!("cannot read request: '{msg}'"));
+
+    let mut line = String::new();
+    read_header_line(reader, &mut line)?;
+
+    let mut request_line = line.split_whitespace();
+    let (method, path) = match (request_line.next(), request_line.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => return Err(bad_request(format!("invalid request line: '{}'", line.trim()))),
+    };
+
+    let mut content_length = 0;
+    let mut header_count = 0;
+    loop {
+        line.clear();
+        if read_header_line(reader, &mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+
+        header_count += 1;
+        if header_count > MAX_HEADER_COUNT {
+            return Err(HttpResponse::error(431, &format!("request has more than {MAX_HEADER_COUNT} headers")));
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|err| bad_request(format!("invalid content length: {err}")))?;
+            }
+        }
+    }
+
+    // NOTE check the size before the body is allocated
+    if content_length > max_body_size {
+        return Err(HttpResponse::error(413, &format!("request body is larger than {max_body_size} bytes")));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(|err| bad_request(err.to_string()))?;
+
+    Ok(HttpRequest { method, path, body })
+}
+
+/// Reads a single request line or header limiting its size.
+fn read_header_line<R: BufRead>(reader: &mut R, line: &mut String) -> Result<usize, HttpResponse> {
+    let size = Read::take(reader, MAX_HEADER_LINE_SIZE as u64 + 1)
+        .read_line(line)
+        .map_err(|err| HttpResponse::error(400, &format!("cannot read request: '{err}'")))?;
+
+    if size > MAX_HEADER_LINE_SIZE {
+        return Err(HttpResponse::error(431, &format!("request line is longer than {MAX_HEADER_LINE_SIZE} bytes")));
+    }
+
+    Ok(size)
+}
+
+fn handle_request(request: &HttpRequest, registry: &JobRegistry) -> HttpResponse {
+    let segments = request.path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("POST", ["jobs"]) => submit_job(request.body.as_slice(), registry),
+        ("GET", ["jobs", id]) => with_job(registry, id, |job| {
+            let info = JobInfo {
+                id: id.to_string(),
+                status: job.status,
+                elapsed: job.timer.elapsed_secs_as_f64(),
+                progress: job.progress.clone(),
+                error: job.error.clone(),
+            };
+
+            HttpResponse::new(200, serde_json::to_string(&info).unwrap_or_default())
+        }),
+        ("GET", ["jobs", id, "solution"]) => with_job(registry, id, |job| match &job.solution {
+            Some(solution) => HttpResponse::new(200, solution.clone()),
+            None => HttpResponse::error(404, "solution is not available yet"),
+        }),
+        ("DELETE", ["jobs", id]) => with_job(registry, id, |job| {
+            if !matches!(job.status, JobStatus::Queued | JobStatus::Running) {
+                return HttpResponse::error(409, "job is not running");
+            }
+
+            job.should_interrupt.store(true, Ordering::Relaxed);
+
+            HttpResponse::new(202, json!({ "id": id }).to_string())
+        }),
+        _ => HttpResponse::error(404, "unknown endpoint"),
+    }
+}
+
+fn with_job<F>(registry: &JobRegistry, id: &str, func: F) -> HttpResponse
+where
+    F: FnOnce(&Job) -> HttpResponse,
+{
+    match registry.get(id) {
+        Some(job) => func(&lock_job(&job)),
+        None => HttpResponse::error(404, &format!("unknown job: '{id}'")),
+    }
+}
+
+fn submit_job(body: &[u8], registry: &JobRegistry) -> HttpResponse {
+    let JobRequest { problem, matrices, config } = match serde_json::from_slice(body) {
+        Ok(request) => request,
+        Err(err) => return HttpResponse::error(400, &format!("cannot deserialize request: '{err}'")),
+    };
+
+    let problem = match (problem, matrices).read_pragmatic() {
+        Ok(problem) => Arc::new(problem),
+        Err(errs) => return HttpResponse::new(400, errs.to_json()),
+    };
+
+    let mut config = config.unwrap_or_default();
+    config.telemetry.get_or_insert_with(TelemetryConfig::new_with_progress);
+
+    let job = Arc::new(Mutex::new(Job {
+        status: JobStatus::Queued,
+        timer: Timer::start(),
+        progress: None,
+        solution: None,
+        error: None,
+        should_interrupt: Arc::new(AtomicBool::new(false)),
+        completed: None,
+    }));
+
+    match registry.add(PendingJob { problem, config, job }) {
+        Some(id) => HttpResponse::new(202, json!({ "id": id }).to_string()),
+        None => HttpResponse::error(503, "too many queued jobs"),
+    }
+}
+
+/// Takes jobs from the queue and solves them one by one.
+fn run_job_worker(receiver: Arc<Mutex<Receiver<PendingJob>>>) {
+    // NOTE lock is released right after the job is received
+    while let Ok(PendingJob { problem, config, job }) = { receiver.lock().unwrap().recv() } {
+        let should_interrupt = {
+            let mut job = lock_job(&job);
+            job.status = JobStatus::Running;
+            job.should_interrupt.clone()
+        };
+
+        // NOTE job cancelled while it was in the queue is not solved at all
+        let result = if should_interrupt.load(Ordering::Relaxed) {
+            Err("job is cancelled before it is started".into())
+        } else {
+            // NOTE solver panic should not stop the worker, so it is reported as job failure
+            catch_unwind(AssertUnwindSafe(|| solve_job(problem, &config, job.clone(), should_interrupt.clone())))
+                .unwrap_or_else(|err| {
+                    let message = err
+                        .downcast_ref::<&str>()
+                        .cloned()
+                        .or_else(|| err.downcast_ref::<String>().map(|str| str.as_str()))
+                        .map(|msg| format!("solver panicked: '{msg}'"))
+                        .unwrap_or_else(|| "solver panicked with unknown error".to_string());
+
+                    Err(message.into())
+                })
+        };
+
+        let mut job = lock_job(&job);
+        match result {
+            Ok(solution) => {
+                job.status =
+                    if should_interrupt.load(Ordering::Relaxed) { JobStatus::Cancelled } else { JobStatus::Finished };
+                job.solution = Some(solution);
+            }
+            Err(_) if should_interrupt.load(Ordering::Relaxed) && job.solution.is_none() => {
+                job.status = JobStatus::Cancelled;
+            }
+            Err(err) => {
+                job.status = JobStatus::Failed;
+                job.error = Some(err.to_string());
+            }
+        }
+        job.completed = Some(Instant::now());
+    }
+}
+
+/// Locks the job ignoring poisoning: the job is still consistent when solver panics while holding the lock.
+fn lock_job(job: &Mutex<Job>) -> MutexGuard<'_, Job> {
+    job.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Solves the problem reusing config based solver setup. Solver's logger is redirected to
+/// job's progress, each new best known solution is kept as job's solution and its quota
+/// can be interrupted by the client.
+fn solve_job(
+    problem: Arc<Problem>,
+    config: &Config,
+    job: Arc<Mutex<Job>>,
+    should_interrupt: Arc<AtomicBool>,
+) -> Result<String, GenericError> {
+    let max_time = config.termination.as_ref().and_then(|termination| termination.max_time);
+    let logger: InfoLogger = Arc::new({
+        let job = job.clone();
+        move |msg: &str| lock_job(&job).progress = Some(msg.to_string())
+    });
+    let best_solution_callback = create_best_solution_callback(problem.clone(), move |solution| {
+        lock_job(&job).solution = serde_json::to_string(solution).ok();
+    });
+
+    let solution = create_builder_from_config_with_environment(problem.clone(), Default::default(), config, |env| {
+        Environment { quota: Some(create_cancellable_quota(max_time, should_interrupt)), logger, ..env }
+    })
+    .and_then(|builder| builder.build())
+    .map(|config| Solver::new(problem.clone(), config).with_best_solution_callback(best_solution_callback))
+    .and_then(|solver| solver.solve())?;
+
+    let output_type = if config.output.as_ref().and_then(|output| output.include_geojson).unwrap_or(false) {
+        PragmaticOutputType::Combined
+    } else {
+        Default::default()
+    };
+
+    let mut writer = BufWriter::new(Vec::new());
+    write_pragmatic(problem.as_ref(), &solution, output_type, &mut writer)?;
+
+    let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
+
+    String::from_utf8(bytes).map_err(|err| format!("{err}").into())
+}
@@ -9,23 +9,30 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use vrp_cli::core::solver::TargetHeuristic;
-use vrp_cli::extensions::solve::config::create_builder_from_config_file;
+use vrp_cli::extensions::analyze::create_solution_diff_for_jobs;
+use vrp_cli::extensions::solve::config::{create_builder_from_config, read_config, RandomConfig};
 use vrp_cli::get_locations_serialized;
 use vrp_cli::scientific::tsplib::{TsplibProblem, TsplibSolution};
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::GoalContext;
 use vrp_core::prelude::*;
 use vrp_core::rosomaxa::evolution::*;
+use vrp_core::rosomaxa::prelude::MultiObjective;
 use vrp_core::rosomaxa::{get_default_population, get_default_selection_size};
 use vrp_core::solver::*;
 use vrp_core::utils::*;
-use vrp_pragmatic::format::solution::{write_pragmatic, PragmaticOutputType};
+use vrp_pragmatic::construction::enablers::{JobTie, VehicleTie};
+use vrp_pragmatic::format::solution::{
+    create_best_solution_callback, deserialize_solution, write_pragmatic, write_pragmatic_solutions,
+    PragmaticOutputType, Solution as ApiSolution,
+};
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
 const MATRIX_ARG_NAME: &str = "matrix";
+const ROUTING_GRAPH_ARG_NAME: &str = "routing-graph";
 const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const MIN_CV_ARG_NAME: &str = "min-cv";
@@ -34,6 +41,9 @@ const GEO_JSON_ARG_NAME: &str = "geo-json";
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const INIT_SIZE_ARG_NAME: &str = "init-size";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const BEST_SOLUTIONS_ARG_NAME: &str = "best-solutions";
+const OUT_POPULATION_ARG_NAME: &str = "out-population";
+const OUT_CHANGES_ARG_NAME: &str = "out-changes";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 const CONFIG_ARG_NAME: &str = "config";
 const LOG_ARG_NAME: &str = "log";
@@ -43,11 +53,15 @@ const PARALLELISM_ARG_NAME: &str = "parallelism";
 const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
 const ROUNDED_ARG_NAME: &str = "round";
+const SEED_ARG_NAME: &str = "seed";
+const BKS_ARG_NAME: &str = "bks";
+const BKS_GAP_ARG_NAME: &str = "bks-gap";
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, GenericError>>);
 
-struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Result<Solution, GenericError>>);
+#[allow(clippy::type_complexity)]
+struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Result<Vec<Solution>, GenericError>>);
 
 #[allow(clippy::type_complexity)]
 struct SolutionWriter(
@@ -70,6 +84,7 @@ type FormatMap<'a> = HashMap<&'a str, (ProblemReader, InitSolutionReader, Soluti
 fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) {
     if cfg!(feature = "scientific-format") {
         use vrp_scientific::common::read_init_solution;
+        use vrp_scientific::darp::{DarpProblem, DarpSolution};
         use vrp_scientific::lilim::{LilimProblem, LilimSolution};
         use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
 
@@ -84,7 +99,9 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
                 })),
                 InitSolutionReader(Box::new({
                     let random = random.clone();
-                    move |file, problem| read_init_solution(BufReader::new(file), problem, random.clone())
+                    move |file, problem| {
+                        read_init_solution(BufReader::new(file), problem, random.clone()).map(|solution| vec![solution])
+                    }
                 })),
                 SolutionWriter(Box::new(|_, solution, mut writer, _| solution.write_solomon(&mut writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
@@ -97,11 +114,28 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_lilim(is_rounded)
                 })),
-                InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
+                InitSolutionReader(Box::new({
+                    let random = random.clone();
+                    move |file, problem| {
+                        read_init_solution(BufReader::new(file), problem, random.clone()).map(|solution| vec![solution])
+                    }
+                })),
                 SolutionWriter(Box::new(|_, solution, mut writer, _| solution.write_lilim(&mut writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
+        formats.insert(
+            "darp",
+            (
+                ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
+                    assert!(matrices.is_none());
+                    BufReader::new(problem).read_darp(is_rounded)
+                })),
+                InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
+                SolutionWriter(Box::new(|_, solution, mut writer, _| solution.write_darp(&mut writer))),
+                LocationWriter(Box::new(|_, _| unimplemented!())),
+            ),
+        );
         formats.insert(
             "tsplib",
             (
@@ -110,7 +144,7 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
                     BufReader::new(problem).read_tsplib(is_rounded)
                 })),
                 InitSolutionReader(Box::new(move |file, problem| {
-                    read_init_solution(BufReader::new(file), problem, random.clone())
+                    read_init_solution(BufReader::new(file), problem, random.clone()).map(|solution| vec![solution])
                 })),
                 SolutionWriter(Box::new(|_, solution, mut writer, _| solution.write_tsplib(&mut writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
@@ -119,21 +153,29 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
     }
 }
 
-fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>) {
+fn add_pragmatic(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) {
+    use vrp_cli::extensions::import::read_pragmatic_with_matrix_files;
     use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
-    use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
+    use vrp_pragmatic::format::solution::read_init_solutions as read_init_pragmatic;
+
+    let routing_graph = matches.get_one::<String>(ROUTING_GRAPH_ARG_NAME).cloned();
 
     formats.insert(
         "pragmatic",
         (
-            ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                if let Some(matrices) = matrices {
-                    let matrices = matrices.into_iter().map(BufReader::new).collect();
-                    (BufReader::new(problem), matrices).read_pragmatic()
-                } else {
-                    BufReader::new(problem).read_pragmatic()
+            ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
+                match (matrices, routing_graph.as_ref()) {
+                    (Some(matrices), _) => {
+                        // NOTE matrix files are memory-mapped and their format is detected automatically
+                        let problem = deserialize_problem(BufReader::new(problem))?;
+                        read_pragmatic_with_matrix_files(problem, matrices.as_slice()).map_err(|errs| errs.into())
+                    }
+                    (None, Some(routing_graph)) => {
+                        let problem = deserialize_problem(BufReader::new(problem))?;
+                        read_pragmatic_with_road_graph(problem, routing_graph)
+                    }
+                    (None, None) => BufReader::new(problem).read_pragmatic().map_err(|errs| errs.into()),
                 }
-                .map_err(|errs| errs.into())
             })),
             InitSolutionReader(Box::new(move |file, problem| {
                 read_init_pragmatic(BufReader::new(file), problem, random.clone())
@@ -160,7 +202,7 @@ fn get_formats<'a>(matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>)
     let mut formats = FormatMap::default();
 
     add_scientific(&mut formats, matches, random.clone());
-    add_pragmatic(&mut formats, random);
+    add_pragmatic(&mut formats, matches, random);
 
     formats
 }
@@ -172,7 +214,7 @@ pub fn get_solve_app() -> Command {
             Arg::new(FORMAT_ARG_NAME)
                 .help("Specifies the problem type")
                 .required(true)
-                .value_parser(["solomon", "lilim", "tsplib", "pragmatic"])
+                .value_parser(["solomon", "lilim", "darp", "tsplib", "pragmatic"])
                 .index(1),
         )
         .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
@@ -201,7 +243,8 @@ pub fn get_solve_app() -> Command {
         )
         .arg(
             Arg::new(INIT_SOLUTION_ARG_NAME)
-                .help("Specifies path to file with initial solution")
+                .help("Specifies path to file with initial solution. For pragmatic format, the file can contain \
+                       multiple solutions as newline-delimited json, e.g. a population of the previous run.")
                 .short('i')
                 .long(INIT_SOLUTION_ARG_NAME)
                 .required(false)
@@ -227,6 +270,27 @@ pub fn get_solve_app() -> Command {
                 .long(OUT_RESULT_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(BEST_SOLUTIONS_ARG_NAME)
+                .help("Specifies path to file for writing each new best known solution as newline-delimited json, \
+                       use '-' for std out. Applicable only for pragmatic format.")
+                .long(BEST_SOLUTIONS_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(OUT_POPULATION_ARG_NAME)
+                .help("Specifies path to file for writing solutions of the final population as newline-delimited json, \
+                       they can be passed as initial solutions to the next run. Applicable only for pragmatic format.")
+                .long(OUT_POPULATION_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(OUT_CHANGES_ARG_NAME)
+                .help("Specifies path to file for writing only changes of the result solution comparing to the first \
+                       initial solution. Applicable only for pragmatic format.")
+                .long(OUT_CHANGES_ARG_NAME)
+                .required(false)
+        )
         .arg(
             Arg::new(GET_LOCATIONS_ARG_NAME)
                 .help("Returns list of unique locations")
@@ -255,6 +319,14 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(ROUTING_GRAPH_ARG_NAME)
+                .help("Specifies path to file with road network graph used to calculate routing matrices \
+                       and route geometries in geojson output. Applicable only for pragmatic format.")
+                .long(ROUTING_GRAPH_ARG_NAME)
+                .required(false)
+                .conflicts_with(MATRIX_ARG_NAME)
+        )
         .arg(
             Arg::new(CHECK_ARG_NAME)
                 .help("Specifies whether final solution should be checked for feasibility")
@@ -301,6 +373,26 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(SEED_ARG_NAME)
+                .help("Specifies a seed for random generators to make the run reproducible. Overrides the config value.")
+                .long(SEED_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(BKS_ARG_NAME)
+                .help("Specifies the best known solution: either a path to solution file in the problem format or \
+                       comma separated fitness values. It is used to report a gap in telemetry and to stop search \
+                       once the gap is small enough.")
+                .long(BKS_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(BKS_GAP_ARG_NAME)
+                .help("Specifies a gap (in percent) to the best known solution at which search is stopped. Default is 0.01")
+                .long(BKS_GAP_ARG_NAME)
+                .required(false)
+        )
 }
 
 /// Runs solver commands.
@@ -344,14 +436,32 @@ pub fn run_solve(
                         let init_solutions = init_solution
                             .map(|file| read_init_solution(problem.clone(), environment.clone(), file, init_reader))
                             .unwrap_or_else(|| Ok(Vec::default()))?;
+                        let target_proximity =
+                            get_target_proximity(matches, problem.clone(), environment.clone(), init_reader)?;
+                        let previous_solution = get_previous_solution(&init_solutions, matches);
 
                         let solver = if let Some(config) = config {
-                            from_config_parameters(problem.clone(), init_solutions, config)?
+                            let seed = parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed")?;
+                            from_config_parameters(problem.clone(), init_solutions, config, seed, target_proximity)?
                         } else {
-                            from_cli_parameters(problem.clone(), environment, init_solutions, matches)?
+                            from_cli_parameters(
+                                problem.clone(),
+                                environment,
+                                init_solutions,
+                                target_proximity,
+                                matches,
+                            )?
                         };
+                        let solver = with_best_solutions_writer(solver, problem.clone(), problem_format, matches)?;
 
-                        let solution = solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?;
+                        let solutions =
+                            solver.solve_all().map_err(|err| format!("cannot find any solution: '{err}'"))?;
+
+                        write_population(&problem, &solutions, problem_format, matches)?;
+
+                        let solution = solutions.into_iter().next().expect("no solutions in population");
+
+                        write_changes(&problem, previous_solution.as_ref(), &solution, problem_format, matches)?;
 
                         solution_writer(&problem, solution, out_buffer, geo_buffer).unwrap();
 
@@ -378,19 +488,159 @@ fn read_init_solution(
     file: File,
     InitSolutionReader(init_reader): &InitSolutionReader,
 ) -> Result<Vec<InsertionContext>, GenericError> {
-    init_reader(file, problem.clone())
-        .map_err(|err| format!("cannot read initial solution '{err}'").into())
-        .map(|solution| vec![InsertionContext::new_from_solution(problem.clone(), (solution, None), environment)])
+    init_reader(file, problem.clone()).map_err(|err| format!("cannot read initial solution '{err}'").into()).map(
+        |solutions| {
+            solutions
+                .into_iter()
+                .map(|solution| {
+                    InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone())
+                })
+                .collect()
+        },
+    )
+}
+
+fn get_target_proximity(
+    matches: &ArgMatches,
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    init_reader: &InitSolutionReader,
+) -> Result<Option<(Vec<f64>, f64)>, GenericError> {
+    let Some(bks) = matches.get_one::<String>(BKS_ARG_NAME) else { return Ok(None) };
+
+    let target_fitness = match bks.split(',').map(|value| value.trim().parse::<f64>()).collect::<Result<Vec<_>, _>>() {
+        Ok(fitness) => fitness,
+        Err(_) => read_init_solution(problem.clone(), environment, open_file(bks, "best known solution"), init_reader)?
+            .first()
+            .map(|insertion_ctx| problem.goal.fitness(insertion_ctx).collect())
+            .ok_or_else(|| GenericError::from("no best known solution found"))?,
+    };
+
+    if target_fitness.len() != problem.goal.size() {
+        return Err(format!(
+            "best known solution fitness has {} values, but the goal has {} objectives",
+            target_fitness.len(),
+            problem.goal.size()
+        )
+        .into());
+    }
+
+    let gap = parse_float_value::<f64>(matches, BKS_GAP_ARG_NAME, "best known solution gap")?.unwrap_or(0.01);
+
+    Ok(Some((target_fitness, gap / 100.)))
+}
+
+fn write_population(
+    problem: &Problem,
+    solutions: &[Solution],
+    problem_format: &str,
+    matches: &ArgMatches,
+) -> Result<(), GenericError> {
+    let Some(path) = matches.get_one::<String>(OUT_POPULATION_ARG_NAME) else { return Ok(()) };
+
+    if problem_format != "pragmatic" {
+        return Err(format!("writing population is not supported for '{problem_format}' format").into());
+    }
+
+    let mut writer = BufWriter::new(create_file(path, "out population"));
+
+    write_pragmatic_solutions(problem, solutions, &mut writer)
+}
+
+/// Returns a copy of the first initial solution when changes are requested as it is used as the previous plan.
+fn get_previous_solution(init_solutions: &[InsertionContext], matches: &ArgMatches) -> Option<Solution> {
+    matches
+        .get_one::<String>(OUT_CHANGES_ARG_NAME)
+        .and_then(|_| init_solutions.first())
+        .map(|insertion_ctx| insertion_ctx.solution.deep_copy().into())
+}
+
+fn write_changes(
+    problem: &Problem,
+    previous_solution: Option<&Solution>,
+    solution: &Solution,
+    problem_format: &str,
+    matches: &ArgMatches,
+) -> Result<(), GenericError> {
+    let Some(path) = matches.get_one::<String>(OUT_CHANGES_ARG_NAME) else { return Ok(()) };
+
+    if problem_format != "pragmatic" {
+        return Err(format!("writing changes is not supported for '{problem_format}' format").into());
+    }
+
+    let previous_solution =
+        previous_solution.ok_or_else(|| GenericError::from("writing changes requires initial solution"))?;
+
+    // NOTE conditional jobs, such as breaks or reloads, are bound to the vehicle and not a part of the plan
+    let job_ids = problem
+        .jobs
+        .all()
+        .filter(|job| job.dimens().get_vehicle_id().is_none())
+        .filter_map(|job| job.dimens().get_job_id().cloned())
+        .collect::<Vec<_>>();
+    let left = get_api_solution(problem, previous_solution)?;
+    let right = get_api_solution(problem, solution)?;
+
+    let diff = create_solution_diff_for_jobs(job_ids.iter(), &left, &right);
+
+    serde_json::to_writer_pretty(BufWriter::new(create_file(path, "out changes")), &diff)
+        .map_err(|err| format!("cannot write changes: {err}").into())
+}
+
+fn get_api_solution(problem: &Problem, solution: &Solution) -> Result<ApiSolution, GenericError> {
+    let mut buffer = BufWriter::new(Vec::new());
+    write_pragmatic(problem, solution, PragmaticOutputType::OnlyPragmatic, &mut buffer)?;
+    let bytes = buffer.into_inner().map_err(|err| format!("{err}"))?;
+
+    deserialize_solution(BufReader::new(bytes.as_slice())).map_err(|err| format!("{err}").into())
+}
+
+fn with_best_solutions_writer(
+    solver: Solver,
+    problem: Arc<Problem>,
+    problem_format: &str,
+    matches: &ArgMatches,
+) -> Result<Solver, GenericError> {
+    let Some(path) = matches.get_one::<String>(BEST_SOLUTIONS_ARG_NAME) else { return Ok(solver) };
+
+    if problem_format != "pragmatic" {
+        return Err(format!("writing best solutions is not supported for '{problem_format}' format").into());
+    }
+
+    let writer: Box<dyn Write + Send> =
+        if path == "-" { Box::new(std::io::stdout()) } else { Box::new(create_file(path, "best solutions")) };
+    let writer = Mutex::new(BufWriter::new(writer));
+
+    Ok(solver.with_best_solution_callback(create_best_solution_callback(problem, move |solution| {
+        let mut writer = writer.lock().unwrap();
+        // NOTE write errors should not stop the search
+        let _ = serde_json::to_writer(&mut *writer, solution)
+            .map_err(std::io::Error::from)
+            .and_then(|_| writeln!(writer))
+            .and_then(|_| writer.flush());
+    })))
 }
 
 fn from_config_parameters(
     problem: Arc<Problem>,
     init_solutions: Vec<InsertionContext>,
     config: File,
+    seed: Option<u64>,
+    target_proximity: Option<(Vec<f64>, f64)>,
 ) -> Result<Solver, GenericError> {
-    create_builder_from_config_file(problem.clone(), init_solutions, BufReader::new(config))
-        .and_then(|builder| builder.build())
-        .map(|config| Solver::new(problem.clone(), config))
+    let target_fitness = target_proximity.as_ref().map(|(target_fitness, _)| target_fitness.clone());
+
+    read_config(BufReader::new(config))
+        .and_then(|mut config| {
+            if let Some(seed) = seed {
+                config.environment.get_or_insert_with(Default::default).random =
+                    Some(RandomConfig { seed: Some(seed) });
+            }
+
+            create_builder_from_config(problem.clone(), init_solutions, &config)
+        })
+        .and_then(|builder| builder.with_target_proximity(target_proximity).build())
+        .map(|config| with_target_fitness(Solver::new(problem.clone(), config), target_fitness))
         .map_err(|err| format!("cannot read config: '{err}'").into())
 }
 
@@ -398,8 +648,10 @@ fn from_cli_parameters(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
     init_solutions: Vec<InsertionContext>,
+    target_proximity: Option<(Vec<f64>, f64)>,
     matches: &ArgMatches,
 ) -> Result<Solver, GenericError> {
+    let target_fitness = target_proximity.as_ref().map(|(target_fitness, _)| target_fitness.clone());
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
 
     let max_generations = parse_int_value::<usize>(matches, GENERATIONS_ARG_NAME, "max generations")?;
@@ -417,6 +669,7 @@ fn from_cli_parameters(
         .with_max_generations(max_generations)
         .with_max_time(max_time)
         .with_min_cv(min_cv, "min_cv".to_string())
+        .with_target_proximity(target_proximity)
         .with_context(RefinementContext::new(
             problem.clone(),
             get_population(mode, problem.goal.clone(), environment.clone()),
@@ -431,7 +684,14 @@ fn from_cli_parameters(
     }
     .build()?;
 
-    Ok(Solver::new(problem.clone(), config))
+    Ok(with_target_fitness(Solver::new(problem.clone(), config), target_fitness))
+}
+
+fn with_target_fitness(solver: Solver, target_fitness: Option<Vec<f64>>) -> Solver {
+    match target_fitness {
+        Some(target_fitness) => solver.with_target_fitness(target_fitness),
+        None => solver,
+    }
 }
 
 fn get_min_cv(matches: &ArgMatches) -> Result<Option<(String, usize, f64, bool)>, GenericError> {
@@ -472,6 +732,10 @@ fn get_environment(matches: &ArgMatches) -> Result<Arc<Environment>, GenericErro
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
     let quota = Some(create_interruption_quota(max_time));
     let is_experimental = matches.get_one::<bool>(EXPERIMENTAL_ARG_NAME).copied().unwrap_or(false);
+    let random: Arc<dyn Random + Send + Sync> = match parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed")? {
+        Some(seed) => Arc::new(DefaultRandom::new_with_seed(seed)),
+        None => Arc::new(DefaultRandom::default()),
+    };
 
     matches
         .get_one::<String>(PARALLELISM_ARG_NAME)
@@ -485,18 +749,12 @@ fn get_environment(matches: &ArgMatches) -> Result<Arc<Environment>, GenericErro
                 } else {
                     Arc::new(|_: &str| {})
                 };
-                Ok(Arc::new(Environment::new(
-                    Arc::new(DefaultRandom::default()),
-                    quota.clone(),
-                    parallelism,
-                    logger,
-                    is_experimental,
-                )))
+                Ok(Arc::new(Environment::new(random.clone(), quota.clone(), parallelism, logger, is_experimental)))
             } else {
                 Err("cannot parse parallelism parameter".into())
             }
         })
-        .unwrap_or_else(|| Ok(Arc::new(Environment { quota, is_experimental, ..Environment::default() })))
+        .unwrap_or_else(|| Ok(Arc::new(Environment { random, quota, is_experimental, ..Environment::default() })))
 }
 
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
@@ -564,12 +822,86 @@ fn get_async_evolution(
     unreachable!()
 }
 
+#[cfg(feature = "road-routing")]
+fn read_pragmatic_with_road_graph(
+    problem: vrp_pragmatic::format::problem::Problem,
+    routing_graph: &str,
+) -> Result<Problem, GenericError> {
+    use vrp_cli::extensions::solve::routing::read_pragmatic_with_road_graph;
+    use vrp_cli::routing::graph::read_road_graph;
+
+    let graph = read_road_graph(BufReader::new(open_file(routing_graph, "routing graph")))?;
+
+    read_pragmatic_with_road_graph(problem, Arc::new(graph))
+}
+
+#[cfg(not(feature = "road-routing"))]
+fn read_pragmatic_with_road_graph(
+    _problem: vrp_pragmatic::format::problem::Problem,
+    _routing_graph: &str,
+) -> Result<Problem, GenericError> {
+    Err("road routing is not supported: build with road-routing feature".into())
+}
+
 fn check_pragmatic_solution_with_args(matches: &ArgMatches) -> Result<(), GenericError> {
-    check_solution(matches, "pragmatic", PROBLEM_ARG_NAME, OUT_RESULT_ARG_NAME, MATRIX_ARG_NAME)
+    match matches.get_one::<String>(ROUTING_GRAPH_ARG_NAME) {
+        Some(routing_graph) => check_pragmatic_solution_with_road_graph(matches, routing_graph),
+        None => check_solution(matches, "pragmatic", PROBLEM_ARG_NAME, OUT_RESULT_ARG_NAME, MATRIX_ARG_NAME),
+    }
+}
+
+#[cfg(feature = "road-routing")]
+fn check_pragmatic_solution_with_road_graph(matches: &ArgMatches, routing_graph: &str) -> Result<(), GenericError> {
+    use vrp_cli::extensions::check::check_pragmatic_solution_with_matrices;
+    use vrp_cli::extensions::solve::routing::create_road_matrices;
+    use vrp_cli::routing::graph::read_road_graph;
+    use vrp_pragmatic::format::problem::deserialize_problem;
+    use vrp_pragmatic::format::solution::deserialize_solution;
+
+    let problem_path = matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap();
+    let solution_path = matches
+        .get_one::<String>(OUT_RESULT_ARG_NAME)
+        .ok_or_else(|| GenericError::from("check with routing graph requires solution to be written in a file"))?;
+
+    let problem = deserialize_problem(BufReader::new(open_file(problem_path, "problem")))
+        .map_err(|errs| format!("cannot read problem: '{errs}'"))?;
+    let solution = deserialize_solution(BufReader::new(open_file(solution_path, "solution")))
+        .map_err(|err| format!("cannot read solution: '{err}'"))?;
+
+    // NOTE checker uses the same routing matrices as solver, so they are created from road graph again
+    let graph = read_road_graph(BufReader::new(open_file(routing_graph, "routing graph")))?;
+    let matrices = create_road_matrices(&problem, &graph)?;
+
+    check_pragmatic_solution_with_matrices(problem, solution, Some(matrices)).map_err(|errs| {
+        format!("checker found {} errors:\n{}", errs.len(), GenericError::join_many(&errs, "\n")).into()
+    })
+}
+
+#[cfg(not(feature = "road-routing"))]
+fn check_pragmatic_solution_with_road_graph(_matches: &ArgMatches, _routing_graph: &str) -> Result<(), GenericError> {
+    Err("road routing is not supported: build with road-routing feature".into())
 }
 
 /// Creates interruption quota.
 pub fn create_interruption_quota(max_time: Option<usize>) -> Arc<dyn Quota + Send + Sync> {
+    let should_interrupt = Arc::new(AtomicBool::new(false));
+
+    // NOTE ignore error which happens in unit tests
+    let _ = ctrlc::set_handler({
+        let should_interrupt = should_interrupt.clone();
+        move || {
+            should_interrupt.store(true, Ordering::Relaxed);
+        }
+    });
+
+    create_cancellable_quota(max_time, should_interrupt)
+}
+
+/// Creates a quota which is reached when max time is elapsed or interruption flag is set.
+pub fn create_cancellable_quota(
+    max_time: Option<usize>,
+    should_interrupt: Arc<AtomicBool>,
+) -> Arc<dyn Quota + Send + Sync> {
     struct InterruptionQuota {
         inner: Option<Arc<dyn Quota + Send + Sync>>,
         should_interrupt: Arc<AtomicBool>,
@@ -577,21 +909,11 @@ pub fn create_interruption_quota(max_time: Option<usize>) -> Arc<dyn Quota + Sen
 
     impl Quota for InterruptionQuota {
         fn is_reached(&self) -> bool {
-            self.inner.as_ref().map_or(false, |inner| inner.is_reached())
-                || self.should_interrupt.load(Ordering::Relaxed)
+            self.inner.as_ref().is_some_and(|inner| inner.is_reached()) || self.should_interrupt.load(Ordering::Relaxed)
         }
     }
 
     let inner = max_time.map::<Arc<dyn Quota + Send + Sync>, _>(|time| Arc::new(TimeQuota::new(time as f64)));
-    let should_interrupt = Arc::new(AtomicBool::new(false));
-
-    // NOTE ignore error which happens in unit tests
-    let _ = ctrlc::set_handler({
-        let should_interrupt = should_interrupt.clone();
-        move || {
-            should_interrupt.store(true, Ordering::Relaxed);
-        }
-    });
 
     Arc::new(InterruptionQuota { inner, should_interrupt })
 }
@@ -2,6 +2,7 @@
 #[path = "../../../tests/unit/extensions/analyze/clusters_test.rs"]
 mod clusters_test;
 
+use crate::extensions::import::read_matrix;
 use std::io::{BufReader, BufWriter, Read};
 use std::sync::Arc;
 use vrp_core::construction::clustering::dbscan::create_job_clusters;
@@ -10,7 +11,7 @@ use vrp_core::models::Problem;
 use vrp_core::prelude::GenericError;
 use vrp_core::utils::Environment;
 use vrp_pragmatic::construction::enablers::JobTie;
-use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
+use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
 use vrp_pragmatic::format::solution::serialize_named_locations_as_geojson;
 use vrp_pragmatic::format::{get_coord_index, MultiFormatError};
 
@@ -63,7 +64,7 @@ fn get_core_problem<F: Read>(
     let problem = deserialize_problem(problem_reader)?;
 
     let matrices = matrices_readers.map(|matrices| {
-        matrices.into_iter().map(|file| deserialize_matrix(BufReader::new(file))).collect::<Result<Vec<_>, _>>()
+        matrices.into_iter().map(|file| read_matrix(BufReader::new(file))).collect::<Result<Vec<_>, _>>()
     });
 
     let matrices = if let Some(matrices) = matrices { Some(matrices?) } else { None };
@@ -0,0 +1,258 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/diff_test.rs"]
+mod diff_test;
+
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{BufReader, BufWriter, Read};
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::problem::{deserialize_problem, Job, Problem};
+use vrp_pragmatic::format::solution::{
+    deserialize_solution, serialize_solutions_overlay_as_geojson, Solution, Statistic, Tour,
+};
+use vrp_pragmatic::format::Location;
+
+/// Represents a difference between two solutions of the same problem.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDiff {
+    /// A difference in total statistic.
+    pub statistic: StatisticDiff,
+    /// Tours which are present only in one of the solutions.
+    pub tours: ToursDiff,
+    /// Jobs which have different vehicle assignment or position in the tour.
+    pub jobs: Vec<JobDiff>,
+}
+
+/// Represents a difference in statistic: all deltas are calculated as right minus left.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatisticDiff {
+    /// Total cost delta.
+    pub cost: f64,
+    /// Total distance delta.
+    pub distance: i64,
+    /// Total duration delta.
+    pub duration: i64,
+    /// Driving time delta.
+    pub driving: i64,
+    /// Serving time delta.
+    pub serving: i64,
+    /// Waiting time delta.
+    pub waiting: i64,
+    /// Break time delta.
+    #[serde(rename = "break")]
+    pub break_time: i64,
+}
+
+/// Represents tours which appeared or disappeared in the right solution.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToursDiff {
+    /// Tours which are present only in the right solution.
+    pub added: Vec<TourKey>,
+    /// Tours which are present only in the left solution.
+    pub removed: Vec<TourKey>,
+}
+
+/// Identifies a tour by vehicle id and shift index.
+#[derive(Clone, Debug, Serialize, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub struct TourKey {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+}
+
+/// Represents a job assignment in the solution.
+#[derive(Clone, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JobAssignment {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// An index of the job's first activity among job activities of the tour.
+    /// Departure, arrival, breaks, reloads and other vehicle activities are not counted.
+    pub position: usize,
+}
+
+/// Represents a job with changed assignment. Missing assignment means that the job is unassigned.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobDiff {
+    /// Job id.
+    pub job_id: String,
+    /// Job assignment in the left solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left: Option<JobAssignment>,
+    /// Job assignment in the right solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub right: Option<JobAssignment>,
+    /// True if job is served by another vehicle (or shift) in the right solution.
+    pub is_vehicle_changed: bool,
+    /// A position shift in the tour of the same vehicle: right minus left.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_shift: Option<i64>,
+}
+
+/// Compares two pragmatic solutions of the same problem. Returns the difference serialized as json
+/// and, optionally, as geojson overlay of both solutions with highlighted changed jobs.
+pub fn get_solution_diff<F: Read>(
+    problem_reader: BufReader<F>,
+    left_reader: BufReader<F>,
+    right_reader: BufReader<F>,
+    with_geojson: bool,
+) -> Result<(String, Option<String>), GenericError> {
+    let problem = deserialize_problem(problem_reader).map_err(|errs| format!("cannot read problem: {errs}"))?;
+    let left = deserialize_solution(left_reader).map_err(|err| format!("cannot read left solution: {err}"))?;
+    let right = deserialize_solution(right_reader).map_err(|err| format!("cannot read right solution: {err}"))?;
+
+    let diff = create_solution_diff(&problem, &left, &right);
+    let diff_json = serde_json::to_string_pretty(&diff).map_err(|err| format!("cannot write diff: {err}"))?;
+
+    let geojson = if with_geojson {
+        let locations = get_changed_job_locations(&problem, &diff);
+        let mut writer = BufWriter::new(Vec::new());
+
+        serialize_solutions_overlay_as_geojson(&[("left", &left), ("right", &right)], &locations, &mut writer)
+            .map_err(|err| format!("cannot write diff as geojson: '{err}'"))?;
+
+        let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
+        Some(String::from_utf8(bytes).map_err(|err| format!("{err}"))?)
+    } else {
+        None
+    };
+
+    Ok((diff_json, geojson))
+}
+
+/// Creates a difference between left and right solutions of the given problem.
+pub fn create_solution_diff(problem: &Problem, left: &Solution, right: &Solution) -> SolutionDiff {
+    // NOTE jobs completed according to the problem state are not a part of the plan anymore
+    let completed = problem
+        .state
+        .iter()
+        .flat_map(|state| state.vehicles.iter())
+        .flat_map(|vehicle| vehicle.completed.iter().flatten())
+        .collect::<BTreeSet<_>>();
+
+    let job_ids = problem.plan.jobs.iter().map(|job| &job.id).filter(|job_id| !completed.contains(job_id));
+
+    create_solution_diff_for_jobs(job_ids, left, right)
+}
+
+/// Creates a difference between left and right solutions considering only the given jobs.
+pub fn create_solution_diff_for_jobs<'a>(
+    job_ids: impl Iterator<Item = &'a String>,
+    left: &Solution,
+    right: &Solution,
+) -> SolutionDiff {
+    let job_ids = job_ids.collect::<Vec<_>>();
+    let job_set = job_ids.iter().copied().collect::<BTreeSet<_>>();
+    let (left_jobs, right_jobs) = (get_job_assignments(left, &job_set), get_job_assignments(right, &job_set));
+    let (left_tours, right_tours) = (get_tour_keys(left), get_tour_keys(right));
+
+    let jobs = job_ids
+        .into_iter()
+        .filter_map(|job_id| {
+            let left = left_jobs.get(job_id).cloned();
+            let right = right_jobs.get(job_id).cloned();
+
+            let is_vehicle_changed = match (&left, &right) {
+                (Some(left), Some(right)) => {
+                    left.vehicle_id != right.vehicle_id || left.shift_index != right.shift_index
+                }
+                (None, None) => false,
+                _ => true,
+            };
+
+            let position_shift = match (&left, &right) {
+                (Some(left), Some(right)) if !is_vehicle_changed && left.position != right.position => {
+                    Some(right.position as i64 - left.position as i64)
+                }
+                _ => None,
+            };
+
+            if is_vehicle_changed || position_shift.is_some() {
+                Some(JobDiff { job_id: job_id.clone(), left, right, is_vehicle_changed, position_shift })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    SolutionDiff {
+        statistic: get_statistic_diff(&left.statistic, &right.statistic),
+        tours: ToursDiff {
+            added: right_tours.difference(&left_tours).cloned().collect(),
+            removed: left_tours.difference(&right_tours).cloned().collect(),
+        },
+        jobs,
+    }
+}
+
+fn get_statistic_diff(left: &Statistic, right: &Statistic) -> StatisticDiff {
+    StatisticDiff {
+        cost: right.cost - left.cost,
+        distance: right.distance - left.distance,
+        duration: right.duration - left.duration,
+        driving: right.times.driving - left.times.driving,
+        serving: right.times.serving - left.times.serving,
+        waiting: right.times.waiting - left.times.waiting,
+        break_time: right.times.break_time - left.times.break_time,
+    }
+}
+
+fn get_tour_key(tour: &Tour) -> TourKey {
+    TourKey { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index }
+}
+
+fn get_tour_keys(solution: &Solution) -> BTreeSet<TourKey> {
+    solution.tours.iter().map(get_tour_key).collect()
+}
+
+fn get_job_assignments(solution: &Solution, job_ids: &BTreeSet<&String>) -> BTreeMap<String, JobAssignment> {
+    solution.tours.iter().fold(BTreeMap::new(), |mut acc, tour| {
+        // NOTE vehicle activities, such as departure or break, do not affect job positions
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .filter(|activity| job_ids.contains(&activity.job_id))
+            .enumerate()
+            .for_each(|(position, activity)| {
+                // NOTE keep position of the first job's activity only
+                acc.entry(activity.job_id.clone()).or_insert_with(|| JobAssignment {
+                    vehicle_id: tour.vehicle_id.clone(),
+                    shift_index: tour.shift_index,
+                    position,
+                });
+            });
+
+        acc
+    })
+}
+
+fn get_changed_job_locations(problem: &Problem, diff: &SolutionDiff) -> Vec<(String, Location, usize)> {
+    let jobs = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<BTreeMap<_, _>>();
+
+    // NOTE jobs with changed vehicle are colored differently from jobs with changed position only
+    diff.jobs
+        .iter()
+        .filter_map(|job_diff| jobs.get(job_diff.job_id.as_str()).map(|job| (job_diff, job)))
+        .flat_map(|(job_diff, job)| {
+            let color_idx = if job_diff.is_vehicle_changed { 0 } else { 1 };
+            get_job_locations(job).map(move |location| (job_diff.job_id.clone(), location, color_idx))
+        })
+        .collect()
+}
+
+fn get_job_locations(job: &Job) -> impl Iterator<Item = Location> + '_ {
+    [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .flat_map(|task| task.places.iter())
+        .map(|place| place.location.clone())
+}
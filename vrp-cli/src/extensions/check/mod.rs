@@ -4,12 +4,13 @@
 #[path = "../../../tests/unit/extensions/check/check_test.rs"]
 mod check_test;
 
+use crate::extensions::import::read_matrix;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::prelude::GenericError;
 use vrp_pragmatic::checker::CheckerContext;
-use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
-use vrp_pragmatic::format::solution::deserialize_solution;
+use vrp_pragmatic::format::problem::{deserialize_problem, Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::{deserialize_solution, Solution};
 
 /// Checks pragmatic solution feasibility.
 pub fn check_pragmatic_solution<F: Read>(
@@ -28,7 +29,7 @@ pub fn check_pragmatic_solution<F: Read>(
             matrices_readers
                 .into_iter()
                 .map(|file| {
-                    deserialize_matrix(BufReader::new(file))
+                    read_matrix(BufReader::new(file))
                         .map_err(|errs| vec![format!("cannot read matrix: '{errs}'").into()])
                 })
                 .collect::<Result<Vec<_>, _>>()?,
@@ -37,6 +38,16 @@ pub fn check_pragmatic_solution<F: Read>(
         None
     };
 
+    check_pragmatic_solution_with_matrices(problem, solution, matrices)
+}
+
+/// Checks pragmatic solution feasibility using routing matrices which are already read or created,
+/// e.g. from road graph.
+pub fn check_pragmatic_solution_with_matrices(
+    problem: Problem,
+    solution: Solution,
+    matrices: Option<Vec<Matrix>>,
+) -> Result<(), Vec<GenericError>> {
     let core_problem = Arc::new(
         (problem.clone(), matrices.clone())
             .read_pragmatic()
@@ -4,6 +4,7 @@ mod fleet_test;
 
 use super::*;
 use vrp_pragmatic::format::problem::{Fleet, VehicleCosts, VehicleLimits, VehicleShift, VehicleType};
+use vrp_pragmatic::format::LoadAmount;
 
 /// Generates fleet of vehicles.
 pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize) -> Fleet {
@@ -27,17 +28,20 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
                 profile: VehicleProfile {
                     matrix: get_random_item(profiles.as_slice(), &rnd).expect("cannot find any profile").name.clone(),
                     scale: None,
+                    parking: None,
                 },
                 costs: get_random_item(costs.as_slice(), &rnd).expect("cannot find any costs").clone(),
                 shifts: get_random_item(shifts.as_slice(), &rnd).expect("cannot find any shifts").clone(),
                 capacity: get_random_item(capacities.as_slice(), &rnd).expect("cannot find any capacity").clone(),
                 skills: get_random_item(skills.as_slice(), &rnd).expect("cannot find any skills").clone(),
                 limits: get_random_item(limits.as_slice(), &rnd).expect("cannot find any limits").clone(),
+                compartments: None,
+                calendar: None,
             }
         })
         .collect();
 
-    Fleet { vehicles, profiles, resources: None }
+    Fleet { vehicles, profiles, drivers: None, resources: None, dimensions: problem_proto.fleet.dimensions.clone() }
 }
 
 fn get_from_vehicle<F, T>(problem_proto: &Problem, func: F) -> Vec<T>
@@ -55,7 +59,7 @@ fn get_vehicle_shifts(problem_proto: &Problem) -> Vec<Vec<VehicleShift>> {
     get_from_vehicle(problem_proto, |vehicle| vehicle.shifts.clone())
 }
 
-fn get_vehicle_capacities(problem_proto: &Problem) -> Vec<Vec<i32>> {
+fn get_vehicle_capacities(problem_proto: &Problem) -> Vec<LoadAmount> {
     get_from_vehicle(problem_proto, |vehicle| vehicle.capacity.clone())
 }
 
@@ -6,7 +6,7 @@ use super::get_random_item;
 use vrp_core::prelude::GenericError;
 use vrp_core::utils::{DefaultRandom, Random};
 use vrp_pragmatic::format::problem::{Job, JobPlace, JobTask, Plan, Problem};
-use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::{LoadAmount, Location};
 
 /// Generates a new plan for given problem with amount of jobs specified by`jobs_size` and
 /// bounding box of size `area_size` (half size in meters). When not specified, jobs bounding
@@ -38,6 +38,10 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            tolerance: None,
+                            days: None,
+                            durations: None,
+                            parking: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -69,6 +73,9 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                max_ride_duration: job_proto.max_ride_duration,
+                product: None,
+                frequency: None,
             }
         })
         .collect();
@@ -158,7 +165,7 @@ fn get_plan_time_windows(plan: &Plan) -> Vec<Vec<Vec<String>>> {
     get_plan_places(plan).flat_map(|job_place| job_place.times.iter()).cloned().collect()
 }
 
-fn get_plan_demands(plan: &Plan) -> Vec<Vec<i32>> {
+fn get_plan_demands(plan: &Plan) -> Vec<LoadAmount> {
     plan.jobs.iter().flat_map(get_job_tasks).filter_map(|job_task| job_task.demand.as_ref()).cloned().collect()
 }
 
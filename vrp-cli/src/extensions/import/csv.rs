@@ -70,8 +70,12 @@ mod actual {
                 duration: job.duration as f64,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                tolerance: None,
+                days: None,
+                durations: None,
+                parking: None,
             }],
-            demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
+            demand: if job.demand != 0 { Some(vec![job.demand.abs()].into()) } else { None },
             order: None,
         };
 
@@ -101,6 +105,9 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                max_ride_duration: None,
+                product: None,
+                frequency: None,
             })
             .collect();
 
@@ -116,7 +123,7 @@ mod actual {
                 VehicleType {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
-                    profile: VehicleProfile { matrix: vehicle.profile, scale: None },
+                    profile: VehicleProfile { matrix: vehicle.profile, scale: None, parking: None },
                     costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
@@ -128,10 +135,13 @@ mod actual {
                         breaks: None,
                         reloads: None,
                         recharges: None,
+                        depots: None,
                     }],
-                    capacity: vec![vehicle.capacity],
+                    capacity: vec![vehicle.capacity].into(),
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    calendar: None,
                 }
             })
             .collect();
@@ -161,10 +171,16 @@ mod actual {
             plan: Plan { jobs, relations: None, clustering: None },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile { name, speed: None, fifo: None, speed_bands: None })
+                    .collect(),
+                drivers: None,
                 resources: None,
+                dimensions: None,
             },
             objectives: None,
+            state: None,
         })
     }
 }
@@ -0,0 +1,74 @@
+//! Import routing matrix logic: converts json matrix to binary format and reads matrix in any of them.
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/import/matrix_test.rs"]
+mod matrix_test;
+
+use std::io::{BufRead, BufReader, Read, Write};
+use vrp_core::models::Problem as CoreProblem;
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::{FormatError, MultiFormatError};
+
+/// Converts routing matrix from json to binary format.
+pub fn import_matrix<R: Read, W: Write>(reader: BufReader<R>, writer: &mut W) -> Result<(), GenericError> {
+    let matrix = deserialize_matrix(reader).map_err(|errs| format!("cannot read json matrix: '{errs}'"))?;
+
+    serialize_matrix_binary(&matrix, writer)
+}
+
+/// Reads routing matrix detecting its format (json or binary) automatically.
+pub fn read_matrix<R: Read>(mut reader: BufReader<R>) -> Result<Matrix, MultiFormatError> {
+    let is_binary = reader.fill_buf().map(is_binary_matrix).map_err(create_read_error)?;
+
+    if is_binary {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).map_err(create_read_error)?;
+
+        deserialize_matrix_binary(data.as_slice())
+    } else {
+        deserialize_matrix(reader)
+    }
+}
+
+/// Reads problem with routing matrix files detecting their format (json or binary) automatically.
+/// Files are memory-mapped, so binary matrices are decoded directly into routing data used by the solver.
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(unsafe_code)]
+pub fn read_pragmatic_with_matrix_files(
+    problem: Problem,
+    files: &[std::fs::File],
+) -> Result<CoreProblem, MultiFormatError> {
+    let mut matrices = Vec::with_capacity(files.len());
+    let mut binary_matrices = Vec::with_capacity(files.len());
+
+    for file in files {
+        // SAFETY: the routing matrix file is not expected to be modified while it is being read
+        let data = unsafe { memmap2::Mmap::map(file) }.map_err(create_read_error)?;
+
+        if is_binary_matrix(&data) {
+            binary_matrices.push(deserialize_binary_matrix(&data)?);
+        } else {
+            matrices.push(deserialize_matrix(BufReader::new(&data[..]))?);
+        }
+    }
+
+    match (matrices.is_empty(), binary_matrices.is_empty()) {
+        (_, true) => (problem, matrices).read_pragmatic(),
+        (true, false) => (problem, binary_matrices).read_pragmatic(),
+        (false, false) => Err(vec![FormatError::new(
+            "E0001".to_string(),
+            "cannot deserialize matrix".to_string(),
+            "use either json or binary format for all matrix files".to_string(),
+        )]
+        .into()),
+    }
+}
+
+fn create_read_error(err: std::io::Error) -> MultiFormatError {
+    vec![FormatError::new(
+        "E0001".to_string(),
+        "cannot deserialize matrix".to_string(),
+        format!("check matrix file: '{err}'"),
+    )]
+    .into()
+}
@@ -3,6 +3,9 @@
 mod csv;
 pub use self::csv::*;
 
+mod matrix;
+pub use self::matrix::*;
+
 use std::io::{BufReader, Read};
 use vrp_core::prelude::GenericError;
 use vrp_pragmatic::format::problem::Problem;
@@ -153,6 +153,18 @@ pub enum SearchOperatorType {
         probability: OperatorProbabilityType,
     },
 
+    /// A metaheuristic which recombines routes from the population using set partitioning.
+    #[serde(rename(deserialize = "recombine"))]
+    #[serde(rename_all = "camelCase")]
+    Recombine {
+        /// Max amount of distinct routes in the route pool.
+        max_routes: usize,
+        /// Time limit (in ms) of the branch and bound search. Default is 200.
+        time_limit: Option<usize>,
+        /// Probability of operator.
+        probability: OperatorProbabilityType,
+    },
+
     /// A local search heuristic.
     #[serde(rename(deserialize = "local-search"))]
     LocalSearch {
@@ -323,6 +335,17 @@ pub enum LocalOperatorType {
 
     #[serde(rename(deserialize = "sequence"))]
     Sequence { weight: usize },
+
+    #[serde(rename(deserialize = "two-opt"))]
+    TwoOpt { weight: usize, neighbours: usize },
+
+    #[serde(rename(deserialize = "or-opt"))]
+    #[serde(rename_all = "camelCase")]
+    OrOpt { weight: usize, neighbours: usize, max_chain_size: usize },
+
+    #[serde(rename(deserialize = "three-opt"))]
+    #[serde(rename_all = "camelCase")]
+    ThreeOpt { weight: usize, neighbours: usize, max_segment_size: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -356,6 +379,21 @@ pub struct TelemetryConfig {
     metrics: Option<MetricsConfig>,
 }
 
+impl TelemetryConfig {
+    /// Creates a telemetry config which logs progress using default settings.
+    pub fn new_with_progress() -> Self {
+        Self {
+            progress: Some(ProgressConfig {
+                enabled: true,
+                log_best: None,
+                log_population: None,
+                dump_population: None,
+            }),
+            metrics: None,
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ProgressConfig {
@@ -379,9 +417,12 @@ pub struct MetricsConfig {
 }
 
 /// An environment specific configuration.
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Default, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct EnvironmentConfig {
+    /// Specifies a random generator configuration.
+    pub random: Option<RandomConfig>,
+
     /// Specifies a data parallelism configuration.
     pub parallelism: Option<ParallelismConfig>,
 
@@ -392,6 +433,14 @@ pub struct EnvironmentConfig {
     pub is_experimental: Option<bool>,
 }
 
+/// Random generator configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RandomConfig {
+    /// Specifies a seed used to initialize random generators. Default is randomized.
+    pub seed: Option<u64>,
+}
+
 /// Data parallelism configuration.
 #[derive(Clone, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -635,6 +684,17 @@ fn create_operator(
                 create_operator_probability(probability, environment.random.clone()),
             )
         }
+        SearchOperatorType::Recombine { max_routes, time_limit, probability } => {
+            if *max_routes < 2 {
+                return Err(format!("max routes must be greater than 1. Specified: {max_routes}").into());
+            }
+
+            let recreate = Arc::new(RecreateWithCheapest::new(environment.random.clone()));
+            (
+                Arc::new(RecombineSearch::new(recreate, *max_routes, time_limit.unwrap_or(200))),
+                create_operator_probability(probability, environment.random.clone()),
+            )
+        }
     })
 }
 
@@ -719,6 +779,13 @@ fn create_local_search(
                 (Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
             LocalOperatorType::Sequence { weight } => (Arc::new(ExchangeSequence::default()), *weight),
+            LocalOperatorType::TwoOpt { weight, neighbours } => (Arc::new(ExchangeTwoOpt::new(*neighbours)), *weight),
+            LocalOperatorType::OrOpt { weight, neighbours, max_chain_size } => {
+                (Arc::new(ExchangeOrOpt::new(*neighbours, *max_chain_size)), *weight)
+            }
+            LocalOperatorType::ThreeOpt { weight, neighbours, max_segment_size } => {
+                (Arc::new(ExchangeThreeOpt::new(*neighbours, *max_segment_size)), *weight)
+            }
         })
         .collect::<Vec<_>>();
 
@@ -767,12 +834,13 @@ fn get_telemetry_mode(environment: Arc<Environment>, telemetry_config: &Option<T
     }
 }
 
-fn configure_from_environment(
-    environment_config: &Option<EnvironmentConfig>,
-    max_time: Option<usize>,
-) -> Arc<Environment> {
+fn configure_from_environment(environment_config: &Option<EnvironmentConfig>, max_time: Option<usize>) -> Environment {
     let mut environment = Environment::new_with_time_quota(max_time);
 
+    if let Some(seed) = environment_config.as_ref().and_then(|c| c.random.as_ref()).and_then(|r| r.seed) {
+        environment.random = Arc::new(DefaultRandom::new_with_seed(seed));
+    }
+
     if let Some(parallelism) = environment_config.as_ref().and_then(|c| c.parallelism.as_ref()) {
         // TODO validate parameters
         environment.parallelism = Parallelism::new(parallelism.num_thread_pools, parallelism.threads_per_pool);
@@ -790,7 +858,7 @@ fn configure_from_environment(
         environment.is_experimental = is_experimental;
     }
 
-    Arc::new(environment)
+    environment
 }
 
 /// Reads config from reader.
@@ -816,8 +884,24 @@ pub fn create_builder_from_config(
     solutions: Vec<InsertionContext>,
     config: &Config,
 ) -> Result<ProblemConfigBuilder, GenericError> {
-    let environment =
-        configure_from_environment(&config.environment, config.termination.as_ref().and_then(|t| t.max_time));
+    create_builder_from_config_with_environment(problem, solutions, config, |environment| environment)
+}
+
+/// Creates a solver `Builder` from config allowing to adjust environment created from it,
+/// e.g. to override quota or logger.
+pub fn create_builder_from_config_with_environment<F>(
+    problem: Arc<Problem>,
+    solutions: Vec<InsertionContext>,
+    config: &Config,
+    configure_environment: F,
+) -> Result<ProblemConfigBuilder, GenericError>
+where
+    F: FnOnce(Environment) -> Environment,
+{
+    let environment = Arc::new(configure_environment(configure_from_environment(
+        &config.environment,
+        config.termination.as_ref().and_then(|t| t.max_time),
+    )));
     let telemetry_mode = get_telemetry_mode(environment.clone(), &config.telemetry);
     let mut builder = create_default_config_builder(problem.clone(), environment.clone(), telemetry_mode.clone())
         .with_init_solutions(solutions, None);
@@ -1,3 +1,6 @@
 //! Solve command helpers
 
 pub mod config;
+
+#[cfg(feature = "road-routing")]
+pub mod routing;
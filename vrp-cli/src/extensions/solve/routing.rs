@@ -0,0 +1,125 @@
+//! Provides functionality to create routing matrices and route geometries for pragmatic problem using
+//! a local road network graph.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/routing_test.rs"]
+mod routing_test;
+
+use std::sync::{Arc, Mutex};
+use vrp_core::models::common::{Location as CoreLocation, Profile};
+use vrp_core::prelude::{GenericError, Problem as CoreProblem, TransportCost};
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::{Location, RouteGeometry, RouteGeometryDimension};
+use vrp_pragmatic::get_unique_locations;
+use vrp_routing::graph::{RoadGraph, RoadProfile};
+use vrp_routing::matrix::create_matrix_data;
+use vrp_routing::path::PathSearch;
+
+/// Reads pragmatic problem using routing matrices calculated with road graph. Road graph is also
+/// used to get route geometries in geojson output.
+pub fn read_pragmatic_with_road_graph(problem: Problem, graph: Arc<RoadGraph>) -> Result<CoreProblem, GenericError> {
+    let matrices = create_road_matrices(&problem, graph.as_ref())?;
+    let route_geometry = RoadRouteGeometry::new(&problem, graph);
+
+    let problem = (problem, matrices).read_pragmatic()?;
+    let route_geometry = RoadRouteGeometry { transport: Some(problem.transport.clone()), ..route_geometry };
+
+    let mut extras = problem.extras.as_ref().clone();
+    extras.set_route_geometry(Arc::new(route_geometry));
+
+    Ok(CoreProblem { extras: Arc::new(extras), ..problem })
+}
+
+/// Creates routing matrices for each problem profile using road graph. Profile speed, if specified,
+/// limits road speeds. Unreachable routes are marked by error codes.
+pub fn create_road_matrices(problem: &Problem, graph: &RoadGraph) -> Result<Vec<Matrix>, GenericError> {
+    let locations = get_unique_locations(problem)
+        .into_iter()
+        .filter_map(|location| match location {
+            Location::Coordinate { lat, lng } => Some(Ok((lat, lng))),
+            Location::Custom { .. } => None,
+            Location::Reference { .. } => {
+                Some(Err("road graph can be used only with locations specified as coordinates"))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let profiles = get_road_profiles(problem);
+
+    let matrices = create_matrix_data(graph, profiles.as_slice(), locations.as_slice())
+        .into_iter()
+        .zip(profiles)
+        .map(|(data, profile)| {
+            let is_reachable = |value: &f64| *value >= 0.;
+            let has_unreachable = !data.durations.iter().all(is_reachable);
+            let round = |values: Vec<f64>| {
+                values.into_iter().map(|value| if is_reachable(&value) { value.round() as i64 } else { 0 }).collect()
+            };
+
+            Matrix {
+                profile: Some(profile.name),
+                timestamp: None,
+                error_codes: has_unreachable
+                    .then(|| data.durations.iter().map(|value| if is_reachable(value) { 0 } else { 1 }).collect()),
+                travel_times: round(data.durations),
+                distances: round(data.distances),
+                entries: None,
+                geometries: None,
+            }
+        })
+        .collect();
+
+    Ok(matrices)
+}
+
+fn get_road_profiles(problem: &Problem) -> Vec<RoadProfile> {
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .map(|profile| RoadProfile { name: profile.name.clone(), max_speed: profile.speed })
+        .collect()
+}
+
+/// A route geometry which finds the fastest path on road graph. Route duration known by transport
+/// costs limits the search, so unreachable routes are not searched at all.
+struct RoadRouteGeometry {
+    graph: Arc<RoadGraph>,
+    speeds: Vec<Vec<f64>>,
+    coordinates: Vec<Option<(f64, f64)>>,
+    transport: Option<Arc<dyn TransportCost + Send + Sync>>,
+    search: Mutex<PathSearch>,
+}
+
+impl RoadRouteGeometry {
+    fn new(problem: &Problem, graph: Arc<RoadGraph>) -> Self {
+        let speeds = get_road_profiles(problem).iter().map(|profile| graph.get_speeds(profile)).collect();
+        let coordinates = get_unique_locations(problem)
+            .into_iter()
+            .map(|location| match location {
+                Location::Coordinate { lat, lng } => Some((lat, lng)),
+                _ => None,
+            })
+            .collect();
+
+        let search = Mutex::new(PathSearch::new(graph.as_ref()));
+
+        Self { graph, speeds, coordinates, transport: None, search }
+    }
+}
+
+impl RouteGeometry for RoadRouteGeometry {
+    fn get_geometry(&self, profile: usize, from: CoreLocation, to: CoreLocation) -> Option<Vec<(f64, f64)>> {
+        let speeds = self.speeds.get(profile)?;
+        // NOTE matrix durations are rounded, so give a small margin to the limit
+        let max_weight = self
+            .transport
+            .as_ref()
+            .map_or(f64::INFINITY, |transport| transport.duration_approx(&Profile::new(profile, None), from, to) + 1.);
+        let from = (*self.coordinates.get(from)?)?;
+        let to = (*self.coordinates.get(to)?)?;
+
+        let mut search = self.search.lock().ok()?;
+        search.get_route_geometry(self.graph.as_ref(), speeds.as_slice(), from, to, max_weight)
+    }
+}
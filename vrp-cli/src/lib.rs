@@ -29,6 +29,8 @@ mod lib_test;
 
 pub use vrp_core as core;
 pub use vrp_pragmatic as pragmatic;
+#[cfg(feature = "road-routing")]
+pub use vrp_routing as routing;
 #[cfg(feature = "scientific-format")]
 pub use vrp_scientific as scientific;
 
@@ -12,6 +12,9 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        max_ride_duration: None,
+        product: None,
+        frequency: None,
     }
 }
 
@@ -20,7 +23,16 @@ pub fn create_empty_job_task() -> JobTask {
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        tolerance: None,
+        days: None,
+        durations: None,
+        parking: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
@@ -31,7 +43,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
     VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
-        profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+        profile: VehicleProfile { matrix: "car".to_string(), scale: None, parking: None },
         costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
         shifts: vec![VehicleShift {
             start: ShiftStart {
@@ -43,15 +55,18 @@ pub fn create_test_vehicle_type() -> VehicleType {
             breaks: None,
             reloads: None,
             recharges: None,
+            depots: None,
         }],
-        capacity: vec![10],
+        capacity: vec![10].into(),
         skills: None,
         limits: None,
+        compartments: None,
+        calendar: None,
     }
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile { name: "car".to_string(), speed: None, fifo: None, speed_bands: None }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
@@ -66,7 +81,7 @@ pub fn create_test_job(lat: f64, lng: f64) -> Job {
                 times: Some(vec![create_test_time_window()]),
                 ..create_empty_job_place()
             }],
-            demand: Some(vec![1]),
+            demand: Some(vec![1].into()),
             ..create_empty_job_task()
         }]),
         ..create_empty_job()
@@ -36,3 +36,12 @@ fn can_detect_wrong_argument() {
 
     assert!(get_analyze_app().try_get_matches_from(args).is_err());
 }
+
+#[test]
+fn can_run_analyze_diff() {
+    let solution_path = "../examples/data/pragmatic/simple.basic.solution.json";
+    let args = vec!["analyze", "diff", "pragmatic", PRAGMATIC_PROBLEM_PATH, solution_path, solution_path];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+}
@@ -24,3 +24,36 @@ fn can_import_csv_problem_from_args() {
     assert_eq!(problem.jobs.size(), 3);
     assert_eq!(problem.fleet.vehicles.len(), 30);
 }
+
+#[test]
+fn can_import_matrix_from_args() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "import",
+        "matrix",
+        "--input-files",
+        "../examples/data/pragmatic/simple.basic.matrix.json",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_import_app().try_get_matches_from(args).unwrap();
+
+    run_import(&matches).unwrap();
+
+    let matrix = vrp_cli::extensions::import::read_matrix(BufReader::new(File::open(tmpfile.path()).unwrap())).unwrap();
+    assert_eq!(matrix.profile, Some("normal_car".to_string()));
+    assert_eq!(matrix.travel_times.len(), matrix.distances.len());
+}
+
+#[test]
+fn can_require_one_matrix_file_on_import() {
+    let args = vec!["import", "matrix", "--input-files", CSV_JOBS_PATH, VEHICLES_JOBS_PATH];
+    let matches = get_import_app().try_get_matches_from(args).unwrap();
+
+    let result = run_import(&matches);
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "matrix format expects one file with routing matrix in json format as an input"
+    );
+}
@@ -0,0 +1,213 @@
+use super::*;
+use std::io::Read;
+use std::net::SocketAddr;
+use std::time::Duration;
+use vrp_pragmatic::format::solution::Solution as ApiSolution;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn create_limits() -> ServeLimits {
+    ServeLimits {
+        max_body_size: 1024 * 1024,
+        max_connections: 4,
+        max_jobs: 1,
+        max_queued_jobs: 4,
+        job_ttl: Duration::from_secs(3600),
+    }
+}
+
+fn start_server() -> SocketAddr {
+    start_server_with_limits(create_limits())
+}
+
+fn start_server_with_limits(limits: ServeLimits) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    thread::spawn(move || serve(listener, limits));
+
+    address
+}
+
+fn send_request(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+    let head = format!("{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n", body.len());
+
+    send_raw_request(address, format!("{head}\r\n{body}").as_str())
+}
+
+fn send_raw_request(address: SocketAddr, request: &str) -> (u16, serde_json::Value) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    // NOTE server might close connection before the whole request is sent
+    let _ = stream.write_all(request.as_bytes());
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+
+    (status, serde_json::from_str(body).unwrap())
+}
+
+fn submit_job(address: SocketAddr, config: serde_json::Value) -> String {
+    let problem: serde_json::Value =
+        serde_json::from_reader(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap();
+    let body = json!({ "problem": problem, "config": config }).to_string();
+
+    let (status, response) = send_request(address, "POST", "/jobs", body.as_str());
+    assert_eq!(status, 202);
+
+    response["id"].as_str().unwrap().to_string()
+}
+
+fn wait_for_status(address: SocketAddr, id: &str, expected: &str) -> serde_json::Value {
+    wait_for_status_change(address, id, &["queued", "running"], expected)
+}
+
+fn wait_for_status_change(address: SocketAddr, id: &str, pending: &[&str], expected: &str) -> serde_json::Value {
+    for _ in 0..600 {
+        let (status, info) = send_request(address, "GET", format!("/jobs/{id}").as_str(), "");
+        assert_eq!(status, 200);
+
+        if pending.iter().all(|pending| info["status"] != *pending) {
+            assert_eq!(info["status"], expected);
+            return info;
+        }
+
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    unreachable!("job has not stopped in time")
+}
+
+#[test]
+fn can_submit_job_and_fetch_solution() {
+    let address = start_server();
+
+    let id = submit_job(address, json!({ "termination": { "maxGenerations": 10 } }));
+    let info = wait_for_status(address, id.as_str(), "finished");
+    let (status, solution) = send_request(address, "GET", format!("/jobs/{id}/solution").as_str(), "");
+
+    assert!(info["progress"].is_string());
+    assert_eq!(status, 200);
+    let solution: ApiSolution = serde_json::from_value(solution).unwrap();
+    assert_eq!(solution.tours.len(), 1);
+}
+
+#[test]
+fn can_cancel_running_job() {
+    let address = start_server();
+
+    let id = submit_job(address, json!({ "termination": { "maxTime": 300 } }));
+    wait_for_status_change(address, id.as_str(), &["queued"], "running");
+    let (status, _) = send_request(address, "DELETE", format!("/jobs/{id}").as_str(), "");
+    wait_for_status(address, id.as_str(), "cancelled");
+    let (solution_status, _) = send_request(address, "GET", format!("/jobs/{id}/solution").as_str(), "");
+    let (cancel_status, _) = send_request(address, "DELETE", format!("/jobs/{id}").as_str(), "");
+
+    assert_eq!(status, 202);
+    assert_eq!(solution_status, 200);
+    assert_eq!(cancel_status, 409);
+}
+
+#[test]
+fn can_reject_invalid_requests() {
+    let address = start_server();
+
+    let (invalid_body, _) = send_request(address, "POST", "/jobs", "{}");
+    let (unknown_job, _) = send_request(address, "GET", "/jobs/42", "");
+    let (unknown_endpoint, _) = send_request(address, "GET", "/unknown", "");
+
+    assert_eq!(invalid_body, 400);
+    assert_eq!(unknown_job, 404);
+    assert_eq!(unknown_endpoint, 404);
+}
+
+#[test]
+fn can_reject_too_large_request() {
+    let address = start_server_with_limits(ServeLimits { max_body_size: 8, ..create_limits() });
+
+    let (status, response) = send_request(address, "POST", "/jobs", json!({ "problem": {} }).to_string().as_str());
+
+    assert_eq!(status, 413);
+    assert_eq!(response["error"], "request body is larger than 8 bytes");
+}
+
+#[test]
+fn can_remove_completed_jobs_after_ttl() {
+    let address = start_server_with_limits(ServeLimits { job_ttl: Duration::from_secs(1), ..create_limits() });
+
+    let id = submit_job(address, json!({ "termination": { "maxGenerations": 1 } }));
+    wait_for_status(address, id.as_str(), "finished");
+    thread::sleep(Duration::from_millis(1100));
+    let (status, _) = send_request(address, "GET", format!("/jobs/{id}").as_str(), "");
+
+    assert_eq!(status, 404);
+}
+
+#[test]
+fn can_reject_too_large_headers() {
+    let address = start_server();
+    let long_header = format!("GET /jobs/0 HTTP/1.1\r\nHost: {}\r\n\r\n", "a".repeat(MAX_HEADER_LINE_SIZE));
+    let many_headers = format!("GET /jobs/0 HTTP/1.1\r\n{}\r\n", "Host: localhost\r\n".repeat(MAX_HEADER_COUNT + 1));
+
+    let (long_header_status, _) = send_raw_request(address, long_header.as_str());
+    let (many_headers_status, _) = send_raw_request(address, many_headers.as_str());
+
+    assert_eq!(long_header_status, 431);
+    assert_eq!(many_headers_status, 431);
+}
+
+#[test]
+fn can_reject_job_when_queue_is_full() {
+    let address = start_server_with_limits(ServeLimits { max_queued_jobs: 1, ..create_limits() });
+    let config = json!({ "termination": { "maxTime": 300 } });
+
+    let running_id = submit_job(address, config.clone());
+    wait_for_status_change(address, running_id.as_str(), &["queued"], "running");
+    let queued_id = submit_job(address, config.clone());
+    let problem: serde_json::Value =
+        serde_json::from_reader(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap();
+    let body = json!({ "problem": problem, "config": config }).to_string();
+    let (rejected_status, response) = send_request(address, "POST", "/jobs", body.as_str());
+    let (queued_cancel_status, _) = send_request(address, "DELETE", format!("/jobs/{queued_id}").as_str(), "");
+    let (running_cancel_status, _) = send_request(address, "DELETE", format!("/jobs/{running_id}").as_str(), "");
+    wait_for_status(address, queued_id.as_str(), "cancelled");
+    let (queued_solution_status, _) = send_request(address, "GET", format!("/jobs/{queued_id}/solution").as_str(), "");
+
+    assert_eq!(rejected_status, 503);
+    assert_eq!(response["error"], "too many queued jobs");
+    assert_eq!(queued_cancel_status, 202);
+    assert_eq!(running_cancel_status, 202);
+    assert_eq!(queued_solution_status, 404);
+}
+
+#[test]
+fn can_keep_worker_running_when_solver_panics() {
+    let address = start_server();
+
+    let failed_id = submit_job(
+        address,
+        json!({ "termination": { "maxGenerations": 1 }, "evolution": { "population": { "type": "elitism", "maxSize": 0 } } }),
+    );
+    let failed_info = wait_for_status(address, failed_id.as_str(), "failed");
+    let finished_id = submit_job(address, json!({ "termination": { "maxGenerations": 1 } }));
+    wait_for_status(address, finished_id.as_str(), "finished");
+    let (status, _) = send_request(address, "GET", format!("/jobs/{failed_id}").as_str(), "");
+
+    assert!(failed_info["error"].as_str().unwrap().starts_with("solver panicked"));
+    assert_eq!(status, 200);
+}
+
+#[test]
+fn can_reject_non_loopback_address_without_opt_in() {
+    let get_address = |args: &[&str]| {
+        let args = [&["serve"], args].concat();
+        get_listen_address(&get_serve_app().try_get_matches_from(args).unwrap()).ok()
+    };
+
+    assert_eq!(get_address(&[]), Some("127.0.0.1:8080".to_string()));
+    assert_eq!(get_address(&["--address", "[::1]:8080"]), Some("[::1]:8080".to_string()));
+    assert_eq!(get_address(&["--address", "0.0.0.0:8080"]), None);
+    assert_eq!(get_address(&["--address", "0.0.0.0:8080", "--allow-remote"]), Some("0.0.0.0:8080".to_string()));
+}
@@ -1,8 +1,10 @@
 use super::*;
+use vrp_pragmatic::format::problem::deserialize_problem;
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
 const SOLOMON_PROBLEM_PATH: &str = "../examples/data/scientific/solomon/C101.25.txt";
 const LILIM_PROBLEM_PATH: &str = "../examples/data/scientific/lilim/LC101.txt";
+const DARP_PROBLEM_PATH: &str = "../examples/data/scientific/darp/sample.txt";
 
 struct DummyWrite {}
 
@@ -34,6 +36,144 @@ fn can_solve_pragmatic_problem_with_generation_limit() {
     run_solve_with_out_writer(&matches);
 }
 
+#[test]
+fn can_solve_pragmatic_problem_with_binary_matrix() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let matrix = BufReader::new(open_file("../examples/data/pragmatic/simple.basic.matrix.json", "matrix"));
+    vrp_cli::extensions::import::import_matrix(matrix, &mut tmpfile.as_file()).unwrap();
+    let matrix_path = tmpfile.path().to_str().unwrap();
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--matrix", matrix_path, "--max-generations", "1"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+#[cfg(feature = "road-routing")]
+fn can_solve_pragmatic_problem_with_routing_graph() {
+    use vrp_cli::extensions::solve::routing::create_road_matrices;
+    use vrp_cli::routing::graph::read_road_graph;
+
+    let graph_path = "../examples/data/pragmatic/simple.basic.graph.json";
+    let solution_file = tempfile::NamedTempFile::new().unwrap();
+    let geojson_file = tempfile::NamedTempFile::new().unwrap();
+    let matrix_file = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--routing-graph",
+        graph_path,
+        "--max-generations",
+        "1",
+        "--out-result",
+        solution_file.path().to_str().unwrap(),
+        "--geo-json",
+        geojson_file.path().to_str().unwrap(),
+        "--check",
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve(&matches, create_write_buffer).unwrap();
+
+    // NOTE road geometry goes through graph nodes, so some legs are not straight lines
+    let geojson: serde_json::Value =
+        serde_json::from_reader(BufReader::new(open_file(geojson_file.path().to_str().unwrap(), "geojson"))).unwrap();
+    let leg_sizes = geojson["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|feature| feature["properties"]["leg_idx"].is_string())
+        .map(|feature| feature["geometry"]["coordinates"].as_array().unwrap().len())
+        .collect::<Vec<_>>();
+    assert!(!leg_sizes.is_empty());
+    assert!(leg_sizes.iter().any(|&size| size > 2));
+
+    // NOTE check solution feasibility using the same road matrices
+    let problem = deserialize_problem(BufReader::new(open_file(PRAGMATIC_PROBLEM_PATH, "problem"))).unwrap();
+    let graph = read_road_graph(BufReader::new(open_file(graph_path, "routing graph"))).unwrap();
+    let matrices = create_road_matrices(&problem, &graph).unwrap();
+    vrp_pragmatic::format::problem::serialize_matrix_binary(&matrices[0], &mut matrix_file.as_file()).unwrap();
+    let result = vrp_cli::extensions::check::check_pragmatic_solution(
+        BufReader::new(open_file(PRAGMATIC_PROBLEM_PATH, "problem")),
+        BufReader::new(open_file(solution_file.path().to_str().unwrap(), "solution")),
+        Some(vec![BufReader::new(open_file(matrix_file.path().to_str().unwrap(), "matrix"))]),
+    );
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn can_not_use_routing_graph_with_matrix() {
+    let args =
+        vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--routing-graph", "graph.json", "--matrix", "matrix.json"];
+
+    assert!(get_solve_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn can_write_best_solutions_as_ndjson() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--best-solutions",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let solutions = std::io::BufRead::lines(BufReader::new(tmpfile.as_file()))
+        .map(|line| serde_json::from_str::<vrp_pragmatic::format::solution::Solution>(&line.unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert!(!solutions.is_empty());
+}
+
+#[test]
+fn can_reuse_population_and_write_changes() {
+    let population = tempfile::NamedTempFile::new().unwrap();
+    let changes = tempfile::NamedTempFile::new().unwrap();
+    let population_path = population.path().to_str().unwrap();
+    let changes_path = changes.path().to_str().unwrap();
+
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "-n", "10", "--out-population", population_path];
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+
+    let solutions =
+        vrp_pragmatic::format::solution::deserialize_solutions(BufReader::new(population.as_file())).unwrap();
+    assert!(!solutions.is_empty());
+
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "-n",
+        "10",
+        "--init-solution",
+        population_path,
+        "--out-changes",
+        changes_path,
+    ];
+    run_solve_with_out_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+
+    let changes = serde_json::from_reader::<_, serde_json::Value>(BufReader::new(changes.as_file())).unwrap();
+    assert!(changes.get("statistic").is_some());
+    assert!(changes.get("jobs").is_some_and(|jobs| jobs.is_array()));
+}
+
+#[test]
+fn can_require_init_solution_for_changes() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "-n", "1", "--out-changes", "changes.json"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err_and(|err| err.to_string().contains("requires initial solution")));
+}
+
 #[test]
 fn can_solve_lilim_problem_with_multiple_limits() {
     let args = vec!["solve", "lilim", LILIM_PROBLEM_PATH, "--max-time", "300", "--max-generations", "1"];
@@ -42,6 +182,14 @@ fn can_solve_lilim_problem_with_multiple_limits() {
     run_solve_with_out_writer(&matches);
 }
 
+#[test]
+fn can_solve_darp_problem() {
+    let args = vec!["solve", "darp", DARP_PROBLEM_PATH, "--max-generations", "10"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
 #[test]
 fn can_solve_solomon_problem_with_generation_limit() {
     run_solve_with_out_writer(&get_solomon_matches(&["--max-generations", "1"]));
@@ -49,7 +197,7 @@ fn can_solve_solomon_problem_with_generation_limit() {
 
 #[test]
 fn can_require_problem_path() {
-    for format in &["pragmatic", "solomon", "lilim", "tsplib"] {
+    for format in &["pragmatic", "solomon", "lilim", "darp", "tsplib"] {
         get_solve_app().try_get_matches_from(vec!["solve", format]).unwrap_err();
     }
 }
@@ -116,6 +264,19 @@ fn can_specify_parallelism() {
     }
 }
 
+#[test]
+fn can_specify_seed() {
+    for (params, result) in
+        [(vec!["--seed", "42"], Some(Some(42))), (vec![], Some(None)), (vec!["--seed", "abc"], None)]
+    {
+        let matches = get_solomon_matches(params.as_slice());
+
+        let seed = get_environment(&matches).ok().map(|e| e.random.get_seed());
+
+        assert_eq!(seed, result);
+    }
+}
+
 #[test]
 fn can_use_init_size() {
     for (params, result) in [
@@ -150,3 +311,43 @@ fn can_specify_cv() {
         assert_eq!(min_cv, result);
     }
 }
+
+#[test]
+fn can_solve_tsplib_problem_with_best_known_solution() {
+    let args = vec![
+        "solve",
+        "tsplib",
+        "../examples/data/scientific/tsplib/A-n32-k5.vrp",
+        "--round",
+        "--max-generations",
+        "10",
+        "--bks",
+        "../examples/data/scientific/tsplib/A-n32-k5.sol",
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_get_target_proximity() {
+    for (params, result) in [
+        (vec!["--bks", "0,3,191.8", "--bks-gap", "1"], Ok(Some((vec![0., 3., 191.8], 0.01)))),
+        (vec!["--bks", "0, 3, 191.8"], Ok(Some((vec![0., 3., 191.8], 0.0001)))),
+        (
+            vec!["--bks", "3,191.8"],
+            Err("best known solution fitness has 2 values, but the goal has 3 objectives".into()),
+        ),
+        (vec![], Ok(None)),
+    ] {
+        let matches = get_solomon_matches(params.as_slice());
+        let environment = get_environment(&matches).unwrap();
+        let formats = get_formats(&matches, environment.random.clone());
+        let (ProblemReader(problem_reader), init_reader, _, _) = formats.get("solomon").unwrap();
+        let problem = Arc::new(problem_reader(open_file(SOLOMON_PROBLEM_PATH, "problem"), None).unwrap());
+
+        let target_proximity = get_target_proximity(&matches, problem, environment, init_reader);
+
+        assert_eq!(target_proximity, result);
+    }
+}
@@ -0,0 +1,113 @@
+use super::*;
+use std::fs::File;
+use vrp_pragmatic::format::problem::{ProblemState, VehicleState};
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn read_problem_and_solution() -> (Problem, Solution) {
+    let problem = deserialize_problem(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap();
+    let solution = deserialize_solution(BufReader::new(File::open(PRAGMATIC_SOLUTION_PATH).unwrap())).unwrap();
+
+    (problem, solution)
+}
+
+#[test]
+fn can_detect_no_changes() {
+    let (problem, solution) = read_problem_and_solution();
+
+    let diff = create_solution_diff(&problem, &solution, &solution);
+
+    assert!(diff.jobs.is_empty());
+    assert!(diff.tours.added.is_empty());
+    assert!(diff.tours.removed.is_empty());
+    assert_eq!(diff.statistic.cost, 0.);
+    assert_eq!(diff.statistic.distance, 0);
+}
+
+#[test]
+fn can_detect_position_shifts_and_statistic_deltas() {
+    let (problem, left) = read_problem_and_solution();
+    let mut right = left.clone();
+    right.tours[0].stops.swap(1, 2);
+    right.statistic.distance += 100;
+    right.statistic.times.driving += 10;
+
+    let diff = create_solution_diff(&problem, &left, &right);
+
+    let shifts = diff.jobs.iter().map(|job| (job.job_id.as_str(), job.position_shift)).collect::<Vec<_>>();
+    assert_eq!(shifts, vec![("job1", Some(2)), ("job2", Some(-1)), ("job3", Some(-1))]);
+    let positions = diff.jobs.iter().map(|job| job.right.as_ref().map(|right| right.position)).collect::<Vec<_>>();
+    assert_eq!(positions, vec![Some(2), Some(0), Some(1)]);
+    assert!(diff.jobs.iter().all(|job| !job.is_vehicle_changed));
+    assert_eq!(diff.statistic.distance, 100);
+    assert_eq!(diff.statistic.driving, 10);
+}
+
+#[test]
+fn can_ignore_vehicle_activities_in_positions() {
+    let (problem, left) = read_problem_and_solution();
+    let mut right = left.clone();
+    let mut break_stop = right.tours[0].stops[0].clone();
+    break_stop.activities_mut().iter_mut().for_each(|activity| {
+        activity.job_id = "break".to_string();
+        activity.activity_type = "break".to_string();
+    });
+    right.tours[0].stops.insert(1, break_stop);
+
+    let diff = create_solution_diff(&problem, &left, &right);
+
+    assert!(diff.jobs.is_empty());
+}
+
+#[test]
+fn can_detect_changed_vehicles_and_tours() {
+    let (problem, left) = read_problem_and_solution();
+    let mut right = left.clone();
+    right.tours[0].vehicle_id = "vehicle_2".to_string();
+
+    let diff = create_solution_diff(&problem, &left, &right);
+
+    assert_eq!(diff.jobs.len(), 3);
+    assert!(diff.jobs.iter().all(|job| job.is_vehicle_changed && job.position_shift.is_none()));
+    assert_eq!(diff.tours.added, vec![TourKey { vehicle_id: "vehicle_2".to_string(), shift_index: 0 }]);
+    assert_eq!(diff.tours.removed, vec![TourKey { vehicle_id: "vehicle_1".to_string(), shift_index: 0 }]);
+}
+
+#[test]
+fn can_skip_completed_jobs() {
+    let (mut problem, left) = read_problem_and_solution();
+    let mut right = left.clone();
+    right.tours[0].vehicle_id = "vehicle_2".to_string();
+    problem.state = Some(ProblemState {
+        vehicles: vec![VehicleState {
+            vehicle_id: "vehicle_1".to_string(),
+            shift_index: None,
+            location: Location::Coordinate { lat: 52.5, lng: 13.4 },
+            time: "2019-07-04T10:00:00Z".to_string(),
+            completed: Some(vec!["job1".to_string()]),
+            picked_up: None,
+            committed: None,
+        }],
+    });
+
+    let diff = create_solution_diff(&problem, &left, &right);
+
+    assert_eq!(diff.jobs.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job2", "job3"]);
+}
+
+#[test]
+fn can_get_solution_diff_with_geojson() {
+    let open = |path: &str| BufReader::new(File::open(path).unwrap());
+
+    let (diff, geojson) = get_solution_diff(
+        open(PRAGMATIC_PROBLEM_PATH),
+        open(PRAGMATIC_SOLUTION_PATH),
+        open(PRAGMATIC_SOLUTION_PATH),
+        true,
+    )
+    .expect("cannot get solution diff");
+
+    assert!(diff.contains("statistic"));
+    assert!(geojson.is_some_and(|geojson| geojson.contains("LineString")));
+}
@@ -17,9 +17,12 @@ fn can_generate_jobs_with_time_windows() {
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
             profiles: vec![create_test_vehicle_profile()],
+            drivers: None,
             resources: None,
+            dimensions: None,
         },
         objectives: None,
+        state: None,
     };
 
     let result =
@@ -0,0 +1,87 @@
+use super::*;
+use std::fs::File;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+
+fn get_json_matrix() -> String {
+    let matrix = deserialize_matrix(BufReader::new(File::open(PRAGMATIC_MATRIX_PATH).unwrap())).unwrap();
+
+    to_json(&matrix)
+}
+
+fn to_json(matrix: &Matrix) -> String {
+    serde_json::to_string(matrix).unwrap()
+}
+
+fn get_binary_matrix_data() -> Vec<u8> {
+    let mut data = Vec::new();
+    import_matrix(BufReader::new(File::open(PRAGMATIC_MATRIX_PATH).unwrap()), &mut data).unwrap();
+
+    data
+}
+
+#[test]
+fn can_import_json_matrix_as_binary() {
+    let data = get_binary_matrix_data();
+
+    assert!(is_binary_matrix(data.as_slice()));
+    assert_eq!(to_json(&deserialize_matrix_binary(data.as_slice()).unwrap()), get_json_matrix());
+}
+
+#[test]
+fn can_read_matrix_in_any_format() {
+    let json_matrix = read_matrix(BufReader::new(File::open(PRAGMATIC_MATRIX_PATH).unwrap())).unwrap();
+    let binary_matrix = read_matrix(BufReader::new(get_binary_matrix_data().as_slice())).unwrap();
+
+    assert_eq!(to_json(&json_matrix), get_json_matrix());
+    assert_eq!(to_json(&binary_matrix), get_json_matrix());
+}
+
+#[test]
+fn can_read_problem_with_matrix_file_in_any_format() {
+    let read_problem = |file: &File| {
+        let problem = deserialize_problem(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap();
+        read_pragmatic_with_matrix_files(problem, std::slice::from_ref(file)).unwrap()
+    };
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(tmpfile.path(), get_binary_matrix_data()).unwrap();
+    let matrix = deserialize_matrix(BufReader::new(File::open(PRAGMATIC_MATRIX_PATH).unwrap())).unwrap();
+    let size = (matrix.travel_times.len() as f64).sqrt().round() as usize;
+
+    let json_problem = read_problem(&File::open(PRAGMATIC_MATRIX_PATH).unwrap());
+    let binary_problem = read_problem(tmpfile.as_file());
+
+    let profile = json_problem.fleet.profiles.first().unwrap();
+    (0..size).flat_map(|from| (0..size).map(move |to| (from, to))).for_each(|(from, to)| {
+        let idx = from * size + to;
+        for problem in [&json_problem, &binary_problem] {
+            assert_eq!(problem.transport.duration_approx(profile, from, to), matrix.travel_times[idx] as f64);
+            assert_eq!(problem.transport.distance_approx(profile, from, to), matrix.distances[idx] as f64);
+        }
+    });
+}
+
+#[test]
+fn can_detect_mixed_matrix_file_formats() {
+    let problem = deserialize_problem(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap();
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(tmpfile.path(), get_binary_matrix_data()).unwrap();
+
+    let result = read_pragmatic_with_matrix_files(
+        problem,
+        &[File::open(PRAGMATIC_MATRIX_PATH).unwrap(), tmpfile.reopen().unwrap()],
+    );
+
+    assert!(result.unwrap_err().to_string().contains("use either json or binary format for all matrix files"));
+}
+
+#[test]
+fn can_detect_invalid_json_matrix_on_import() {
+    let mut data = Vec::new();
+
+    let result = import_matrix(BufReader::new("{}".as_bytes()), &mut data);
+
+    assert!(result.unwrap_err().to_string().starts_with("cannot read json matrix"));
+    assert!(data.is_empty());
+}
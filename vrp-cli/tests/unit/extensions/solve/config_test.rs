@@ -55,7 +55,7 @@ fn can_read_full_config() {
     match hyper_config {
         HyperType::StaticSelective { operators } => {
             let operators = operators.expect("cannot get operators");
-            assert_eq!(operators.len(), 4);
+            assert_eq!(operators.len(), 5);
             match operators.first().unwrap() {
                 SearchOperatorType::Decomposition { routes, repeat, probability } => {
                     assert_eq!(*repeat, 4);
@@ -74,15 +74,24 @@ fn can_read_full_config() {
             }
 
             match operators.get(1).unwrap() {
+                SearchOperatorType::Recombine { max_routes, time_limit, probability } => {
+                    assert_eq!(*max_routes, 200);
+                    assert_eq!(*time_limit, Some(200));
+                    assert_eq!(as_scalar_probability(probability), 0.01);
+                }
+                _ => unreachable!(),
+            }
+
+            match operators.get(2).unwrap() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.05);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 4);
+                    assert_eq!(inners.len(), 7);
                 }
                 _ => unreachable!(),
             }
 
-            match operators.get(2).unwrap() {
+            match operators.get(3).unwrap() {
                 SearchOperatorType::RuinRecreate { probability, ruins, recreates } => {
                     assert_eq!(as_scalar_probability(probability), 1.);
                     assert_eq!(ruins.len(), 7);
@@ -110,6 +119,9 @@ fn can_read_full_config() {
     let environment = config.environment.expect("no environment config");
     assert_eq!(environment.is_experimental, Some(false));
 
+    let random = environment.random.expect("no random config");
+    assert_eq!(random.seed, Some(42));
+
     let parallelism = environment.parallelism.expect("no parallelism config");
     assert_eq!(parallelism.num_thread_pools, 6);
     assert_eq!(parallelism.threads_per_pool, 8);
@@ -157,6 +169,35 @@ fn can_configure_telemetry_metrics() {
     assert_eq!(metrics.evolution.len(), 10 + 1);
 }
 
+#[test]
+fn can_reproduce_solution_with_seed() {
+    let config = Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        environment: Some(EnvironmentConfig { random: Some(RandomConfig { seed: Some(42) }), ..Default::default() }),
+        telemetry: Some(TelemetryConfig {
+            progress: None,
+            metrics: Some(MetricsConfig { enabled: true, track_population: None }),
+        }),
+        ..Default::default()
+    };
+    // NOTE use single thread to avoid influence of thread scheduling on the result
+    let solve = || {
+        ThreadPool::new(1).execute(|| {
+            create_builder_from_config(create_example_problem(), Vec::default(), &config)
+                .and_then(|config_builder| config_builder.build())
+                .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+                .and_then(|solver| solver.solve())
+                .unwrap()
+        })
+    };
+
+    let first = solve();
+    let second = solve();
+
+    assert_eq!(first.cost, second.cost);
+    assert_eq!(first.telemetry.and_then(|metrics| metrics.seed), Some(42));
+}
+
 fn as_scalar_probability(probability: &OperatorProbabilityType) -> f64 {
     match probability {
         OperatorProbabilityType::Scalar { scalar } => *scalar,
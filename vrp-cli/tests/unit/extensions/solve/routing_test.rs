@@ -0,0 +1,96 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_routing::graph::read_road_graph;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_GRAPH_PATH: &str = "../examples/data/pragmatic/simple.basic.graph.json";
+
+fn get_problem() -> Problem {
+    deserialize_problem(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap()
+}
+
+fn get_graph() -> RoadGraph {
+    read_road_graph(BufReader::new(File::open(PRAGMATIC_GRAPH_PATH).unwrap())).unwrap()
+}
+
+fn get_route(problem: &Problem, matrix: &Matrix, from: (f64, f64), to: (f64, f64)) -> (i64, i64) {
+    let locations = get_unique_locations(problem);
+    let get_index = |(lat, lng)| locations.iter().position(|location| *location == Location::new_coordinate(lat, lng));
+    let idx = get_index(from).unwrap() * locations.len() + get_index(to).unwrap();
+
+    (matrix.travel_times[idx], matrix.distances[idx])
+}
+
+parameterized_test! {can_create_road_matrices, (speed, expected), {
+    can_create_road_matrices_impl(speed, expected);
+}}
+
+can_create_road_matrices! {
+    case01_profile_road_speed: (None, (170, 2122)),
+    case02_limited_speed: (Some(10.), (212, 2122)),
+}
+
+fn can_create_road_matrices_impl(speed: Option<f64>, expected: (i64, i64)) {
+    let mut problem = get_problem();
+    problem.fleet.profiles[0].speed = speed;
+
+    let matrices = create_road_matrices(&problem, &get_graph()).unwrap();
+
+    assert_eq!(matrices.len(), 1);
+    assert_eq!(matrices[0].profile, Some("normal_car".to_string()));
+    assert_eq!(matrices[0].travel_times.len(), 16);
+    assert!(matrices[0].error_codes.is_none());
+    assert_eq!(get_route(&problem, &matrices[0], (52.5316, 13.3884), (52.5225, 13.4095)), expected);
+    assert_eq!(get_route(&problem, &matrices[0], (52.5225, 13.4095), (52.5225, 13.4095)), (0, 0));
+}
+
+#[test]
+fn can_mark_unreachable_locations() {
+    let mut problem = get_problem();
+    problem.plan.jobs[0].deliveries.as_mut().unwrap()[0].places[0].location = Location::new_coordinate(52.6, 13.6);
+    let graph = get_graph();
+    let graph = RoadGraph::new(
+        graph.coordinates().iter().cloned().chain(std::iter::once((52.6, 13.6))).collect(),
+        graph.edges().to_vec(),
+        graph.classes().to_vec(),
+    )
+    .unwrap();
+
+    let matrices = create_road_matrices(&problem, &graph).unwrap();
+
+    let error_codes = matrices[0].error_codes.as_ref().unwrap();
+    assert_eq!(error_codes.iter().filter(|&&code| code == 1).count(), 6);
+    assert_eq!(get_route(&problem, &matrices[0], (52.6, 13.6), (52.6, 13.6)), (0, 0));
+}
+
+#[test]
+fn can_detect_reference_locations() {
+    let mut problem = get_problem();
+    problem.plan.jobs[0].deliveries.as_mut().unwrap()[0].places[0].location = Location::new_reference(0);
+
+    let result = create_road_matrices(&problem, &get_graph());
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("road graph can be used only with locations specified as coordinates".to_string())
+    );
+}
+
+#[test]
+fn can_read_pragmatic_problem_with_road_geometry() {
+    let problem = get_problem();
+    let locations = get_unique_locations(&problem);
+    let graph = Arc::new(get_graph());
+
+    let core_problem = read_pragmatic_with_road_graph(problem, graph.clone()).unwrap();
+
+    let route_geometry = core_problem.extras.get_route_geometry().expect("no route geometry");
+    let geometry = route_geometry.get_geometry(0, 0, 1).expect("no geometry");
+    assert_eq!(geometry.first().cloned(), Some(locations[0].to_lat_lng()));
+    assert_eq!(geometry.last().cloned(), Some(locations[1].to_lat_lng()));
+    assert!(geometry.len() > 2);
+    assert!(geometry.iter().skip(1).take(geometry.len() - 2).all(|point| graph.coordinates().contains(point)));
+    assert!(route_geometry.get_geometry(1, 0, 1).is_none());
+}
@@ -7,8 +7,15 @@ use vrp_pragmatic::format::MultiFormatError;
 fn can_get_locations_serialized() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], ..create_empty_plan() },
-        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], resources: None },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![],
+            drivers: None,
+            resources: None,
+            dimensions: None,
+        },
         objectives: None,
+        state: None,
     };
 
     let locations = get_locations_serialized(&problem).unwrap().replace([' ', '\n'], "");
@@ -22,10 +29,13 @@ fn can_get_solution_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, fifo: None, speed_bands: None }],
+            drivers: None,
             resources: None,
+            dimensions: None,
         },
         objectives: None,
+        state: None,
     };
     let problem = Arc::new(problem.read_pragmatic().unwrap());
 
@@ -22,5 +22,11 @@ pub use self::reserved_time::*;
 mod schedule_update;
 pub use self::schedule_update::*;
 
+mod service_time;
+pub use self::service_time::*;
+
+mod soft_time_window;
+pub use self::soft_time_window::*;
+
 mod travel_info;
 pub use self::travel_info::*;
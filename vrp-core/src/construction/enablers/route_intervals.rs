@@ -76,7 +76,11 @@ pub struct FixedRouteIntervals {
 
 impl RouteIntervals for FixedRouteIntervals {
     fn is_marker_job(&self, job: &Job) -> bool {
-        job.as_single().map_or(false, |single| (self.is_marker_single_fn)(single))
+        match job {
+            Job::Single(single) => (self.is_marker_single_fn)(single),
+            // NOTE a multi job is a marker when at least one of its sub jobs is a marker
+            Job::Multi(multi) => multi.jobs.iter().any(|single| (self.is_marker_single_fn)(single)),
+        }
     }
 
     fn is_marker_assignable(&self, route: &Route, job: &Job) -> bool {
@@ -0,0 +1,265 @@
+//! Provides functionality to model service time which depends on time of day and parking (setup)
+//! time which is shared by consecutive activities at the same location.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/service_time_test.rs"]
+mod service_time_test;
+
+use crate::models::common::*;
+use crate::models::problem::{ActivityCost, Actor, Single, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use crate::models::Extras;
+use hashbrown::HashMap;
+use std::sync::Arc;
+
+const SERVICE_DURATIONS_KEY: &str = "service_durations";
+const VEHICLE_PARKING_KEY: &str = "vehicle_parking";
+const LOCATION_PARKING_KEY: &str = "location_parking";
+
+/// Specifies a piecewise constant service duration band: the band starts at given time and lasts
+/// till the start of the next one. Before the first band, original job place duration is used.
+#[derive(Clone, Debug)]
+pub struct ServiceDurationBand {
+    /// A time when the band starts.
+    pub start: Timestamp,
+    /// A service duration used when service starts within the band.
+    pub duration: Duration,
+}
+
+/// A trait to get or set time dependent service durations of job places.
+pub trait ServiceDurationDimension {
+    /// Sets service duration bands sorted by start time, one collection per job place.
+    fn set_service_durations(&mut self, durations: Vec<Option<Vec<ServiceDurationBand>>>) -> &mut Self;
+
+    /// Gets service duration bands, one collection per job place.
+    fn get_service_durations(&self) -> Option<&Vec<Option<Vec<ServiceDurationBand>>>>;
+}
+
+impl ServiceDurationDimension for Dimensions {
+    fn set_service_durations(&mut self, durations: Vec<Option<Vec<ServiceDurationBand>>>) -> &mut Self {
+        self.set_value(SERVICE_DURATIONS_KEY, durations);
+        self
+    }
+
+    fn get_service_durations(&self) -> Option<&Vec<Option<Vec<ServiceDurationBand>>>> {
+        self.get_value(SERVICE_DURATIONS_KEY)
+    }
+}
+
+/// A trait to get or set default parking time of the vehicle.
+pub trait VehicleParkingDimension {
+    /// Sets parking time used at job locations which have no parking time specified.
+    fn set_vehicle_parking(&mut self, parking: Duration) -> &mut Self;
+
+    /// Gets parking time used at job locations which have no parking time specified.
+    fn get_vehicle_parking(&self) -> Option<Duration>;
+}
+
+impl VehicleParkingDimension for Dimensions {
+    fn set_vehicle_parking(&mut self, parking: Duration) -> &mut Self {
+        self.set_value(VEHICLE_PARKING_KEY, parking);
+        self
+    }
+
+    fn get_vehicle_parking(&self) -> Option<Duration> {
+        self.get_value(VEHICLE_PARKING_KEY).cloned()
+    }
+}
+
+/// Specifies parking time per job location. `None` value means that vehicle's default parking
+/// time is used at the location.
+pub type LocationParkingIndex = HashMap<Location, Option<Duration>>;
+
+/// A trait to get or set location parking index.
+pub trait LocationParkingDimension {
+    /// Sets location parking index.
+    fn set_location_parking(&mut self, index: Arc<LocationParkingIndex>) -> &mut Self;
+
+    /// Gets location parking index.
+    fn get_location_parking(&self) -> Option<&Arc<LocationParkingIndex>>;
+}
+
+impl LocationParkingDimension for Extras {
+    fn set_location_parking(&mut self, index: Arc<LocationParkingIndex>) -> &mut Self {
+        self.set_value(LOCATION_PARKING_KEY, index);
+        self
+    }
+
+    fn get_location_parking(&self) -> Option<&Arc<LocationParkingIndex>> {
+        self.get_value(LOCATION_PARKING_KEY)
+    }
+}
+
+/// Returns service duration of the job place for given service start time.
+pub fn get_place_service_duration(
+    single: &Single,
+    place_idx: usize,
+    duration: Duration,
+    service_start: Timestamp,
+) -> Duration {
+    get_service_duration_bands(single, place_idx)
+        .map_or(duration, |bands| get_band_duration(bands, duration, service_start))
+}
+
+/// Returns service duration of the activity for given service start time.
+pub fn get_service_duration(activity: &Activity, service_start: Timestamp) -> Duration {
+    activity.job.as_ref().map_or(activity.place.duration, |single| {
+        get_place_service_duration(single, activity.place.idx, activity.place.duration, service_start)
+    })
+}
+
+/// Returns parking time needed by the actor to start serving jobs at `to` location when it comes
+/// from `from` location. Consecutive activities at the same location share parking time.
+pub fn get_location_parking_time(
+    index: &LocationParkingIndex,
+    actor: &Actor,
+    from: Location,
+    to: Location,
+) -> Duration {
+    if from == to {
+        return 0.;
+    }
+
+    index.get(&to).and_then(|parking| parking.or_else(|| actor.vehicle.dimens.get_vehicle_parking())).unwrap_or(0.)
+}
+
+/// Provides way to calculate activity costs with time dependent service durations.
+pub struct TimeDependentActivityCost {
+    inner: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl TimeDependentActivityCost {
+    /// Creates a new instance of `TimeDependentActivityCost`.
+    pub fn new(inner: Arc<dyn ActivityCost + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+impl ActivityCost for TimeDependentActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        let actor = route.actor.as_ref();
+        let service_start = arrival.max(activity.place.time.start);
+        let extra_duration = get_service_duration(activity, service_start) - activity.place.duration;
+
+        self.inner.cost(route, activity, arrival)
+            + extra_duration * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        let service_start = arrival.max(activity.place.time.start);
+        let extra_duration = get_service_duration(activity, service_start) - activity.place.duration;
+
+        self.inner.estimate_departure(route, activity, arrival) + extra_duration
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        let bands = activity.job.as_ref().and_then(|single| get_service_duration_bands(single, activity.place.idx));
+
+        match bands {
+            Some(bands) => {
+                let service_start = get_latest_service_start(bands, activity.place.duration, departure);
+                self.inner.estimate_arrival(route, activity, service_start + activity.place.duration)
+            }
+            None => self.inner.estimate_arrival(route, activity, departure),
+        }
+    }
+}
+
+/// Provides way to calculate transport costs which include parking time at job locations. Parking
+/// time is charged as service time. Time independent approximations do not include parking time.
+pub struct ParkingTransportCost {
+    inner: Arc<dyn TransportCost + Send + Sync>,
+    index: Arc<LocationParkingIndex>,
+}
+
+impl ParkingTransportCost {
+    /// Creates a new instance of `ParkingTransportCost`.
+    pub fn new(inner: Arc<dyn TransportCost + Send + Sync>, index: Arc<LocationParkingIndex>) -> Self {
+        Self { inner, index }
+    }
+
+    fn get_travel_info(
+        &self,
+        route: &Route,
+        from: Location,
+        to: Location,
+        travel_time: TravelTime,
+    ) -> (Duration, TravelTime) {
+        let parking = get_location_parking_time(self.index.as_ref(), route.actor.as_ref(), from, to);
+
+        // NOTE parking happens after driving, so the vehicle arrives to the location earlier
+        let travel_time = match travel_time {
+            TravelTime::Arrival(arrival) => TravelTime::Arrival(arrival - parking),
+            TravelTime::Departure(_) => travel_time,
+        };
+
+        (parking, travel_time)
+    }
+}
+
+impl TransportCost for ParkingTransportCost {
+    fn cost(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Cost {
+        let actor = route.actor.as_ref();
+        let (parking, travel_time) = self.get_travel_info(route, from, to, travel_time);
+
+        self.inner.cost(route, from, to, travel_time)
+            + parking * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
+    }
+
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let (parking, travel_time) = self.get_travel_info(route, from, to, travel_time);
+
+        self.inner.duration(route, from, to, travel_time) + parking
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        let (_, travel_time) = self.get_travel_info(route, from, to, travel_time);
+
+        self.inner.distance(route, from, to, travel_time)
+    }
+
+    fn neighbours(&self, profile: &Profile, from: Location) -> Option<Vec<Location>> {
+        self.inner.neighbours(profile, from)
+    }
+}
+
+fn get_service_duration_bands(single: &Single, place_idx: usize) -> Option<&Vec<ServiceDurationBand>> {
+    single
+        .dimens
+        .get_service_durations()
+        .and_then(|durations| durations.get(place_idx))
+        .and_then(|bands| bands.as_ref())
+        .filter(|bands| !bands.is_empty())
+}
+
+fn get_band_duration(bands: &[ServiceDurationBand], duration: Duration, service_start: Timestamp) -> Duration {
+    bands.iter().rev().find(|band| band.start <= service_start).map_or(duration, |band| band.duration)
+}
+
+/// Returns the latest service start which allows to finish service not later than given departure.
+fn get_latest_service_start(bands: &[ServiceDurationBand], duration: Duration, departure: Timestamp) -> Timestamp {
+    let first = (Timestamp::MIN, bands.first().map_or(Timestamp::MAX, |band| band.start), duration);
+    let others = bands
+        .iter()
+        .enumerate()
+        .map(|(idx, band)| (band.start, bands.get(idx + 1).map_or(Timestamp::MAX, |next| next.start), band.duration));
+
+    // NOTE the first segment always has a solution as it is not limited from the left. Segment end
+    //      is exclusive: service started exactly at the next band start has the next band duration
+    std::iter::once(first)
+        .chain(others)
+        .filter_map(|(from, to, duration)| {
+            let service_start = departure - duration;
+            let service_start = if service_start < to { service_start } else { to.next_down() };
+            (service_start >= from).then_some(service_start)
+        })
+        .fold(Timestamp::MIN, |acc, service_start| acc.max(service_start))
+}
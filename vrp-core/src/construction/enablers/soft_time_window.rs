@@ -0,0 +1,223 @@
+//! Provides functionality to model soft time windows: a job time window which can be violated
+//! within some tolerated span at the cost of penalty.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/soft_time_window_test.rs"]
+mod soft_time_window_test;
+
+use crate::construction::heuristics::{RouteContext, StateKey};
+use crate::models::common::*;
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use rosomaxa::prelude::compare_floats;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+const TIME_WINDOW_TOLERANCES_KEY: &str = "tw_tolerances";
+
+/// Specifies how much a job time window can be violated and at which cost.
+#[derive(Clone, Debug)]
+pub struct TimeWindowTolerance {
+    /// A max duration of service start before time window start.
+    pub earliness: Duration,
+    /// A max duration of service start after time window end.
+    pub lateness: Duration,
+    /// A penalty per time unit of earliness or lateness.
+    pub penalty: Cost,
+}
+
+impl TimeWindowTolerance {
+    /// Creates a new instance of `TimeWindowTolerance`.
+    pub fn new(earliness: Duration, lateness: Duration, penalty: Cost) -> Self {
+        Self { earliness, lateness, penalty }
+    }
+
+    /// Expands original time window by tolerated spans. Expanded time window is used as hard one.
+    pub fn expand(&self, time: &TimeWindow) -> TimeWindow {
+        TimeWindow::new(time.start - self.earliness, time.end + self.lateness)
+    }
+
+    /// Returns service start at given arrival time. Time window is expected to be expanded.
+    /// A vehicle which arrives before original time window start waits for it, unless earliness
+    /// penalty is lower than given waiting cost per time unit, so it is better to start service early.
+    pub fn get_service_start(&self, time: &TimeWindow, arrival: Timestamp, waiting_cost: Cost) -> Timestamp {
+        let original_start = time.start + self.earliness;
+
+        if arrival < original_start && self.penalty >= waiting_cost {
+            original_start
+        } else {
+            arrival.max(time.start)
+        }
+    }
+
+    /// Returns earliness and lateness of service start at given time. Time window is expected
+    /// to be expanded.
+    pub fn deviation(&self, time: &TimeWindow, service_start: Timestamp) -> (Duration, Duration) {
+        let earliness = (time.start + self.earliness - service_start).max(0.);
+        let lateness = (service_start - (time.end - self.lateness)).max(0.);
+
+        (earliness, lateness)
+    }
+
+    /// Returns penalty of service start at given time. Time window is expected to be expanded.
+    pub fn get_penalty(&self, time: &TimeWindow, service_start: Timestamp) -> Cost {
+        let (earliness, lateness) = self.deviation(time, service_start);
+
+        (earliness + lateness) * self.penalty
+    }
+}
+
+/// A trait to get or set time window tolerances of job places.
+pub trait TimeWindowToleranceDimension {
+    /// Sets time window tolerances, one per job place.
+    fn set_time_window_tolerances(&mut self, tolerances: Vec<Option<TimeWindowTolerance>>) -> &mut Self;
+
+    /// Gets time window tolerances, one per job place.
+    fn get_time_window_tolerances(&self) -> Option<&Vec<Option<TimeWindowTolerance>>>;
+}
+
+impl TimeWindowToleranceDimension for Dimensions {
+    fn set_time_window_tolerances(&mut self, tolerances: Vec<Option<TimeWindowTolerance>>) -> &mut Self {
+        self.set_value(TIME_WINDOW_TOLERANCES_KEY, tolerances);
+        self
+    }
+
+    fn get_time_window_tolerances(&self) -> Option<&Vec<Option<TimeWindowTolerance>>> {
+        self.get_value(TIME_WINDOW_TOLERANCES_KEY)
+    }
+}
+
+/// Returns time window tolerance of the job place used by given activity.
+pub fn get_time_window_tolerance(activity: &Activity) -> Option<&TimeWindowTolerance> {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_time_window_tolerances())
+        .and_then(|tolerances| tolerances.get(activity.place.idx))
+        .and_then(|tolerance| tolerance.as_ref())
+}
+
+/// Returns service start of the activity at given arrival time taking into account its soft time window.
+pub fn get_time_window_service_start(route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+    match get_time_window_tolerance(activity) {
+        Some(tolerance) => {
+            let actor = route.actor.as_ref();
+            let waiting_cost = actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time;
+
+            tolerance.get_service_start(&activity.place.time, arrival, waiting_cost)
+        }
+        None => arrival.max(activity.place.time.start),
+    }
+}
+
+/// Returns earliness and lateness of the activity service start at given time.
+pub fn get_time_window_deviation(activity: &Activity, service_start: Timestamp) -> Option<(Duration, Duration)> {
+    get_time_window_tolerance(activity).map(|tolerance| tolerance.deviation(&activity.place.time, service_start))
+}
+
+/// Updates route state with total penalty of soft time window violations and with index of the last
+/// activity which has a soft time window.
+pub fn update_time_window_penalty(route_ctx: &mut RouteContext, penalty_key: StateKey, last_idx_key: StateKey) {
+    let route = route_ctx.route();
+    let (penalty, last_idx) = route.tour.all_activities().enumerate().fold(
+        (Cost::default(), None),
+        |(penalty, last_idx), (idx, activity)| match get_time_window_tolerance(activity) {
+            Some(tolerance) => {
+                let service_start = get_time_window_service_start(route, activity, activity.schedule.arrival);
+                (penalty + tolerance.get_penalty(&activity.place.time, service_start), Some(idx))
+            }
+            None => (penalty, last_idx),
+        },
+    );
+
+    route_ctx.state_mut().put_route_state(penalty_key, penalty);
+    route_ctx.state_mut().put_route_state::<Option<usize>>(last_idx_key, last_idx);
+}
+
+/// Estimates a change of soft time window penalty of the activities after the one at given index
+/// when it departs at given time instead of its scheduled departure. Activities are visited until
+/// the departure shift is absorbed or there are no activities with soft time window ahead.
+pub fn estimate_time_window_penalty_delta(
+    route_ctx: &RouteContext,
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+    activity_idx: usize,
+    departure: Timestamp,
+    last_idx_key: StateKey,
+) -> Cost {
+    let route = route_ctx.route();
+    let Some(last_idx) = route_ctx.state().get_route_state::<Option<usize>>(last_idx_key).copied().flatten() else {
+        return Cost::default();
+    };
+    let Some(prev) = route.tour.get(activity_idx) else {
+        return Cost::default();
+    };
+
+    (activity_idx + 1..=last_idx)
+        .filter_map(|idx| route.tour.get(idx))
+        .try_fold((Cost::default(), prev, departure), |(delta, prev, departure), current| {
+            if compare_floats(departure, prev.schedule.departure) == Ordering::Equal {
+                return Err(delta);
+            }
+
+            let arrival = departure
+                + transport.duration(
+                    route,
+                    prev.place.location,
+                    current.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            let delta = delta
+                + get_time_window_tolerance(current).map_or(Cost::default(), |tolerance| {
+                    let old_start = get_time_window_service_start(route, current, current.schedule.arrival);
+                    let new_start = get_time_window_service_start(route, current, arrival);
+
+                    tolerance.get_penalty(&current.place.time, new_start)
+                        - tolerance.get_penalty(&current.place.time, old_start)
+                });
+
+            Ok((delta, current, activity.estimate_departure(route, current, arrival)))
+        })
+        .map_or_else(|delta| delta, |(delta, _, _)| delta)
+}
+
+/// Provides way to calculate activity costs which include penalty for soft time window violations.
+///
+/// Please note, that activity cost covers only given activity, a penalty change caused by delaying
+/// further activities of the route is estimated by `estimate_time_window_penalty_delta`.
+pub struct SoftTimeWindowActivityCost {
+    inner: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl SoftTimeWindowActivityCost {
+    /// Creates a new instance of `SoftTimeWindowActivityCost`.
+    pub fn new(inner: Arc<dyn ActivityCost + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+impl ActivityCost for SoftTimeWindowActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        let Some(tolerance) = get_time_window_tolerance(activity) else {
+            return self.inner.cost(route, activity, arrival);
+        };
+
+        let actor = route.actor.as_ref();
+        let waiting_cost = actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time;
+        let service_start = tolerance.get_service_start(&activity.place.time, arrival, waiting_cost);
+        let penalty = tolerance.get_penalty(&activity.place.time, service_start);
+
+        self.inner.cost(route, activity, service_start) + (service_start - arrival) * waiting_cost + penalty
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        let service_start = get_time_window_service_start(route, activity, arrival);
+
+        self.inner.estimate_departure(route, activity, service_start)
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        self.inner.estimate_arrival(route, activity, departure)
+    }
+}
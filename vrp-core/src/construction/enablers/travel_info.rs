@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/travel_info_test.rs"]
+mod travel_info_test;
+
 use crate::construction::heuristics::{ActivityContext, RouteContext};
 use crate::models::common::{Distance, Duration, Timestamp};
 use crate::models::problem::{TransportCost, TravelTime};
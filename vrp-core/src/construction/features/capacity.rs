@@ -108,10 +108,9 @@ impl<T: LoadOps> MultiTrip for CapacitatedMultiTrip<T> {
                 // determine static deliveries loaded at the begin and static pickups brought to the end
                 let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
                     (acc, T::default()),
-                    |acc, activity| {
-                        get_demand(activity)
-                            .map(|demand| (acc.0 + demand.delivery.0, acc.1 + demand.pickup.0))
-                            .unwrap_or_else(|| acc)
+                    |acc, activity| match get_demand::<T>(activity) {
+                        Some(demand) => (acc.0 + demand.delivery.0.clone(), acc.1 + demand.pickup.0.clone()),
+                        None => acc,
                     },
                 );
 
@@ -123,18 +122,19 @@ impl<T: LoadOps> MultiTrip for CapacitatedMultiTrip<T> {
                         let change = get_demand(activity).map(|demand| demand.change()).unwrap_or_else(T::default);
 
                         let current = current + change;
-                        let max = max.max_load(current);
+                        let max = max.max_load(current.clone());
 
-                        state.put_activity_state(CURRENT_CAPACITY_KEY, activity_idx, current);
-                        state.put_activity_state(MAX_PAST_CAPACITY_KEY, activity_idx, max);
+                        state.put_activity_state(CURRENT_CAPACITY_KEY, activity_idx, current.clone());
+                        state.put_activity_state(MAX_PAST_CAPACITY_KEY, activity_idx, max.clone());
 
                         (current, max)
                     },
                 );
 
-                let current_max = (start_idx..=end_idx).rev().fold(current, |max, activity_idx| {
-                    let max = max.max_load(*state.get_activity_state(CURRENT_CAPACITY_KEY, activity_idx).unwrap());
-                    state.put_activity_state(MAX_FUTURE_CAPACITY_KEY, activity_idx, max);
+                let current_max = (start_idx..=end_idx).rev().fold(current.clone(), |max, activity_idx| {
+                    let current = state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity_idx).unwrap().clone();
+                    let max = max.max_load(current);
+                    state.put_activity_state(MAX_FUTURE_CAPACITY_KEY, activity_idx, max.clone());
                     max
                 });
 
@@ -249,7 +249,7 @@ fn has_demand_violation<T: LoadOps>(
     stopped: bool,
 ) -> Option<bool> {
     let demand = demand?;
-    let capacity = if let Some(capacity) = capacity.copied() {
+    let capacity = if let Some(capacity) = capacity.cloned() {
         capacity
     } else {
         return Some(stopped);
@@ -257,16 +257,16 @@ fn has_demand_violation<T: LoadOps>(
 
     // check how static delivery affect past max load
     if demand.delivery.0.is_not_empty() {
-        let past: T = state.get_activity_state(MAX_PAST_CAPACITY_KEY, pivot_idx).copied().unwrap_or_default();
-        if !capacity.can_fit(&(past + demand.delivery.0)) {
+        let past: T = state.get_activity_state(MAX_PAST_CAPACITY_KEY, pivot_idx).cloned().unwrap_or_default();
+        if !capacity.can_fit(&(past + demand.delivery.0.clone())) {
             return Some(stopped);
         }
     }
 
     // check how static pickup affect future max load
     if demand.pickup.0.is_not_empty() {
-        let future: T = state.get_activity_state(MAX_FUTURE_CAPACITY_KEY, pivot_idx).copied().unwrap_or_default();
-        if !capacity.can_fit(&(future + demand.pickup.0)) {
+        let future: T = state.get_activity_state(MAX_FUTURE_CAPACITY_KEY, pivot_idx).cloned().unwrap_or_default();
+        if !capacity.can_fit(&(future + demand.pickup.0.clone())) {
             return Some(false);
         }
     }
@@ -274,12 +274,12 @@ fn has_demand_violation<T: LoadOps>(
     // check dynamic load change
     let change = demand.change();
     if change.is_not_empty() {
-        let future: T = state.get_activity_state(MAX_FUTURE_CAPACITY_KEY, pivot_idx).copied().unwrap_or_default();
-        if !capacity.can_fit(&(future + change)) {
+        let future: T = state.get_activity_state(MAX_FUTURE_CAPACITY_KEY, pivot_idx).cloned().unwrap_or_default();
+        if !capacity.can_fit(&(future + change.clone())) {
             return Some(false);
         }
 
-        let current: T = state.get_activity_state(CURRENT_CAPACITY_KEY, pivot_idx).copied().unwrap_or_default();
+        let current: T = state.get_activity_state(CURRENT_CAPACITY_KEY, pivot_idx).cloned().unwrap_or_default();
         if !capacity.can_fit(&(current + change)) {
             return Some(false);
         }
@@ -0,0 +1,458 @@
+//! A feature to model vehicles with multiple compartments: each job with demand is loaded into a single
+//! compartment which accepts job's product, and load of every compartment is limited through the route.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/compartments_test.rs"]
+mod compartments_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+use hashbrown::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+const VEHICLE_COMPARTMENTS_KEY: &str = "vehicle_compartments";
+const JOB_PRODUCT_KEY: &str = "job_product";
+
+/// Specifies how many assignment alternatives are explored before the search gives up.
+const MAX_ASSIGNMENT_STEPS: usize = 10_000;
+
+/// Specifies a vehicle compartment.
+#[derive(Clone, Debug)]
+pub struct Compartment<T: LoadOps> {
+    /// Compartment id.
+    pub id: String,
+    /// Compartment capacity.
+    pub capacity: T,
+    /// Products accepted by compartment. A compartment without products accepts any job.
+    pub products: Option<HashSet<String>>,
+}
+
+impl<T: LoadOps> Compartment<T> {
+    /// Checks whether compartment accepts given job's product. A job without product can be
+    /// loaded only into a compartment without products restriction.
+    pub fn accepts(&self, product: Option<&String>) -> bool {
+        match (self.products.as_ref(), product) {
+            (None, _) => true,
+            (Some(products), Some(product)) => products.contains(product),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+/// A trait to get or set vehicle compartments.
+pub trait VehicleCompartmentsDimension<T: LoadOps> {
+    /// Sets vehicle compartments.
+    fn set_vehicle_compartments(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self;
+
+    /// Gets vehicle compartments.
+    fn get_vehicle_compartments(&self) -> Option<&Vec<Compartment<T>>>;
+}
+
+impl<T: LoadOps> VehicleCompartmentsDimension<T> for Dimensions {
+    fn set_vehicle_compartments(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self {
+        self.set_value(VEHICLE_COMPARTMENTS_KEY, compartments);
+        self
+    }
+
+    fn get_vehicle_compartments(&self) -> Option<&Vec<Compartment<T>>> {
+        self.get_value(VEHICLE_COMPARTMENTS_KEY)
+    }
+}
+
+/// A trait to get or set job product.
+pub trait JobProductDimension {
+    /// Sets job product.
+    fn set_job_product(&mut self, product: String) -> &mut Self;
+
+    /// Gets job product.
+    fn get_job_product(&self) -> Option<&String>;
+}
+
+impl JobProductDimension for Dimensions {
+    fn set_job_product(&mut self, product: String) -> &mut Self {
+        self.set_value(JOB_PRODUCT_KEY, product);
+        self
+    }
+
+    fn get_job_product(&self) -> Option<&String> {
+        self.get_value(JOB_PRODUCT_KEY)
+    }
+}
+
+/// Creates a feature which assigns demand of each job to one of vehicle compartments accepting job's
+/// product and limits load of each compartment. Vehicles without compartments are not affected.
+/// This is a hard constraint.
+///
+/// NOTE compartment loads are not reset by reloads.
+pub fn create_compartments_feature<T: LoadOps>(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CompartmentsConstraint::<T> { code, phantom: Default::default() })
+        .with_state(CompartmentsState::<T> {
+            state_keys: vec![COMPARTMENT_LOADS_KEY, COMPARTMENT_ASSIGNMENT_KEY],
+            phantom: Default::default(),
+        })
+        .build()
+}
+
+/// Finds an assignment of route jobs with demand to vehicle compartments (as index in the vehicle's
+/// compartments list) which respects products compatibility and compartment capacities. Jobs are
+/// assigned in tour order to the first fitting compartment, other alternatives are explored only
+/// if such greedy assignment fails. Returns `None` if the vehicle has no compartments or no
+/// assignment is found.
+pub fn find_compartment_assignment<T: LoadOps>(route: &Route) -> Option<HashMap<Job, usize>> {
+    let compartments = route.actor.vehicle.dimens.get_vehicle_compartments()?;
+    let job_loads = get_job_loads::<T>(route);
+    let mut profiles = vec![vec![T::default(); route.tour.total()]; compartments.len()];
+    let mut assignment = HashMap::default();
+    let mut steps = 0;
+
+    if assign_jobs(compartments, job_loads.as_slice(), &mut profiles, &mut assignment, &mut steps) {
+        Some(assignment)
+    } else {
+        None
+    }
+}
+
+/// Keeps compartment loads at the activity: the load after the activity, maximum load in the past
+/// and in the future of the route.
+struct CompartmentLoads<T: LoadOps> {
+    current: Vec<T>,
+    max_past: Vec<T>,
+    max_future: Vec<T>,
+}
+
+struct CompartmentsConstraint<T: LoadOps> {
+    code: ViolationCode,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> CompartmentsConstraint<T> {
+    fn evaluate_job(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
+        let compartments = get_compartments::<T>(&route_ctx.route().actor)?;
+
+        let has_demand = match job {
+            Job::Single(single) => has_demand::<T>(&single.dimens),
+            Job::Multi(multi) => multi.jobs.iter().any(|single| has_demand::<T>(&single.dimens)),
+        };
+        let product = job.dimens().get_job_product();
+
+        if has_demand && !compartments.iter().any(|compartment| compartment.accepts(product)) {
+            ConstraintViolation::fail(self.code)
+        } else {
+            ConstraintViolation::success()
+        }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let compartments = get_compartments::<T>(&route_ctx.route().actor)?;
+        let demand = get_demand::<T>(activity_ctx.target)?;
+        let job = activity_ctx.target.retrieve_job()?;
+        let product = job.dimens().get_job_product();
+
+        let state = route_ctx.state();
+        let loads = state.get_activity_state::<CompartmentLoads<T>>(COMPARTMENT_LOADS_KEY, activity_ctx.index);
+
+        // NOTE a partially inserted multi job has to use the same compartment
+        let assigned = if route_ctx.route().tour.contains(&job) {
+            state
+                .get_route_state::<HashMap<Job, usize>>(COMPARTMENT_ASSIGNMENT_KEY)
+                .and_then(|assignment| assignment.get(&job))
+                .copied()
+        } else {
+            None
+        };
+
+        let can_fit = compartments
+            .iter()
+            .enumerate()
+            .filter(|(idx, compartment)| assigned.map_or_else(|| compartment.accepts(product), |other| other == *idx))
+            .any(|(idx, compartment)| {
+                let get_load = |values: Option<&Vec<T>>| values.and_then(|values| values.get(idx)).cloned();
+                let current = get_load(loads.map(|loads| &loads.current)).unwrap_or_default();
+                let max_past = get_load(loads.map(|loads| &loads.max_past)).unwrap_or_default();
+                let max_future = get_load(loads.map(|loads| &loads.max_future)).unwrap_or_default();
+
+                can_fit_demand(&compartment.capacity, demand, current, max_past, max_future)
+            });
+
+        if can_fit {
+            ConstraintViolation::success()
+        } else {
+            ConstraintViolation::skip(self.code)
+        }
+    }
+}
+
+impl<T: LoadOps> FeatureConstraint for CompartmentsConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.evaluate_job(route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if source.dimens().get_job_product() == candidate.dimens().get_job_product() {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+}
+
+struct CompartmentsState<T: LoadOps> {
+    state_keys: Vec<StateKey>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureState for CompartmentsState<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(&mut solution_ctx.routes[route_index]);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let actor = route_ctx.route().actor.clone();
+        let compartments = if let Some(compartments) = get_compartments::<T>(&actor) {
+            compartments
+        } else {
+            return;
+        };
+
+        let job_loads = get_job_loads::<T>(route_ctx.route());
+        let previous = route_ctx.state().get_route_state::<HashMap<Job, usize>>(COMPARTMENT_ASSIGNMENT_KEY);
+        let assignment = get_stable_assignment(compartments, job_loads.as_slice(), previous);
+
+        let total = route_ctx.route().tour.total();
+        let mut profiles = vec![vec![T::default(); total]; compartments.len()];
+        job_loads.iter().for_each(|(job, loads)| {
+            if let Some(idx) = assignment.get(job) {
+                add_loads(&mut profiles[*idx], loads);
+            }
+        });
+
+        let max_past = profiles.iter().map(|profile| get_running_max(profile.iter())).collect::<Vec<_>>();
+        let max_future = profiles
+            .iter()
+            .map(|profile| {
+                let mut max_future = get_running_max(profile.iter().rev());
+                max_future.reverse();
+                max_future
+            })
+            .collect::<Vec<_>>();
+
+        let state = route_ctx.state_mut();
+        (0..total).for_each(|activity_idx| {
+            let get_loads = |values: &[Vec<T>]| values.iter().map(|loads| loads[activity_idx].clone()).collect();
+            state.put_activity_state(
+                COMPARTMENT_LOADS_KEY,
+                activity_idx,
+                CompartmentLoads::<T> {
+                    current: get_loads(profiles.as_slice()),
+                    max_past: get_loads(max_past.as_slice()),
+                    max_future: get_loads(max_future.as_slice()),
+                },
+            );
+        });
+        state.put_route_state(COMPARTMENT_ASSIGNMENT_KEY, assignment);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx))
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Keeps previously assigned compartments, so accepted insertions stay valid, and assigns new jobs
+/// to the first fitting compartment.
+fn get_stable_assignment<T: LoadOps>(
+    compartments: &[Compartment<T>],
+    job_loads: &[(Job, Vec<T>)],
+    previous: Option<&HashMap<Job, usize>>,
+) -> HashMap<Job, usize> {
+    let total = job_loads.first().map_or(0, |(_, loads)| loads.len());
+    let mut profiles = vec![vec![T::default(); total]; compartments.len()];
+    let mut assignment = HashMap::default();
+
+    job_loads.iter().for_each(|(job, loads)| {
+        if let Some(&idx) = previous.and_then(|previous| previous.get(job)).filter(|&&idx| idx < compartments.len()) {
+            add_loads(&mut profiles[idx], loads);
+            assignment.insert(job.clone(), idx);
+        }
+    });
+
+    let mut is_feasible = true;
+    job_loads.iter().for_each(|(job, loads)| {
+        if assignment.contains_key(job) {
+            return;
+        }
+
+        let product = job.dimens().get_job_product();
+        let fitting = compartments.iter().enumerate().find(|(idx, compartment)| {
+            compartment.accepts(product) && can_fit_loads(&compartment.capacity, &profiles[*idx], loads)
+        });
+
+        // NOTE a job which does not fit anywhere is put into the first compatible compartment
+        let idx = fitting.map(|(idx, _)| idx).or_else(|| {
+            is_feasible = false;
+            compartments.iter().position(|compartment| compartment.accepts(product))
+        });
+
+        if let Some(idx) = idx {
+            add_loads(&mut profiles[idx], loads);
+            assignment.insert(job.clone(), idx);
+        }
+    });
+
+    if !is_feasible {
+        // NOTE this can happen when route is modified without insertion evaluation
+        let mut profiles = vec![vec![T::default(); total]; compartments.len()];
+        let mut other = HashMap::default();
+        let mut steps = 0;
+        if assign_jobs(compartments, job_loads, &mut profiles, &mut other, &mut steps) {
+            return other;
+        }
+    }
+
+    assignment
+}
+
+/// Assigns jobs to compartments using depth first search.
+fn assign_jobs<T: LoadOps>(
+    compartments: &[Compartment<T>],
+    job_loads: &[(Job, Vec<T>)],
+    profiles: &mut [Vec<T>],
+    assignment: &mut HashMap<Job, usize>,
+    steps: &mut usize,
+) -> bool {
+    let ((job, loads), rest) = if let Some(item) = job_loads.split_first() {
+        item
+    } else {
+        return true;
+    };
+
+    let product = job.dimens().get_job_product();
+
+    for (idx, compartment) in compartments.iter().enumerate() {
+        if *steps > MAX_ASSIGNMENT_STEPS {
+            return false;
+        }
+        *steps += 1;
+
+        if !compartment.accepts(product) || !can_fit_loads(&compartment.capacity, &profiles[idx], loads) {
+            continue;
+        }
+
+        add_loads(&mut profiles[idx], loads);
+        if assign_jobs(compartments, rest, profiles, assignment, steps) {
+            assignment.insert(job.clone(), idx);
+            return true;
+        }
+        sub_loads(&mut profiles[idx], loads);
+    }
+
+    false
+}
+
+/// Returns jobs with demand in the order of their first appearance in the tour with their load
+/// contribution after each activity of the tour.
+fn get_job_loads<T: LoadOps>(route: &Route) -> Vec<(Job, Vec<T>)> {
+    let total = route.tour.total();
+    let mut positions = HashMap::<Job, usize>::default();
+    let mut job_loads: Vec<(Job, Vec<T>)> = Vec::new();
+
+    // NOTE static deliveries are loaded at the tour start
+    let changes = route
+        .tour
+        .all_activities()
+        .map(|activity| {
+            let (job, demand) = activity.retrieve_job().zip(get_demand::<T>(activity))?;
+            let job_idx = *positions.entry(job.clone()).or_insert_with(|| {
+                job_loads.push((job, vec![T::default(); total]));
+                job_loads.len() - 1
+            });
+
+            let loads = &mut job_loads[job_idx].1;
+            loads[0] = loads[0].clone() + demand.delivery.0.clone();
+
+            Some((job_idx, demand.change()))
+        })
+        .collect::<Vec<_>>();
+
+    job_loads.iter_mut().enumerate().for_each(|(job_idx, (_, loads))| {
+        (1..total).for_each(|activity_idx| {
+            let change = changes[activity_idx].as_ref().filter(|(other_idx, _)| *other_idx == job_idx);
+            loads[activity_idx] = match change {
+                Some((_, change)) => loads[activity_idx - 1].clone() + change.clone(),
+                None => loads[activity_idx - 1].clone(),
+            };
+        });
+    });
+
+    job_loads
+}
+
+fn can_fit_demand<T: LoadOps>(capacity: &T, demand: &Demand<T>, current: T, max_past: T, max_future: T) -> bool {
+    // check how static delivery affect past max load
+    if demand.delivery.0.is_not_empty() && !capacity.can_fit(&(max_past + demand.delivery.0.clone())) {
+        return false;
+    }
+
+    // check how static pickup affect future max load
+    if demand.pickup.0.is_not_empty() && !capacity.can_fit(&(max_future.clone() + demand.pickup.0.clone())) {
+        return false;
+    }
+
+    // check dynamic load change
+    let change = demand.change();
+    if change.is_not_empty() {
+        return capacity.can_fit(&(max_future + change.clone())) && capacity.can_fit(&(current + change));
+    }
+
+    true
+}
+
+fn can_fit_loads<T: LoadOps>(capacity: &T, profile: &[T], loads: &[T]) -> bool {
+    profile.iter().zip(loads.iter()).all(|(current, load)| capacity.can_fit(&(current.clone() + load.clone())))
+}
+
+fn add_loads<T: LoadOps>(profile: &mut [T], loads: &[T]) {
+    profile.iter_mut().zip(loads.iter()).for_each(|(current, load)| *current = current.clone() + load.clone());
+}
+
+fn sub_loads<T: LoadOps>(profile: &mut [T], loads: &[T]) {
+    profile.iter_mut().zip(loads.iter()).for_each(|(current, load)| *current = current.clone() - load.clone());
+}
+
+fn get_running_max<'a, T: LoadOps>(loads: impl Iterator<Item = &'a T>) -> Vec<T> {
+    loads
+        .scan(T::default(), |max, load| {
+            *max = max.clone().max_load(load.clone());
+            Some(max.clone())
+        })
+        .collect()
+}
+
+fn get_compartments<T: LoadOps>(actor: &Actor) -> Option<&Vec<Compartment<T>>> {
+    actor.vehicle.dimens.get_vehicle_compartments()
+}
+
+fn has_demand<T: LoadOps>(dimens: &Dimensions) -> bool {
+    let demand: Option<&Demand<T>> = dimens.get_demand();
+    demand.is_some()
+}
+
+fn get_demand<T: LoadOps>(activity: &Activity) -> Option<&Demand<T>> {
+    activity.job.as_ref().and_then(|job| job.dimens.get_demand())
+}
@@ -0,0 +1,66 @@
+//! A feature to limit amount of vehicles which can start their tours at the same depot.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/depot_capacity_test.rs"]
+mod depot_capacity_test;
+
+use super::*;
+use hashbrown::HashMap;
+
+/// Creates a feature which limits amount of tours started at the given depot locations.
+/// This is a hard constraint.
+pub fn create_depot_capacity_feature(
+    name: &str,
+    code: ViolationCode,
+    capacities: HashMap<Location, usize>,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_constraint(DepotCapacityConstraint { code, capacities }).build()
+}
+
+struct DepotCapacityConstraint {
+    code: ViolationCode,
+    capacities: HashMap<Location, usize>,
+}
+
+impl DepotCapacityConstraint {
+    fn evaluate_route(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext) -> Option<ConstraintViolation> {
+        // NOTE only a new tour can exceed depot capacity
+        if route_ctx.route().tour.has_jobs() {
+            return None;
+        }
+
+        let actor = &route_ctx.route().actor;
+        let location = get_start_location(actor)?;
+        let capacity = self.capacities.get(&location)?;
+
+        let used = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| &route_ctx.route().actor)
+            .filter(|other| *other != actor && get_start_location(other) == Some(location))
+            .count();
+
+        if used >= *capacity {
+            Some(ConstraintViolation { code: self.code, stopped: true })
+        } else {
+            None
+        }
+    }
+}
+
+impl FeatureConstraint for DepotCapacityConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, .. } => self.evaluate_route(solution_ctx, route_ctx),
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+fn get_start_location(actor: &Actor) -> Option<Location> {
+    actor.detail.start.as_ref().map(|start| start.location)
+}
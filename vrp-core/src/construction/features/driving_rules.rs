@@ -0,0 +1,360 @@
+//! A feature to model driving time legislation, such as EU drive time regulation: continuous
+//! driving time is limited, so a break has to be taken once the limit is reached, and total driving
+//! time of the tour is capped. Breaks are modeled as marker jobs which split the tour into intervals
+//! and are inserted into the tour only when they are required.
+//!
+//! A break which can be split into parts is modeled as a multi job: only its last sub job is a marker
+//! which resets driving time, preceding sub jobs have to be placed within the same interval.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/driving_rules_test.rs"]
+mod driving_rules_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::problem::Multi;
+use crate::models::solution::Route;
+use hashbrown::HashSet;
+
+/// Specifies driving time limits of the vehicle.
+#[derive(Clone, Debug)]
+pub struct DrivingRules {
+    /// Max continuous driving time after which a break has to be taken.
+    pub max_driving_time: Duration,
+    /// Max total driving time of the tour.
+    pub max_total_driving_time: Duration,
+}
+
+/// Specifies a driving rules function. It should return a fixed value for the same actor all the time.
+pub type DrivingRulesFn = Arc<dyn Fn(&Actor) -> Option<DrivingRules> + Send + Sync>;
+
+/// Specifies a function which checks whether given single job is a driving break.
+pub type DrivingBreakFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+
+/// Specifies a function which checks whether given driving break job can be assigned to the route.
+pub type DrivingBreakAssignableFn = Box<dyn Fn(&Route, &Job) -> bool + Send + Sync>;
+
+/// Creates a feature which limits continuous and total driving time of the tour. Driving break jobs
+/// are inserted into the tour when continuous driving time limit is reached.
+pub fn create_driving_rules_feature(
+    name: &str,
+    code: ViolationCode,
+    driving_rules_fn: DrivingRulesFn,
+    is_break_single_fn: DrivingBreakFn,
+    is_assignable_fn: DrivingBreakAssignableFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) -> Result<Feature, GenericError> {
+    create_multi_trip_feature(
+        name,
+        code,
+        &[DRIVING_TIME_KEY, TOTAL_DRIVING_TIME_KEY, DRIVING_INTERVALS_KEY],
+        MarkerInsertionPolicy::Any,
+        Arc::new(DrivingRulesMultiTrip {
+            route_intervals: FixedRouteIntervals {
+                is_marker_single_fn: Box::new({
+                    let is_break_single_fn = is_break_single_fn.clone();
+                    move |single| (is_break_single_fn)(single)
+                }),
+                is_new_interval_needed_fn: Box::new({
+                    let driving_rules_fn = driving_rules_fn.clone();
+                    move |route_ctx| {
+                        route_ctx.route().tour.end_idx().is_some_and(|end_idx| {
+                            let current = get_driving_time(route_ctx, end_idx);
+
+                            (driving_rules_fn)(route_ctx.route().actor.as_ref())
+                                .is_some_and(|rules| current > rules.max_driving_time)
+                        })
+                    }
+                }),
+                is_obsolete_interval_fn: Box::new({
+                    let driving_rules_fn = driving_rules_fn.clone();
+                    let transport = transport.clone();
+                    move |route_ctx, left, right| {
+                        let route = route_ctx.route();
+
+                        // NOTE a split break is removed when its first part is not a break anymore,
+                        //      e.g. when jobs before it were removed
+                        let is_first_part_invalid = route
+                            .tour
+                            .get(right.start)
+                            .and_then(|activity| activity.job.as_ref())
+                            .is_some_and(|single| !is_first_part_kept(route_ctx, single, left.start));
+                        if is_first_part_invalid {
+                            return true;
+                        }
+
+                        // NOTE a break without location keeps location of its original neighbour when
+                        //      the neighbour is moved, so it is removed only when it is not needed anymore
+                        let end_idx = get_end_idx(route_ctx, right.end);
+                        let new_driving_time = get_driving_time(route_ctx, left.end)
+                            + get_driving_time(route_ctx, end_idx)
+                            - get_driving_time(route_ctx, right.start + 1)
+                            + get_travel_duration(transport.as_ref(), route, left.end, right.start + 1);
+
+                        (driving_rules_fn)(route.actor.as_ref())
+                            .is_some_and(|rules| new_driving_time <= rules.max_driving_time)
+                    }
+                }),
+                is_assignable_fn,
+                intervals_key: DRIVING_INTERVALS_KEY,
+            },
+            transport,
+            code,
+            driving_rules_fn,
+            is_break_single_fn,
+        }),
+    )
+}
+
+struct DrivingRulesMultiTrip {
+    route_intervals: FixedRouteIntervals,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    code: ViolationCode,
+    driving_rules_fn: DrivingRulesFn,
+    is_break_single_fn: DrivingBreakFn,
+}
+
+impl MultiTrip for DrivingRulesMultiTrip {
+    fn get_route_intervals(&self) -> &dyn RouteIntervals {
+        &self.route_intervals
+    }
+
+    fn get_constraint(&self) -> &dyn FeatureConstraint {
+        self
+    }
+
+    fn recalculate_states(&self, route_ctx: &mut RouteContext) {
+        if (self.driving_rules_fn)(route_ctx.route().actor.as_ref()).is_none() {
+            return;
+        }
+
+        let last_idx = route_ctx.route().tour.total() - 1;
+        let marker_intervals = self.route_intervals.resolve_marker_intervals(route_ctx).collect::<Vec<_>>();
+
+        let total_driving_time =
+            marker_intervals.into_iter().fold(Duration::default(), |total, (start_idx, end_idx)| {
+                let (route, state) = route_ctx.as_mut();
+
+                let end_idx = if end_idx != last_idx { end_idx + 1 } else { end_idx };
+
+                // NOTE driving time is reset at the interval start which is the break activity
+                let interval_driving_time = (start_idx..end_idx).fold(Duration::default(), |acc, activity_idx| {
+                    let counter =
+                        acc + get_travel_duration(self.transport.as_ref(), route, activity_idx, activity_idx + 1);
+                    state.put_activity_state(DRIVING_TIME_KEY, activity_idx + 1, counter);
+
+                    counter
+                });
+
+                total + interval_driving_time
+            });
+
+        route_ctx.state_mut().put_route_state(TOTAL_DRIVING_TIME_KEY, total_driving_time);
+    }
+
+    fn try_recover(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], _: &[Job]) -> bool {
+        let routes = &mut solution_ctx.routes;
+
+        let jobs: HashSet<_> = if route_indices.is_empty() {
+            solution_ctx.ignored.iter().filter(|job| self.route_intervals.is_marker_job(job)).cloned().collect()
+        } else {
+            routes
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| route_indices.contains(idx))
+                .flat_map(|(_, route_ctx)| {
+                    solution_ctx
+                        .ignored
+                        .iter()
+                        .filter(|job| self.route_intervals.is_marker_assignable(route_ctx.route(), job))
+                })
+                .cloned()
+                .collect()
+        };
+
+        if jobs.is_empty() {
+            false
+        } else {
+            solution_ctx.ignored.retain(|job| !jobs.contains(job));
+            solution_ctx.locked.extend(jobs.iter().cloned());
+            solution_ctx.required.extend(jobs);
+
+            true
+        }
+    }
+}
+
+impl FeatureConstraint for DrivingRulesMultiTrip {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => ConstraintViolation::success(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl DrivingRulesMultiTrip {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let rules = (self.driving_rules_fn)(route_ctx.route().actor.as_ref())?;
+
+        let (interval_start, interval_end) = self
+            .route_intervals
+            .resolve_marker_intervals(route_ctx)
+            .find(|(_, end_idx)| activity_ctx.index <= *end_idx)
+            .expect("invalid markers state");
+        let interval_driving_time = get_driving_time(route_ctx, get_end_idx(route_ctx, interval_end));
+
+        let (prev_to_target, target_to_next, prev_to_next) = self.get_travel_durations(route_ctx, activity_ctx);
+        let delta = prev_to_target + target_to_next - prev_to_next;
+
+        let total_driving_time =
+            route_ctx.state().get_route_state::<Duration>(TOTAL_DRIVING_TIME_KEY).copied().unwrap_or_default();
+        if total_driving_time + delta > rules.max_total_driving_time {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let is_new_break = activity_ctx.target.job.as_ref().is_some_and(|single| (self.is_break_single_fn)(single));
+
+        if is_new_break && !self.is_split_break_kept(route_ctx, activity_ctx, interval_start, interval_end) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let is_violation = if is_new_break {
+            // S ----- A ---- [B] ------ C ----- F
+
+            // check S->B
+            let current_driving_time = get_driving_time(route_ctx, activity_ctx.index);
+            let is_begin_violates = current_driving_time + prev_to_target > rules.max_driving_time;
+
+            // check B->F
+            let is_end_violates = activity_ctx.next.is_some() && {
+                let next_driving_time = get_driving_time(route_ctx, activity_ctx.index + 1);
+
+                interval_driving_time - next_driving_time + target_to_next > rules.max_driving_time
+            };
+
+            is_begin_violates || is_end_violates
+        } else {
+            interval_driving_time + delta > rules.max_driving_time
+        };
+
+        if is_violation {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+
+    /// Checks that parts of split breaks stay within the same interval as their marker part:
+    /// * preceding parts of the new break are placed after the interval start and are valid breaks
+    /// * the new break does not separate parts of the break which closes the interval
+    fn is_split_break_kept(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        interval_start: usize,
+        interval_end: usize,
+    ) -> bool {
+        let tour = &route_ctx.route().tour;
+
+        let is_target_kept =
+            activity_ctx.target.job.as_ref().is_none_or(|single| is_first_part_kept(route_ctx, single, interval_start));
+
+        let is_next_kept = tour
+            .get(interval_end + 1)
+            .and_then(|activity| activity.job.as_ref())
+            .filter(|single| (self.is_break_single_fn)(single))
+            .and_then(|single| get_first_part_idx(route_ctx, single))
+            .is_none_or(|first_part_idx| first_part_idx > activity_ctx.index);
+
+        is_target_kept && is_next_kept
+    }
+
+    /// Returns driving durations for prev->target, target->next and prev->next legs.
+    fn get_travel_durations(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> (Duration, Duration, Duration) {
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let departure = prev.schedule.departure;
+
+        let prev_to_target = self.transport.duration(
+            route,
+            prev.place.location,
+            target.place.location,
+            TravelTime::Departure(departure),
+        );
+
+        activity_ctx.next.map_or((prev_to_target, Duration::default(), Duration::default()), |next| {
+            let target_to_next = self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure + prev_to_target),
+            );
+            let prev_to_next = self.transport.duration(
+                route,
+                prev.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+
+            (prev_to_target, target_to_next, prev_to_next)
+        })
+    }
+}
+
+/// Returns index of the first part of the split break which the given break part belongs to.
+fn get_first_part_idx(route_ctx: &RouteContext, single: &Arc<Single>) -> Option<usize> {
+    Multi::roots(single).and_then(|multi| route_ctx.route().tour.index(&Job::Multi(multi)))
+}
+
+/// Checks that the first part of the split break, if any, is placed within the interval which starts at
+/// the given index, is taken after some driving and is not shorter than its duration. Otherwise, it is
+/// not considered as a break.
+fn is_first_part_kept(route_ctx: &RouteContext, single: &Arc<Single>, interval_start: usize) -> bool {
+    get_first_part_idx(route_ctx, single).is_none_or(|first_part_idx| {
+        let is_taken_after_driving = get_driving_time(route_ctx, first_part_idx) > Duration::default();
+        let is_long_enough = route_ctx.route().tour.get(first_part_idx).is_some_and(|activity| {
+            let schedule = &activity.schedule;
+            schedule.departure - schedule.arrival.max(activity.place.time.start) >= activity.place.duration
+        });
+
+        first_part_idx >= interval_start && is_taken_after_driving && is_long_enough
+    })
+}
+
+fn get_driving_time(route_ctx: &RouteContext, activity_idx: usize) -> Duration {
+    route_ctx.state().get_activity_state::<Duration>(DRIVING_TIME_KEY, activity_idx).copied().unwrap_or_default()
+}
+
+fn get_travel_duration(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    from_idx: usize,
+    to_idx: usize,
+) -> Duration {
+    route.tour.get(from_idx).zip(route.tour.get(to_idx)).map_or(Duration::default(), |(from, to)| {
+        transport.duration(
+            route,
+            from.place.location,
+            to.place.location,
+            TravelTime::Departure(from.schedule.departure),
+        )
+    })
+}
+
+fn get_end_idx(route_ctx: &RouteContext, end_idx: usize) -> usize {
+    let last_idx = route_ctx.route().tour.total() - 1;
+    end_idx + if end_idx == last_idx { 0 } else { 1 }
+}
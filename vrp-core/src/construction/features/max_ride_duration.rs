@@ -0,0 +1,193 @@
+//! A feature to limit how long goods or passengers of a multi job stay in the vehicle: a ride
+//! duration is measured from departure at the first job's activity till service start at the last
+//! one. This is a typical constraint of dial-a-ride problems.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/max_ride_duration_test.rs"]
+mod max_ride_duration_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+
+const MAX_RIDE_DURATION_KEY: &str = "max_ride_duration";
+
+/// A trait to get or set max ride duration of the multi job.
+pub trait MaxRideDurationDimension {
+    /// Sets max ride duration.
+    fn set_max_ride_duration(&mut self, duration: Duration) -> &mut Self;
+
+    /// Gets max ride duration.
+    fn get_max_ride_duration(&self) -> Option<Duration>;
+}
+
+impl MaxRideDurationDimension for Dimensions {
+    fn set_max_ride_duration(&mut self, duration: Duration) -> &mut Self {
+        self.set_value(MAX_RIDE_DURATION_KEY, duration);
+        self
+    }
+
+    fn get_max_ride_duration(&self) -> Option<Duration> {
+        self.get_value(MAX_RIDE_DURATION_KEY).copied()
+    }
+}
+
+/// Creates a feature which limits ride duration of multi jobs with max ride duration dimension set.
+/// This is a hard constraint.
+pub fn create_max_ride_duration_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    state_key: StateKey,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(MaxRideDurationConstraint { code, transport, activity, state_key })
+        .with_state(MaxRideDurationState { state_keys: vec![state_key] })
+        .build()
+}
+
+/// Returns ride duration between given first and last activities of the job.
+pub fn get_ride_duration(first: &Activity, last: &Activity) -> Duration {
+    get_service_start(last) - first.schedule.departure
+}
+
+fn get_service_start(activity: &Activity) -> Timestamp {
+    activity.schedule.arrival.max(activity.place.time.start)
+}
+
+fn get_max_ride_duration(job: &Job) -> Option<Duration> {
+    job.as_multi().and_then(|multi| multi.dimens.get_max_ride_duration())
+}
+
+struct MaxRideDurationConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    state_key: StateKey,
+}
+
+impl MaxRideDurationConstraint {
+    /// Estimates service start and departure time of the target activity.
+    fn estimate_target_schedule(&self, route: &Route, activity_ctx: &ActivityContext) -> (Timestamp, Timestamp) {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+
+        (arrival.max(target.place.time.start), self.activity.estimate_departure(route, target, arrival))
+    }
+
+    /// Estimates how much arrival at next activity is delayed by insertion of target activity.
+    fn estimate_delay(&self, route: &Route, activity_ctx: &ActivityContext, departure: Timestamp) -> Duration {
+        activity_ctx.next.map_or(Duration::default(), |next| {
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    activity_ctx.target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            (arrival - next.schedule.arrival).max(0.)
+        })
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let (service_start, departure) = self.estimate_target_schedule(route, activity_ctx);
+        let delay = self.estimate_delay(route, activity_ctx, departure);
+
+        // NOTE delay can only be absorbed by waiting later in the route, so it is an upper bound of
+        //      ride duration increase for jobs which are on board when leaving previous activity
+        let min_slack = route_ctx.state().get_activity_state::<Duration>(self.state_key, activity_ctx.index).copied();
+        if min_slack.is_some_and(|min_slack| delay > min_slack) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let job = activity_ctx.target.retrieve_job()?;
+        let max_ride_duration = get_max_ride_duration(&job)?;
+
+        // NOTE ride duration of the job without activities in the route is checked on its next insertion
+        let tour = &route.tour;
+        let (first_idx, last_idx) = tour.index(&job).zip(tour.index_last(&job))?;
+
+        let ride_start = if first_idx <= activity_ctx.index { tour[first_idx].schedule.departure } else { departure };
+        let ride_end =
+            if last_idx > activity_ctx.index { get_service_start(&tour[last_idx]) + delay } else { service_start };
+
+        if ride_end - ride_start > max_ride_duration {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+}
+
+impl FeatureConstraint for MaxRideDurationConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct MaxRideDurationState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for MaxRideDurationState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(&mut solution_ctx.routes[route_index]);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let state_key = self.state_keys[0];
+        let tour = &route_ctx.route().tour;
+
+        // keep the smallest ride duration slack among jobs which are on board when leaving given activity
+        let mut min_slacks = vec![None::<Duration>; tour.total()];
+        tour.jobs().filter_map(|job| get_max_ride_duration(job).zip(Some(job))).for_each(|(max_ride_duration, job)| {
+            if let Some((first_idx, last_idx)) = tour.index(job).zip(tour.index_last(job)) {
+                let slack = max_ride_duration - get_ride_duration(&tour[first_idx], &tour[last_idx]);
+                min_slacks.iter_mut().take(last_idx).skip(first_idx).for_each(|min_slack| {
+                    *min_slack = Some(min_slack.map_or(slack, |other: Duration| other.min(slack)));
+                });
+            }
+        });
+
+        // NOTE state is written for all activities as it is not cleared between insertions
+        if min_slacks.iter().any(|min_slack| min_slack.is_some()) {
+            min_slacks.into_iter().enumerate().for_each(|(activity_idx, min_slack)| {
+                let min_slack = min_slack.unwrap_or(Duration::MAX);
+                route_ctx.state_mut().put_activity_state(state_key, activity_idx, min_slack)
+            });
+        }
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.is_stale())
+            .for_each(|route_ctx| self.accept_route_state(route_ctx))
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
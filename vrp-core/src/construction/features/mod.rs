@@ -11,6 +11,15 @@ use std::sync::Arc;
 mod capacity;
 pub use self::capacity::*;
 
+mod compartments;
+pub use self::compartments::*;
+
+mod depot_capacity;
+pub use self::depot_capacity::*;
+
+mod driving_rules;
+pub use self::driving_rules::*;
+
 mod fast_service;
 pub use self::fast_service::*;
 
@@ -20,12 +29,21 @@ pub use self::fleet_usage::*;
 mod locked_jobs;
 pub use self::locked_jobs::*;
 
+mod max_ride_duration;
+pub use self::max_ride_duration::*;
+
 mod minimize_unassigned;
 pub use self::minimize_unassigned::*;
 
+mod periodic;
+pub use self::periodic::*;
+
 mod shared_resource;
 pub use self::shared_resource::*;
 
+mod shift_overlap;
+pub use self::shift_overlap::*;
+
 mod total_value;
 pub use self::total_value::*;
 
@@ -54,6 +72,16 @@ pub const TOTAL_DISTANCE_KEY: StateKey = StateKey(3);
 pub const TOTAL_DURATION_KEY: StateKey = StateKey(4);
 /// A key which tracks global duration limit.
 pub const LIMIT_DURATION_KEY: StateKey = StateKey(5);
+/// A key which tracks total penalty of soft time window violations.
+pub const TIME_WINDOW_PENALTY_KEY: StateKey = StateKey(6);
+/// A key which tracks min ride duration slack of jobs on board.
+pub const MAX_RIDE_DURATION_KEY: StateKey = StateKey(7);
+/// A key which tracks driving time since last driving break.
+pub const DRIVING_TIME_KEY: StateKey = StateKey(8);
+/// A key which tracks total driving time.
+pub const TOTAL_DRIVING_TIME_KEY: StateKey = StateKey(9);
+/// A key which tracks loads of vehicle compartments.
+pub const COMPARTMENT_LOADS_KEY: StateKey = StateKey(10);
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: StateKey = StateKey(11);
@@ -69,6 +97,10 @@ pub const MAX_LOAD_KEY: StateKey = StateKey(15);
 pub const RECHARGE_INTERVALS_KEY: StateKey = StateKey(16);
 /// A key to track recharge distance.
 pub const RECHARGE_DISTANCE_KEY: StateKey = StateKey(17);
+/// A key which tracks driving break intervals.
+pub const DRIVING_INTERVALS_KEY: StateKey = StateKey(18);
+/// A key which tracks assignment of jobs to vehicle compartments.
+pub const COMPARTMENT_ASSIGNMENT_KEY: StateKey = StateKey(19);
 
 /// A key for balancing max load.
 pub const BALANCE_MAX_LOAD_KEY: StateKey = StateKey(20);
@@ -78,3 +110,10 @@ pub const BALANCE_ACTIVITY_KEY: StateKey = StateKey(21);
 pub const BALANCE_DISTANCE_KEY: StateKey = StateKey(22);
 /// A key for balancing duration.
 pub const BALANCE_DURATION_KEY: StateKey = StateKey(23);
+
+/// A key which tracks latest tour end allowed by other shifts of the same vehicle.
+pub const SHIFT_LATEST_END_KEY: StateKey = StateKey(24);
+/// A key which tracks periodic jobs visited in the tour.
+pub const PERIODIC_VISITS_KEY: StateKey = StateKey(25);
+/// A key which tracks index of the last activity with soft time window.
+pub const TIME_WINDOW_LAST_IDX_KEY: StateKey = StateKey(26);
@@ -0,0 +1,227 @@
+//! A feature to schedule visits of periodic jobs on different days of multi-day planning horizon.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/periodic_test.rs"]
+mod periodic_test;
+
+use super::*;
+use hashbrown::HashSet;
+
+const VISIT_FREQUENCY_KEY: &str = "visit_frequency";
+const VEHICLE_DAY_KEY: &str = "vehicle_day";
+
+/// Specifies visit frequency of a periodic job: each visit is a separate job which shares the
+/// same frequency with other visits of the periodic job.
+#[derive(Clone, Debug)]
+pub struct VisitFrequency {
+    /// An id of the periodic job.
+    pub id: String,
+    /// Total amount of visits.
+    pub count: usize,
+    /// A minimum amount of days between two consecutive visits.
+    pub min_spacing: usize,
+    /// A maximum amount of days between two consecutive visits.
+    pub max_spacing: Option<usize>,
+    /// Allowed day patterns: if specified, visit days have to be a subset of one of them.
+    pub patterns: Option<Vec<Vec<usize>>>,
+}
+
+impl VisitFrequency {
+    /// Checks whether visits on given days can be completed to a valid visit schedule by the
+    /// remaining visits using days of the planning horizon. Both days and horizon are expected
+    /// to be sorted.
+    pub fn is_feasible(&self, days: &[usize], horizon: &[usize]) -> bool {
+        if days.len() > self.count || days.windows(2).any(|pair| pair[0] == pair[1]) {
+            return false;
+        }
+
+        if self.count == 0 {
+            return true;
+        }
+
+        let min_spacing = self.min_spacing.max(1);
+        let max_spacing = self.max_spacing.unwrap_or(usize::MAX);
+        let is_valid_gap = |gap: usize| gap >= min_spacing && gap <= max_spacing;
+
+        if let Some(patterns) = self.patterns.as_ref() {
+            return patterns.iter().any(|pattern| {
+                let mut pattern = pattern.clone();
+                pattern.sort();
+
+                days.iter().all(|day| pattern.contains(day))
+                    && pattern.iter().all(|day| horizon.binary_search(day).is_ok())
+                    && pattern.windows(2).all(|pair| is_valid_gap(pair[1] - pair[0]))
+            });
+        }
+
+        // NOTE find whether there is a sequence of horizon days with exact visit count which
+        //      includes all given days: reachable[idx][count] is true when such sequence with
+        //      given visit count can end at horizon[idx]
+        let has_day_between = |start: usize, end: usize| {
+            let idx = days.partition_point(|&day| day <= start);
+            days.get(idx).is_some_and(|&day| day < end)
+        };
+
+        let mut reachable = vec![vec![false; self.count + 1]; horizon.len()];
+        for (idx, &day) in horizon.iter().enumerate() {
+            reachable[idx][1] = days.first().is_none_or(|&first| first >= day);
+
+            for (prev_idx, &prev_day) in horizon.iter().enumerate().take(idx) {
+                if !is_valid_gap(day - prev_day) || has_day_between(prev_day, day) {
+                    continue;
+                }
+
+                (1..self.count).for_each(|count| reachable[idx][count + 1] |= reachable[prev_idx][count]);
+            }
+        }
+
+        horizon.iter().enumerate().any(|(idx, &day)| {
+            reachable[idx].get(self.count).copied().unwrap_or(false) && days.last().is_none_or(|&last| last <= day)
+        })
+    }
+}
+
+/// A trait to get or set visit frequency of the job.
+pub trait VisitFrequencyDimension {
+    /// Sets visit frequency.
+    fn set_visit_frequency(&mut self, frequency: Arc<VisitFrequency>) -> &mut Self;
+
+    /// Gets visit frequency.
+    fn get_visit_frequency(&self) -> Option<&Arc<VisitFrequency>>;
+}
+
+impl VisitFrequencyDimension for Dimensions {
+    fn set_visit_frequency(&mut self, frequency: Arc<VisitFrequency>) -> &mut Self {
+        self.set_value(VISIT_FREQUENCY_KEY, frequency);
+        self
+    }
+
+    fn get_visit_frequency(&self) -> Option<&Arc<VisitFrequency>> {
+        self.get_value(VISIT_FREQUENCY_KEY)
+    }
+}
+
+/// A trait to get or set a calendar day of the vehicle's shift.
+pub trait VehicleDayDimension {
+    /// Sets vehicle's calendar day.
+    fn set_vehicle_day(&mut self, day: usize) -> &mut Self;
+
+    /// Gets vehicle's calendar day.
+    fn get_vehicle_day(&self) -> Option<usize>;
+}
+
+impl VehicleDayDimension for Dimensions {
+    fn set_vehicle_day(&mut self, day: usize) -> &mut Self {
+        self.set_value(VEHICLE_DAY_KEY, day);
+        self
+    }
+
+    fn get_vehicle_day(&self) -> Option<usize> {
+        self.get_value(VEHICLE_DAY_KEY).cloned()
+    }
+}
+
+/// Creates a feature which assigns visits of the same periodic job (see `VisitFrequencyDimension`)
+/// to tours on different days (see `VehicleDayDimension`) respecting visit spacing and day patterns.
+/// Planning horizon is defined by days of given actors. This is a hard constraint.
+pub fn create_periodic_feature(
+    name: &str,
+    total_jobs: usize,
+    code: ViolationCode,
+    actors: &[Arc<Actor>],
+) -> Result<Feature, GenericError> {
+    let mut horizon = actors.iter().filter_map(|actor| actor.vehicle.dimens.get_vehicle_day()).collect::<Vec<_>>();
+    horizon.sort();
+    horizon.dedup();
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(PeriodicConstraint { total_jobs, code, horizon })
+        .with_state(PeriodicState { state_keys: vec![PERIODIC_VISITS_KEY] })
+        .build()
+}
+
+struct PeriodicConstraint {
+    total_jobs: usize,
+    code: ViolationCode,
+    horizon: Vec<usize>,
+}
+
+impl FeatureConstraint for PeriodicConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                job.dimens().get_visit_frequency().and_then(|frequency| {
+                    // NOTE other visits can be outside of partially solved problem
+                    let is_partial_problem = solution_ctx.get_jobs_amount() != self.total_jobs;
+                    let day = route_ctx.route().actor.vehicle.dimens.get_vehicle_day();
+
+                    let day = match day {
+                        Some(day) if !is_partial_problem => day,
+                        _ => return ConstraintViolation::fail(self.code),
+                    };
+
+                    let mut days = solution_ctx
+                        .routes
+                        .iter()
+                        .filter(|route_ctx| {
+                            route_ctx
+                                .state()
+                                .get_route_state::<HashSet<String>>(PERIODIC_VISITS_KEY)
+                                .is_some_and(|ids| ids.contains(&frequency.id))
+                        })
+                        .filter_map(|route_ctx| route_ctx.route().actor.vehicle.dimens.get_vehicle_day())
+                        .chain(std::iter::once(day))
+                        .collect::<Vec<_>>();
+                    days.sort();
+
+                    if frequency.is_feasible(days.as_slice(), self.horizon.as_slice()) {
+                        None
+                    } else {
+                        ConstraintViolation::fail(self.code)
+                    }
+                })
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_visit_frequency(), candidate.dimens().get_visit_frequency()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct PeriodicState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for PeriodicState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if job.dimens().get_visit_frequency().is_some() {
+            let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+            let ids = get_periodic_ids(route_ctx);
+
+            route_ctx.state_mut().put_route_state(PERIODIC_VISITS_KEY, ids);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let ids = get_periodic_ids(route_ctx);
+            route_ctx.state_mut().put_route_state(PERIODIC_VISITS_KEY, ids);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_periodic_ids(route_ctx: &RouteContext) -> HashSet<String> {
+    route_ctx.route().tour.jobs().filter_map(|job| job.dimens().get_visit_frequency().map(|f| f.id.clone())).collect()
+}
@@ -13,7 +13,7 @@ use std::cmp::Ordering;
 use std::ops::{Add, RangeInclusive, Sub};
 
 /// Represents a shared unique resource.
-pub trait SharedResource: Add + Sub + PartialOrd + Copy + Sized + Send + Sync + Default + 'static {}
+pub trait SharedResource: Add + Sub + PartialOrd + Clone + Sized + Send + Sync + Default + 'static {}
 /// Represents a shared resource id.
 pub type SharedResourceId = usize;
 /// Specifies a type for a shared resource interval function.
@@ -163,7 +163,7 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
 
                     if let Some((resource_demand, id)) = resource_demand_with_id {
                         let entry = acc.entry(id).or_insert_with(T::default);
-                        *entry = *entry + resource_demand;
+                        *entry = entry.clone() + resource_demand;
                     }
 
                     acc
@@ -177,7 +177,7 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
             (self.interval_fn)(route_ctx).cloned().unwrap_or_default().into_iter().for_each(|(start_idx, _)| {
                 let resource_available = (self.resource_capacity_fn)(get_activity_by_idx(route_ctx.route(), start_idx))
                     .and_then(|(total_capacity, resource_id)| {
-                        total_demand.get(&resource_id).map(|total_demand| total_capacity - *total_demand)
+                        total_demand.get(&resource_id).map(|total_demand| total_capacity - total_demand.clone())
                     });
 
                 if let Some(resource_available) = resource_available {
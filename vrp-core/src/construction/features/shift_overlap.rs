@@ -0,0 +1,229 @@
+//! A feature to prevent overlapping of tours performed by different shifts of the same vehicle and
+//! to keep a minimum rest time between them.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/shift_overlap_test.rs"]
+mod shift_overlap_test;
+
+use super::*;
+use crate::models::common::{Duration, Timestamp};
+use crate::models::problem::{ActivityCost, Actor, TransportCost, TravelTime};
+use hashbrown::HashMap;
+
+const SHIFT_REST_KEY: &str = "shift_rest";
+
+/// A trait to get or set a minimum rest time between shifts of the same vehicle.
+pub trait ShiftRestDimension {
+    /// Sets minimum rest time between shifts.
+    fn set_shift_rest(&mut self, rest: Duration) -> &mut Self;
+
+    /// Gets minimum rest time between shifts.
+    fn get_shift_rest(&self) -> Option<Duration>;
+}
+
+impl ShiftRestDimension for Dimensions {
+    fn set_shift_rest(&mut self, rest: Duration) -> &mut Self {
+        self.set_value(SHIFT_REST_KEY, rest);
+        self
+    }
+
+    fn get_shift_rest(&self) -> Option<Duration> {
+        self.get_value(SHIFT_REST_KEY).cloned()
+    }
+}
+
+/// Creates a feature which does not allow tours of actors sharing the same vehicle (identified by
+/// vehicle's id) to overlap in time: a tour has to end at least given rest time (see
+/// `ShiftRestDimension`) before the next used shift of the same vehicle starts.
+/// This is a hard constraint.
+pub fn create_shift_overlap_feature(
+    name: &str,
+    code: ViolationCode,
+    actors: &[Arc<Actor>],
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, GenericError> {
+    // NOTE static latest end guarantees that tour does not overlap any later shift of the same
+    //      vehicle, no matter whether that shift is used or not
+    let static_ends = actors
+        .iter()
+        .map(|actor| {
+            let latest_end = actors
+                .iter()
+                .filter(|other| is_sibling(actor, other) && get_shift_start(other) > get_shift_start(actor))
+                .map(|other| get_shift_start(other) - get_shift_rest(actor))
+                .fold(f64::MAX, f64::min);
+
+            (actor.clone(), latest_end)
+        })
+        .collect::<HashMap<_, _>>();
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(ShiftOverlapConstraint { code, transport, activity })
+        .with_state(ShiftOverlapState { static_ends, state_keys: vec![SHIFT_LATEST_END_KEY] })
+        .build()
+}
+
+struct ShiftOverlapConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl ShiftOverlapConstraint {
+    fn evaluate_route(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext) -> Option<ConstraintViolation> {
+        // NOTE only a new tour can overlap already used shifts, existing ones are limited by their latest end
+        if route_ctx.route().tour.has_jobs() {
+            return None;
+        }
+
+        let actor = &route_ctx.route().actor;
+        let start = get_shift_start(actor);
+        let end = get_tour_end(route_ctx);
+        let rest = get_shift_rest(actor);
+
+        let has_overlap = solution_ctx
+            .routes
+            .iter()
+            .filter(|other| other.route().tour.has_jobs() && is_sibling(actor, &other.route().actor))
+            .any(|other| {
+                let other_start = get_shift_start(&other.route().actor);
+
+                if other_start <= start {
+                    get_tour_end(other) + rest > start
+                } else {
+                    end + rest > other_start
+                }
+            });
+
+        if has_overlap {
+            ConstraintViolation::fail(self.code)
+        } else {
+            ConstraintViolation::success()
+        }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let latest_end = *route_ctx.state().get_route_state::<Timestamp>(SHIFT_LATEST_END_KEY)?;
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let departure = self.activity.estimate_departure(route, target, arrival);
+
+        let new_end = if let Some(next) = activity_ctx.next {
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+            let waiting = *route_ctx.state().get_activity_state(WAITING_KEY, activity_ctx.index + 1).unwrap_or(&0.);
+            let delay = (arrival - next.schedule.arrival - waiting).max(0.);
+
+            get_tour_end(route_ctx) + delay
+        } else {
+            departure
+        };
+
+        if new_end > latest_end {
+            ConstraintViolation::skip(self.code)
+        } else {
+            ConstraintViolation::success()
+        }
+    }
+}
+
+impl FeatureConstraint for ShiftOverlapConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, .. } => self.evaluate_route(solution_ctx, route_ctx),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct ShiftOverlapState {
+    static_ends: HashMap<Arc<Actor>, Timestamp>,
+    state_keys: Vec<StateKey>,
+}
+
+impl ShiftOverlapState {
+    /// Sets latest tour end for every route using only later shifts of the same vehicle which are in use.
+    fn update_latest_ends(&self, solution_ctx: &mut SolutionContext) {
+        let used_shifts = solution_ctx
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route().tour.has_jobs())
+            .map(|route_ctx| route_ctx.route().actor.clone())
+            .collect::<Vec<_>>();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let actor = route_ctx.route().actor.clone();
+            let latest_end = used_shifts
+                .iter()
+                .filter(|other| is_sibling(&actor, other) && get_shift_start(other) > get_shift_start(&actor))
+                .map(|other| get_shift_start(other) - get_shift_rest(&actor))
+                .fold(f64::MAX, f64::min);
+
+            route_ctx.state_mut().put_route_state(SHIFT_LATEST_END_KEY, latest_end);
+        });
+    }
+}
+
+impl FeatureState for ShiftOverlapState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.update_latest_ends(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        // NOTE: usage of other shifts is not known here, so tour is allowed to grow only till the
+        //       next shift of the same vehicle. This state will be overridden by update_latest_ends
+        //       after other accept method calls.
+        let static_end = self.static_ends.get(&route_ctx.route().actor).copied().unwrap_or(f64::MAX);
+        let latest_end = static_end.max(get_tour_end(route_ctx));
+
+        route_ctx.state_mut().put_route_state(SHIFT_LATEST_END_KEY, latest_end);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_latest_ends(solution_ctx);
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn is_sibling(actor: &Arc<Actor>, other: &Arc<Actor>) -> bool {
+    actor != other && actor.vehicle.dimens.get_id().is_some_and(|id| other.vehicle.dimens.get_id() == Some(id))
+}
+
+fn get_shift_start(actor: &Actor) -> Timestamp {
+    actor.detail.time.start
+}
+
+fn get_shift_rest(actor: &Actor) -> Duration {
+    actor.vehicle.dimens.get_shift_rest().unwrap_or_default()
+}
+
+fn get_tour_end(route_ctx: &RouteContext) -> Timestamp {
+    route_ctx.route().tour.end().map_or(get_shift_start(&route_ctx.route().actor), |end| end.schedule.departure)
+}
@@ -5,6 +5,7 @@
 mod transport_test;
 
 use super::*;
+use crate::construction::enablers::{estimate_time_window_penalty_delta, update_time_window_penalty};
 use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
 use crate::models::common::Timestamp;
 use crate::models::problem::{ActivityCost, Single, TransportCost, TravelTime};
@@ -266,7 +267,17 @@ impl TransportObjective {
 
         let old_costs = tp_cost_old + act_cost_old + waiting_cost;
 
-        new_costs - old_costs
+        // NOTE delaying next activity can change soft time window penalty of activities after it
+        let penalty_delta = estimate_time_window_penalty_delta(
+            route_ctx,
+            self.activity.as_ref(),
+            self.transport.as_ref(),
+            activity_ctx.index + 1,
+            dep_time_right,
+            TIME_WINDOW_LAST_IDX_KEY,
+        );
+
+        new_costs - old_costs + penalty_delta
     }
 
     fn analyze_route_leg(
@@ -322,6 +333,8 @@ impl TransportState {
             schedule_state_keys.latest_arrival,
             schedule_state_keys.total_duration,
             schedule_state_keys.total_distance,
+            TIME_WINDOW_PENALTY_KEY,
+            TIME_WINDOW_LAST_IDX_KEY,
         ];
 
         Self { schedule_state_keys: ScheduleStateKeys::default(), transport, activity, all_state_keys }
@@ -336,6 +349,7 @@ impl FeatureState for TransportState {
 
     fn accept_route_state(&self, route_ctx: &mut RouteContext) {
         update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref(), &self.schedule_state_keys);
+        update_time_window_penalty(route_ctx, TIME_WINDOW_PENALTY_KEY, TIME_WINDOW_LAST_IDX_KEY);
     }
 
     fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
@@ -346,6 +360,7 @@ impl FeatureState for TransportState {
                 self.transport.as_ref(),
                 &self.schedule_state_keys,
             );
+            update_time_window_penalty(route_ctx, TIME_WINDOW_PENALTY_KEY, TIME_WINDOW_LAST_IDX_KEY);
         })
     }
 
@@ -2,7 +2,7 @@
 #[path = "../../../tests/unit/construction/heuristics/context_test.rs"]
 mod context_test;
 
-use crate::construction::features::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::features::{TIME_WINDOW_PENALTY_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
 use crate::construction::heuristics::factories::*;
 use crate::models::common::Cost;
 use crate::models::problem::*;
@@ -130,24 +130,7 @@ pub struct SolutionContext {
 impl SolutionContext {
     /// Gets total cost of the solution.
     pub fn get_total_cost(&self) -> Cost {
-        let get_cost = |costs: &Costs, distance: f64, duration: f64| {
-            costs.fixed
-                + costs.per_distance * distance
-                // NOTE this is incorrect when timing costs are different: fitness value will be
-                // different from actual cost. However we accept this so far as it is simpler for
-                // implementation and pragmatic format does not expose this feature
-                // .
-                // TODO calculate actual cost
-                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
-        };
-
-        self.routes.iter().fold(Cost::default(), |acc, route_ctx| {
-            let actor = &route_ctx.route.actor;
-            let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
-            let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
-
-            acc + get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
-        })
+        self.routes.iter().map(|route_ctx| route_ctx.get_route_cost()).sum()
     }
 
     /// Returns amount of jobs considered by solution context.
@@ -272,6 +255,27 @@ impl RouteContext {
         RouteContext { route: new_route, state: new_state, cache: RouteCache { is_stale: self.cache.is_stale } }
     }
 
+    /// Gets total cost of the route.
+    pub fn get_route_cost(&self) -> Cost {
+        let get_cost = |costs: &Costs, distance: f64, duration: f64| {
+            costs.fixed
+                + costs.per_distance * distance
+                // NOTE this is incorrect when timing costs are different: fitness value will be
+                // different from actual cost. However we accept this so far as it is simpler for
+                // implementation and pragmatic format does not expose this feature
+                // .
+                // TODO calculate actual cost
+                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+        };
+
+        let actor = &self.route.actor;
+        let distance = self.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+        let duration = self.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+        let penalty = self.state.get_route_state::<f64>(TIME_WINDOW_PENALTY_KEY).cloned().unwrap_or(0.);
+
+        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration) + penalty
+    }
+
     /// Returns a reference to route.
     pub fn route(&self) -> &Route {
         &self.route
@@ -9,13 +9,14 @@ use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
 use std::iter::Sum;
 use std::ops::{Add, ControlFlow, Mul, Sub};
+use tinyvec::TinyVec;
 
 const CAPACITY_DIMENSION_KEY: &str = "cpc";
 const DEMAND_DIMENSION_KEY: &str = "dmd";
 const LOAD_DIMENSION_SIZE: usize = 8;
 
 /// Represents a load type used to represent customer's demand or vehicle's load.
-pub trait Load: Add + Sub + PartialOrd + Copy + Default + Debug + Send + Sync {
+pub trait Load: Add + Sub + PartialOrd + Clone + Default + Debug + Send + Sync {
     /// Returns true if it represents an empty load.
     fn is_not_empty(&self) -> bool;
 
@@ -63,7 +64,7 @@ pub trait DemandDimension<T: LoadOps> {
 impl<T: LoadOps> Demand<T> {
     /// Returns capacity change as difference between pickup and delivery.
     pub fn change(&self) -> T {
-        self.pickup.0 + self.pickup.1 - self.delivery.0 - self.delivery.1
+        self.pickup.0.clone() + self.pickup.1.clone() - self.delivery.0.clone() - self.delivery.1.clone()
     }
 }
 
@@ -75,7 +76,7 @@ impl<T: LoadOps> Default for Demand<T> {
 
 impl<T: LoadOps> Clone for Demand<T> {
     fn clone(&self) -> Self {
-        Self { pickup: self.pickup, delivery: self.delivery }
+        Self { pickup: self.pickup.clone(), delivery: self.delivery.clone() }
     }
 }
 
@@ -199,92 +200,74 @@ impl Display for SingleDimLoad {
     }
 }
 
-/// Specifies multi dimensional load type.
-#[derive(Clone, Copy, Debug)]
+/// Specifies multi dimensional load type. The amount of dimensions is not limited: the first
+/// `LOAD_DIMENSION_SIZE` values are stored inline, the rest is moved to the heap.
+#[derive(Clone, Debug, Default)]
 pub struct MultiDimLoad {
     /// Load data.
-    pub load: [i32; LOAD_DIMENSION_SIZE],
-    /// Actual used size.
-    pub size: usize,
+    pub load: TinyVec<[i32; LOAD_DIMENSION_SIZE]>,
 }
 
 impl MultiDimLoad {
     /// Creates a new instance of `MultiDimLoad`.
     pub fn new(data: Vec<i32>) -> Self {
-        assert!(data.len() <= LOAD_DIMENSION_SIZE);
-
-        let mut load = [0; LOAD_DIMENSION_SIZE];
-        for (idx, value) in data.iter().enumerate() {
-            load[idx] = *value;
-        }
+        Self { load: data.into_iter().collect() }
+    }
 
-        Self { load, size: data.len() }
+    /// Returns an actual amount of dimensions.
+    pub fn size(&self) -> usize {
+        self.load.len()
     }
 
     fn get(&self, idx: usize) -> i32 {
-        self.load[idx]
+        self.load.get(idx).copied().unwrap_or(0)
+    }
+
+    fn zip_with<F: Fn(i32, i32) -> i32>(mut self, rhs: &Self, func: F) -> Self {
+        if self.load.len() < rhs.load.len() {
+            self.load.resize(rhs.load.len(), 0);
+        }
+
+        self.load.iter_mut().enumerate().for_each(|(idx, value)| *value = func(*value, rhs.get(idx)));
+
+        self
     }
 
     /// Converts to vector representation.
     pub fn as_vec(&self) -> Vec<i32> {
-        if self.size == 0 {
+        if self.load.is_empty() {
             vec![0]
         } else {
-            self.load[..self.size].to_vec()
+            self.load.to_vec()
         }
     }
 }
 
 impl Load for MultiDimLoad {
     fn is_not_empty(&self) -> bool {
-        self.size == 0 || self.load.iter().any(|v| *v != 0)
+        self.load.is_empty() || self.load.iter().any(|v| *v != 0)
     }
 
     fn max_load(self, other: Self) -> Self {
-        let mut result = self;
-        result.load.iter_mut().zip(other.load.iter()).for_each(|(a, b)| *a = (*a).max(*b));
-
-        result
+        self.zip_with(&other, |a, b| a.max(b))
     }
 
     fn can_fit(&self, other: &Self) -> bool {
-        self.load.iter().zip(other.load.iter()).all(|(a, b)| a >= b)
+        (0..self.size().max(other.size())).all(|idx| self.get(idx) >= other.get(idx))
     }
 
     fn ratio(&self, other: &Self) -> f64 {
-        self.load.iter().zip(other.load.iter()).fold(0., |acc, (a, b)| (*a as f64 / *b as f64).max(acc))
+        (0..self.size().max(other.size())).fold(0., |acc, idx| (self.get(idx) as f64 / other.get(idx) as f64).max(acc))
     }
 }
 
 impl LoadOps for MultiDimLoad {}
 
-impl Default for MultiDimLoad {
-    fn default() -> Self {
-        Self { load: [0; LOAD_DIMENSION_SIZE], size: 0 }
-    }
-}
-
 impl Add for MultiDimLoad {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        fn sum(acc: MultiDimLoad, rhs: &MultiDimLoad) -> MultiDimLoad {
-            let mut dimens = acc;
-
-            for (idx, value) in rhs.load.iter().enumerate() {
-                dimens.load[idx] += *value;
-            }
-
-            dimens.size = dimens.size.max(rhs.size);
-
-            dimens
-        }
-
-        if self.load.len() >= rhs.load.len() {
-            sum(self, &rhs)
-        } else {
-            sum(rhs, &self)
-        }
+        self.zip_with(&rhs, |a, b| a + b)
     }
 }
 
@@ -292,21 +275,13 @@ impl Sub for MultiDimLoad {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut dimens = self;
-
-        for (idx, value) in rhs.load.iter().enumerate() {
-            dimens.load[idx] -= *value;
-        }
-
-        dimens.size = dimens.size.max(rhs.size);
-
-        dimens
+        self.zip_with(&rhs, |a, b| a - b)
     }
 }
 
 impl PartialOrd for MultiDimLoad {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let size = self.size.max(other.size);
+        let size = self.size().max(other.size());
         (0..size)
             .try_fold(None, |acc, idx| {
                 let result = self.get(idx).cmp(&other.get(idx));
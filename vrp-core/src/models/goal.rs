@@ -19,8 +19,10 @@ use std::sync::Arc;
 
 /// A type alias for a list of feature objectives.
 type Objectives = Vec<Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync>>;
+/// A type alias for a single level of global objective: objectives with their comparison policy.
+type GlobalLevel = (Objectives, LevelPolicy);
 /// A type alias for a pair of alternative objectives (global and local).
-type Alternative = (Vec<Objectives>, Vec<Objectives>);
+type Alternative = (Vec<GlobalLevel>, Vec<Objectives>);
 
 /// Defines Vehicle Routing Problem variant by global and local objectives:
 /// A **global objective** defines the way two VRP solutions are compared in order to select better one:
@@ -37,7 +39,7 @@ type Alternative = (Vec<Objectives>, Vec<Objectives>);
 /// for vehicles/jobs, etc.
 #[derive(Clone, Default)]
 pub struct GoalContext {
-    global_objectives: Vec<Objectives>,
+    global_objectives: Vec<GlobalLevel>,
     local_objectives: Vec<Objectives>,
     flatten_objectives: Objectives,
     alternatives: Option<(Vec<Alternative>, f64)>,
@@ -52,6 +54,49 @@ pub struct Target {
     pub global: Vec<Vec<String>>,
     /// A local hierarchical objective function specified by names of corresponding features.
     pub local: Vec<Vec<String>>,
+    /// Policies which specify how solutions are compared on each level of global objective function.
+    /// A level without policy uses default one.
+    pub policies: Vec<LevelPolicy>,
+}
+
+/// Specifies how two solutions are compared on a single level of global objective function.
+/// By default, level's objectives are compared using dominance order of their fitness values.
+#[derive(Clone, Debug, Default)]
+pub struct LevelPolicy {
+    /// Positive weights of level's objectives. When specified, solutions are compared by weighted sum of
+    /// objectives' fitness values instead of dominance order. The weights are also applied to
+    /// insertion cost estimates of a local objective level which consists of this level's objectives.
+    pub weights: Option<Vec<f64>>,
+    /// A tolerance within which fitness values are considered equal, so the comparison falls through
+    /// to the next level.
+    pub tolerance: Option<LevelTolerance>,
+}
+
+/// Specifies a tolerance of fitness values comparison. Two values are considered equal when their
+/// difference does not exceed either absolute or relative threshold.
+///
+/// Please note, that such comparison is not transitive: `a` can be equal to `b` and `b` to `c`, while
+/// `a` is less than `c`. Solutions are ranked using pairwise comparisons, so it does not break the
+/// search, but a chain of slightly worse solutions can be accepted over time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LevelTolerance {
+    /// A max difference relative to the biggest magnitude of two values, e.g. 0.02 means 2%.
+    pub relative: f64,
+    /// A max absolute difference.
+    pub absolute: f64,
+}
+
+impl LevelTolerance {
+    /// Compares two fitness values within the tolerance.
+    pub fn compare(&self, a: f64, b: f64) -> Ordering {
+        let difference = (a - b).abs();
+
+        if difference <= self.absolute || difference <= self.relative * a.abs().max(b.abs()) {
+            Ordering::Equal
+        } else {
+            compare_floats(a, b)
+        }
+    }
 }
 
 /// A `Goal` type defines the goal of optimization in terms of feature names.
@@ -70,7 +115,8 @@ impl Goal {
     where
         I: IntoIterator<Item = Vec<String>>,
     {
-        let target = Target { global: global.into_iter().collect(), local: local.into_iter().collect() };
+        let target =
+            Target { global: global.into_iter().collect(), local: local.into_iter().collect(), policies: vec![] };
 
         Self { target, alternatives: None }
     }
@@ -82,16 +128,24 @@ impl Goal {
     where
         I: IntoIterator<Item = Vec<String>>,
     {
-        let target = Target { global: global.into_iter().collect(), local: local.into_iter().collect() };
+        let target =
+            Target { global: global.into_iter().collect(), local: local.into_iter().collect(), policies: vec![] };
 
         let (alternatives, probability) = alternatives;
         let alternatives = alternatives
             .into_iter()
-            .map(|(g, l)| Target { global: g.into_iter().collect(), local: l.into_iter().collect() })
+            .map(|(g, l)| Target { global: g.into_iter().collect(), local: l.into_iter().collect(), policies: vec![] })
             .collect();
 
         Self { target, alternatives: Some((alternatives, probability)) }
     }
+
+    /// Sets policies which specify how solutions are compared on each level of target's global
+    /// objective function.
+    pub fn with_policies(mut self, policies: Vec<LevelPolicy>) -> Self {
+        self.target.policies = policies;
+        self
+    }
 }
 
 impl GoalContext {
@@ -144,15 +198,67 @@ impl GoalContext {
             })
         };
 
-        let global_objectives = remap_objectives(&goal.target.global)?;
-        let local_objectives = remap_objectives(&goal.target.local)?;
+        let remap_global = |target: &Target| -> Result<Vec<GlobalLevel>, GenericError> {
+            if target.policies.len() > target.global.len() {
+                return Err("the amount of level policies exceeds the amount of global objective levels".into());
+            }
+
+            remap_objectives(&target.global)?
+                .into_iter()
+                .enumerate()
+                .map(|(idx, objectives)| {
+                    let policy = target.policies.get(idx).cloned().unwrap_or_default();
+                    check_level_policy(&policy, objectives.len())
+                        .map_err(|err| format!("invalid policy of global objective level {idx}: {err}"))?;
+
+                    Ok((objectives, policy))
+                })
+                .collect()
+        };
+
+        let remap_local = |target: &Target| -> Result<Vec<Objectives>, GenericError> {
+            let weighted_levels = target
+                .global
+                .iter()
+                .zip(target.policies.iter())
+                .filter_map(|(ids, policy)| policy.weights.as_ref().map(|weights| (ids, weights)))
+                .collect::<Vec<_>>();
+
+            Ok(remap_objectives(&target.local)?
+                .into_iter()
+                .zip(target.local.iter())
+                .map(|(objectives, local_ids)| {
+                    let weighted_level = weighted_levels.iter().find(|(global_ids, _)| {
+                        !local_ids.is_empty() && local_ids.iter().all(|id| global_ids.contains(id))
+                    });
+
+                    let Some((global_ids, weights)) = weighted_level else { return objectives };
+
+                    objectives
+                        .into_iter()
+                        .zip(local_ids.iter())
+                        .map(|(objective, id)| {
+                            let weight =
+                                global_ids.iter().position(|global_id| global_id == id).map(|idx| weights[idx]);
+                            let objective: Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync> =
+                                Arc::new(WeightedObjective { objective, weight: weight.unwrap_or(1.) });
+
+                            objective
+                        })
+                        .collect()
+                })
+                .collect())
+        };
+
+        let global_objectives = remap_global(&goal.target)?;
+        let local_objectives = remap_local(&goal.target)?;
 
         let alternatives = if let Some((alternatives, probability)) = goal.alternatives {
             let alternatives = alternatives
                 .into_iter()
                 .map(|target| {
-                    let global = remap_objectives(&target.global)?;
-                    let local = remap_objectives(&target.local)?;
+                    let global = remap_global(&target)?;
+                    let local = remap_local(&target)?;
                     Ok((global, local))
                 })
                 .collect::<Result<Vec<_>, GenericError>>()?;
@@ -168,7 +274,7 @@ impl GoalContext {
 
         let states = features.iter().filter_map(|feature| feature.state.clone()).collect();
         let constraints = features.iter().filter_map(|feature| feature.constraint.clone()).collect();
-        let flatten_objectives = global_objectives.iter().flat_map(|inners| inners.iter()).cloned().collect();
+        let flatten_objectives = global_objectives.iter().flat_map(|(inners, _)| inners.iter()).cloned().collect();
 
         Ok(Self { global_objectives, flatten_objectives, local_objectives, alternatives, constraints, states })
     }
@@ -381,11 +487,9 @@ impl MultiObjective for GoalContext {
     fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
         self.global_objectives
             .iter()
-            .try_fold(Ordering::Equal, |_, objectives| {
-                match dominance_order(a, b, objectives.iter().map(|o| o.as_ref())) {
-                    Ordering::Equal => ControlFlow::Continue(Ordering::Equal),
-                    order => ControlFlow::Break(order),
-                }
+            .try_fold(Ordering::Equal, |_, (objectives, policy)| match get_level_order(a, b, objectives, policy) {
+                Ordering::Equal => ControlFlow::Continue(Ordering::Equal),
+                order => ControlFlow::Break(order),
             })
             .unwrap_value()
     }
@@ -453,7 +557,7 @@ impl GoalContext {
         let (alternatives, _) = self.alternatives.as_ref().expect("no alternatives");
 
         let (global_objectives, local_objectives) = alternatives[idx].clone();
-        let flatten_objectives = global_objectives.iter().flat_map(|inners| inners.iter()).cloned().collect();
+        let flatten_objectives = global_objectives.iter().flat_map(|(inners, _)| inners.iter()).cloned().collect();
 
         Self { global_objectives, flatten_objectives, local_objectives, ..self.clone() }
     }
@@ -511,3 +615,96 @@ impl GoalContext {
             .collect()
     }
 }
+
+fn check_level_policy(policy: &LevelPolicy, size: usize) -> Result<(), GenericError> {
+    if let Some(weights) = policy.weights.as_ref() {
+        if weights.len() != size {
+            return Err(format!("expected {size} weights, got {}", weights.len()).into());
+        }
+
+        if weights.iter().any(|weight| !weight.is_finite() || *weight <= 0.) {
+            return Err("weights should be finite positive numbers".into());
+        }
+    }
+
+    if let Some(LevelTolerance { relative, absolute }) = policy.tolerance {
+        if [relative, absolute].iter().any(|value| !value.is_finite() || *value < 0.) {
+            return Err("tolerance should be specified by finite non-negative numbers".into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Compares two solutions on a single level of global objective function using its policy.
+fn get_level_order(
+    a: &InsertionContext,
+    b: &InsertionContext,
+    objectives: &Objectives,
+    policy: &LevelPolicy,
+) -> Ordering {
+    match (policy.weights.as_ref(), policy.tolerance.as_ref()) {
+        (Some(weights), tolerance) => {
+            let weighted_sum = |solution: &InsertionContext| {
+                objectives
+                    .iter()
+                    .zip(weights.iter())
+                    .map(|(objective, weight)| objective.fitness(solution) * weight)
+                    .sum()
+            };
+            let (fitness_a, fitness_b) = (weighted_sum(a), weighted_sum(b));
+
+            match tolerance {
+                Some(tolerance) => tolerance.compare(fitness_a, fitness_b),
+                None => compare_floats(fitness_a, fitness_b),
+            }
+        }
+        (None, Some(tolerance)) => {
+            let objectives = objectives
+                .iter()
+                .map(|objective| TolerantObjective { objective: objective.as_ref(), tolerance })
+                .collect::<Vec<_>>();
+
+            dominance_order(a, b, objectives.iter())
+        }
+        (None, None) => dominance_order(a, b, objectives.iter().map(|o| o.as_ref())),
+    }
+}
+
+/// An objective wrapper which compares fitness values within tolerance.
+struct TolerantObjective<'a> {
+    objective: &'a (dyn FeatureObjective<Solution = InsertionContext> + Send + Sync),
+    tolerance: &'a LevelTolerance,
+}
+
+impl Objective for TolerantObjective<'_> {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        self.tolerance.compare(self.objective.fitness(a), self.objective.fitness(b))
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.objective.fitness(solution)
+    }
+}
+
+/// An objective wrapper which scales fitness and insertion cost estimate by given weight.
+struct WeightedObjective {
+    objective: Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync>,
+    weight: f64,
+}
+
+impl Objective for WeightedObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.objective.fitness(solution) * self.weight
+    }
+}
+
+impl FeatureObjective for WeightedObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        self.objective.estimate(move_ctx) * self.weight
+    }
+}
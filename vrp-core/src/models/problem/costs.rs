@@ -4,8 +4,8 @@ mod costs_test;
 
 use crate::models::common::*;
 use crate::models::solution::{Activity, Route};
-use hashbrown::HashMap;
-use rosomaxa::prelude::GenericError;
+use hashbrown::{HashMap, HashSet};
+use rosomaxa::prelude::{compare_floats, GenericError};
 use rosomaxa::utils::CollectGroupBy;
 use std::sync::Arc;
 
@@ -76,6 +76,12 @@ pub trait TransportCost {
 
     /// Returns time-dependent travel distance between locations specific for given actor.
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance;
+
+    /// Returns locations which are close to the given one when routing data is sparse (e.g. k nearest
+    /// neighbours graph). `None` means that any location can be considered as a neighbour.
+    fn neighbours(&self, _: &Profile, _: Location) -> Option<Vec<Location>> {
+        None
+    }
 }
 
 /// Contains matrix routing data for specific profile and, optionally, time.
@@ -97,6 +103,22 @@ impl MatrixData {
     }
 }
 
+/// Contains sparse matrix routing data for specific profile: only routes between some locations are
+/// defined, e.g. between each location and its k nearest neighbours.
+pub struct SparseMatrixData {
+    /// A routing profile index.
+    pub index: usize,
+    /// Routing entries as (from, to, duration, distance).
+    pub entries: Vec<(Location, Location, Duration, Distance)>,
+}
+
+impl SparseMatrixData {
+    /// Creates `SparseMatrixData` instance.
+    pub fn new(index: usize, entries: Vec<(Location, Location, Duration, Distance)>) -> Self {
+        Self { index, entries }
+    }
+}
+
 /// A fallback for transport costs if from->to entry is not defined.
 pub trait TransportFallback: Send + Sync {
     /// Returns fallback duration.
@@ -106,8 +128,8 @@ pub trait TransportFallback: Send + Sync {
     fn distance(&self, profile: &Profile, from: Location, to: Location) -> Distance;
 }
 
-/// A trivial implementation of no fallback for transport cost.
-struct NoFallback;
+/// A trivial implementation of no fallback for transport cost: panics when route is unknown.
+pub struct NoFallback;
 
 impl TransportFallback for NoFallback {
     fn duration(&self, profile: &Profile, from: Location, to: Location) -> Duration {
@@ -119,6 +141,39 @@ impl TransportFallback for NoFallback {
     }
 }
 
+/// Specifies a piecewise constant speed band used by time dependent routing. A band starts at
+/// given time and lasts till the start of the next one.
+#[derive(Clone, Debug)]
+pub struct SpeedBand {
+    /// A time when the band starts.
+    pub start: Timestamp,
+    /// A speed factor relative to free flow speed defined by routing matrix (e.g. 0.5 means twice slower).
+    pub factor: f64,
+}
+
+/// Specifies how time dependent travel information is calculated for a routing profile.
+#[derive(Clone, Debug)]
+pub enum TravelModel {
+    /// Durations and distances are linearly interpolated between matrices with different timestamps.
+    /// When `fifo` is set, departing later never leads to arriving earlier (first-in-first-out property).
+    /// It can be set only for a profile with time aware matrices.
+    Interpolation {
+        /// Enforces FIFO property.
+        fifo: bool,
+    },
+
+    /// Durations are calculated from time agnostic matrix by walking piecewise constant speed bands.
+    /// The first band is applied to any time before its start, the last one lasts infinitely. Such
+    /// model has FIFO property by design. Cannot be used when any of the matrices is time aware.
+    SpeedBands(Vec<SpeedBand>),
+}
+
+impl Default for TravelModel {
+    fn default() -> Self {
+        Self::Interpolation { fifo: false }
+    }
+}
+
 /// Creates time agnostic or time aware routing costs based on matrix data passed.
 /// Panics at runtime if given route path is not present in matrix data.
 pub fn create_matrix_transport_cost(
@@ -132,6 +187,16 @@ pub fn create_matrix_transport_cost(
 pub fn create_matrix_transport_cost_with_fallback<T: TransportFallback + 'static>(
     costs: Vec<MatrixData>,
     fallback: T,
+) -> Result<Arc<dyn TransportCost + Send + Sync>, GenericError> {
+    create_matrix_transport_cost_with_models(costs, HashMap::default(), fallback)
+}
+
+/// Creates routing costs based on matrix data passed and travel models specified per profile index.
+/// Profiles without explicitly specified travel model use the default one.
+pub fn create_matrix_transport_cost_with_models<T: TransportFallback + 'static>(
+    costs: Vec<MatrixData>,
+    models: HashMap<usize, TravelModel>,
+    fallback: T,
 ) -> Result<Arc<dyn TransportCost + Send + Sync>, GenericError> {
     if costs.is_empty() {
         return Err("no matrix data found".into());
@@ -151,13 +216,181 @@ pub fn create_matrix_transport_cost_with_fallback<T: TransportFallback + 'static
         return Err("duration lengths don't match".into());
     }
 
-    Ok(if costs.iter().any(|costs| costs.timestamp.is_some()) {
-        Arc::new(TimeAwareMatrixTransportCost::new(costs, size, fallback)?)
+    let is_time_aware = costs.iter().any(|costs| costs.timestamp.is_some());
+
+    let bands = models
+        .iter()
+        .filter_map(|(&profile, model)| match model {
+            TravelModel::SpeedBands(bands) => Some((profile, bands.clone())),
+            TravelModel::Interpolation { .. } => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let fifo = models
+        .iter()
+        .filter_map(|(&profile, model)| match model {
+            TravelModel::Interpolation { fifo: true } => Some(profile),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    if fifo.iter().any(|&profile| !costs.iter().any(|matrix| matrix.index == profile && matrix.timestamp.is_some())) {
+        return Err("fifo can be used only with time aware matrices".into());
+    }
+
+    Ok(match (is_time_aware, bands.is_empty()) {
+        (true, false) => return Err("speed bands cannot be used with time aware matrices".into()),
+        (true, true) => Arc::new(TimeAwareMatrixTransportCost::new(costs, size, fifo, fallback)?),
+        (false, false) => Arc::new(SpeedBandMatrixTransportCost::new(
+            TimeAgnosticMatrixTransportCost::new(costs, size, fallback)?,
+            bands,
+        )?),
+        (false, true) => Arc::new(TimeAgnosticMatrixTransportCost::new(costs, size, fallback)?),
+    })
+}
+
+/// Creates time agnostic routing costs based on sparse matrix data and travel models specified per profile
+/// index. Routes which are not defined in sparse data are estimated by the fallback. Locations defined for
+/// a source location are reported as its neighbours, so sparse data is also used as a neighbourhood graph.
+pub fn create_sparse_matrix_transport_cost<T: TransportFallback + 'static>(
+    costs: Vec<SparseMatrixData>,
+    models: HashMap<usize, TravelModel>,
+    fallback: T,
+) -> Result<Arc<dyn TransportCost + Send + Sync>, GenericError> {
+    if costs.is_empty() {
+        return Err("no matrix data found".into());
+    }
+
+    let mut costs = costs;
+    costs.sort_by_key(|data| data.index);
+
+    if (0..).zip(costs.iter().map(|c| &c.index)).any(|(a, &b)| a != b) {
+        return Err("sparse matrix data should be specified once per profile".into());
+    }
+
+    if models.values().any(|model| matches!(model, TravelModel::Interpolation { fifo: true })) {
+        return Err("fifo can be used only with time aware matrices".into());
+    }
+
+    let bands = models
+        .into_iter()
+        .filter_map(|(profile, model)| match model {
+            TravelModel::SpeedBands(bands) => Some((profile, bands)),
+            TravelModel::Interpolation { .. } => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let matrices = costs.into_iter().map(|data| SparseMatrix::new(data.entries)).collect::<Result<Vec<_>, _>>()?;
+    let transport = SparseMatrixTransportCost { matrices, fallback };
+
+    Ok(if bands.is_empty() {
+        Arc::new(transport)
     } else {
-        Arc::new(TimeAgnosticMatrixTransportCost::new(costs, size, fallback)?)
+        Arc::new(SpeedBandMatrixTransportCost::new(transport, bands)?)
     })
 }
 
+/// Keeps routing data of a single profile in compressed sparse row format using compact types.
+struct SparseMatrix {
+    offsets: Vec<u32>,
+    targets: Vec<u32>,
+    durations: Vec<f32>,
+    distances: Vec<f32>,
+}
+
+impl SparseMatrix {
+    fn new(entries: Vec<(Location, Location, Duration, Distance)>) -> Result<Self, GenericError> {
+        let mut entries = entries;
+        entries.sort_by_key(|&(from, to, ..)| (from, to));
+
+        if entries.windows(2).any(|pair| (pair[0].0, pair[0].1) == (pair[1].0, pair[1].1)) {
+            return Err("sparse matrix has duplicate entries".into());
+        }
+
+        let max_location = entries.iter().map(|&(from, to, ..)| from.max(to)).max().unwrap_or(0);
+        if max_location >= u32::MAX as usize || entries.len() >= u32::MAX as usize {
+            return Err("sparse matrix is too big".into());
+        }
+
+        let rows = entries.last().map_or(0, |&(from, ..)| from + 1);
+        let mut offsets = vec![0_u32; rows + 1];
+        entries.iter().for_each(|&(from, ..)| offsets[from + 1] += 1);
+        (1..offsets.len()).for_each(|idx| offsets[idx] += offsets[idx - 1]);
+
+        let (targets, durations, distances) = entries.into_iter().fold(
+            (Vec::new(), Vec::new(), Vec::new()),
+            |(mut targets, mut durations, mut distances), (_, to, duration, distance)| {
+                targets.push(to as u32);
+                durations.push(duration as f32);
+                distances.push(distance as f32);
+
+                (targets, durations, distances)
+            },
+        );
+
+        Ok(Self { offsets, targets, durations, distances })
+    }
+
+    /// Returns a range of entries defined for the source location.
+    fn get_row(&self, from: Location) -> Option<std::ops::Range<usize>> {
+        match (self.offsets.get(from), self.offsets.get(from + 1)) {
+            (Some(&start), Some(&end)) => Some(start as usize..end as usize),
+            _ => None,
+        }
+    }
+
+    /// Returns an index of from->to entry, if it is defined.
+    fn get_index(&self, from: Location, to: Location) -> Option<usize> {
+        let row = self.get_row(from)?;
+        let to = u32::try_from(to).ok()?;
+
+        self.targets[row.clone()].binary_search(&to).ok().map(|idx| row.start + idx)
+    }
+
+    fn neighbours(&self, from: Location) -> Vec<Location> {
+        self.get_row(from).map_or_else(Vec::new, |row| self.targets[row].iter().map(|&to| to as Location).collect())
+    }
+}
+
+/// A time agnostic routing costs backed by sparse matrix.
+struct SparseMatrixTransportCost<T: TransportFallback> {
+    matrices: Vec<SparseMatrix>,
+    fallback: T,
+}
+
+impl<T: TransportFallback> TransportCost for SparseMatrixTransportCost<T> {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        let matrix = self.matrices.get(profile.index).unwrap();
+
+        matrix
+            .get_index(from, to)
+            .map(|idx| matrix.durations[idx] as Duration)
+            .unwrap_or_else(|| self.fallback.duration(profile, from, to))
+            * profile.scale
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        let matrix = self.matrices.get(profile.index).unwrap();
+
+        matrix
+            .get_index(from, to)
+            .map(|idx| matrix.distances[idx] as Distance)
+            .unwrap_or_else(|| self.fallback.distance(profile, from, to))
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn neighbours(&self, profile: &Profile, from: Location) -> Option<Vec<Location>> {
+        self.matrices.get(profile.index).map(|matrix| matrix.neighbours(from))
+    }
+}
+
 /// A time agnostic matrix routing costs.
 struct TimeAgnosticMatrixTransportCost<T: TransportFallback> {
     durations: Vec<Vec<Duration>>,
@@ -222,14 +455,14 @@ impl<T: TransportFallback> TransportCost for TimeAgnosticMatrixTransportCost<T>
 
 /// A time aware matrix costs.
 struct TimeAwareMatrixTransportCost<T: TransportFallback> {
-    costs: HashMap<usize, (Vec<u64>, Vec<MatrixData>)>,
+    costs: HashMap<usize, (Vec<Timestamp>, Vec<MatrixData>, bool)>,
     size: usize,
     fallback: T,
 }
 
 impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
     /// Creates an instance of `TimeAwareMatrixTransportCost`.
-    fn new(costs: Vec<MatrixData>, size: usize, fallback: T) -> Result<Self, GenericError> {
+    fn new(costs: Vec<MatrixData>, size: usize, fifo: HashSet<usize>, fallback: T) -> Result<Self, GenericError> {
         if costs.iter().any(|matrix| matrix.timestamp.is_none()) {
             return Err("time-aware routing requires all matrices to have timestamp".into());
         }
@@ -243,101 +476,209 @@ impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
         let costs = costs
             .into_iter()
             .map(|(profile, mut matrices)| {
-                matrices.sort_by(|a, b| (a.timestamp.unwrap() as u64).cmp(&(b.timestamp.unwrap() as u64)));
-                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap() as u64).collect();
+                matrices.sort_by(|a, b| compare_floats(a.timestamp.unwrap(), b.timestamp.unwrap()));
+                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap()).collect();
 
-                (profile, (timestamps, matrices))
+                (profile, (timestamps, matrices, fifo.contains(&profile)))
             })
             .collect();
 
         Ok(Self { costs, size, fallback })
     }
 
-    fn interpolate_duration(
+    /// Returns travel duration and distance between two locations at given time.
+    fn interpolate(
         &self,
         profile: &Profile,
         from: Location,
         to: Location,
         travel_time: TravelTime,
-    ) -> Duration {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
-        };
-
-        let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
+    ) -> (Duration, Distance) {
+        let (timestamps, matrices, is_fifo) = self.costs.get(&profile.index).unwrap();
         let data_idx = from * self.size + to;
 
-        let duration = match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => matrices.get(matrix_idx).unwrap().durations.get(data_idx).copied(),
-            Err(0) => matrices.first().unwrap().durations.get(data_idx).copied(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                matrices.last().unwrap().durations.get(data_idx).copied()
+        if matrices.first().and_then(|matrix| matrix.durations.get(data_idx)).is_none() {
+            return (
+                self.fallback.duration(profile, from, to) * profile.scale,
+                self.fallback.distance(profile, from, to),
+            );
+        }
+
+        let duration = |idx: usize| matrices[idx].durations[data_idx] * profile.scale;
+        let distance = |idx: usize| matrices[idx].distances[data_idx];
+
+        match (travel_time, is_fifo) {
+            (TravelTime::Departure(departure), true) => {
+                // NOTE departing later is never worse than departing now: vehicle can wait
+                let (departure_used, arrival) = timestamps
+                    .iter()
+                    .enumerate()
+                    .skip_while(|(_, &timestamp)| timestamp <= departure)
+                    .map(|(idx, &timestamp)| (timestamp, timestamp + duration(idx)))
+                    .fold(
+                        (departure, departure + interpolate_value(timestamps, departure, duration)),
+                        |best, candidate| if candidate.1 < best.1 { candidate } else { best },
+                    );
+
+                (arrival - departure, interpolate_value(timestamps, departure_used, distance))
+            }
+            (TravelTime::Arrival(arrival), true) => {
+                // NOTE find the latest departure time which allows to arrive in time
+                let last_idx = timestamps.len() - 1;
+                let departure = if timestamps[last_idx] + duration(last_idx) <= arrival {
+                    arrival - duration(last_idx)
+                } else {
+                    (0..last_idx)
+                        .rev()
+                        .find_map(|idx| {
+                            let (left_time, right_time) = (timestamps[idx], timestamps[idx + 1]);
+                            let left_arrival = left_time + duration(idx);
+                            let right_arrival = right_time + duration(idx + 1);
+
+                            if right_arrival <= arrival {
+                                Some(right_time)
+                            } else if left_arrival <= arrival {
+                                let ratio = (arrival - left_arrival) / (right_arrival - left_arrival);
+                                Some(left_time + ratio * (right_time - left_time))
+                            } else {
+                                None
+                            }
+                        })
+                        .unwrap_or_else(|| arrival - duration(0))
+                };
+
+                (arrival - departure, interpolate_value(timestamps, departure, distance))
             }
-            Err(matrix_idx) => {
-                let left_matrix = matrices.get(matrix_idx - 1).unwrap();
-                let right_matrix = matrices.get(matrix_idx).unwrap();
-
-                matrices
-                    .get(matrix_idx - 1)
-                    .unwrap()
-                    .durations
-                    .get(data_idx)
-                    .zip(matrices.get(matrix_idx).unwrap().durations.get(data_idx))
-                    .map(|(&left_value, &right_value)| {
-                        // perform linear interpolation
-                        let ratio = (timestamp - left_matrix.timestamp.unwrap())
-                            / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
-
-                        left_value + ratio * (right_value - left_value)
-                    })
+            (TravelTime::Arrival(timestamp), false) | (TravelTime::Departure(timestamp), false) => {
+                (interpolate_value(timestamps, timestamp, duration), interpolate_value(timestamps, timestamp, distance))
             }
         }
-        .unwrap_or_else(|| self.fallback.duration(profile, from, to));
+    }
+}
 
-        duration * profile.scale
+impl<T: TransportFallback> TransportCost for TimeAwareMatrixTransportCost<T> {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.interpolate(profile, from, to, TravelTime::Departure(0.)).0
     }
 
-    fn interpolate_distance(
-        &self,
-        profile: &Profile,
-        from: Location,
-        to: Location,
-        travel_time: TravelTime,
-    ) -> Distance {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
-        };
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.interpolate(profile, from, to, TravelTime::Departure(0.)).1
+    }
 
-        let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
-        let data_idx = from * self.size + to;
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.interpolate(&route.actor.vehicle.profile, from, to, travel_time).0
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.interpolate(&route.actor.vehicle.profile, from, to, travel_time).1
+    }
+}
 
-        match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => matrices.get(matrix_idx).unwrap().distances.get(data_idx),
-            Err(0) => matrices.first().unwrap().distances.get(data_idx),
-            Err(matrix_idx) if matrix_idx == matrices.len() => matrices.last().unwrap().distances.get(data_idx),
-            Err(matrix_idx) => matrices.get(matrix_idx - 1).unwrap().distances.get(data_idx),
+/// A time dependent matrix costs which walks piecewise constant speed bands (Ichoua, Gendreau and Potvin model)
+/// to adjust free flow durations defined by time agnostic matrix.
+struct SpeedBandMatrixTransportCost<T: TransportCost> {
+    inner: T,
+    bands: HashMap<usize, Vec<SpeedBand>>,
+}
+
+impl<T: TransportCost> SpeedBandMatrixTransportCost<T> {
+    /// Creates an instance of `SpeedBandMatrixTransportCost`.
+    fn new(inner: T, bands: HashMap<usize, Vec<SpeedBand>>) -> Result<Self, GenericError> {
+        if bands.values().any(|bands| bands.is_empty()) {
+            return Err("speed bands should not be empty".into());
+        }
+
+        if bands.values().flatten().any(|band| !band.factor.is_finite() || band.factor <= 0.) {
+            return Err("speed band factor should be positive".into());
+        }
+
+        if bands.values().any(|bands| bands.windows(2).any(|pair| pair[0].start >= pair[1].start)) {
+            return Err("speed bands should be sorted by start time without duplicates".into());
+        }
+
+        Ok(Self { inner, bands })
+    }
+
+    /// Returns travel duration between two locations walking bands forward from departure or backward from arrival.
+    fn walk_bands(&self, profile: &Profile, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let free_flow = self.inner.duration_approx(profile, from, to);
+
+        let Some(bands) = self.bands.get(&profile.index) else {
+            return free_flow;
+        };
+
+        let mut remaining = free_flow;
+
+        match travel_time {
+            TravelTime::Departure(departure) => {
+                let mut time = departure;
+                let mut idx = bands.partition_point(|band| band.start <= departure).saturating_sub(1);
+
+                loop {
+                    let factor = bands[idx].factor;
+                    match bands.get(idx + 1) {
+                        Some(next) if time + remaining / factor > next.start => {
+                            remaining -= (next.start - time) * factor;
+                            time = next.start;
+                            idx += 1;
+                        }
+                        _ => return time + remaining / factor - departure,
+                    }
+                }
+            }
+            TravelTime::Arrival(arrival) => {
+                let mut time = arrival;
+                let mut idx = bands.partition_point(|band| band.start < arrival).saturating_sub(1);
+
+                loop {
+                    let band = &bands[idx];
+                    if idx > 0 && time - remaining / band.factor < band.start {
+                        remaining -= (time - band.start) * band.factor;
+                        time = band.start;
+                        idx -= 1;
+                    } else {
+                        return arrival - (time - remaining / band.factor);
+                    }
+                }
+            }
         }
-        .copied()
-        .unwrap_or_else(|| self.fallback.distance(profile, from, to))
     }
 }
 
-impl<T: TransportFallback> TransportCost for TimeAwareMatrixTransportCost<T> {
+impl<T: TransportCost> TransportCost for SpeedBandMatrixTransportCost<T> {
     fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
-        self.interpolate_duration(profile, from, to, TravelTime::Departure(0.))
+        self.inner.duration_approx(profile, from, to)
     }
 
     fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
-        self.interpolate_distance(profile, from, to, TravelTime::Departure(0.))
+        self.inner.distance_approx(profile, from, to)
     }
 
     fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
-        self.interpolate_duration(&route.actor.vehicle.profile, from, to, travel_time)
+        self.walk_bands(&route.actor.vehicle.profile, from, to, travel_time)
     }
 
-    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
-        self.interpolate_distance(&route.actor.vehicle.profile, from, to, travel_time)
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.inner.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn neighbours(&self, profile: &Profile, from: Location) -> Option<Vec<Location>> {
+        self.inner.neighbours(profile, from)
+    }
+}
+
+/// Linearly interpolates value between two closest timestamps. Uses the first or the last value
+/// when timestamp is out of range.
+fn interpolate_value<F: Fn(usize) -> f64>(timestamps: &[Timestamp], timestamp: Timestamp, value: F) -> f64 {
+    match timestamps.partition_point(|&time| time <= timestamp) {
+        0 => value(0),
+        idx if idx == timestamps.len() => value(idx - 1),
+        idx => {
+            let (left_time, right_time) = (timestamps[idx - 1], timestamps[idx]);
+            let ratio = (timestamp - left_time) / (right_time - left_time);
+            let left_value = value(idx - 1);
+
+            left_value + ratio * (value(idx) - left_value)
+        }
     }
 }
@@ -8,6 +8,7 @@ use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering::Less;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
+use std::iter::once;
 use std::sync::Arc;
 
 /// Represents operating costs for driver and vehicle.
@@ -25,12 +26,14 @@ pub struct Costs {
     pub per_service_time: f64,
 }
 
-/// Represents driver detail (reserved for future use).
+/// Represents driver detail (driver shift).
 #[derive(Clone, Hash, Eq, PartialEq)]
-pub struct DriverDetail {}
+pub struct DriverDetail {
+    /// Time window when driver is available for work.
+    pub time: TimeWindow,
+}
 
-/// Represents a driver, person who drives Vehicle. Reserved for future usage, e.g. to allow
-/// reuse same vehicle more than once at different times.
+/// Represents a driver, person who drives Vehicle.
 pub struct Driver {
     /// Specifies operating costs for driver.
     pub costs: Costs,
@@ -38,7 +41,8 @@ pub struct Driver {
     /// Dimensions which contains extra work requirements.
     pub dimens: Dimensions,
 
-    /// Specifies driver details.
+    /// Specifies driver details. Each detail can be assigned to one actor at most at the same time.
+    /// A driver without details is available at any time and can be shared by all actors.
     pub details: Vec<DriverDetail>,
 }
 
@@ -52,6 +56,16 @@ pub struct VehiclePlace {
     pub time: TimeInterval,
 }
 
+/// Specifies alternative start and end locations (depots) of the vehicle detail.
+#[derive(Clone, Default, Hash, Eq, PartialEq)]
+pub struct VehicleDepots {
+    /// Alternative start locations.
+    pub start: Vec<Location>,
+
+    /// Alternative end locations.
+    pub end: Vec<Location>,
+}
+
 /// Represents a vehicle detail (vehicle shift).
 #[derive(Clone, Hash, Eq, PartialEq)]
 pub struct VehicleDetail {
@@ -60,6 +74,10 @@ pub struct VehicleDetail {
 
     /// A place where vehicle ends.
     pub end: Option<VehiclePlace>,
+
+    /// Alternative locations of start and end places. An actor is created for each combination of
+    /// start and end locations, but only one of them can be used at the same time.
+    pub depots: VehicleDepots,
 }
 
 /// Represents a vehicle.
@@ -113,6 +131,13 @@ impl Debug for Actor {
 /// A grouping function for collection of actors.
 pub type ActorGroupKeyFn = Box<dyn Fn(&[Arc<Actor>]) -> Box<dyn Fn(&Arc<Actor>) -> usize + Send + Sync>>;
 
+/// A function which returns a pairing key of the vehicle. The vehicle can be driven only by drivers
+/// which have its key or have no keys at all. `None` means that any driver can drive the vehicle.
+pub type VehiclePairingKeyFn = Box<dyn Fn(&Vehicle) -> Option<String>>;
+
+/// A function which returns pairing keys of the driver. `None` means that the driver can drive any vehicle.
+pub type DriverPairingKeysFn = Box<dyn Fn(&Driver) -> Option<Vec<String>>>;
+
 /// Represents available resources to serve jobs.
 pub struct Fleet {
     /// All fleet drivers.
@@ -129,39 +154,124 @@ pub struct Fleet {
 
     /// A grouped actors.
     pub groups: HashMap<usize, HashSet<Arc<Actor>>>,
+
+    /// Actors which cannot be used together with given one as they share the same vehicle
+    /// detail or the same driver detail.
+    pub conflicts: HashMap<Arc<Actor>, Vec<Arc<Actor>>>,
 }
 
 impl Fleet {
     /// Creates a new instance of `Fleet`.
     pub fn new(drivers: Vec<Arc<Driver>>, vehicles: Vec<Arc<Vehicle>>, group_key: ActorGroupKeyFn) -> Fleet {
-        // TODO we should also consider multiple drivers to support smart vehicle-driver assignment.
-        assert_eq!(drivers.len(), 1);
+        Self::new_with_pairing(drivers, vehicles, group_key, Box::new(|_| None), Box::new(|_| None))
+    }
+
+    /// Creates a new instance of `Fleet` using pairing keys which decide whether the driver can be
+    /// paired with the vehicle. An actor is created for each vehicle detail (and each combination of
+    /// its start and end locations) and each compatible driver detail which time intersects with
+    /// vehicle detail's one.
+    pub fn new_with_pairing(
+        drivers: Vec<Arc<Driver>>,
+        vehicles: Vec<Arc<Vehicle>>,
+        group_key: ActorGroupKeyFn,
+        vehicle_key_fn: VehiclePairingKeyFn,
+        driver_keys_fn: DriverPairingKeysFn,
+    ) -> Fleet {
+        assert!(!drivers.is_empty());
         assert!(!vehicles.is_empty());
 
+        // NOTE drivers are indexed by pairing key to avoid checking all driver-vehicle pairs
+        let all_drivers = (0..drivers.len()).collect::<Vec<_>>();
+        let (any_drivers, keyed_drivers) = drivers.iter().enumerate().fold(
+            (Vec::new(), HashMap::<String, Vec<usize>>::new()),
+            |(mut any_drivers, mut keyed_drivers), (driver_idx, driver)| {
+                match (driver_keys_fn)(driver.as_ref()) {
+                    Some(keys) => {
+                        keys.into_iter().for_each(|key| keyed_drivers.entry(key).or_default().push(driver_idx))
+                    }
+                    None => any_drivers.push(driver_idx),
+                }
+                (any_drivers, keyed_drivers)
+            },
+        );
+        let get_driver_indices = |vehicle: &Vehicle| match (vehicle_key_fn)(vehicle) {
+            Some(key) => {
+                let mut indices = any_drivers
+                    .iter()
+                    .chain(keyed_drivers.get(&key).into_iter().flatten())
+                    .copied()
+                    .collect::<Vec<_>>();
+                indices.sort_unstable();
+                indices.dedup();
+                indices
+            }
+            None => all_drivers.clone(),
+        };
+
         let profiles: HashMap<usize, Profile> = vehicles.iter().map(|v| (v.profile.index, v.profile.clone())).collect();
         let mut profiles = profiles.into_iter().collect::<Vec<_>>();
         profiles.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Less));
         let (_, profiles): (Vec<_>, Vec<_>) = profiles.into_iter().unzip();
 
-        let actors = vehicles
-            .iter()
-            .flat_map(|vehicle| {
-                vehicle.details.iter().map(|detail| {
-                    Arc::new(Actor {
-                        vehicle: vehicle.clone(),
-                        driver: drivers.first().unwrap().clone(),
-                        detail: ActorDetail {
-                            start: detail.start.clone(),
-                            end: detail.end.clone(),
-                            time: TimeWindow {
-                                start: detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
-                                end: detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(f64::MAX),
-                            },
-                        },
-                    })
-                })
+        // NOTE keep track of vehicle and driver details used by each actor to detect conflicts
+        let mut resources: HashMap<(usize, usize), Vec<Arc<Actor>>> = HashMap::new();
+        let mut actors = Vec::new();
+
+        for (vehicle_idx, vehicle) in vehicles.iter().enumerate() {
+            let vehicle_details = vehicle
+                .details
+                .iter()
+                .enumerate()
+                .flat_map(|(detail_idx, detail)| get_depot_details(detail).map(move |detail| (detail_idx, detail)));
+
+            let driver_indices = get_driver_indices(vehicle.as_ref());
+
+            for (vehicle_detail_idx, vehicle_detail) in vehicle_details {
+                let vehicle_detail = &vehicle_detail;
+                let vehicle_key = (vehicle_idx, vehicle_detail_idx);
+
+                for &driver_idx in driver_indices.iter() {
+                    let driver = &drivers[driver_idx];
+
+                    if driver.details.is_empty() {
+                        if let Some(actor) = create_actor(vehicle, vehicle_detail, driver, None) {
+                            resources.entry(vehicle_key).or_default().push(actor.clone());
+                            actors.push(actor);
+                        }
+                        continue;
+                    }
+
+                    // NOTE driver keys are shifted to avoid clashes with vehicle keys
+                    let driver_keys = driver
+                        .details
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, detail)| ((vehicles.len() + driver_idx, idx), detail));
+
+                    for (driver_key, driver_detail) in driver_keys {
+                        if let Some(actor) = create_actor(vehicle, vehicle_detail, driver, Some(driver_detail)) {
+                            resources.entry(vehicle_key).or_default().push(actor.clone());
+                            resources.entry(driver_key).or_default().push(actor.clone());
+                            actors.push(actor);
+                        }
+                    }
+                }
+            }
+        }
+
+        let conflicts = resources
+            .into_values()
+            .filter(|actors| actors.len() > 1)
+            .fold(HashMap::<_, HashSet<_>>::new(), |mut acc, resource_actors| {
+                resource_actors.iter().for_each(|actor| {
+                    let conflicts = acc.entry(actor.clone()).or_default();
+                    conflicts.extend(resource_actors.iter().filter(|&other| other != actor).cloned());
+                });
+                acc
             })
-            .collect::<Vec<_>>();
+            .into_iter()
+            .map(|(actor, conflicts)| (actor, conflicts.into_iter().collect()))
+            .collect();
 
         let group_key = (*group_key)(&actors);
         let groups: HashMap<_, HashSet<_>> = actors.iter().cloned().fold(HashMap::new(), |mut acc, actor| {
@@ -169,10 +279,63 @@ impl Fleet {
             acc
         });
 
-        Fleet { drivers, vehicles, profiles, actors, groups }
+        Fleet { drivers, vehicles, profiles, actors, groups, conflicts }
     }
 }
 
+/// Returns vehicle details with all combinations of start and end locations.
+fn get_depot_details(detail: &VehicleDetail) -> impl Iterator<Item = VehicleDetail> + '_ {
+    // NOTE alternative locations are ignored when there is no corresponding place
+    let get_places = |place: &Option<VehiclePlace>, alternatives: &[Location]| match place {
+        Some(place) => once(place.location)
+            .chain(alternatives.iter().copied().filter(|&location| location != place.location))
+            .map(|location| Some(VehiclePlace { location, ..place.clone() }))
+            .collect::<Vec<_>>(),
+        None => vec![None],
+    };
+
+    let starts = get_places(&detail.start, &detail.depots.start);
+    let ends = get_places(&detail.end, &detail.depots.end);
+
+    starts.into_iter().flat_map(move |start| {
+        ends.clone().into_iter().map(move |end| VehicleDetail {
+            start: start.clone(),
+            end,
+            depots: VehicleDepots::default(),
+        })
+    })
+}
+
+fn create_actor(
+    vehicle: &Arc<Vehicle>,
+    vehicle_detail: &VehicleDetail,
+    driver: &Arc<Driver>,
+    driver_detail: Option<&DriverDetail>,
+) -> Option<Arc<Actor>> {
+    let mut start = vehicle_detail.start.clone();
+    let mut end = vehicle_detail.end.clone();
+
+    let mut time = TimeWindow {
+        start: start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
+        end: end.as_ref().and_then(|e| e.time.latest).unwrap_or(f64::MAX),
+    };
+
+    if let Some(driver_detail) = driver_detail {
+        time = time.overlapping(&driver_detail.time)?;
+
+        if let Some(start) = start.as_mut() {
+            start.time.earliest = Some(time.start);
+            start.time.latest = Some(start.time.latest.unwrap_or(f64::MAX).min(time.end));
+        }
+
+        if let Some(end) = end.as_mut() {
+            end.time.latest = Some(time.end);
+        }
+    }
+
+    Some(Arc::new(Actor { vehicle: vehicle.clone(), driver: driver.clone(), detail: ActorDetail { start, end, time } }))
+}
+
 impl Debug for Fleet {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(short_type_name::<Self>())
@@ -5,7 +5,7 @@ mod jobs_test;
 use crate::models::common::*;
 use crate::models::problem::{Costs, Fleet, TransportCost};
 use crate::utils::short_type_name;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use rosomaxa::utils::compare_floats_f32;
 use std::cmp::Ordering::Less;
 use std::fmt::{Debug, Formatter};
@@ -306,6 +306,10 @@ fn create_index(
     transport: &Arc<dyn TransportCost + Send + Sync>,
 ) -> HashMap<usize, JobIndex> {
     let avg_profile_costs = get_avg_profile_costs(fleet);
+    let location_jobs = jobs.iter().fold(HashMap::<Location, Vec<Job>>::new(), |mut acc, job| {
+        get_job_locations(job).flatten().for_each(|location| acc.entry(location).or_default().push(job.clone()));
+        acc
+    });
 
     fleet.profiles.iter().fold(HashMap::new(), |mut acc, profile| {
         let avg_costs = avg_profile_costs.get(&profile.index).unwrap();
@@ -320,12 +324,13 @@ fn create_index(
 
         // create job index
         let item = jobs.iter().cloned().fold(HashMap::new(), |mut acc, job| {
-            let mut sorted_job_costs: Vec<(Job, LowPrecisionCost)> = jobs
-                .iter()
-                .filter(|j| **j != job)
-                .map(|j| (j.clone(), get_cost_between_jobs(profile, avg_costs, transport.as_ref(), &job, j)))
-                .take(MAX_NEIGHBOURS)
-                .collect();
+            let get_job_cost =
+                |j: &Job| (j.clone(), get_cost_between_jobs(profile, avg_costs, transport.as_ref(), &job, j));
+            let mut sorted_job_costs: Vec<(Job, LowPrecisionCost)> =
+                match get_sparse_neighbours(profile, transport.as_ref(), &location_jobs, &job) {
+                    Some(neighbours) => neighbours.iter().map(get_job_cost).collect(),
+                    None => jobs.iter().filter(|j| **j != job).map(get_job_cost).take(MAX_NEIGHBOURS).collect(),
+                };
             sorted_job_costs.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Less));
 
             let fleet_costs = starts
@@ -344,6 +349,32 @@ fn create_index(
     })
 }
 
+/// Returns jobs which are located at the same or neighbour locations when transport cost is backed by
+/// sparse routing data. Returns `None` when neighbourhood is not restricted.
+fn get_sparse_neighbours(
+    profile: &Profile,
+    transport: &(dyn TransportCost + Send + Sync),
+    location_jobs: &HashMap<Location, Vec<Job>>,
+    job: &Job,
+) -> Option<Vec<Job>> {
+    let locations = get_job_locations(job).collect::<Option<Vec<_>>>().filter(|locations| !locations.is_empty())?;
+    let neighbours =
+        locations.iter().map(|&location| transport.neighbours(profile, location)).collect::<Option<Vec<_>>>()?;
+
+    let mut visited = HashSet::new();
+
+    Some(
+        locations
+            .into_iter()
+            .chain(neighbours.into_iter().flatten())
+            .filter_map(|location| location_jobs.get(&location))
+            .flatten()
+            .filter(|&neighbour| neighbour != job && visited.insert(neighbour.clone()))
+            .cloned()
+            .collect(),
+    )
+}
+
 fn get_cost_between_locations(
     profile: &Profile,
     costs: &Costs,
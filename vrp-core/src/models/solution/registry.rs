@@ -12,6 +12,8 @@ pub struct Registry {
     available: HashMap<usize, HashSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
     all: Vec<Arc<Actor>>,
+    used: HashSet<Arc<Actor>>,
+    conflicts: Arc<HashMap<Arc<Actor>, Vec<Arc<Actor>>>>,
     random: Arc<dyn Random + Send + Sync>,
 }
 
@@ -24,19 +26,56 @@ impl Registry {
             .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
             .collect();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        Self {
+            available: fleet.groups.clone(),
+            index,
+            all: fleet.actors.to_vec(),
+            used: HashSet::default(),
+            conflicts: Arc::new(fleet.conflicts.clone()),
+            random,
+        }
     }
 
-    /// Removes an actor from the list of available actors.
+    /// Removes an actor from the list of available actors. Actors which share the same vehicle or
+    /// driver with the given one are removed too.
     /// Returns whether the actor was present in the registry.
     pub fn use_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().remove(actor)
+        let is_removed = self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().remove(actor);
+        self.used.insert(actor.clone());
+
+        if let Some(conflicts) = self.conflicts.get(actor) {
+            conflicts.iter().for_each(|conflict| {
+                if let Some(group_id) = self.index.get(conflict) {
+                    self.available.get_mut(group_id).unwrap().remove(conflict);
+                }
+            });
+        }
+
+        is_removed
     }
 
-    /// Adds actor to the list of available actors.
+    /// Adds actor to the list of available actors. Actors which share the same vehicle or driver
+    /// with the given one are added back too, if they have no other used conflicting actors.
     /// Returns whether the actor was not present in the registry.
     pub fn free_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().insert(actor.clone())
+        self.used.remove(actor);
+        let is_added =
+            self.available.get_mut(self.index.get(actor).expect("unknown actor")).unwrap().insert(actor.clone());
+
+        let released = self
+            .conflicts
+            .get(actor)
+            .into_iter()
+            .flatten()
+            .filter(|conflict| self.can_be_available(conflict))
+            .filter_map(|conflict| self.index.get(conflict).map(|group_id| (*group_id, conflict.clone())))
+            .collect::<Vec<_>>();
+
+        released.into_iter().for_each(|(group_id, conflict)| {
+            self.available.get_mut(&group_id).unwrap().insert(conflict);
+        });
+
+        is_added
     }
 
     /// Returns all actors.
@@ -64,6 +103,8 @@ impl Registry {
             available: self.available.clone(),
             index: self.index.clone(),
             all: self.all.clone(),
+            used: self.used.clone(),
+            conflicts: self.conflicts.clone(),
             random: self.random.clone(),
         }
     }
@@ -86,7 +127,14 @@ impl Registry {
                 .map(|(actor, idx)| (actor.clone(), *idx))
                 .collect(),
             all: self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            used: self.used.clone(),
+            conflicts: self.conflicts.clone(),
             random: self.random.clone(),
         }
     }
+
+    fn can_be_available(&self, actor: &Arc<Actor>) -> bool {
+        !self.used.contains(actor)
+            && self.conflicts.get(actor).into_iter().flatten().all(|other| !self.used.contains(other))
+    }
 }
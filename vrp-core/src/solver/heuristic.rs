@@ -444,6 +444,7 @@ mod statik {
                 (Arc::new(ExchangeInterRouteRandom::default()), 30),
                 (Arc::new(ExchangeIntraRouteRandom::default()), 30),
                 (Arc::new(RescheduleDeparture::default()), 20),
+                (Arc::new(SwitchDepot::default()), 10),
             ],
             1,
             2,
@@ -653,6 +654,7 @@ mod dynamic {
                 (Arc::new(ExchangeInterRouteRandom::default()), 1),
                 (Arc::new(ExchangeIntraRouteRandom::default()), 1),
                 (Arc::new(ExchangeSequence::default()), 1),
+                (Arc::new(SwitchDepot::default()), 1),
             ],
             1,
             1,
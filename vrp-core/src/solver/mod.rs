@@ -87,6 +87,10 @@
 
 extern crate rand;
 
+#[cfg(test)]
+#[path = "../../tests/unit/solver/solver_test.rs"]
+mod solver_test;
+
 use crate::construction::heuristics::{InsertionContext, StateKey};
 use crate::models::{GoalContext, Problem, Solution};
 use crate::solver::search::Recreate;
@@ -113,6 +117,13 @@ const SOLUTION_ORDER_KEY: StateKey = StateKey(2);
 /// A key to store solution weights information.
 const SOLUTION_WEIGHTS_KEY: StateKey = StateKey(3);
 
+/// A callback which is called with each new best known solution discovered during the search.
+pub type BestSolutionCallback = Arc<dyn Fn(&Solution) + Send + Sync>;
+
+/// Solution post processing hooks shared between the solver and best known solution notifications.
+type SolutionProcessingHooks =
+    Arc<Vec<Box<dyn HeuristicSolutionProcessing<Solution = InsertionContext> + Send + Sync>>>;
+
 /// A type which encapsulates information needed to perform solution refinement process.
 pub struct RefinementContext {
     /// Original problem definition.
@@ -123,6 +134,10 @@ pub struct RefinementContext {
     pub state: HashMap<String, Box<dyn Any + Sync + Send>>,
     /// Provides some basic implementation of context functionality.
     inner_context: TelemetryHeuristicContext<GoalContext, InsertionContext>,
+    /// A callback to notify about a new best known solution and fitness of the last reported one.
+    best_solution_callback: Option<(BestSolutionCallback, Option<Vec<f64>>)>,
+    /// Post processing applied to a best known solution before it is reported.
+    best_solution_processing: Option<SolutionProcessingHooks>,
 }
 
 /// Defines instant refinement speed type.
@@ -145,13 +160,49 @@ impl RefinementContext {
     ) -> Self {
         let inner_context =
             TelemetryHeuristicContext::new(problem.goal.clone(), population, telemetry_mode, environment.clone());
-        Self { problem, environment, inner_context, state: Default::default() }
+        Self {
+            problem,
+            environment,
+            inner_context,
+            state: Default::default(),
+            best_solution_callback: None,
+            best_solution_processing: None,
+        }
     }
 
     /// Adds solution to population.
     pub fn add_solution(&mut self, solution: InsertionContext) {
         self.inner_context.add_solution(solution);
     }
+
+    /// Sets a callback which is called with each new best known solution.
+    pub fn set_best_solution_callback(&mut self, callback: BestSolutionCallback) {
+        self.best_solution_callback = Some((callback, None));
+    }
+
+    /// Sets target fitness, e.g. of the best known solution, used to report a gap in telemetry.
+    pub fn set_target_fitness(&mut self, target_fitness: Vec<f64>) {
+        self.inner_context.set_target_fitness(target_fitness);
+    }
+
+    fn notify_best_solution(&mut self) {
+        let Some((callback, last_fitness)) = self.best_solution_callback.as_mut() else { return };
+        let Some((best, _)) = self.inner_context.ranked().next() else { return };
+
+        // NOTE population keeps the best known individual on top, so its fitness changes only on improvement
+        let fitness = best.fitness().collect::<Vec<_>>();
+        if last_fitness.as_ref() != Some(&fitness) {
+            // NOTE apply the same post processing as for the final solution, e.g. unclustering
+            let insertion_ctx = self
+                .best_solution_processing
+                .iter()
+                .flat_map(|hooks| hooks.iter())
+                .fold(best.deep_copy(), |insertion_ctx, hook| hook.post_process(insertion_ctx));
+
+            (callback)(&(insertion_ctx.solution, None).into());
+            *last_fitness = Some(fitness);
+        }
+    }
 }
 
 impl HeuristicContext for RefinementContext {
@@ -183,11 +234,13 @@ impl HeuristicContext for RefinementContext {
     }
 
     fn on_initial(&mut self, solution: Self::Solution, item_time: Timer) {
-        self.inner_context.on_initial(solution, item_time)
+        self.inner_context.on_initial(solution, item_time);
+        self.notify_best_solution();
     }
 
     fn on_generation(&mut self, offspring: Vec<Self::Solution>, termination_estimate: f64, generation_time: Timer) {
-        self.inner_context.on_generation(offspring, termination_estimate, generation_time)
+        self.inner_context.on_generation(offspring, termination_estimate, generation_time);
+        self.notify_best_solution();
     }
 
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution> {
@@ -285,23 +338,62 @@ impl Solver {
         Self { problem, config }
     }
 
+    /// Sets a callback which is called with each new best known solution discovered during the search.
+    /// Such solutions are post processed the same way as the final one.
+    pub fn with_best_solution_callback(mut self, callback: BestSolutionCallback) -> Self {
+        self.config.context.set_best_solution_callback(callback);
+        self
+    }
+
+    /// Sets target fitness, e.g. of the best known solution, to report a gap of found solutions
+    /// in telemetry. Use `with_target_proximity` of config builder to stop search close to it.
+    pub fn with_target_fitness(mut self, target_fitness: Vec<f64>) -> Self {
+        self.config.context.set_target_fitness(target_fitness);
+        self
+    }
+
     /// Solves a Vehicle Routing Problem and returns a feasible solution in case of success
     /// or error description, if solution cannot be found.
     pub fn solve(self) -> Result<Solution, GenericError> {
+        // NOTE select the first best individual from population
+        self.solve_all()?.into_iter().next().ok_or_else(|| "cannot find any solution".into())
+    }
+
+    /// Solves a Vehicle Routing Problem and returns all solutions from the final population, the best
+    /// is the first one. These solutions can be used as initial ones when the problem is solved again,
+    /// e.g. when an in-progress plan is reoptimized.
+    pub fn solve_all(self) -> Result<Vec<Solution>, GenericError> {
         (self.config.context.environment.logger)(&format!(
             "total jobs: {}, actors: {}",
             self.problem.jobs.size(),
             self.problem.fleet.actors.len()
         ));
 
-        let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+        let mut config = self.config;
+        if config.context.best_solution_callback.is_some() {
+            let hooks: SolutionProcessingHooks = Arc::new(std::mem::take(&mut config.processing.solution));
+            config.processing.solution = vec![Box::new(SharedSolutionProcessing(hooks.clone()))];
+            config.context.best_solution_processing = Some(hooks);
+        }
 
-        // NOTE select the first best individual from population
-        let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
-            .ok_or_else(|| "cannot find any solution".to_string())?;
+        let (solutions, mut metrics) = EvolutionSimulator::new(config)?.run()?;
+
+        if solutions.is_empty() {
+            return Err("cannot find any solution".into());
+        }
 
-        let solution = (insertion_ctx.solution, metrics).into();
+        // NOTE telemetry metrics are attached to the best solution only
+        Ok(solutions.into_iter().map(|insertion_ctx| (insertion_ctx.solution, metrics.take()).into()).collect())
+    }
+}
+
+/// Applies shared solution post processing hooks.
+struct SharedSolutionProcessing(SolutionProcessingHooks);
+
+impl HeuristicSolutionProcessing for SharedSolutionProcessing {
+    type Solution = InsertionContext;
 
-        Ok(solution)
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        self.0.iter().fold(solution, |solution, hook| hook.post_process(solution))
     }
 }
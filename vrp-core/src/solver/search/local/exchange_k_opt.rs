@@ -0,0 +1,488 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/exchange_k_opt_test.rs"]
+mod exchange_k_opt_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::{Job, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use crate::solver::search::LocalOperator;
+use crate::solver::RefinementContext;
+use hashbrown::{HashMap, HashSet};
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+
+const MIN_JOBS: usize = 3;
+
+/// A local search operator which reverses a segment of a route (2-opt move).
+pub struct ExchangeTwoOpt {
+    neighbours: usize,
+}
+
+impl ExchangeTwoOpt {
+    /// Creates a new instance of `ExchangeTwoOpt` which considers only given amount of
+    /// the nearest neighbours of each job.
+    pub fn new(neighbours: usize) -> Self {
+        Self { neighbours }
+    }
+}
+
+impl Default for ExchangeTwoOpt {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl LocalOperator for ExchangeTwoOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        optimize_random_route(insertion_ctx, self.neighbours, get_two_opt_moves)
+    }
+}
+
+/// A local search operator which relocates a chain of consecutive jobs inside a route (Or-opt move).
+pub struct ExchangeOrOpt {
+    neighbours: usize,
+    max_chain_size: usize,
+}
+
+impl ExchangeOrOpt {
+    /// Creates a new instance of `ExchangeOrOpt`.
+    pub fn new(neighbours: usize, max_chain_size: usize) -> Self {
+        assert!(max_chain_size > 0);
+
+        Self { neighbours, max_chain_size }
+    }
+}
+
+impl Default for ExchangeOrOpt {
+    fn default() -> Self {
+        Self::new(10, 3)
+    }
+}
+
+impl LocalOperator for ExchangeOrOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        optimize_random_route(insertion_ctx, self.neighbours, |nodes, idx, neighbours| {
+            get_or_opt_moves(nodes, idx, neighbours, self.max_chain_size)
+        })
+    }
+}
+
+/// A local search operator which swaps two adjacent segments of a route without reversing them
+/// (restricted 3-opt move).
+pub struct ExchangeThreeOpt {
+    neighbours: usize,
+    max_segment_size: usize,
+}
+
+impl ExchangeThreeOpt {
+    /// Creates a new instance of `ExchangeThreeOpt`.
+    pub fn new(neighbours: usize, max_segment_size: usize) -> Self {
+        assert!(max_segment_size > 0);
+
+        Self { neighbours, max_segment_size }
+    }
+}
+
+impl Default for ExchangeThreeOpt {
+    fn default() -> Self {
+        Self::new(10, 8)
+    }
+}
+
+impl LocalOperator for ExchangeThreeOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        optimize_random_route(insertion_ctx, self.neighbours, |nodes, idx, neighbours| {
+            get_three_opt_moves(nodes, idx, neighbours, self.max_segment_size)
+        })
+    }
+}
+
+/// A segment of route activities: start and end indices (inclusive) and reverse flag.
+type Segment = (usize, usize, bool);
+
+/// A route move: segments in their new order replace all activities between move boundaries.
+/// Only activities from moved segments are removed and inserted back in the route.
+struct RouteMove {
+    segments: Vec<Segment>,
+    moved: Vec<usize>,
+    gain: Cost,
+}
+
+impl RouteMove {
+    /// Returns indices of activities which stay in place and surround the move.
+    fn boundaries(&self) -> (usize, usize) {
+        let start = self.segments.iter().map(|&(start, _, _)| start).min().unwrap();
+        let end = self.segments.iter().map(|&(_, end, _)| end).max().unwrap();
+
+        (start - 1, end + 1)
+    }
+
+    /// Returns activity indices in their new order.
+    fn order(&self) -> impl Iterator<Item = usize> + '_ {
+        self.segments.iter().flat_map(|&(start, end, is_reversed)| {
+            let (forward, backward) =
+                if is_reversed { (None, Some((start..=end).rev())) } else { (Some(start..=end), None) };
+
+            forward.into_iter().flatten().chain(backward.into_iter().flatten())
+        })
+    }
+}
+
+/// Keeps route activities with their transport costs precalculated along the route.
+struct RouteNodes<'a> {
+    route: &'a Route,
+    activities: Vec<&'a Activity>,
+    transport: &'a (dyn TransportCost + Send + Sync),
+    movable: Vec<bool>,
+    positions: HashMap<Job, usize>,
+    forward: Vec<Cost>,
+    backward: Vec<Cost>,
+    last: usize,
+}
+
+impl<'a> RouteNodes<'a> {
+    fn new(insertion_ctx: &'a InsertionContext, route_ctx: &'a RouteContext) -> Self {
+        let route = route_ctx.route();
+        let transport = insertion_ctx.problem.transport.as_ref();
+        let locked = &insertion_ctx.solution.locked;
+
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+        let jobs = activities.iter().map(|activity| activity.retrieve_job()).collect::<Vec<_>>();
+        // NOTE only single jobs are moved, so precedence of multi job activities is kept
+        let movable = jobs
+            .iter()
+            .map(|job| job.as_ref().is_some_and(|job| job.as_single().is_some() && !locked.contains(job)))
+            .collect();
+        let positions = jobs.iter().enumerate().filter_map(|(idx, job)| job.clone().map(|job| (job, idx))).collect();
+        let last = jobs.iter().rposition(|job| job.is_some()).unwrap_or(0);
+
+        let mut nodes = Self {
+            route,
+            activities,
+            transport,
+            movable,
+            positions,
+            forward: vec![Cost::default()],
+            backward: vec![Cost::default()],
+            last,
+        };
+
+        (0..nodes.activities.len()).for_each(|idx| {
+            nodes.forward.push(nodes.forward[idx] + nodes.cost(idx, idx + 1));
+            nodes.backward.push(nodes.backward[idx] + nodes.cost(idx + 1, idx));
+        });
+
+        nodes
+    }
+
+    /// Returns transport cost between two activities. Open route has no end activity, so
+    /// a virtual one with zero costs is used instead.
+    fn cost(&self, from: usize, to: usize) -> Cost {
+        match (self.activities.get(from), self.activities.get(to)) {
+            (Some(from), Some(to)) => self.transport.cost(
+                self.route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(from.schedule.departure),
+            ),
+            _ => Cost::default(),
+        }
+    }
+
+    fn is_movable(&self, start: usize, end: usize) -> bool {
+        start >= 1 && end <= self.last && (start..=end).all(|idx| self.movable[idx])
+    }
+
+    fn get_job(&self, idx: usize) -> Option<Job> {
+        self.activities.get(idx).and_then(|activity| activity.retrieve_job())
+    }
+
+    fn get_neighbours(&self, insertion_ctx: &InsertionContext, idx: usize, size: usize) -> Vec<usize> {
+        let job = self.get_job(idx).expect("job activity is expected");
+        let profile = &self.route.actor.vehicle.profile;
+        let departure = self.activities[idx].schedule.departure;
+
+        insertion_ctx
+            .problem
+            .jobs
+            .neighbors(profile, &job, departure)
+            .take(size)
+            .filter_map(|(job, _)| self.positions.get(job).copied())
+            .collect()
+    }
+
+    /// Creates an improving move from given segments if they are not empty and consist of movable activities.
+    fn create_move(&self, segments: Vec<Segment>, moved: &[usize]) -> Option<RouteMove> {
+        let is_valid = segments.iter().all(|&(start, end, _)| start <= end)
+            && segments.iter().all(|&(start, end, is_reversed)| !is_reversed || self.is_movable(start, end))
+            && moved.iter().all(|&idx| self.is_movable(idx, idx));
+
+        if !is_valid {
+            return None;
+        }
+
+        let route_move = RouteMove { segments, moved: moved.to_vec(), gain: Cost::default() };
+        let (start, end) = route_move.boundaries();
+
+        let (new_cost, tail) =
+            route_move.segments.iter().fold((Cost::default(), start), |(cost, prev), &(first, last, is_reversed)| {
+                let (head, tail, internal) = if is_reversed {
+                    (last, first, self.backward[last] - self.backward[first])
+                } else {
+                    (first, last, self.forward[last] - self.forward[first])
+                };
+
+                (cost + self.cost(prev, head) + internal, tail)
+            });
+        let new_cost = new_cost + self.cost(tail, end);
+        let old_cost = self.forward[end] - self.forward[start];
+        let gain = old_cost - new_cost;
+
+        if compare_floats(gain, 0.) == Ordering::Greater {
+            Some(RouteMove { gain, ..route_move })
+        } else {
+            None
+        }
+    }
+}
+
+/// Gets 2-opt moves which make a node adjacent to one of its neighbours by reversing a segment between them.
+fn get_two_opt_moves(nodes: &RouteNodes, idx: usize, neighbours: &[usize]) -> Vec<RouteMove> {
+    neighbours
+        .iter()
+        .filter_map(|&other| {
+            let (start, end) = match other.cmp(&idx) {
+                Ordering::Greater => (idx + 1, other),
+                _ => (other, idx.checked_sub(1)?),
+            };
+
+            if start < end {
+                nodes.create_move(vec![(start, end, true)], (start..=end).collect::<Vec<_>>().as_slice())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Gets Or-opt moves which relocate a chain started from a node next to one of its neighbours.
+fn get_or_opt_moves(nodes: &RouteNodes, idx: usize, neighbours: &[usize], max_chain_size: usize) -> Vec<RouteMove> {
+    (idx..(idx + max_chain_size).min(nodes.last + 1))
+        .take_while(|&end| nodes.is_movable(end, end))
+        .flat_map(|end| {
+            let chain = (idx..=end).collect::<Vec<_>>();
+
+            neighbours.iter().filter(move |&&other| other < idx || other > end).flat_map(move |&other| {
+                // NOTE either insert chain after the neighbour or insert reversed chain before it
+                let (after, before) = if other > end {
+                    (
+                        vec![(end + 1, other, false), (idx, end, false)],
+                        vec![(end + 1, other - 1, false), (idx, end, true)],
+                    )
+                } else {
+                    (
+                        vec![(idx, end, false), (other + 1, idx - 1, false)],
+                        vec![(idx, end, true), (other, idx - 1, false)],
+                    )
+                };
+
+                [after, before].into_iter().map(|segments| (segments, chain.clone())).collect::<Vec<_>>()
+            })
+        })
+        .filter_map(|(segments, chain)| {
+            let segments = segments.into_iter().filter(|&(start, end, _)| start <= end).collect::<Vec<_>>();
+            nodes.create_move(segments, chain.as_slice())
+        })
+        .collect()
+}
+
+/// Gets restricted 3-opt moves which swap two adjacent segments, so that a node is followed by its neighbour.
+fn get_three_opt_moves(
+    nodes: &RouteNodes,
+    idx: usize,
+    neighbours: &[usize],
+    max_segment_size: usize,
+) -> Vec<RouteMove> {
+    neighbours
+        .iter()
+        .filter_map(|&other| match other.cmp(&idx) {
+            Ordering::Greater if other > idx + 1 => Some((idx, other)),
+            Ordering::Less if other + 1 < idx => Some((other, idx)),
+            _ => None,
+        })
+        .filter(|&(prev, second)| second - prev - 1 <= max_segment_size)
+        .flat_map(|(prev, second)| {
+            (second..(second + max_segment_size).min(nodes.last + 1)).map(move |end| {
+                let (first, second) = ((prev + 1, second - 1, false), (second, end, false));
+                // NOTE move the shorter segment only
+                let moved = if first.1 - first.0 <= second.1 - second.0 { first } else { second };
+
+                (vec![second, first], (moved.0..=moved.1).collect::<Vec<_>>())
+            })
+        })
+        .filter_map(|(segments, moved)| nodes.create_move(segments, moved.as_slice()))
+        .collect()
+}
+
+/// Optimizes randomly selected route by applying improving moves until local optimum is reached.
+/// A move is applied only if it improves the solution according to the goal.
+/// Uses don't look bits to skip activities which had no feasible improving moves since last route change.
+fn optimize_random_route<F>(
+    insertion_ctx: &InsertionContext,
+    neighbours: usize,
+    get_moves: F,
+) -> Option<InsertionContext>
+where
+    F: Fn(&RouteNodes, usize, &[usize]) -> Vec<RouteMove>,
+{
+    let route_indices = get_route_indices(insertion_ctx);
+
+    if route_indices.is_empty() {
+        return None;
+    }
+
+    let random = insertion_ctx.environment.random.as_ref();
+    let route_idx = route_indices[random.uniform_int(0, route_indices.len() as i32 - 1) as usize];
+
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+    let quota = insertion_ctx.environment.quota.clone();
+    let max_iterations = insertion_ctx.solution.routes[route_idx].route().tour.job_count() * neighbours.max(1);
+
+    let mut dont_look = HashSet::<Job>::default();
+    let mut iterations = 0;
+
+    while iterations < max_iterations && !quota.as_ref().is_some_and(|quota| quota.is_reached()) {
+        let nodes = RouteNodes::new(&insertion_ctx, &insertion_ctx.solution.routes[route_idx]);
+        let mut result = None;
+
+        for idx in 1..=nodes.last {
+            let job = match nodes.get_job(idx) {
+                Some(job) if nodes.is_movable(idx, idx) && !dont_look.contains(&job) => job,
+                _ => continue,
+            };
+
+            let mut moves = get_moves(&nodes, idx, nodes.get_neighbours(&insertion_ctx, idx, neighbours).as_slice());
+            moves.sort_by(|a, b| compare_floats(b.gain, a.gain));
+
+            result = moves.iter().take(neighbours.max(1)).find_map(|route_move| {
+                apply_move(&insertion_ctx, route_idx, route_move)
+                    .map(|route_ctx| create_moved_ctx(&insertion_ctx, route_idx, route_ctx))
+                    .filter(|moved_ctx| is_improved(moved_ctx, &insertion_ctx))
+                    .map(|moved_ctx| (route_move.boundaries(), moved_ctx))
+            });
+
+            if result.is_some() {
+                break;
+            }
+
+            dont_look.insert(job);
+        }
+
+        let Some(((start, end), moved_ctx)) = result else { break };
+
+        (start..=end).filter_map(|idx| nodes.get_job(idx)).for_each(|job| {
+            dont_look.remove(&job);
+        });
+
+        insertion_ctx = moved_ctx;
+        iterations += 1;
+    }
+
+    if iterations > 0 {
+        Some(insertion_ctx)
+    } else {
+        None
+    }
+}
+
+/// Creates a copy of the solution with the route replaced by the moved one.
+fn create_moved_ctx(insertion_ctx: &InsertionContext, route_idx: usize, route_ctx: RouteContext) -> InsertionContext {
+    let mut moved_ctx = insertion_ctx.deep_copy();
+    moved_ctx.solution.routes[route_idx] = route_ctx;
+    finalize_insertion_ctx(&mut moved_ctx);
+
+    moved_ctx
+}
+
+/// Checks whether the moved solution is strictly better according to the goal. Transport gain of the move
+/// alone is not enough: reinserted jobs can cause waiting or time window penalties or worsen other objectives.
+fn is_improved(moved_ctx: &InsertionContext, insertion_ctx: &InsertionContext) -> bool {
+    insertion_ctx.problem.goal.total_order(moved_ctx, insertion_ctx) == Ordering::Less
+}
+
+fn get_route_indices(insertion_ctx: &InsertionContext) -> Vec<usize> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, route_ctx)| {
+            let movable_jobs = route_ctx
+                .route()
+                .tour
+                .jobs()
+                .filter(|job| job.as_single().is_some() && !insertion_ctx.solution.locked.contains(*job))
+                .count();
+
+            if movable_jobs >= MIN_JOBS {
+                Some(idx)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Applies move to a copy of the route: moved jobs are removed and then inserted back one by one
+/// at their new positions. Returns none if any of insertions violates a hard constraint.
+fn apply_move(insertion_ctx: &InsertionContext, route_idx: usize, route_move: &RouteMove) -> Option<RouteContext> {
+    let goal = insertion_ctx.problem.goal.as_ref();
+    let mut route_ctx = insertion_ctx.solution.routes[route_idx].deep_copy();
+
+    // NOTE activities which are not moved keep their relative order, so a moved job has to be
+    //      inserted after all activities which precede it in the new order
+    let (start, _) = route_move.boundaries();
+    let insertions = route_move
+        .order()
+        .enumerate()
+        .filter(|(_, idx)| route_move.moved.contains(idx))
+        .map(|(position, idx)| {
+            let job = route_ctx.route().tour.get(idx).and_then(|activity| activity.retrieve_job());
+            (job.expect("moved activity has no job"), start + position)
+        })
+        .collect::<Vec<_>>();
+
+    insertions.iter().for_each(|(job, _)| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+    goal.accept_route_state(&mut route_ctx);
+
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    insertions.into_iter().try_for_each(|(job, leg_idx)| {
+        let eval_ctx =
+            EvaluationContext { goal, job: &job, leg_selection: &leg_selection, result_selector: &result_selector };
+
+        match eval_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            &route_ctx,
+            InsertionPosition::Concrete(leg_idx),
+            InsertionResult::make_failure(),
+        ) {
+            InsertionResult::Success(success) => {
+                success.activities.into_iter().for_each(|(activity, index)| {
+                    route_ctx.route_mut().tour.insert_at(activity, index + 1);
+                });
+                goal.accept_route_state(&mut route_ctx);
+
+                Some(())
+            }
+            InsertionResult::Failure(_) => None,
+        }
+    })?;
+
+    Some(route_ctx)
+}
@@ -9,6 +9,9 @@ use std::sync::Arc;
 mod exchange_inter_route;
 pub use self::exchange_inter_route::*;
 
+mod exchange_k_opt;
+pub use self::exchange_k_opt::*;
+
 mod exchange_intra_route;
 pub use self::exchange_intra_route::*;
 
@@ -21,6 +24,9 @@ pub use self::exchange_swap_star::*;
 mod reschedule_departure;
 pub use self::reschedule_departure::*;
 
+mod switch_depot;
+pub use self::switch_depot::*;
+
 /// Specifies behavior of a local search operator.
 pub trait LocalOperator {
     /// Applies local search operator to passed solution in order to explore possible
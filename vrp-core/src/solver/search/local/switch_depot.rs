@@ -0,0 +1,76 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/switch_depot_test.rs"]
+mod switch_depot_test;
+
+use super::*;
+use crate::models::problem::{Actor, Job};
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+
+/// A local search operator which moves a route to an alternative depot of the same vehicle:
+/// jobs of the route are reinserted into a new route which starts or ends at another location.
+#[derive(Default)]
+pub struct SwitchDepot {}
+
+impl LocalOperator for SwitchDepot {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        let random = insertion_ctx.environment.random.clone();
+        let solution = &insertion_ctx.solution;
+
+        let candidates = solution
+            .routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route_ctx)| route_ctx.route().tour.jobs().all(|job| !solution.locked.contains(job)))
+            .map(|(route_idx, route_ctx)| (route_idx, get_alternative_actors(insertion_ctx, &route_ctx.route().actor)))
+            .filter(|(_, alternatives)| !alternatives.is_empty())
+            .collect::<Vec<_>>();
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let (route_idx, alternatives) = &candidates[random.uniform_int(0, candidates.len() as i32 - 1) as usize];
+        let alternative = &alternatives[random.uniform_int(0, alternatives.len() as i32 - 1) as usize];
+
+        let mut insertion_ctx = insertion_ctx.deep_copy();
+        let solution = &mut insertion_ctx.solution;
+
+        let route_ctx = solution.routes.remove(*route_idx);
+        let jobs = route_ctx.route().tour.jobs().cloned().collect::<Vec<Job>>();
+        solution.registry.free_route(route_ctx);
+
+        // NOTE alternative actor might be not available due to conflicts with other used actors
+        let route_ctx = solution.registry.get_route(alternative)?;
+        solution.routes.push(route_ctx);
+        solution.required.extend(jobs);
+
+        let mut insertion_ctx = RecreateWithCheapest::new(random).run(refinement_ctx, insertion_ctx);
+        insertion_ctx.restore();
+
+        Some(insertion_ctx)
+    }
+}
+
+/// Returns actors of the same vehicle and driver which differ only by start or end location.
+fn get_alternative_actors(insertion_ctx: &InsertionContext, actor: &Arc<Actor>) -> Vec<Arc<Actor>> {
+    let get_locations = |actor: &Actor| {
+        (actor.detail.start.as_ref().map(|p| p.location), actor.detail.end.as_ref().map(|p| p.location))
+    };
+
+    insertion_ctx
+        .solution
+        .registry
+        .resources()
+        .all()
+        .filter(|other| {
+            Arc::ptr_eq(&other.vehicle, &actor.vehicle)
+                && Arc::ptr_eq(&other.driver, &actor.driver)
+                && other.detail.time == actor.detail.time
+                && get_locations(other) != get_locations(actor)
+        })
+        .collect()
+}
@@ -33,6 +33,9 @@ pub use self::infeasible_search::InfeasibleSearch;
 mod local_search;
 pub use self::local_search::LocalSearch;
 
+mod recombine_search;
+pub use self::recombine_search::RecombineSearch;
+
 mod redistribute_search;
 pub use self::redistribute_search::RedistributeSearch;
 
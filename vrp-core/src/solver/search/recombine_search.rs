@@ -0,0 +1,333 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/recombine_search_test.rs"]
+mod recombine_search_test;
+
+use crate::construction::heuristics::*;
+use crate::construction::probing::repair_solution_from_unknown;
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Fleet, Job};
+use crate::models::GoalContext;
+use crate::solver::search::{create_environment_with_custom_quota, Recreate};
+use crate::solver::RefinementContext;
+use hashbrown::{HashMap, HashSet};
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::iter::once;
+use std::sync::Arc;
+
+/// A search operator which recombines routes of individuals from the population: it collects
+/// distinct routes into a pool and solves a set partitioning problem over it using a branch
+/// and bound method. Found partition is used to build a new solution.
+/// NOTE: set partitioning minimizes total route cost only, so the operator is not a part of the
+/// default heuristic and recombined solution is accepted only if it is better according to the goal.
+pub struct RecombineSearch {
+    recreate: Arc<dyn Recreate + Send + Sync>,
+    max_routes: usize,
+    quota_limit: usize,
+}
+
+impl RecombineSearch {
+    /// Creates a new instance of `RecombineSearch`.
+    pub fn new(recreate: Arc<dyn Recreate + Send + Sync>, max_routes: usize, quota_limit: usize) -> Self {
+        assert!(max_routes > 1);
+
+        Self { recreate, max_routes, quota_limit }
+    }
+}
+
+impl HeuristicSearchOperator for RecombineSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+        let insertion_ctx = solution;
+
+        let pool = create_route_pool(refinement_ctx, insertion_ctx, self.max_routes);
+        let environment =
+            create_environment_with_custom_quota(Some(self.quota_limit), refinement_ctx.environment.as_ref());
+        let is_terminated = || environment.quota.as_ref().is_some_and(|quota| quota.is_reached());
+
+        let conflicts = get_actor_conflicts(&insertion_ctx.problem.fleet, &pool.actor_index);
+
+        solve_set_partitioning(&pool.columns, pool.total_jobs, &conflicts, pool.upper_bound, &is_terminated)
+            .map(|selected| self.create_recombined(refinement_ctx, insertion_ctx, &pool, selected))
+            .filter(|recombined| insertion_ctx.problem.goal.total_order(recombined, insertion_ctx) == Ordering::Less)
+            .unwrap_or_else(|| insertion_ctx.deep_copy())
+    }
+}
+
+impl RecombineSearch {
+    fn create_recombined(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+        pool: &RoutePool,
+        selected: Vec<usize>,
+    ) -> InsertionContext {
+        let mut combined = insertion_ctx.deep_copy();
+        combined.solution.routes = selected.into_iter().map(|idx| pool.routes[idx].deep_copy()).collect();
+
+        let mut new_insertion_ctx = repair_solution_from_unknown(&combined, &|| {
+            InsertionContext::new(insertion_ctx.problem.clone(), insertion_ctx.environment.clone())
+        });
+
+        // NOTE routes are feasible on their own, but repair can still unassign jobs
+        if new_insertion_ctx.solution.unassigned.len() > insertion_ctx.solution.unassigned.len() {
+            let solution = &mut new_insertion_ctx.solution;
+            solution.required.extend(solution.unassigned.drain().map(|(job, _)| job));
+
+            new_insertion_ctx = self.recreate.run(refinement_ctx, new_insertion_ctx);
+        }
+
+        new_insertion_ctx
+    }
+}
+
+/// Represents a route in the set partitioning problem.
+pub(crate) struct RouteColumn {
+    /// Sorted indices of jobs served by the route.
+    pub jobs: Vec<usize>,
+    /// An index of actor which serves the route.
+    pub actor: usize,
+    /// A route cost.
+    pub cost: Cost,
+}
+
+struct RoutePool<'a> {
+    routes: Vec<&'a RouteContext>,
+    columns: Vec<RouteColumn>,
+    actor_index: HashMap<Arc<Actor>, usize>,
+    total_jobs: usize,
+    upper_bound: Cost,
+}
+
+fn create_route_pool<'a>(
+    refinement_ctx: &'a RefinementContext,
+    insertion_ctx: &'a InsertionContext,
+    max_routes: usize,
+) -> RoutePool<'a> {
+    let actor_index = insertion_ctx
+        .problem
+        .fleet
+        .actors
+        .iter()
+        .enumerate()
+        .map(|(idx, actor)| (actor.clone(), idx))
+        .collect::<HashMap<_, _>>();
+
+    // NOTE only jobs assigned in original solution have to be covered
+    let job_index = get_route_ctxs(insertion_ctx)
+        .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .enumerate()
+        .map(|(idx, job)| (job.clone(), idx))
+        .collect::<HashMap<Job, _>>();
+
+    let mut distinct: HashMap<(usize, Vec<usize>), (Cost, &RouteContext)> = HashMap::default();
+    let mut original_keys = Vec::default();
+
+    once(insertion_ctx).chain(refinement_ctx.ranked().map(|(individual, _)| individual)).enumerate().for_each(
+        |(individual_idx, individual)| {
+            get_route_ctxs(individual).for_each(|route_ctx| {
+                let jobs =
+                    route_ctx.route().tour.jobs().map(|job| job_index.get(job).copied()).collect::<Option<Vec<_>>>();
+                let (Some(mut jobs), Some(actor)) = (jobs, actor_index.get(&route_ctx.route().actor).copied()) else {
+                    return;
+                };
+                jobs.sort_unstable();
+                jobs.dedup();
+
+                let key = (actor, jobs);
+                let cost = route_ctx.get_route_cost();
+
+                if individual_idx == 0 {
+                    original_keys.push(key.clone());
+                }
+
+                match distinct.get(&key) {
+                    Some((other, _)) if *other <= cost => {}
+                    _ => {
+                        distinct.insert(key, (cost, route_ctx));
+                    }
+                }
+            });
+        },
+    );
+
+    let upper_bound = get_route_ctxs(insertion_ctx).map(|route_ctx| route_ctx.get_route_cost()).sum::<Cost>();
+
+    // NOTE keep routes of original solution and prefer routes with the lowest cost per job
+    let original_keys = original_keys.into_iter().collect::<HashSet<_>>();
+    let mut entries = distinct
+        .into_iter()
+        .map(|(key, (cost, route_ctx))| (original_keys.contains(&key), key, cost, route_ctx))
+        .collect::<Vec<_>>();
+    entries.sort_by(|(a_original, a_key, a_cost, _), (b_original, b_key, b_cost, _)| {
+        b_original
+            .cmp(a_original)
+            .then_with(|| compare_floats(*a_cost / a_key.1.len() as Cost, *b_cost / b_key.1.len() as Cost))
+            .then_with(|| a_key.cmp(b_key))
+    });
+    entries.truncate(max_routes.max(original_keys.len()));
+
+    let (routes, columns) = entries
+        .into_iter()
+        .map(|(_, (actor, jobs), cost, route_ctx)| (route_ctx, RouteColumn { jobs, actor, cost }))
+        .unzip();
+
+    RoutePool { routes, columns, actor_index, total_jobs: job_index.len(), upper_bound }
+}
+
+fn get_route_ctxs(insertion_ctx: &InsertionContext) -> impl Iterator<Item = &RouteContext> + '_ {
+    insertion_ctx.solution.routes.iter().filter(|route_ctx| route_ctx.route().tour.has_jobs())
+}
+
+fn get_actor_conflicts(fleet: &Fleet, actor_index: &HashMap<Arc<Actor>, usize>) -> Vec<Vec<usize>> {
+    fleet
+        .actors
+        .iter()
+        .map(|actor| {
+            fleet
+                .conflicts
+                .get(actor)
+                .into_iter()
+                .flatten()
+                .filter_map(|other| actor_index.get(other).copied())
+                .collect()
+        })
+        .collect()
+}
+
+/// Solves set partitioning problem over given columns using depth first branch and bound:
+/// each job has to be covered exactly once and each actor (including conflicting ones) can
+/// be used at most once. Returns indices of selected columns if solution with total cost lower
+/// than upper bound is found.
+pub(crate) fn solve_set_partitioning(
+    columns: &[RouteColumn],
+    total_jobs: usize,
+    conflicts: &[Vec<usize>],
+    upper_bound: Cost,
+    is_terminated: &dyn Fn() -> bool,
+) -> Option<Vec<usize>> {
+    let mut job_columns = vec![Vec::<usize>::default(); total_jobs];
+    columns.iter().enumerate().filter(|(_, column)| !column.jobs.is_empty()).for_each(|(idx, column)| {
+        column.jobs.iter().for_each(|&job| job_columns[job].push(idx));
+    });
+    job_columns.iter_mut().for_each(|candidates| {
+        candidates.sort_by(|&a, &b| compare_floats(columns[a].cost, columns[b].cost));
+    });
+
+    // NOTE a minimal cost share of the job is used to estimate lower bound of partial solution
+    let job_shares = job_columns
+        .iter()
+        .map(|candidates| {
+            candidates
+                .iter()
+                .map(|&idx| columns[idx].cost / columns[idx].jobs.len() as Cost)
+                .min_by(|a, b| compare_floats(*a, *b))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let total_actors = columns.iter().map(|column| column.actor + 1).chain(once(conflicts.len())).max().unwrap_or(0);
+
+    let mut search = PartitionSearch {
+        columns,
+        conflicts,
+        job_columns,
+        job_shares,
+        covered: vec![false; total_jobs],
+        blocked: vec![0; total_actors],
+        selected: Vec::default(),
+        best: None,
+        best_cost: upper_bound,
+        is_terminated,
+    };
+
+    let remaining_share = search.job_shares.iter().sum();
+    search.branch(0., remaining_share);
+
+    search.best
+}
+
+struct PartitionSearch<'a> {
+    columns: &'a [RouteColumn],
+    conflicts: &'a [Vec<usize>],
+    job_columns: Vec<Vec<usize>>,
+    job_shares: Vec<Cost>,
+    covered: Vec<bool>,
+    blocked: Vec<usize>,
+    selected: Vec<usize>,
+    best: Option<Vec<usize>>,
+    best_cost: Cost,
+    is_terminated: &'a dyn Fn() -> bool,
+}
+
+impl PartitionSearch<'_> {
+    fn branch(&mut self, cost: Cost, remaining_share: Cost) -> bool {
+        if (self.is_terminated)() {
+            return false;
+        }
+
+        if compare_floats(cost + remaining_share, self.best_cost) != Ordering::Less {
+            return true;
+        }
+
+        // NOTE branch on the most constrained job
+        let job = (0..self.covered.len())
+            .filter(|&job| !self.covered[job])
+            .map(|job| (job, self.job_columns[job].iter().filter(|&&idx| self.is_available(idx)).count()))
+            .min_by_key(|(_, count)| *count);
+
+        let job = match job {
+            Some((_, 0)) => return true,
+            Some((job, _)) => job,
+            None => {
+                self.best = Some(self.selected.clone());
+                self.best_cost = cost;
+                return true;
+            }
+        };
+
+        let candidates =
+            self.job_columns[job].iter().copied().filter(|&idx| self.is_available(idx)).collect::<Vec<_>>();
+
+        candidates.into_iter().all(|idx| {
+            let column = &self.columns[idx];
+            let share = column.jobs.iter().map(|&job| self.job_shares[job]).sum::<Cost>();
+
+            self.set_column(idx, true);
+            let is_continue = self.branch(cost + column.cost, remaining_share - share);
+            self.set_column(idx, false);
+
+            is_continue
+        })
+    }
+
+    fn is_available(&self, idx: usize) -> bool {
+        let column = &self.columns[idx];
+
+        self.blocked[column.actor] == 0 && column.jobs.iter().all(|&job| !self.covered[job])
+    }
+
+    fn set_column(&mut self, idx: usize, is_selected: bool) {
+        let column = &self.columns[idx];
+
+        column.jobs.iter().for_each(|&job| self.covered[job] = is_selected);
+        self.conflicts.get(column.actor).into_iter().flatten().chain(once(&column.actor)).for_each(|&actor| {
+            if is_selected {
+                self.blocked[actor] += 1;
+            } else {
+                self.blocked[actor] -= 1;
+            }
+        });
+
+        if is_selected {
+            self.selected.push(idx);
+        } else {
+            self.selected.pop();
+        }
+    }
+}
@@ -25,7 +25,7 @@ impl<T: LoadOps> DemandJobSelector<T> {
     }
 
     fn get_capacity(demand: &Demand<T>) -> T {
-        demand.pickup.0 + demand.delivery.0 + demand.pickup.1 + demand.delivery.1
+        demand.pickup.0.clone() + demand.delivery.0.clone() + demand.pickup.1.clone() + demand.delivery.1.clone()
     }
 
     fn get_job_demand(job: &Job) -> Option<T> {
@@ -38,6 +38,7 @@ pub fn test_vehicle_detail() -> VehicleDetail {
             location: 0,
             time: TimeInterval { earliest: None, latest: Some(DEFAULT_ACTOR_TIME_WINDOW.end) },
         }),
+        depots: Default::default(),
     }
 }
 
@@ -25,6 +25,7 @@ fn create_detail(
             location,
             time: time.map_or(Default::default(), |(_, end)| TimeInterval { earliest: None, latest: Some(end) }),
         }),
+        depots: Default::default(),
     }
 }
 
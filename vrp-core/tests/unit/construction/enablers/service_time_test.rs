@@ -0,0 +1,154 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::SimpleActivityCost;
+
+fn create_activity_with_bands(tw: (f64, f64), duration: Duration, bands: Vec<(Timestamp, Duration)>) -> Activity {
+    let mut dimens = Dimensions::default();
+    dimens.set_service_durations(vec![Some(
+        bands.into_iter().map(|(start, duration)| ServiceDurationBand { start, duration }).collect(),
+    )]);
+
+    ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(tw.0, tw.1), duration)
+        .job(Some(SingleBuilder::default().dimens(dimens).build_shared()))
+        .build()
+}
+
+fn create_route_with_parking(parking: Option<Duration>) -> Route {
+    let mut vehicle = VehicleBuilder::default().id("v1").build();
+    if let Some(parking) = parking {
+        vehicle.dimens.set_vehicle_parking(parking);
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+
+    RouteBuilder::default().with_vehicle(&fleet, "v1").build()
+}
+
+parameterized_test! {can_get_service_duration, (service_start, expected), {
+    can_get_service_duration_impl(service_start, expected);
+}}
+
+can_get_service_duration! {
+    case01_before_bands: (0., 10.),
+    case02_at_first_band: (100., 30.),
+    case03_inside_first_band: (150., 30.),
+    case04_at_second_band: (200., 5.),
+    case05_after_last_band: (1000., 5.),
+}
+
+fn can_get_service_duration_impl(service_start: Timestamp, expected: Duration) {
+    let activity = create_activity_with_bands((0., 1000.), 10., vec![(100., 30.), (200., 5.)]);
+
+    let duration = get_service_duration(&activity, service_start);
+
+    assert_eq!(duration, expected);
+}
+
+parameterized_test! {can_estimate_departure_and_arrival, (arrival, expected_departure, expected_arrival), {
+    can_estimate_departure_and_arrival_impl(arrival, expected_departure, expected_arrival);
+}}
+
+can_estimate_departure_and_arrival! {
+    case01_before_bands: (50., 60., 50.),
+    case02_crosses_band_start: (95., 105., 95.),
+    case03_inside_first_band: (110., 140., 110.),
+    case04_shorter_duration_later: (190., 220., 215.),
+    case05_after_last_band: (300., 305., 300.),
+    case06_waits_for_time_window: (0., 30., 20.),
+}
+
+fn can_estimate_departure_and_arrival_impl(
+    arrival: Timestamp,
+    expected_departure: Timestamp,
+    expected_arrival: Timestamp,
+) {
+    let route = RouteBuilder::default().build();
+    let activity = create_activity_with_bands((20., 1000.), 10., vec![(100., 30.), (200., 5.)]);
+    let activity_cost = TimeDependentActivityCost::new(Arc::new(SimpleActivityCost::default()));
+
+    let departure = activity_cost.estimate_departure(&route, &activity, arrival);
+    let latest_arrival = activity_cost.estimate_arrival(&route, &activity, departure);
+
+    assert_eq!(departure, expected_departure);
+    assert_eq!(latest_arrival, expected_arrival);
+}
+
+parameterized_test! {can_get_latest_service_start_at_band_boundary, (bands, departure, expected), {
+    can_get_latest_service_start_at_band_boundary_impl(bands, departure, expected);
+}}
+
+can_get_latest_service_start_at_band_boundary! {
+    case01_next_band_longer: (vec![(100., 30.)], 110., 100_f64.next_down()),
+    case02_next_band_shorter: (vec![(100., 5.)], 110., 105.),
+    case03_before_boundary: (vec![(100., 30.)], 105., 95.),
+    case04_after_boundary: (vec![(100., 30.)], 140., 110.),
+}
+
+fn can_get_latest_service_start_at_band_boundary_impl(
+    bands: Vec<(Timestamp, Duration)>,
+    departure: Timestamp,
+    expected: Timestamp,
+) {
+    let bands = bands.into_iter().map(|(start, duration)| ServiceDurationBand { start, duration }).collect::<Vec<_>>();
+
+    let service_start = get_latest_service_start(&bands, 10., departure);
+
+    assert_eq!(service_start, expected);
+    assert!(service_start + get_band_duration(&bands, 10., service_start) <= departure);
+}
+
+#[test]
+fn can_add_service_duration_difference_to_activity_cost() {
+    let route = RouteBuilder::default().build();
+    let activity = create_activity_with_bands((0., 1000.), 10., vec![(100., 30.)]);
+    let inner = Arc::new(SimpleActivityCost::default());
+    let activity_cost = TimeDependentActivityCost::new(inner.clone());
+
+    assert_eq!(activity_cost.cost(&route, &activity, 50.), inner.cost(&route, &activity, 50.));
+    assert_eq!(activity_cost.cost(&route, &activity, 150.), inner.cost(&route, &activity, 150.) + 40.);
+}
+
+parameterized_test! {can_get_location_parking_time, (vehicle_parking, from, to, expected), {
+    can_get_location_parking_time_impl(vehicle_parking, from, to, expected);
+}}
+
+can_get_location_parking_time! {
+    case01_job_parking: (None, 0, 1, 5.),
+    case02_same_location: (None, 1, 1, 0.),
+    case03_no_parking: (None, 0, 2, 0.),
+    case04_vehicle_parking: (Some(3.), 0, 2, 3.),
+    case05_job_over_vehicle_parking: (Some(3.), 0, 1, 5.),
+    case06_not_job_location: (Some(3.), 1, 0, 0.),
+}
+
+fn can_get_location_parking_time_impl(
+    vehicle_parking: Option<Duration>,
+    from: Location,
+    to: Location,
+    expected: Duration,
+) {
+    let index = vec![(1, Some(5.)), (2, None)].into_iter().collect::<LocationParkingIndex>();
+    let route = create_route_with_parking(vehicle_parking);
+
+    let parking = get_location_parking_time(&index, route.actor.as_ref(), from, to);
+
+    assert_eq!(parking, expected);
+}
+
+#[test]
+fn can_add_parking_time_to_transport_cost() {
+    let index = Arc::new(vec![(10, Some(5.))].into_iter().collect::<LocationParkingIndex>());
+    let route = create_route_with_parking(None);
+    let inner = TestTransportCost::new_shared();
+    let transport = ParkingTransportCost::new(inner.clone(), index);
+
+    assert_eq!(transport.duration(&route, 0, 10, TravelTime::Departure(0.)), 15.);
+    assert_eq!(transport.duration(&route, 0, 10, TravelTime::Arrival(100.)), 15.);
+    assert_eq!(transport.duration(&route, 10, 0, TravelTime::Departure(0.)), 10.);
+    assert_eq!(transport.distance(&route, 0, 10, TravelTime::Departure(0.)), 10.);
+    assert_eq!(transport.duration_approx(&route.actor.vehicle.profile, 0, 10), 10.);
+    assert_eq!(
+        transport.cost(&route, 0, 10, TravelTime::Departure(0.)),
+        inner.cost(&route, 0, 10, TravelTime::Departure(0.)) + 10.
+    );
+}
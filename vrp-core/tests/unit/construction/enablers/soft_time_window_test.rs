@@ -0,0 +1,109 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::SimpleActivityCost;
+
+fn create_activity_with_tolerance(tw: (f64, f64), tolerance: Option<TimeWindowTolerance>) -> Activity {
+    let mut dimens = Dimensions::default();
+    dimens.set_time_window_tolerances(vec![tolerance.clone()]);
+
+    let tw = TimeWindow::new(tw.0, tw.1);
+    let tw = tolerance.map_or(tw.clone(), |tolerance| tolerance.expand(&tw));
+
+    ActivityBuilder::with_location_and_tw(10, tw)
+        .job(Some(SingleBuilder::default().dimens(dimens).build_shared()))
+        .build()
+}
+
+parameterized_test! {can_calculate_time_window_deviation, (service_start, expected), {
+    can_calculate_time_window_deviation_impl(service_start, expected);
+}}
+
+can_calculate_time_window_deviation! {
+    case01_in_time: (20., (0., 0.)),
+    case02_at_start: (10., (0., 0.)),
+    case03_at_end: (30., (0., 0.)),
+    case04_early: (7., (3., 0.)),
+    case05_late: (40., (0., 10.)),
+    case06_earliest: (5., (5., 0.)),
+    case07_latest: (50., (0., 20.)),
+}
+
+fn can_calculate_time_window_deviation_impl(service_start: Timestamp, expected: (Duration, Duration)) {
+    let tolerance = TimeWindowTolerance::new(5., 20., 2.);
+    let expanded = tolerance.expand(&TimeWindow::new(10., 30.));
+
+    let deviation = tolerance.deviation(&expanded, service_start);
+
+    assert_eq!(expanded.start, 5.);
+    assert_eq!(expanded.end, 50.);
+    assert_eq!(deviation, expected);
+    assert_eq!(tolerance.get_penalty(&expanded, service_start), (expected.0 + expected.1) * 2.);
+}
+
+parameterized_test! {can_get_service_start, (arrival, penalty, expected), {
+    can_get_service_start_impl(arrival, penalty, expected);
+}}
+
+can_get_service_start! {
+    case01_in_time: (20., 3., 20.),
+    case02_waits_when_penalty_is_higher: (8., 3., 10.),
+    case03_waits_when_penalty_is_equal: (8., 2., 10.),
+    case04_starts_early_when_penalty_is_lower: (8., 1., 8.),
+    case05_waits_for_expanded_start: (0., 1., 5.),
+    case06_late: (40., 1., 40.),
+}
+
+fn can_get_service_start_impl(arrival: Timestamp, penalty: Cost, expected: Timestamp) {
+    let tolerance = TimeWindowTolerance::new(5., 20., penalty);
+    let expanded = tolerance.expand(&TimeWindow::new(10., 30.));
+
+    let service_start = tolerance.get_service_start(&expanded, arrival, 2.);
+
+    assert_eq!(service_start, expected);
+}
+
+parameterized_test! {can_add_penalty_to_activity_cost, (tolerance, arrival, expected), {
+    can_add_penalty_to_activity_cost_impl(tolerance, arrival, expected);
+}}
+
+can_add_penalty_to_activity_cost! {
+    case01_no_tolerance: (None, 40., 0.),
+    case02_in_time: (Some(TimeWindowTolerance::new(5., 20., 2.)), 20., 0.),
+    case03_late: (Some(TimeWindowTolerance::new(5., 20., 2.)), 40., 20.),
+    case04_waits_for_original_start: (Some(TimeWindowTolerance::new(5., 20., 2.)), 8., 4.),
+    case05_waits_from_far: (Some(TimeWindowTolerance::new(5., 20., 2.)), 0., 10.),
+    case06_starts_early: (Some(TimeWindowTolerance::new(5., 20., 1.)), 8., 2.),
+    case07_starts_at_expanded_start: (Some(TimeWindowTolerance::new(5., 20., 1.)), 0., 5.),
+}
+
+fn can_add_penalty_to_activity_cost_impl(tolerance: Option<TimeWindowTolerance>, arrival: Timestamp, expected: Cost) {
+    let route = RouteBuilder::default().build();
+    let activity = create_activity_with_tolerance((10., 30.), tolerance);
+    let inner = Arc::new(SimpleActivityCost::default());
+    let activity_cost = SoftTimeWindowActivityCost::new(inner.clone());
+
+    let penalty = activity_cost.cost(&route, &activity, arrival) - inner.cost(&route, &activity, arrival);
+
+    assert_eq!(penalty, expected);
+}
+
+#[test]
+fn can_update_route_penalty_state() {
+    let key = StateKey(1);
+    let mut late_activity = create_activity_with_tolerance((10., 30.), Some(TimeWindowTolerance::new(0., 20., 3.)));
+    late_activity.schedule = Schedule::new(35., 36.);
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .add_activity(late_activity)
+                .add_activity(ActivityBuilder::with_location(20).schedule(Schedule::new(50., 51.)).build())
+                .build(),
+        )
+        .build();
+
+    update_time_window_penalty(&mut route_ctx, key, StateKey(2));
+
+    assert_eq!(route_ctx.state().get_route_state::<f64>(key).cloned(), Some(15.));
+    assert_eq!(route_ctx.state().get_route_state::<Option<usize>>(StateKey(2)).cloned(), Some(Some(1)));
+}
@@ -0,0 +1,60 @@
+use super::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, TimeWindow};
+use crate::models::problem::{
+    create_matrix_transport_cost_with_models, MatrixData, NoFallback, SpeedBand, TravelModel,
+};
+use std::iter::once;
+
+parameterized_test! {can_calculate_travel_crossing_speed_bands, (prev_departure, target_duration, expected_durations), {
+    can_calculate_travel_crossing_speed_bands_impl(prev_departure, target_duration, expected_durations);
+}}
+
+can_calculate_travel_crossing_speed_bands! {
+    case01_same_band: (0., 0., (20., 20.)),
+    case02_second_leg_crosses: (80., 0., (20., 25.)),
+    case03_first_leg_crosses: (90., 0., (30., 10.)),
+    case04_service_crosses: (90., 5., (35., 10.)),
+}
+
+fn can_calculate_travel_crossing_speed_bands_impl(
+    prev_departure: Timestamp,
+    target_duration: Duration,
+    expected_durations: (Duration, Duration),
+) {
+    let bands = vec![
+        SpeedBand { start: 0., factor: 0.5 },
+        SpeedBand { start: 100., factor: 0.25 },
+        SpeedBand { start: 120., factor: 1. },
+    ];
+    let transport = create_matrix_transport_cost_with_models(
+        vec![MatrixData::new(0, None, vec![10.; 9], vec![7.; 9])],
+        once((0, TravelModel::SpeedBands(bands))).collect(),
+        NoFallback,
+    )
+    .unwrap();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .add_activity(
+                    ActivityBuilder::with_location_tw_and_duration(0, TimeWindow::max(), 0.)
+                        .schedule(Schedule::new(prev_departure, prev_departure))
+                        .build(),
+                )
+                .add_activity(ActivityBuilder::with_location_tw_and_duration(2, TimeWindow::max(), 0.).build())
+                .build(),
+        )
+        .build();
+    let target = ActivityBuilder::with_location_tw_and_duration(1, TimeWindow::max(), target_duration).build();
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let (distances, durations) = calculate_travel(&route_ctx, &activity_ctx, transport.as_ref());
+
+    assert_eq!(distances, (7., 7.));
+    assert_eq!(durations, expected_durations);
+}
@@ -0,0 +1,164 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+use crate::models::problem::Vehicle;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+type TestCompartment<'a> = (&'a str, i32, Option<Vec<&'a str>>);
+
+fn create_test_vehicle(compartments: Vec<TestCompartment>) -> Vehicle {
+    let mut vehicle = VehicleBuilder::default().id("v1").capacity(100).build();
+    vehicle.dimens.set_vehicle_compartments(
+        compartments
+            .into_iter()
+            .map(|(id, capacity, products)| Compartment {
+                id: id.to_string(),
+                capacity: SingleDimLoad::new(capacity),
+                products: products.map(|products| products.into_iter().map(|p| p.to_string()).collect()),
+            })
+            .collect(),
+    );
+
+    vehicle
+}
+
+fn create_test_single(id: &str, demand: i32, product: Option<&str>) -> Arc<Single> {
+    let mut dimens = Dimensions::default();
+    dimens.set_id(id).set_demand(create_simple_demand(demand));
+    if let Some(product) = product {
+        dimens.set_job_product(product.to_string());
+    }
+
+    SingleBuilder::default().dimens(dimens).build_shared()
+}
+
+fn create_test_route_ctx(compartments: Vec<TestCompartment>, singles: &[Arc<Single>]) -> RouteContext {
+    let fleet =
+        FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(compartments)).build();
+
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activities(
+                    singles.iter().map(|single| ActivityBuilder::default().job(Some(single.clone())).build()),
+                )
+                .build(),
+        )
+        .build()
+}
+
+fn get_assigned_ids(route: &Route, assignment: &HashMap<Job, usize>) -> Vec<(String, String)> {
+    let compartments: &Vec<Compartment<SingleDimLoad>> = route.actor.vehicle.dimens.get_vehicle_compartments().unwrap();
+    let mut ids = assignment
+        .iter()
+        .map(|(job, idx)| (job.dimens().get_id().unwrap().clone(), compartments[*idx].id.clone()))
+        .collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+#[test]
+fn can_assign_jobs_to_compatible_compartments() {
+    let singles = vec![
+        create_test_single("job1", -2, None),
+        create_test_single("job2", -2, Some("fuel")),
+        create_test_single("job3", 1, None),
+    ];
+    let route_ctx = create_test_route_ctx(vec![("fuel", 2, Some(vec!["fuel"])), ("any", 3, None)], singles.as_slice());
+
+    let assignment = find_compartment_assignment::<SingleDimLoad>(route_ctx.route()).expect("no assignment");
+
+    assert_eq!(
+        get_assigned_ids(route_ctx.route(), &assignment),
+        vec![
+            ("job1".to_string(), "any".to_string()),
+            ("job2".to_string(), "fuel".to_string()),
+            ("job3".to_string(), "any".to_string())
+        ]
+    );
+}
+
+#[test]
+fn can_find_assignment_when_greedy_one_fails() {
+    let singles = vec![create_test_single("job1", -2, None), create_test_single("job2", -3, None)];
+    let route_ctx = create_test_route_ctx(vec![("c1", 3, None), ("c2", 2, None)], singles.as_slice());
+
+    let assignment = find_compartment_assignment::<SingleDimLoad>(route_ctx.route()).expect("no assignment");
+
+    assert_eq!(
+        get_assigned_ids(route_ctx.route(), &assignment),
+        vec![("job1".to_string(), "c2".to_string()), ("job2".to_string(), "c1".to_string())]
+    );
+}
+
+#[test]
+fn can_detect_no_assignment() {
+    let singles =
+        vec![create_test_single("job1", -2, Some("chilled")), create_test_single("job2", -1, Some("chilled"))];
+    let route_ctx = create_test_route_ctx(
+        vec![("chilled", 2, Some(vec!["chilled"])), ("frozen", 5, Some(vec!["frozen"]))],
+        &singles,
+    );
+
+    assert!(find_compartment_assignment::<SingleDimLoad>(route_ctx.route()).is_none());
+}
+
+parameterized_test! {can_evaluate_job_product, (product, expected), {
+    can_evaluate_job_product_impl(product, expected);
+}}
+
+can_evaluate_job_product! {
+    case01_compatible: (Some("fuel"), None),
+    case02_incompatible: (Some("frozen"), Some(true)),
+    case03_no_product: (None, Some(true)),
+}
+
+fn can_evaluate_job_product_impl(product: Option<&str>, expected: Option<bool>) {
+    let feature = create_compartments_feature::<SingleDimLoad>("compartments", VIOLATION_CODE).unwrap();
+    let solution_ctx = crate::helpers::models::domain::create_empty_solution_context();
+    let route_ctx = create_test_route_ctx(vec![("fuel", 2, Some(vec!["fuel", "diesel"]))], &[]);
+    let job = Job::Single(create_test_single("job1", -1, product));
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(result, expected.map(|stopped| ConstraintViolation { code: VIOLATION_CODE, stopped }));
+}
+
+parameterized_test! {can_evaluate_compartment_load, (demand, product, expected), {
+    can_evaluate_compartment_load_impl(demand, product, expected);
+}}
+
+can_evaluate_compartment_load! {
+    case01_fits_chilled: (-1, "chilled", None),
+    case02_fits_chilled_in_mixed: (-2, "chilled", None),
+    case03_exceeds_chilled: (-3, "chilled", Some(false)),
+    case04_fits_mixed: (-2, "frozen", None),
+    case05_exceeds_all: (-3, "frozen", Some(false)),
+    case06_pickup_fits_mixed: (2, "frozen", None),
+}
+
+fn can_evaluate_compartment_load_impl(demand: i32, product: &str, expected: Option<bool>) {
+    let feature = create_compartments_feature::<SingleDimLoad>("compartments", VIOLATION_CODE).unwrap();
+    let singles = vec![create_test_single("job1", -2, Some("chilled")), create_test_single("job2", -1, Some("frozen"))];
+    let mut route_ctx = create_test_route_ctx(
+        vec![("chilled", 3, Some(vec!["chilled"])), ("mixed", 3, Some(vec!["chilled", "frozen"]))],
+        singles.as_slice(),
+    );
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let target = ActivityBuilder::default().job(Some(create_test_single("job3", demand, Some(product)))).build();
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected.map(|stopped| ConstraintViolation { code: VIOLATION_CODE, stopped }));
+}
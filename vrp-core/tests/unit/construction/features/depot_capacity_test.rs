@@ -0,0 +1,81 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_vehicle_with_depots(id: &str) -> Vehicle {
+    Vehicle {
+        details: vec![VehicleDetail { depots: VehicleDepots { start: vec![1], end: vec![] }, ..test_vehicle_detail() }],
+        ..test_vehicle_with_id(id)
+    }
+}
+
+fn get_actor(fleet: &Fleet, vehicle_id: &str, location: Location) -> Arc<Actor> {
+    fleet
+        .actors
+        .iter()
+        .find(|actor| {
+            get_vehicle_id(&actor.vehicle) == vehicle_id
+                && actor.detail.start.as_ref().is_some_and(|start| start.location == location)
+        })
+        .cloned()
+        .unwrap()
+}
+
+fn create_route_ctx(actor: Arc<Actor>, has_jobs: bool) -> RouteContext {
+    let mut route_ctx = RouteContext::new(actor);
+    if has_jobs {
+        route_ctx
+            .route_mut()
+            .tour
+            .insert_last(ActivityBuilder::with_location(2).job(Some(SingleBuilder::default().build_shared())).build());
+    }
+
+    route_ctx
+}
+
+parameterized_test! {can_limit_tours_per_depot, (capacity, used, target, expected), {
+    can_limit_tours_per_depot_impl(capacity, used, target, expected);
+}}
+
+can_limit_tours_per_depot! {
+    case01_new_route_below_capacity: (2, vec![("v1", 1)], ("v2", 1, false), None),
+    case02_new_route_at_capacity: (1, vec![("v1", 1)], ("v2", 1, false), Some(VIOLATION_CODE)),
+    case03_new_route_other_depot: (1, vec![("v1", 1)], ("v2", 0, false), None),
+    case04_existing_route: (1, vec![("v1", 1), ("v2", 1)], ("v2", 1, true), None),
+    case05_no_capacity: (0, vec![], ("v2", 0, false), None),
+}
+
+fn can_limit_tours_per_depot_impl(
+    capacity: usize,
+    used: Vec<(&str, Location)>,
+    target: (&str, Location, bool),
+    expected: Option<ViolationCode>,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![create_vehicle_with_depots("v1"), create_vehicle_with_depots("v2")])
+        .build();
+    let capacities = if capacity > 0 { vec![(1, capacity)].into_iter().collect() } else { HashMap::default() };
+    let constraint =
+        create_depot_capacity_feature("depot_capacity", VIOLATION_CODE, capacities).unwrap().constraint.unwrap();
+    let solution_ctx = SolutionContext {
+        routes: used
+            .into_iter()
+            .map(|(vehicle_id, location)| create_route_ctx(get_actor(&fleet, vehicle_id, location), true))
+            .collect(),
+        ..create_empty_solution_context()
+    };
+    let (vehicle_id, location, has_jobs) = target;
+    let route_ctx = create_route_ctx(get_actor(&fleet, vehicle_id, location), has_jobs);
+
+    let result = constraint.evaluate(&MoveContext::route(
+        &solution_ctx,
+        &route_ctx,
+        &SingleBuilder::default().build_as_job_ref(),
+    ));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
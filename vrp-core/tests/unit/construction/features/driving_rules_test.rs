@@ -0,0 +1,260 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::solution::Activity;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn is_break_single(single: &Single) -> bool {
+    single.dimens.get_id().is_some_and(|id| id == "break")
+}
+
+fn create_feature(max_driving_time: Duration, max_total_driving_time: Duration) -> Feature {
+    create_driving_rules_feature(
+        "driving_rules",
+        VIOLATION_CODE,
+        Arc::new(move |_| Some(DrivingRules { max_driving_time, max_total_driving_time })),
+        Arc::new(is_break_single),
+        Box::new(|_, job| job.as_single().is_some_and(|single| is_break_single(single))),
+        TestTransportCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_break(location: Location) -> Activity {
+    ActivityBuilder::with_location(location)
+        .job(Some(SingleBuilder::default().id("break").location(None).build_shared()))
+        .build()
+}
+
+/// Creates route context with jobs at given locations and breaks at given (index, location).
+fn create_route_ctx(jobs: &[Location], breaks: Vec<(usize, Location)>) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .add_activities(jobs.iter().map(|&location| ActivityBuilder::with_location(location).build()))
+                .build(),
+        )
+        .build();
+
+    breaks.into_iter().for_each(|(index, location)| {
+        route_ctx.route_mut().tour.insert_at(create_break(location), index);
+    });
+
+    route_ctx
+}
+
+parameterized_test! {can_accumulate_driving_time, (breaks, expected_counters, expected_total), {
+    can_accumulate_driving_time_impl(breaks, expected_counters, expected_total);
+}}
+
+can_accumulate_driving_time! {
+    case01_no_breaks: (vec![], vec![0., 5., 10., 15., 30.], 30.),
+    case02_one_break: (vec![(3, 10)], vec![0., 5., 10., 10., 5., 20.], 30.),
+    case03_two_breaks: (vec![(2, 5), (4, 10)], vec![0., 5., 5., 5., 5., 5., 20.], 30.),
+}
+
+fn can_accumulate_driving_time_impl(
+    breaks: Vec<(usize, Location)>,
+    expected_counters: Vec<Duration>,
+    expected_total: Duration,
+) {
+    let mut route_ctx = create_route_ctx(&[5, 10, 15], breaks);
+    let state = create_feature(100., 100.).state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    let counters = (0..route_ctx.route().tour.total()).map(|idx| get_driving_time(&route_ctx, idx)).collect::<Vec<_>>();
+    let total = route_ctx.state().get_route_state::<Duration>(TOTAL_DRIVING_TIME_KEY).copied();
+    assert_eq!(counters, expected_counters);
+    assert_eq!(total, Some(expected_total));
+}
+
+parameterized_test! {can_evaluate_insertion, (limits, breaks, target, index, expected), {
+    can_evaluate_insertion_impl(limits, breaks, target, index, expected);
+}}
+
+can_evaluate_insertion! {
+    case01_job_within_limits: ((30., 40.), vec![], (15, false), 2, None),
+    case02_job_exceeds_driving_time: ((29., 40.), vec![], (15, false), 2, Some(VIOLATION_CODE)),
+    case03_job_exceeds_total_driving_time: ((30., 29.), vec![], (15, false), 2, Some(VIOLATION_CODE)),
+    case04_job_after_break: ((20., 40.), vec![(3, 10)], (15, false), 3, None),
+    case05_job_before_break: ((20., 40.), vec![(3, 10)], (20, false), 2, Some(VIOLATION_CODE)),
+
+    case06_break_within_limits: ((15., 40.), vec![], (5, true), 1, None),
+    case07_break_exceeds_limit_after: ((14., 40.), vec![], (5, true), 1, Some(VIOLATION_CODE)),
+    case08_break_exceeds_limit_before: ((9., 40.), vec![], (10, true), 2, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_insertion_impl(
+    limits: (Duration, Duration),
+    breaks: Vec<(usize, Location)>,
+    target: (Location, bool),
+    index: usize,
+    expected: Option<ViolationCode>,
+) {
+    let (max_driving_time, max_total_driving_time) = limits;
+    let (location, is_break) = target;
+    let target = if is_break { create_break(location) } else { ActivityBuilder::with_location(location).build() };
+    let mut route_ctx = create_route_ctx(&[5, 10], breaks);
+    let feature = create_feature(max_driving_time, max_total_driving_time);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    let result = constraint.evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        },
+    ));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_remove_obsolete_breaks, (max_driving_time, breaks, expected), {
+    can_remove_obsolete_breaks_impl(max_driving_time, breaks, expected);
+}}
+
+can_remove_obsolete_breaks! {
+    case01_keep_required: (20., vec![(3, 10)], vec![0, 5, 10, 10, 15, 0]),
+    case02_remove_not_required: (30., vec![(3, 10)], vec![0, 5, 10, 15, 0]),
+    case03_keep_required_moved: (20., vec![(3, 7)], vec![0, 5, 10, 7, 15, 0]),
+    case04_remove_not_required_moved: (40., vec![(3, 7)], vec![0, 5, 10, 15, 0]),
+}
+
+fn can_remove_obsolete_breaks_impl(
+    max_driving_time: Duration,
+    breaks: Vec<(usize, Location)>,
+    expected: Vec<Location>,
+) {
+    let mut solution_ctx =
+        SolutionContext { routes: vec![create_route_ctx(&[5, 10, 15], breaks)], ..create_empty_solution_context() };
+    let state = create_feature(max_driving_time, 100.).state.unwrap();
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(
+        solution_ctx.routes[0].route().tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+parameterized_test! {can_keep_split_break_parts_in_same_interval, (breaks, split_parts, is_target_split, index, expected), {
+    can_keep_split_break_parts_in_same_interval_impl(breaks, split_parts, is_target_split, index, expected);
+}}
+
+can_keep_split_break_parts_in_same_interval! {
+    case01_parts_within_interval: (vec![], (1, None), true, 1, None),
+    case02_parts_separated_by_break: (vec![(2, 10)], (1, None), true, 3, Some(VIOLATION_CODE)),
+    case03_break_before_parts: (vec![], (2, Some(3)), false, 1, None),
+    case04_break_between_parts: (vec![], (2, Some(3)), false, 2, Some(VIOLATION_CODE)),
+}
+
+fn can_keep_split_break_parts_in_same_interval_impl(
+    breaks: Vec<(usize, Location)>,
+    split_parts: (usize, Option<usize>),
+    is_target_split: bool,
+    index: usize,
+    expected: Option<ViolationCode>,
+) {
+    let (first_part_idx, second_part_idx) = split_parts;
+    let multi = create_split_break_parts();
+    let create_part = |idx: usize| ActivityBuilder::with_location(10).job(Some(multi.jobs[idx].clone())).build();
+    let mut route_ctx = create_route_ctx(&[5, 10], breaks);
+    route_ctx.route_mut().tour.insert_at(create_part(0), first_part_idx);
+    if let Some(second_part_idx) = second_part_idx {
+        route_ctx.route_mut().tour.insert_at(create_part(1), second_part_idx);
+    }
+    let target = if is_target_split { create_part(1) } else { create_break(10) };
+    let feature = create_feature(100., 100.);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    let result = constraint.evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        },
+    ));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+fn create_split_break_parts() -> Arc<Multi> {
+    test_multi_with_id(
+        "split_break",
+        vec![
+            SingleBuilder::default().id("break_part").location(None).build_shared(),
+            SingleBuilder::default().id("break").location(None).build_shared(),
+        ],
+    )
+}
+
+parameterized_test! {can_check_split_break_first_part, (location, schedule, expected), {
+    can_check_split_break_first_part_impl(location, schedule, expected);
+}}
+
+can_check_split_break_first_part! {
+    case01_after_driving: (3, None, None),
+    case02_before_driving: (0, None, Some(VIOLATION_CODE)),
+    case03_too_short: (3, Some(Schedule::new(3., 4.)), Some(VIOLATION_CODE)),
+}
+
+fn can_check_split_break_first_part_impl(
+    location: Location,
+    schedule: Option<Schedule>,
+    expected: Option<ViolationCode>,
+) {
+    let multi = create_split_break_parts();
+    let mut first_part = ActivityBuilder::with_location_tw_and_duration(location, DEFAULT_ACTIVITY_TIME_WINDOW, 2.);
+    if let Some(schedule) = schedule {
+        first_part.schedule(schedule);
+    }
+    let first_part = first_part.job(Some(multi.jobs[0].clone())).build();
+    let target = ActivityBuilder::with_location(5).job(Some(multi.jobs[1].clone())).build();
+    let mut route_ctx = create_route_ctx(&[5, 10], vec![]);
+    route_ctx.route_mut().tour.insert_at(first_part, 1);
+    let feature = create_feature(100., 100.);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    let result = constraint.evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 2,
+            prev: route_ctx.route().tour.get(2).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(3),
+        },
+    ));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_remove_split_break_with_first_part_before_driving() {
+    let multi = create_split_break_parts();
+    let create_part = |idx: usize, location: Location| {
+        ActivityBuilder::with_location(location).job(Some(multi.jobs[idx].clone())).build()
+    };
+    let mut route_ctx = create_route_ctx(&[5, 10, 15], vec![]);
+    route_ctx.route_mut().tour.insert_at(create_part(0, 0), 1);
+    route_ctx.route_mut().tour.insert_at(create_part(1, 10), 4);
+    let mut solution_ctx = SolutionContext { routes: vec![route_ctx], ..create_empty_solution_context() };
+    let state = create_feature(20., 100.).state.unwrap();
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(
+        solution_ctx.routes[0].route().tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>(),
+        vec![0, 5, 10, 15, 0]
+    );
+    assert_eq!(solution_ctx.ignored.len(), 1);
+}
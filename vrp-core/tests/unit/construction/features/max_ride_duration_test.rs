@@ -0,0 +1,119 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::Schedule;
+
+const VIOLATION_CODE: ViolationCode = 1;
+const STATE_KEY: StateKey = StateKey(2);
+
+fn create_test_feature() -> Feature {
+    create_max_ride_duration_feature(
+        "max_ride_duration",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        STATE_KEY,
+    )
+    .unwrap()
+}
+
+fn create_multi_job(max_ride_duration: Duration, locations: (Location, Location)) -> Arc<Multi> {
+    let mut dimens = Dimensions::default();
+    dimens.set_id("multi").set_max_ride_duration(max_ride_duration);
+
+    Multi::new_shared(
+        vec![
+            SingleBuilder::default().id("pickup").location(Some(locations.0)).build_shared(),
+            SingleBuilder::default().id("delivery").location(Some(locations.1)).build_shared(),
+        ],
+        dimens,
+    )
+}
+
+fn create_activity(single: &Arc<Single>, location: Location, time: Timestamp) -> Activity {
+    ActivityBuilder::with_location(location).job(Some(single.clone())).schedule(Schedule::new(time, time)).build()
+}
+
+fn evaluate_insertion(route_activities: Vec<Activity>, target: Activity, index: usize) -> Option<ConstraintViolation> {
+    let feature = create_test_feature();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_start(ActivityBuilder::default().job(None).build())
+                .with_end(ActivityBuilder::default().job(None).schedule(Schedule::new(40., 40.)).build())
+                .add_activities(route_activities)
+                .build(),
+        )
+        .build();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx))
+}
+
+parameterized_test! {can_check_ride_duration_of_jobs_on_board, (index, location, max_ride_duration, expected), {
+    can_check_ride_duration_of_jobs_on_board_impl(index, location, max_ride_duration, expected);
+}}
+
+can_check_ride_duration_of_jobs_on_board! {
+    case01_no_detour: (1, 15, 10., None),
+    case02_detour_within_limit: (1, 25, 20., None),
+    case03_detour_exceeds_limit: (1, 25, 15., Some(VIOLATION_CODE)),
+    case04_detour_before_pickup: (0, 30, 10., None),
+    case05_detour_after_delivery: (2, 30, 10., None),
+}
+
+fn can_check_ride_duration_of_jobs_on_board_impl(
+    index: usize,
+    location: Location,
+    max_ride_duration: Duration,
+    expected: Option<ViolationCode>,
+) {
+    let multi = create_multi_job(max_ride_duration, (10, 20));
+    let route_activities = vec![create_activity(&multi.jobs[0], 10, 10.), create_activity(&multi.jobs[1], 20, 20.)];
+    let target = ActivityBuilder::with_location(location).job(Some(SingleBuilder::default().build_shared())).build();
+
+    let result = evaluate_insertion(route_activities, target, index);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_check_ride_duration_of_target_job, (location, max_ride_duration, expected), {
+    can_check_ride_duration_of_target_job_impl(location, max_ride_duration, expected);
+}}
+
+can_check_ride_duration_of_target_job! {
+    case01_within_limit: (30, 20., None),
+    case02_exceeds_limit: (30, 19., Some(VIOLATION_CODE)),
+}
+
+fn can_check_ride_duration_of_target_job_impl(
+    location: Location,
+    max_ride_duration: Duration,
+    expected: Option<ViolationCode>,
+) {
+    let multi = create_multi_job(max_ride_duration, (10, location));
+    let route_activities = vec![create_activity(&multi.jobs[0], 10, 10.)];
+    let target = ActivityBuilder::with_location(location).job(Some(multi.jobs[1].clone())).build();
+
+    let result = evaluate_insertion(route_activities, target, 1);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_get_ride_duration() {
+    let multi = create_multi_job(10., (10, 20));
+    let first = create_activity(&multi.jobs[0], 10, 5.);
+    let last = ActivityBuilder::with_location_and_tw(20, TimeWindow::new(25., 30.))
+        .job(Some(multi.jobs[1].clone()))
+        .schedule(Schedule::new(20., 25.))
+        .build();
+
+    assert_eq!(get_ride_duration(&first, &last), 20.);
+}
@@ -0,0 +1,114 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_frequency(count: usize, min_spacing: usize, max_spacing: Option<usize>) -> VisitFrequency {
+    VisitFrequency { id: "job".to_string(), count, min_spacing, max_spacing, patterns: None }
+}
+
+fn create_visit(frequency: &Arc<VisitFrequency>, idx: usize) -> Arc<Single> {
+    let mut dimens = Dimensions::default();
+    dimens.set_id(format!("job#{idx}").as_str()).set_visit_frequency(frequency.clone());
+
+    SingleBuilder::default().dimens(dimens).build_shared()
+}
+
+fn create_test_fleet(days: &[Option<usize>]) -> Fleet {
+    days.iter()
+        .enumerate()
+        .fold(FleetBuilder::default().add_driver(test_driver()), |builder, (idx, day)| {
+            let mut vehicle = VehicleBuilder::default().id(format!("v{idx}").as_str()).build();
+            if let Some(day) = day {
+                vehicle.dimens.set_vehicle_day(*day);
+            }
+
+            builder.add_vehicle(vehicle)
+        })
+        .build()
+}
+
+parameterized_test! {can_check_visit_days_feasibility, (frequency, patterns, days, expected), {
+    can_check_visit_days_feasibility_impl(frequency, patterns, days, expected);
+}}
+
+can_check_visit_days_feasibility! {
+    case01_single_visit: ((3, 1, None), None, vec![5], true),
+    case02_enough_spacing: ((3, 2, None), None, vec![0, 2, 4], true),
+    case03_not_enough_spacing: ((3, 2, None), None, vec![0, 1], false),
+    case04_same_day: ((3, 1, None), None, vec![1, 1], false),
+    case05_too_many_visits: ((2, 1, None), None, vec![0, 1, 2], false),
+    case06_gap_can_be_filled: ((3, 1, Some(2)), None, vec![0, 4], true),
+    case07_gap_cannot_be_filled: ((2, 1, Some(2)), None, vec![0, 4], false),
+    case08_gap_cannot_be_split: ((3, 3, Some(3)), None, vec![0, 4], false),
+    case09_pattern_match: ((2, 1, None), Some(vec![vec![0, 2], vec![1, 3]]), vec![3], true),
+    case10_pattern_mismatch: ((2, 1, None), Some(vec![vec![0, 2], vec![1, 3]]), vec![0, 3], false),
+    case11_pattern_outside_horizon: ((2, 1, None), Some(vec![vec![3, 7]]), vec![3], false),
+    case12_gap_can_be_completed: ((3, 2, None), None, vec![0, 2], true),
+    case13_horizon_too_short: ((3, 2, None), None, vec![1, 4], false),
+}
+
+fn can_check_visit_days_feasibility_impl(
+    frequency: (usize, usize, Option<usize>),
+    patterns: Option<Vec<Vec<usize>>>,
+    days: Vec<usize>,
+    expected: bool,
+) {
+    let (count, min_spacing, max_spacing) = frequency;
+    let frequency = VisitFrequency { patterns, ..create_frequency(count, min_spacing, max_spacing) };
+
+    assert_eq!(frequency.is_feasible(days.as_slice(), &[0, 1, 2, 3, 4, 5]), expected);
+}
+
+parameterized_test! {can_evaluate_visit_day, (route_days, used_routes, target_route, expected), {
+    can_evaluate_visit_day_impl(route_days, used_routes, target_route, expected);
+}}
+
+can_evaluate_visit_day! {
+    case01_first_visit: (vec![Some(0), Some(1), Some(2)], vec![], 0, None),
+    case02_enough_spacing: (vec![Some(0), Some(2)], vec![0], 1, None),
+    case03_not_enough_spacing: (vec![Some(0), Some(1), Some(2)], vec![0], 1, Some(VIOLATION_CODE)),
+    case04_same_day: (vec![Some(0), Some(0), Some(2)], vec![0], 1, Some(VIOLATION_CODE)),
+    case05_same_route: (vec![Some(0), Some(2)], vec![0], 0, Some(VIOLATION_CODE)),
+    case06_no_day: (vec![None], vec![], 0, Some(VIOLATION_CODE)),
+    case07_horizon_too_short: (vec![Some(0), Some(1)], vec![], 0, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_visit_day_impl(
+    route_days: Vec<Option<usize>>,
+    used_routes: Vec<usize>,
+    target_route: usize,
+    expected: Option<ViolationCode>,
+) {
+    let fleet = create_test_fleet(route_days.as_slice());
+    let frequency = Arc::new(create_frequency(2, 2, None));
+    let feature =
+        create_periodic_feature("periodic", used_routes.len(), VIOLATION_CODE, fleet.actors.as_slice()).unwrap();
+    let mut solution_ctx = SolutionContext {
+        routes: (0..route_days.len())
+            .map(|idx| {
+                let mut route_builder = RouteBuilder::default();
+                route_builder.with_vehicle(&fleet, format!("v{idx}").as_str());
+                if used_routes.contains(&idx) {
+                    route_builder
+                        .add_activity(ActivityBuilder::default().job(Some(create_visit(&frequency, idx))).build());
+                }
+
+                RouteContextBuilder::default().with_route(route_builder.build()).build()
+            })
+            .collect(),
+        ..create_empty_solution_context()
+    };
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let job = Job::Single(create_visit(&frequency, 100));
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(
+        &solution_ctx,
+        &solution_ctx.routes[target_route],
+        &job,
+    ));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
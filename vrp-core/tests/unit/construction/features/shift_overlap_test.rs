@@ -0,0 +1,128 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, TimeInterval, TimeWindow};
+use crate::models::problem::{Fleet, VehicleDetail, VehiclePlace};
+
+const VIOLATION_CODE: ViolationCode = 1;
+const SHIFT_REST: Duration = 20.;
+
+fn create_shift_detail(start: Timestamp, end: Timestamp) -> VehicleDetail {
+    VehicleDetail {
+        start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(start), latest: None } }),
+        end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(end) } }),
+        depots: Default::default(),
+    }
+}
+
+fn create_test_fleet() -> Fleet {
+    let mut vehicle = VehicleBuilder::default()
+        .id("v1")
+        .details(vec![create_shift_detail(0., 100.), create_shift_detail(150., 300.)])
+        .build();
+    vehicle.dimens.set_shift_rest(SHIFT_REST);
+
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build()
+}
+
+fn create_test_feature(fleet: &Fleet) -> Feature {
+    create_shift_overlap_feature(
+        "shift_overlap",
+        VIOLATION_CODE,
+        fleet.actors.as_slice(),
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn get_shift_actor(fleet: &Fleet, start: Timestamp) -> Arc<Actor> {
+    fleet.actors.iter().find(|actor| actor.detail.time.start == start).cloned().unwrap()
+}
+
+/// Creates a route with one job served at location 10 and tour end at given time.
+fn create_route_ctx(actor: Arc<Actor>, end: Option<Timestamp>) -> RouteContext {
+    let mut route_ctx = RouteContext::new(actor);
+
+    if let Some(end) = end {
+        let start = route_ctx.route().actor.detail.time.start;
+        let tour = &mut route_ctx.route_mut().tour;
+        tour.insert_last(
+            ActivityBuilder::with_location_and_tw(10, TimeWindow::new(0., 1000.))
+                .schedule(Schedule::new(start + 10., start + 20.))
+                .job(Some(SingleBuilder::default().build_shared()))
+                .build(),
+        );
+        tour.get_mut(2).unwrap().schedule = Schedule::new(end, end);
+    }
+
+    route_ctx
+}
+
+parameterized_test! {can_detect_overlap_with_used_shifts, (used, target, expected), {
+    can_detect_overlap_with_used_shifts_impl(used, target, expected);
+}}
+
+can_detect_overlap_with_used_shifts! {
+    case01_enough_rest_before: ((0., 100.), 150., None),
+    case02_not_enough_rest_before: ((0., 140.), 150., Some(VIOLATION_CODE)),
+    case03_enough_rest_after: ((150., 200.), 0., None),
+}
+
+fn can_detect_overlap_with_used_shifts_impl(
+    used: (Timestamp, Timestamp),
+    target: Timestamp,
+    expected: Option<ViolationCode>,
+) {
+    let fleet = create_test_fleet();
+    let feature = create_test_feature(&fleet);
+    let (used_start, used_end) = used;
+    let solution_ctx = SolutionContext {
+        routes: vec![create_route_ctx(get_shift_actor(&fleet, used_start), Some(used_end))],
+        ..create_empty_solution_context()
+    };
+    let route_ctx = create_route_ctx(get_shift_actor(&fleet, target), None);
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(
+        &solution_ctx,
+        &route_ctx,
+        &SingleBuilder::default().build_as_job_ref(),
+    ));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_limit_tour_end_by_next_used_shift, (location, is_next_used, expected), {
+    can_limit_tour_end_by_next_used_shift_impl(location, is_next_used, expected);
+}}
+
+can_limit_tour_end_by_next_used_shift! {
+    case01_fits_before_next: (50, true, None),
+    case02_ends_at_rest_start: (60, true, None),
+    case03_exceeds_rest_start: (70, true, Some(VIOLATION_CODE)),
+    case04_next_is_not_used: (70, false, None),
+}
+
+fn can_limit_tour_end_by_next_used_shift_impl(location: Location, is_next_used: bool, expected: Option<ViolationCode>) {
+    let fleet = create_test_fleet();
+    let feature = create_test_feature(&fleet);
+    let mut routes = vec![create_route_ctx(get_shift_actor(&fleet, 0.), Some(30.))];
+    if is_next_used {
+        routes.push(create_route_ctx(get_shift_actor(&fleet, 150.), Some(200.)));
+    }
+    let mut solution_ctx = SolutionContext { routes, ..create_empty_solution_context() };
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = &solution_ctx.routes[0];
+    let target = ActivityBuilder::with_location_and_tw(location, TimeWindow::new(0., 1000.)).build();
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
@@ -21,11 +21,15 @@ fn create_detail(
             location,
             time: time.map_or(Default::default(), |(_, end)| TimeInterval { earliest: None, latest: Some(end) }),
         }),
+        depots: Default::default(),
     }
 }
 
 mod timing {
     use super::*;
+    use crate::construction::enablers::{
+        SoftTimeWindowActivityCost, TimeWindowTolerance, TimeWindowToleranceDimension,
+    };
     use crate::helpers::models::domain::{create_empty_solution_context, create_registry_context};
     use crate::models::solution::{Activity, Place};
     use rosomaxa::prelude::compare_floats;
@@ -255,6 +259,70 @@ mod timing {
         assert_eq!(compare_floats(result, 30.0), Ordering::Equal);
     }
 
+    parameterized_test! {can_consider_downstream_time_window_penalty, (tolerance, is_before_cheaper), {
+        can_consider_downstream_time_window_penalty_impl(tolerance, is_before_cheaper);
+    }}
+
+    can_consider_downstream_time_window_penalty! {
+        case01_hard_time_window: (None, true),
+        case02_soft_time_window: (Some(TimeWindowTolerance::new(0., 100., 10.)), false),
+    }
+
+    fn can_consider_downstream_time_window_penalty_impl(
+        tolerance: Option<TimeWindowTolerance>,
+        is_before_cheaper: bool,
+    ) {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicles(vec![VehicleBuilder::default().id("v1").build()])
+            .build();
+        let time = TimeWindow::new(0., 15.);
+        let time = tolerance.as_ref().map_or(time.clone(), |tolerance| tolerance.expand(&time));
+        let mut dimens = Dimensions::default();
+        dimens.set_time_window_tolerances(vec![tolerance]);
+        let late = ActivityBuilder::with_location_tw_and_duration(5, time, 0.)
+            .job(Some(SingleBuilder::default().dimens(dimens).build_shared()))
+            .build();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&fleet, "v1")
+                    .add_activity(
+                        ActivityBuilder::with_location_tw_and_duration(10, DEFAULT_ACTIVITY_TIME_WINDOW, 0.).build(),
+                    )
+                    .add_activity(
+                        ActivityBuilder::with_location_tw_and_duration(10, DEFAULT_ACTIVITY_TIME_WINDOW, 0.).build(),
+                    )
+                    .add_activity(late)
+                    .build(),
+            )
+            .build();
+        let activity = Arc::new(SoftTimeWindowActivityCost::new(TestActivityCost::new_shared()));
+        let feature = create_minimize_transport_costs_feature(
+            "transport",
+            TestTransportCost::new_shared(),
+            activity,
+            VIOLATION_CODE,
+        )
+        .unwrap();
+        feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+        let target = ActivityBuilder::with_location_tw_and_duration(10, DEFAULT_ACTIVITY_TIME_WINDOW, 5.).build();
+        let estimate = |index: usize| {
+            let activity_ctx = ActivityContext {
+                index,
+                prev: route_ctx.route().tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(index + 1),
+            };
+            feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx))
+        };
+
+        // NOTE insertion before delays the last activity, insertion after requires an extra trip
+        let (before, after) = (estimate(1), estimate(3));
+
+        assert_eq!(before < after, is_before_cheaper);
+    }
+
     #[test]
     fn can_stop_with_time_route_constraint() {
         let fleet = FleetBuilder::default()
@@ -158,6 +158,7 @@ mod single {
                                 location: v1_end_location,
                                 time: TimeInterval { earliest: None, latest: Some(100.) },
                             }),
+                            depots: Default::default(),
                         }])
                         .build(),
                     VehicleBuilder::default()
@@ -171,6 +172,7 @@ mod single {
                                 location: v2_end_location,
                                 time: TimeInterval { earliest: None, latest: Some(100.) },
                             }),
+                            depots: Default::default(),
                         }])
                         .build(),
                 ])
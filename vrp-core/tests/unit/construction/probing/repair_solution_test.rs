@@ -57,6 +57,7 @@ fn create_test_problem(
                         location: end_location,
                         time: TimeInterval { earliest: end_earliest, latest: end_latest },
                     }),
+                    depots: Default::default(),
                 }])
                 .capacity(capacity)
                 .build()
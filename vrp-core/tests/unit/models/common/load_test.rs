@@ -102,4 +102,17 @@ mod multi {
         assert!(!from_vec(vec![1, 0]).can_fit(&from_vec(vec![0, 1])));
         assert!(!from_vec(vec![3, 0, 2]).can_fit(&from_vec(vec![1, 1, 4])));
     }
+
+    #[test]
+    fn can_use_more_dimensions_than_inline_size() {
+        let capacity = from_vec((1..=12).collect());
+        let demand = from_vec(vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13]);
+
+        assert_eq!(capacity.size(), 12);
+        assert!(!capacity.can_fit(&demand));
+        assert!(capacity.can_fit(&from_vec(vec![1, 2])));
+        assert_eq!((capacity.clone() + demand.clone()).as_vec()[11], 25);
+        assert_eq!((capacity.clone() - demand).as_vec()[11], -1);
+        assert_eq!(capacity.as_vec(), (1..=12).collect::<Vec<_>>());
+    }
 }
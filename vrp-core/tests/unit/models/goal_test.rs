@@ -4,6 +4,7 @@ use crate::helpers::construction::features::create_goal_ctx_with_features;
 use crate::helpers::models::domain::create_empty_insertion_context;
 use crate::helpers::models::solution::{test_actor, ActivityBuilder};
 use crate::models::common::SingleDimLoad;
+use rosomaxa::utils::DefaultRandom;
 
 fn create_constraint_feature(name: &str, violation: Option<ConstraintViolation>) -> Feature {
     struct TestFeatureConstraint {
@@ -199,13 +200,16 @@ can_use_objective_total_order! {
     ),
 }
 
-fn can_use_objective_total_order_impl(
-    feature_map: Vec<Vec<&str>>,
-    left_fitness: Vec<f64>,
-    right_fitness: Vec<f64>,
-    expected: Ordering,
-) {
-    let fitness_fn = Arc::new(|name: &str, insertion_ctx: &InsertionContext| {
+fn create_insertion_ctx_with_fitness(fitness: Vec<f64>) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+    fitness.into_iter().enumerate().for_each(|(idx, value)| {
+        insertion_ctx.solution.state.insert(StateKey(idx), Arc::new(value));
+    });
+    insertion_ctx
+}
+
+fn create_fitness_features(size: usize) -> Vec<Feature> {
+    let fitness_fn: FitnessFn = Arc::new(|name: &str, insertion_ctx: &InsertionContext| {
         insertion_ctx
             .solution
             .state
@@ -214,24 +218,207 @@ fn can_use_objective_total_order_impl(
             .copied()
             .unwrap()
     });
-    let create_insertion_ctx_with_fitness_state = |fitness: Vec<f64>| {
-        let mut insertion_ctx = create_empty_insertion_context();
-        fitness.into_iter().enumerate().for_each(|(idx, value)| {
-            insertion_ctx.solution.state.insert(StateKey(idx), Arc::new(value));
-        });
-        insertion_ctx
+
+    (0..size).map(|idx| create_objective_feature_with_dynamic_cost(&idx.to_string(), fitness_fn.clone())).collect()
+}
+
+fn can_use_objective_total_order_impl(
+    feature_map: Vec<Vec<&str>>,
+    left_fitness: Vec<f64>,
+    right_fitness: Vec<f64>,
+    expected: Ordering,
+) {
+    let goal_ctx = create_goal_ctx_with_features(create_fitness_features(4), feature_map);
+    let left = create_insertion_ctx_with_fitness(left_fitness);
+    let right = create_insertion_ctx_with_fitness(right_fitness);
+
+    assert_eq!(goal_ctx.total_order(&left, &right), expected);
+}
+
+parameterized_test! {can_use_level_policy_total_order, (weights, tolerance, left_fitness, right_fitness, expected), {
+    can_use_level_policy_total_order_impl(weights, tolerance, left_fitness, right_fitness, expected);
+}}
+
+can_use_level_policy_total_order! {
+    case01_weighted_sum_prefers_fewer_tours: (
+        Some(vec![1., 500.]), None,
+        vec![1000., 3., 0.], vec![1200., 2., 0.],
+        Ordering::Greater,
+    ),
+    case02_weighted_sum_prefers_lower_cost: (
+        Some(vec![1., 100.]), None,
+        vec![1000., 3., 0.], vec![1200., 2., 0.],
+        Ordering::Less,
+    ),
+    case03_weighted_sum_equal_uses_next_level: (
+        Some(vec![1., 200.]), None,
+        vec![1000., 3., 1.], vec![1200., 2., 0.],
+        Ordering::Greater,
+    ),
+    case04_relative_within: (
+        None, Some((0.02, 0.)),
+        vec![1005., 3., 0.], vec![1020., 2., 0.],
+        Ordering::Greater,
+    ),
+    case05_relative_outside: (
+        None, Some((0.02, 0.)),
+        vec![1000., 3., 0.], vec![1030., 2., 0.],
+        Ordering::Less,
+    ),
+    case06_absolute_within: (
+        None, Some((0., 20.)),
+        vec![1000., 3., 0.], vec![1019., 2., 0.],
+        Ordering::Greater,
+    ),
+    case07_absolute_outside: (
+        None, Some((0., 20.)),
+        vec![1000., 3., 0.], vec![1021., 2., 0.],
+        Ordering::Less,
+    ),
+    case08_weighted_sum_within: (
+        Some(vec![1., 100.]), Some((0., 50.)),
+        vec![1000., 3., 0.], vec![1040., 3., 1.],
+        Ordering::Less,
+    ),
+    case09_no_policy: (
+        None, None,
+        vec![1000., 3., 1.], vec![1000., 3., 0.],
+        Ordering::Greater,
+    ),
+    case10_relative_at_boundary: (
+        None, Some((0.02, 0.)),
+        vec![1000., 3., 0.], vec![1020.4, 2., 0.],
+        Ordering::Greater,
+    ),
+    case11_relative_above_boundary: (
+        None, Some((0.02, 0.)),
+        vec![1000., 3., 0.], vec![1020.5, 2., 0.],
+        Ordering::Less,
+    ),
+    case12_relative_close_values: (
+        None, Some((0.02, 0.)),
+        vec![1023., 3., 0.], vec![1024., 2., 0.],
+        Ordering::Greater,
+    ),
+}
+
+fn can_use_level_policy_total_order_impl(
+    weights: Option<Vec<f64>>,
+    tolerance: Option<(f64, f64)>,
+    left_fitness: Vec<f64>,
+    right_fitness: Vec<f64>,
+    expected: Ordering,
+) {
+    let (global, level_size) = if weights.is_some() {
+        (vec![vec!["0".to_string(), "1".to_string()], vec!["2".to_string()]], 2)
+    } else {
+        (vec![vec!["0".to_string()], vec!["1".to_string()], vec!["2".to_string()]], 1)
     };
-    let goal_ctx = create_goal_ctx_with_features(
-        vec![
-            create_objective_feature_with_dynamic_cost("0", fitness_fn.clone()),
-            create_objective_feature_with_dynamic_cost("1", fitness_fn.clone()),
-            create_objective_feature_with_dynamic_cost("2", fitness_fn.clone()),
-            create_objective_feature_with_dynamic_cost("3", fitness_fn),
-        ],
-        feature_map,
-    );
-    let left = create_insertion_ctx_with_fitness_state(left_fitness);
-    let right = create_insertion_ctx_with_fitness_state(right_fitness);
+    let tolerance = tolerance.map(|(relative, absolute)| LevelTolerance { relative, absolute });
+    let goal = Goal::no_alternatives(global.clone(), global).with_policies(vec![LevelPolicy { weights, tolerance }]);
+    let goal_ctx = GoalContext::new(create_fitness_features(3).as_slice(), goal).unwrap();
+    let left = create_insertion_ctx_with_fitness(left_fitness);
+    let right = create_insertion_ctx_with_fitness(right_fitness);
 
+    assert_eq!(goal_ctx.global_objectives[0].0.len(), level_size);
     assert_eq!(goal_ctx.total_order(&left, &right), expected);
 }
+
+parameterized_test! {can_compare_population_with_level_policy, (weights, tolerance), {
+    can_compare_population_with_level_policy_impl(weights, tolerance);
+}}
+
+can_compare_population_with_level_policy! {
+    case01_relative: (None, (0.05, 0.)),
+    case02_absolute: (None, (0., 10.)),
+    case03_relative_and_absolute: (None, (0.05, 10.)),
+    case04_weighted_sum: (Some(vec![1., 50.]), (0.02, 5.)),
+}
+
+fn can_compare_population_with_level_policy_impl(weights: Option<Vec<f64>>, tolerance: (f64, f64)) {
+    let random = DefaultRandom::default();
+    let global = if weights.is_some() {
+        vec![vec!["0".to_string(), "1".to_string()], vec!["2".to_string()]]
+    } else {
+        vec![vec!["0".to_string()], vec!["1".to_string()], vec!["2".to_string()]]
+    };
+    let tolerance = Some(LevelTolerance { relative: tolerance.0, absolute: tolerance.1 });
+    let goal = Goal::no_alternatives(global.clone(), global).with_policies(vec![LevelPolicy { weights, tolerance }]);
+    let goal_ctx = GoalContext::new(create_fitness_features(3).as_slice(), goal).unwrap();
+    let population = (0..500)
+        .map(|_| {
+            create_insertion_ctx_with_fitness(vec![
+                random.uniform_real(-100., 1000.),
+                random.uniform_int(0, 10) as f64,
+                random.uniform_real(0., 100.),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    population.iter().enumerate().for_each(|(idx, a)| {
+        assert_eq!(goal_ctx.total_order(a, a), Ordering::Equal);
+        population.iter().skip(idx + 1).for_each(|b| {
+            assert_eq!(goal_ctx.total_order(a, b), goal_ctx.total_order(b, a).reverse());
+        })
+    });
+}
+
+parameterized_test! {can_apply_level_weights_to_local_objectives, (local, expected_cost), {
+    can_apply_level_weights_to_local_objectives_impl(local, expected_cost);
+}}
+
+can_apply_level_weights_to_local_objectives! {
+    case01_same_level: (vec![vec!["0", "1"]], vec![1. * 2. + 3. * 10.]),
+    case02_separate_levels: (vec![vec!["0"], vec!["1"]], vec![2., 30.]),
+    case03_other_level: (vec![vec!["2"]], vec![5.]),
+}
+
+fn can_apply_level_weights_to_local_objectives_impl(local: Vec<Vec<&str>>, expected_cost: Vec<Cost>) {
+    let to_levels = |levels: Vec<Vec<&str>>| {
+        levels.into_iter().map(|level| level.into_iter().map(|id| id.to_string()).collect()).collect::<Vec<_>>()
+    };
+    let features = vec![
+        create_objective_feature_with_fixed_cost("0", 1.),
+        create_objective_feature_with_fixed_cost("1", 3.),
+        create_objective_feature_with_fixed_cost("2", 5.),
+    ];
+    let goal = Goal::no_alternatives(to_levels(vec![vec!["0", "1"], vec!["2"]]), to_levels(local))
+        .with_policies(vec![LevelPolicy { weights: Some(vec![2., 10.]), tolerance: None }]);
+    let goal_ctx = GoalContext::new(features.as_slice(), goal).unwrap();
+    let route_ctx = RouteContext::new(test_actor());
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &ActivityBuilder::default().job(None).build(),
+        target: &ActivityBuilder::default().job(None).build(),
+        next: None,
+    };
+    let move_ctx = MoveContext::activity(&route_ctx, &activity_ctx);
+
+    let result = goal_ctx.estimate(&move_ctx);
+
+    assert_eq!(result, InsertionCost::new(expected_cost.as_slice()));
+}
+
+parameterized_test! {can_detect_invalid_level_policies, (policies, is_valid), {
+    can_detect_invalid_level_policies_impl(policies, is_valid);
+}}
+
+can_detect_invalid_level_policies! {
+    case01_valid: (vec![LevelPolicy { weights: Some(vec![1., 2.]), tolerance: None }], true),
+    case02_wrong_weights_size: (vec![LevelPolicy { weights: Some(vec![1.]), tolerance: None }], false),
+    case03_negative_weight: (vec![LevelPolicy { weights: Some(vec![1., -2.]), tolerance: None }], false),
+    case04_zero_weight: (vec![LevelPolicy { weights: Some(vec![1., 0.]), tolerance: None }], false),
+    case05_negative_tolerance: (
+        vec![LevelPolicy { weights: None, tolerance: Some(LevelTolerance { relative: -0.1, absolute: 0. }) }], false
+    ),
+    case06_too_many_policies: (vec![LevelPolicy::default(), LevelPolicy::default(), LevelPolicy::default()], false),
+}
+
+fn can_detect_invalid_level_policies_impl(policies: Vec<LevelPolicy>, is_valid: bool) {
+    let global = vec![vec!["0".to_string(), "1".to_string()], vec!["2".to_string()]];
+    let goal = Goal::no_alternatives(global.clone(), global).with_policies(policies);
+
+    let result = GoalContext::new(create_fitness_features(3).as_slice(), goal);
+
+    assert_eq!(result.is_ok(), is_valid);
+}
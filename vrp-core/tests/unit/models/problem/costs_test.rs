@@ -1,5 +1,6 @@
 use super::*;
 use crate::helpers::models::solution::test_actor_with_profile;
+use std::iter::once;
 
 fn create_matrix_data(
     profile: Profile,
@@ -15,6 +16,25 @@ fn create_matrix_data(
     }
 }
 
+struct ConstantFallback;
+
+impl TransportFallback for ConstantFallback {
+    fn duration(&self, _: &Profile, _: Location, _: Location) -> Duration {
+        1000.
+    }
+
+    fn distance(&self, _: &Profile, _: Location, _: Location) -> Distance {
+        2000.
+    }
+}
+
+fn create_sparse_matrix_data(
+    profile: Profile,
+    entries: &[(Location, Location, Duration, Distance)],
+) -> SparseMatrixData {
+    SparseMatrixData::new(profile.index, entries.to_vec())
+}
+
 #[test]
 fn can_detect_dimensions_mismatch() {
     assert_eq!(
@@ -36,6 +56,7 @@ fn can_return_error_when_mixing_timestamps() {
         TimeAwareMatrixTransportCost::new(
             vec![create_matrix_data(Profile::default(), None, (0., 1), (0., 1))],
             1,
+            HashSet::default(),
             NoFallback
         )
         .err(),
@@ -49,6 +70,7 @@ fn can_return_error_when_mixing_timestamps() {
                 create_matrix_data(p0.clone(), None, (0., 1), (0., 1))
             ],
             1,
+            HashSet::default(),
             NoFallback
         )
         .err(),
@@ -59,6 +81,7 @@ fn can_return_error_when_mixing_timestamps() {
         TimeAwareMatrixTransportCost::new(
             vec![create_matrix_data(p0.clone(), Some(0.), (0., 1), (0., 1))],
             1,
+            HashSet::default(),
             NoFallback
         )
         .err(),
@@ -73,6 +96,7 @@ fn can_return_error_when_mixing_timestamps() {
                 create_matrix_data(p1, Some(0.), (1., 1), (1., 1)),         //
             ],
             1,
+            HashSet::default(),
             NoFallback
         )
         .err(),
@@ -95,6 +119,7 @@ fn can_interpolate_durations() {
             create_matrix_data(p1.clone(), Some(10.), (400., 2), (5., 2)),
         ],
         2,
+        HashSet::default(),
         NoFallback,
     )
     .unwrap();
@@ -114,6 +139,299 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+#[test]
+fn can_interpolate_distances() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (100., 2), (10., 2)),
+            create_matrix_data(profile, Some(10.), (200., 2), (20., 2)),
+        ],
+        2,
+        HashSet::default(),
+        NoFallback,
+    )
+    .unwrap();
+
+    for &(timestamp, distance) in &[(0., 10.), (10., 20.), (15., 20.), (3., 13.), (5., 15.)] {
+        assert_eq!(costs.distance(&route, 0, 1, TravelTime::Departure(timestamp)), distance);
+    }
+}
+
+parameterized_test! {can_keep_fifo_property, (travel_time, fifo, expected), {
+    can_keep_fifo_property_impl(travel_time, fifo, expected);
+}}
+
+can_keep_fifo_property! {
+    case01_departure_no_fifo: (TravelTime::Departure(0.), false, (100., 10.)),
+    case02_departure_no_fifo: (TravelTime::Departure(5.), false, (55., 15.)),
+    case03_departure_fifo: (TravelTime::Departure(0.), true, (20., 20.)),
+    case04_departure_fifo: (TravelTime::Departure(5.), true, (15., 20.)),
+    case05_departure_fifo: (TravelTime::Departure(10.), true, (10., 20.)),
+    case06_departure_fifo: (TravelTime::Departure(20.), true, (10., 20.)),
+    case07_arrival_fifo: (TravelTime::Arrival(20.), true, (10., 20.)),
+    case08_arrival_fifo: (TravelTime::Arrival(19.), true, (100., 10.)),
+    case09_arrival_fifo: (TravelTime::Arrival(100.), true, (10., 20.)),
+}
+
+fn can_keep_fifo_property_impl(travel_time: TravelTime, fifo: bool, expected: (Duration, Distance)) {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let fifo = if fifo { once(profile.index).collect() } else { HashSet::default() };
+
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (100., 2), (10., 2)),
+            create_matrix_data(profile, Some(10.), (10., 2), (20., 2)),
+        ],
+        2,
+        fifo,
+        NoFallback,
+    )
+    .unwrap();
+
+    let duration = costs.duration(&route, 0, 1, travel_time);
+    let distance = costs.distance(&route, 0, 1, travel_time);
+
+    assert_eq!((duration, distance), expected);
+}
+
+#[test]
+fn can_have_non_decreasing_arrival_with_fifo() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (50., 2), (1., 2)),
+            create_matrix_data(profile.clone(), Some(10.), (100., 2), (1., 2)),
+            create_matrix_data(profile.clone(), Some(20.), (5., 2), (1., 2)),
+            create_matrix_data(profile.clone(), Some(30.), (60., 2), (1., 2)),
+        ],
+        2,
+        once(profile.index).collect(),
+        NoFallback,
+    )
+    .unwrap();
+
+    (0..400).map(|time| time as f64 / 10.).fold(f64::MIN, |prev_arrival, departure| {
+        let arrival = departure + costs.duration(&route, 0, 1, TravelTime::Departure(departure));
+        assert!(arrival >= prev_arrival, "departure at {departure}: {arrival} < {prev_arrival}");
+
+        arrival
+    });
+}
+
+parameterized_test! {can_walk_speed_bands, (travel_time, expected), {
+    can_walk_speed_bands_impl(travel_time, expected);
+}}
+
+can_walk_speed_bands! {
+    case01_departure_single_band: (TravelTime::Departure(0.), 20.),
+    case02_departure_before_first_band: (TravelTime::Departure(-10.), 20.),
+    case03_departure_cross_one: (TravelTime::Departure(90.), 30.),
+    case04_departure_cross_two: (TravelTime::Departure(95.), 27.5),
+    case05_departure_last_band: (TravelTime::Departure(300.), 10.),
+    case06_arrival_single_band: (TravelTime::Arrival(20.), 20.),
+    case07_arrival_cross_one: (TravelTime::Arrival(120.), 30.),
+    case08_arrival_cross_two: (TravelTime::Arrival(122.5), 27.5),
+    case09_arrival_last_band: (TravelTime::Arrival(300.), 10.),
+}
+
+fn can_walk_speed_bands_impl(travel_time: TravelTime, expected: Duration) {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let bands = vec![
+        SpeedBand { start: 0., factor: 0.5 },
+        SpeedBand { start: 100., factor: 0.25 },
+        SpeedBand { start: 120., factor: 1. },
+    ];
+
+    let costs = create_matrix_transport_cost_with_models(
+        vec![create_matrix_data(profile.clone(), None, (10., 4), (7., 4))],
+        once((profile.index, TravelModel::SpeedBands(bands))).collect(),
+        NoFallback,
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration(&route, 0, 1, travel_time), expected);
+    assert_eq!(costs.distance(&route, 0, 1, travel_time), 7.);
+    assert_eq!(costs.duration_approx(&profile, 0, 1), 10.);
+}
+
+parameterized_test! {can_validate_speed_bands, (bands, timestamp, expected), {
+    can_validate_speed_bands_impl(bands, timestamp, expected);
+}}
+
+can_validate_speed_bands! {
+    case01_empty: (vec![], None, Some("speed bands should not be empty")),
+    case02_negative_factor: (vec![(0., -1.)], None, Some("speed band factor should be positive")),
+    case03_zero_factor: (vec![(0., 0.)], None, Some("speed band factor should be positive")),
+    case04_unsorted: (vec![(10., 1.), (0., 1.)], None, Some("speed bands should be sorted by start time without duplicates")),
+    case05_duplicates: (vec![(0., 1.), (0., 1.)], None, Some("speed bands should be sorted by start time without duplicates")),
+    case06_time_aware: (vec![(0., 1.)], Some(0.), Some("speed bands cannot be used with time aware matrices")),
+    case07_valid: (vec![(0., 1.), (10., 0.5)], None, None),
+}
+
+fn can_validate_speed_bands_impl(bands: Vec<(Timestamp, f64)>, timestamp: Option<Timestamp>, expected: Option<&str>) {
+    let profile = Profile::default();
+    let bands = bands.into_iter().map(|(start, factor)| SpeedBand { start, factor }).collect();
+    let matrices = if timestamp.is_some() {
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (1., 4), (1., 4)),
+            create_matrix_data(profile.clone(), Some(1.), (1., 4), (1., 4)),
+        ]
+    } else {
+        vec![create_matrix_data(profile.clone(), None, (1., 4), (1., 4))]
+    };
+
+    let result = create_matrix_transport_cost_with_models(
+        matrices,
+        once((profile.index, TravelModel::SpeedBands(bands))).collect(),
+        NoFallback,
+    );
+
+    assert_eq!(result.err(), expected.map(|err| err.into()));
+}
+
+parameterized_test! {can_validate_fifo, (fifo_profile, is_time_aware, expected), {
+    can_validate_fifo_impl(fifo_profile, is_time_aware, expected);
+}}
+
+can_validate_fifo! {
+    case01_time_aware: (0, true, None),
+    case02_time_agnostic: (0, false, Some("fifo can be used only with time aware matrices")),
+    case03_no_matrix: (1, true, Some("fifo can be used only with time aware matrices")),
+}
+
+fn can_validate_fifo_impl(fifo_profile: usize, is_time_aware: bool, expected: Option<&str>) {
+    let profile = Profile::default();
+    let matrices = if is_time_aware {
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (1., 4), (1., 4)),
+            create_matrix_data(profile.clone(), Some(1.), (1., 4), (1., 4)),
+        ]
+    } else {
+        vec![create_matrix_data(profile.clone(), None, (1., 4), (1., 4))]
+    };
+
+    let result = create_matrix_transport_cost_with_models(
+        matrices,
+        once((fifo_profile, TravelModel::Interpolation { fifo: true })).collect(),
+        NoFallback,
+    );
+
+    assert_eq!(result.err(), expected.map(|err| err.into()));
+}
+
+parameterized_test! {can_use_sparse_matrix_with_fallback, (from, to, expected), {
+    can_use_sparse_matrix_with_fallback_impl(from, to, expected);
+}}
+
+can_use_sparse_matrix_with_fallback! {
+    case01_defined: (0, 1, (10., 100.)),
+    case02_defined_reverse: (1, 0, (11., 110.)),
+    case03_defined_last: (3, 0, (7., 70.)),
+    case04_missing_in_row: (0, 3, (1000., 2000.)),
+    case05_missing_row: (2, 0, (1000., 2000.)),
+    case06_out_of_range: (10, 0, (1000., 2000.)),
+}
+
+fn can_use_sparse_matrix_with_fallback_impl(from: Location, to: Location, expected: (Duration, Distance)) {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let costs = create_sparse_matrix_transport_cost(
+        vec![create_sparse_matrix_data(
+            profile.clone(),
+            &[(3, 0, 7., 70.), (0, 2, 20., 200.), (1, 0, 11., 110.), (0, 1, 10., 100.)],
+        )],
+        HashMap::default(),
+        ConstantFallback,
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration_approx(&profile, from, to), expected.0);
+    assert_eq!(costs.distance_approx(&profile, from, to), expected.1);
+    assert_eq!(costs.duration(&route, from, to, TravelTime::Departure(0.)), expected.0);
+    assert_eq!(costs.distance(&route, from, to, TravelTime::Departure(0.)), expected.1);
+}
+
+#[test]
+fn can_get_neighbours_from_sparse_matrix() {
+    let profile = Profile::default();
+    let costs = create_sparse_matrix_transport_cost(
+        vec![create_sparse_matrix_data(profile.clone(), &[(0, 2, 1., 1.), (0, 1, 2., 2.), (2, 0, 1., 1.)])],
+        HashMap::default(),
+        ConstantFallback,
+    )
+    .unwrap();
+
+    assert_eq!(costs.neighbours(&profile, 0), Some(vec![1, 2]));
+    assert_eq!(costs.neighbours(&profile, 1), Some(vec![]));
+    assert_eq!(costs.neighbours(&profile, 2), Some(vec![0]));
+    assert_eq!(costs.neighbours(&profile, 5), Some(vec![]));
+
+    let dense_costs = create_matrix_transport_cost(vec![create_matrix_data(profile.clone(), None, (1., 4), (1., 4))]);
+    assert_eq!(dense_costs.unwrap().neighbours(&profile, 0), None);
+}
+
+#[test]
+fn can_use_sparse_matrix_with_speed_bands() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let bands = vec![SpeedBand { start: 0., factor: 0.5 }, SpeedBand { start: 100., factor: 1. }];
+
+    let costs = create_sparse_matrix_transport_cost(
+        vec![create_sparse_matrix_data(profile.clone(), &[(0, 1, 10., 7.)])],
+        once((profile.index, TravelModel::SpeedBands(bands))).collect(),
+        ConstantFallback,
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration(&route, 0, 1, TravelTime::Departure(0.)), 20.);
+    assert_eq!(costs.duration(&route, 0, 1, TravelTime::Departure(100.)), 10.);
+    assert_eq!(costs.distance(&route, 0, 1, TravelTime::Departure(0.)), 7.);
+    assert_eq!(costs.neighbours(&profile, 0), Some(vec![1]));
+}
+
+#[test]
+fn can_reject_fifo_with_sparse_matrix() {
+    let profile = Profile::default();
+
+    let result = create_sparse_matrix_transport_cost(
+        vec![create_sparse_matrix_data(profile.clone(), &[(0, 1, 10., 7.)])],
+        once((profile.index, TravelModel::Interpolation { fifo: true })).collect(),
+        ConstantFallback,
+    );
+
+    assert_eq!(result.err(), Some("fifo can be used only with time aware matrices".into()));
+}
+
+parameterized_test! {can_validate_sparse_matrix_data, (data, expected), {
+    can_validate_sparse_matrix_data_impl(data, expected);
+}}
+
+can_validate_sparse_matrix_data! {
+    case01_empty: (vec![], Some("no matrix data found")),
+    case02_duplicate_entries: (vec![(0, vec![(0, 1, 1., 1.), (0, 1, 2., 2.)])], Some("sparse matrix has duplicate entries")),
+    case03_duplicate_profiles: (vec![(0, vec![]), (0, vec![])], Some("sparse matrix data should be specified once per profile")),
+    case04_missing_profile: (vec![(1, vec![])], Some("sparse matrix data should be specified once per profile")),
+    case05_valid: (vec![(1, vec![(0, 1, 1., 1.)]), (0, vec![])], None),
+}
+
+#[allow(clippy::type_complexity)]
+fn can_validate_sparse_matrix_data_impl(
+    data: Vec<(usize, Vec<(Location, Location, Duration, Distance)>)>,
+    expected: Option<&str>,
+) {
+    let costs = data.into_iter().map(|(index, entries)| SparseMatrixData::new(index, entries)).collect();
+
+    let result = create_sparse_matrix_transport_cost(costs, HashMap::default(), ConstantFallback);
+
+    assert_eq!(result.err(), expected.map(|err| err.into()));
+}
+
 mod objective {
     use super::*;
     use crate::construction::heuristics::{InsertionContext, MoveContext, StateKey};
@@ -1,4 +1,7 @@
-use crate::helpers::models::problem::{test_driver, test_vehicle, FleetBuilder};
+use crate::helpers::models::problem::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::{Driver, DriverDetail, Fleet, Vehicle, VehicleDepots, VehicleDetail};
+use std::sync::Arc;
 
 #[test]
 fn fleet_creates_unique_profiles_from_vehicles() {
@@ -19,3 +22,69 @@ fn fleet_creates_unique_profiles_from_vehicles() {
         vec![profile1, profile2]
     )
 }
+
+fn create_driver_with_shifts(shifts: Vec<(f64, f64)>) -> Driver {
+    Driver {
+        details: shifts.into_iter().map(|(start, end)| DriverDetail { time: TimeWindow::new(start, end) }).collect(),
+        ..test_driver()
+    }
+}
+
+#[test]
+fn can_create_actors_from_intersecting_driver_shifts() {
+    let fleet = FleetBuilder::default()
+        .add_driver(create_driver_with_shifts(vec![(0., 100.), (500., 2000.), (3000., 4000.)]))
+        .add_vehicle(test_vehicle(0))
+        .build();
+
+    let mut times =
+        fleet.actors.iter().map(|actor| (actor.detail.time.start, actor.detail.time.end)).collect::<Vec<_>>();
+    times.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    assert_eq!(times, vec![(0., 100.), (500., 1000.)]);
+    assert!(fleet.actors.iter().all(|actor| fleet.conflicts.get(actor).map_or(0, |c| c.len()) == 1));
+}
+
+#[test]
+fn can_create_actors_using_pairing_function() {
+    let drivers = vec![Arc::new(create_driver_with_shifts(vec![(0., 1000.)])), Arc::new(test_driver())];
+    let vehicles = vec![Arc::new(test_vehicle_with_id("v1")), Arc::new(test_vehicle_with_id("v2"))];
+
+    let fleet = Fleet::new_with_pairing(
+        drivers,
+        vehicles,
+        Box::new(|actors| create_details_actor_groups(actors)),
+        Box::new(|vehicle| Some(get_vehicle_id(vehicle).clone())),
+        Box::new(|driver| (!driver.details.is_empty()).then(|| vec!["v1".to_string()])),
+    );
+
+    assert_eq!(fleet.actors.len(), 3);
+    let shift_actors = fleet.actors.iter().filter(|actor| !actor.driver.details.is_empty()).collect::<Vec<_>>();
+    assert_eq!(shift_actors.len(), 1);
+    assert_eq!(get_vehicle_id(&shift_actors[0].vehicle), "v1");
+    // NOTE the v1 vehicle is shared between two drivers
+    assert_eq!(fleet.conflicts.len(), 2);
+}
+
+#[test]
+fn can_create_actors_from_vehicle_depots() {
+    let vehicle = Vehicle {
+        details: vec![VehicleDetail {
+            depots: VehicleDepots { start: vec![1, 2], end: vec![3] },
+            ..test_vehicle_detail()
+        }],
+        ..test_vehicle(0)
+    };
+
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+
+    let mut locations = fleet
+        .actors
+        .iter()
+        .map(|actor| (actor.detail.start.as_ref().unwrap().location, actor.detail.end.as_ref().unwrap().location))
+        .collect::<Vec<_>>();
+    locations.sort();
+
+    assert_eq!(locations, vec![(0, 0), (0, 3), (1, 0), (1, 3), (2, 0), (2, 3)]);
+    assert!(fleet.actors.iter().all(|actor| fleet.conflicts.get(actor).map_or(0, |c| c.len()) == 5));
+}
@@ -1,6 +1,8 @@
 use super::*;
 use crate::helpers::models::problem::*;
-use crate::models::problem::{TravelTime, VehicleDetail, VehiclePlace};
+use crate::models::problem::{
+    create_sparse_matrix_transport_cost, SparseMatrixData, TransportFallback, TravelTime, VehicleDetail, VehiclePlace,
+};
 use crate::models::solution::Route;
 
 #[derive(Default)]
@@ -170,6 +172,61 @@ fn returns_proper_job_neighbours_impl(index: usize, expected: Vec<String>) {
     assert_eq!(result, expected);
 }
 
+struct FarFallback;
+
+impl TransportFallback for FarFallback {
+    fn duration(&self, _: &Profile, from: Location, to: Location) -> Duration {
+        if from == to {
+            0.
+        } else {
+            1000.
+        }
+    }
+
+    fn distance(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        if from == to {
+            0.
+        } else {
+            1000.
+        }
+    }
+}
+
+parameterized_test! {returns_job_neighbours_from_sparse_matrix, (index, expected), {
+    returns_job_neighbours_from_sparse_matrix_impl(index, expected.iter().map(|s: &&str| s.to_string()).collect());
+}}
+
+returns_job_neighbours_from_sparse_matrix! {
+    case1: (0, vec!["s4", "s1", "s2"]),
+    case2: (1, vec!["s0", "s4"]),
+    case3: (2, vec![]),
+    case4: (3, vec![]),
+}
+
+fn returns_job_neighbours_from_sparse_matrix_impl(index: usize, expected: Vec<String>) {
+    let profile = Profile::default();
+    let species = vec![
+        SingleBuilder::default().id("s0").location(Some(0)).build_as_job_ref(),
+        SingleBuilder::default().id("s1").location(Some(1)).build_as_job_ref(),
+        SingleBuilder::default().id("s2").location(Some(2)).build_as_job_ref(),
+        SingleBuilder::default().id("s3").location(Some(3)).build_as_job_ref(),
+        SingleBuilder::default().id("s4").location(Some(0)).build_as_job_ref(),
+    ];
+    let entries = vec![(0, 1, 1., 1.), (0, 2, 2., 2.), (1, 0, 1., 1.)];
+    let transport = create_sparse_matrix_transport_cost(
+        vec![SparseMatrixData::new(profile.index, entries)],
+        Default::default(),
+        FarFallback,
+    )
+    .unwrap();
+    let jobs = Jobs::new(&test_fleet(), species.clone(), &transport);
+
+    let result: Vec<String> =
+        jobs.neighbors(&profile, species.get(index).unwrap(), 0.0).map(|(j, _)| get_job_id(j).clone()).collect();
+
+    assert_eq!(result, expected);
+}
+
 parameterized_test! {returns_proper_job_ranks, (index, profile, expected), {
     returns_proper_job_ranks_impl(index, profile, expected);
 }}
@@ -192,6 +249,7 @@ fn returns_proper_job_ranks_impl(index: usize, profile_index: usize, expected: D
     let create_vehicle_detail = |start_location: usize| VehicleDetail {
         start: Some(VehiclePlace { location: start_location, time: TimeInterval::default() }),
         end: Some(VehiclePlace { location: 0, time: TimeInterval::default() }),
+        depots: Default::default(),
     };
     let fleet = FleetBuilder::default()
         .add_driver(test_driver())
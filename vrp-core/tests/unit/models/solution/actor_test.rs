@@ -1,7 +1,7 @@
 use crate::helpers::models::domain::test_random;
 use crate::helpers::models::problem::{test_driver, test_vehicle_detail, FleetBuilder, VehicleBuilder};
-use crate::models::common::TimeInterval;
-use crate::models::problem::{Actor, VehicleDetail, VehiclePlace};
+use crate::models::common::{TimeInterval, TimeWindow};
+use crate::models::problem::{Actor, Driver, DriverDetail, VehicleDetail, VehiclePlace};
 use crate::models::solution::Registry;
 use std::cmp::Ordering::Less;
 use std::sync::Arc;
@@ -63,6 +63,53 @@ fn create_two_test_vehicle_details() -> Vec<VehicleDetail> {
         VehicleDetail {
             start: Some(VehiclePlace { location: 1, time: TimeInterval { earliest: Some(0.), latest: None } }),
             end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(50.) } }),
+            depots: Default::default(),
         },
     ]
 }
+
+#[test]
+fn can_exclude_actors_sharing_the_same_driver() {
+    let driver = Driver { details: vec![DriverDetail { time: TimeWindow::new(0., 1000.) }], ..test_driver() };
+    let fleet = FleetBuilder::default()
+        .add_driver(driver)
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").build(),
+            VehicleBuilder::default().id("v2").build(),
+            VehicleBuilder::default().id("v3").build(),
+        ])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    let actor = registry.available().next().unwrap();
+
+    assert!(registry.use_actor(&actor));
+    assert_eq!(registry.available().count(), 0);
+
+    assert!(registry.free_actor(&actor));
+    assert_eq!(registry.available().count(), 3);
+}
+
+#[test]
+fn can_keep_actor_excluded_while_another_conflict_is_used() {
+    let create_driver = || Driver { details: vec![DriverDetail { time: TimeWindow::new(0., 1000.) }], ..test_driver() };
+    let fleet = FleetBuilder::default()
+        .add_driver(create_driver())
+        .add_driver(create_driver())
+        .add_vehicles(vec![VehicleBuilder::default().id("v1").build(), VehicleBuilder::default().id("v2").build()])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    let first = fleet.actors[0].clone();
+    let second = fleet
+        .actors
+        .iter()
+        .find(|a| !Arc::ptr_eq(&a.vehicle, &first.vehicle) && !Arc::ptr_eq(&a.driver, &first.driver))
+        .unwrap()
+        .clone();
+
+    registry.use_actor(&first);
+    registry.use_actor(&second);
+    assert_eq!(registry.available().count(), 0);
+
+    registry.free_actor(&first);
+    assert_eq!(registry.available().count(), 1);
+}
@@ -0,0 +1,121 @@
+use super::*;
+use crate::construction::features::create_minimize_transport_costs_feature;
+use crate::helpers::construction::features::create_goal_ctx_with_feature;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::{Costs, Vehicle};
+use crate::models::Problem;
+use crate::models::Solution;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+fn create_insertion_ctx(
+    (problem, solution): (Problem, Solution),
+    job_order: Vec<&str>,
+    locked_ids: &[&str],
+) -> InsertionContext {
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default())),
+        locked_ids,
+    );
+    rearrange_jobs_in_routes(&mut insertion_ctx, &[job_order]);
+
+    insertion_ctx
+}
+
+fn get_operator(name: &str) -> Arc<dyn LocalOperator + Send + Sync> {
+    match name {
+        "two-opt" => Arc::new(ExchangeTwoOpt::default()),
+        "or-opt" => Arc::new(ExchangeOrOpt::default()),
+        "three-opt" => Arc::new(ExchangeThreeOpt::default()),
+        _ => unreachable!(),
+    }
+}
+
+parameterized_test! { can_improve_route, (operator, job_order, locked_ids, expected), {
+    can_improve_route_impl(operator, job_order, locked_ids, expected);
+}}
+
+can_improve_route! {
+    case01_two_opt_reverse: ("two-opt", vec!["c0", "c4", "c3", "c2", "c1", "c5"], &[], Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case02_two_opt_optimal: ("two-opt", vec!["c0", "c1", "c2", "c3", "c4", "c5"], &[], None),
+    case03_two_opt_locked: ("two-opt", vec!["c0", "c4", "c3", "c2", "c1", "c5"], &["c0", "c1", "c2", "c3"], None),
+    case04_or_opt_single: ("or-opt", vec!["c0", "c1", "c4", "c2", "c3", "c5"], &[], Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case05_or_opt_chain: ("or-opt", vec!["c0", "c3", "c4", "c1", "c2", "c5"], &[], Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case06_or_opt_optimal: ("or-opt", vec!["c0", "c1", "c2", "c3", "c4", "c5"], &[], None),
+    case07_three_opt_swap: ("three-opt", vec!["c0", "c4", "c5", "c1", "c2", "c3"], &[], Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case08_three_opt_optimal: ("three-opt", vec!["c0", "c1", "c2", "c3", "c4", "c5"], &[], None),
+}
+
+fn can_improve_route_impl(operator: &str, job_order: Vec<&str>, locked_ids: &[&str], expected: Option<Vec<&str>>) {
+    let insertion_ctx = create_insertion_ctx(generate_matrix_routes_with_defaults(6, 1, true), job_order, locked_ids);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = get_operator(operator).explore(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(
+        result.map(|insertion_ctx| get_customer_ids_from_routes(&insertion_ctx).remove(0)),
+        expected.map(|ids| ids.into_iter().map(|id| id.to_string()).collect::<Vec<_>>())
+    );
+}
+
+parameterized_test! { can_skip_infeasible_moves, operator, {
+    can_skip_infeasible_moves_impl(operator);
+}}
+
+can_skip_infeasible_moves! {
+    case01_two_opt: "two-opt",
+    case02_or_opt: "or-opt",
+    case03_three_opt: "three-opt",
+}
+
+fn can_skip_infeasible_moves_impl(operator: &str) {
+    let insertion_ctx = create_insertion_ctx(
+        generate_matrix_routes_with_disallow_list(5, 1, true, vec![("c0", "cX"), ("cX", "c3")]),
+        vec!["c0", "c2", "c1", "c3", "c4"],
+        &[],
+    );
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = get_operator(operator).explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+}
+
+parameterized_test! { can_reject_transport_gain_with_waiting, operator, {
+    can_reject_transport_gain_with_waiting_impl(operator);
+}}
+
+can_reject_transport_gain_with_waiting! {
+    case01_two_opt: "two-opt",
+    case02_or_opt: "or-opt",
+    case03_three_opt: "three-opt",
+}
+
+fn can_reject_transport_gain_with_waiting_impl(operator: &str) {
+    // NOTE c2 is visited last exactly at its time window start, any shorter tour has to wait for it
+    let (problem, solution) = generate_matrix_routes(
+        6,
+        1,
+        true,
+        |transport, activity| {
+            create_goal_ctx_with_feature(
+                create_minimize_transport_costs_feature("transport", transport, activity, 1).unwrap(),
+            )
+        },
+        |id, location| {
+            let times = if id == "c2" { vec![TimeWindow::new(8., 1000.)] } else { vec![TimeWindow::max()] };
+            SingleBuilder::default().id(id).location(location).duration(0.).times(times).build_shared()
+        },
+        |vehicle| Vehicle { costs: Costs { per_driving_time: 2., per_waiting_time: 2., ..test_costs() }, ..vehicle },
+        |data| (data.clone(), data),
+    );
+    let insertion_ctx = create_insertion_ctx((problem, solution), vec!["c0", "c1", "c3", "c4", "c5", "c2"], &[]);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = get_operator(operator).explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+}
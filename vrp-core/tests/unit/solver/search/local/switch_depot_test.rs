@@ -0,0 +1,64 @@
+use super::*;
+use crate::construction::features::create_minimize_transport_costs_feature;
+use crate::helpers::construction::features::create_goal_ctx_with_feature;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::Location;
+use crate::models::problem::{Vehicle, VehicleDepots, VehicleDetail};
+
+fn create_insertion_ctx(depots: Vec<Location>) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(
+        2,
+        1,
+        false,
+        |transport, activity| {
+            create_goal_ctx_with_feature(
+                create_minimize_transport_costs_feature("transport", transport, activity, 1).unwrap(),
+            )
+        },
+        |id, location| SingleBuilder::default().id(id).location(location).build_shared(),
+        |vehicle| Vehicle {
+            details: vehicle
+                .details
+                .into_iter()
+                .map(|detail| VehicleDetail { depots: VehicleDepots { start: depots.clone(), end: vec![] }, ..detail })
+                .collect(),
+            ..vehicle
+        },
+        |data| (data.clone(), data),
+    );
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![0; 8], vec![1.; 8]))),
+    )
+}
+
+#[test]
+fn can_switch_route_depot() {
+    let insertion_ctx = create_insertion_ctx(vec![1]);
+
+    let new_insertion_ctx = SwitchDepot::default()
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx)
+        .expect("cannot find new solution");
+
+    let routes = &new_insertion_ctx.solution.routes;
+    assert_eq!(routes.len(), 1);
+    assert_eq!(routes[0].route().actor.detail.start.as_ref().map(|start| start.location), Some(1));
+    assert_eq!(get_customer_ids_from_routes(&new_insertion_ctx).iter().map(|ids| ids.len()).sum::<usize>(), 2);
+    assert!(new_insertion_ctx.solution.required.is_empty());
+}
+
+#[test]
+fn can_skip_routes_without_alternative_depots() {
+    let insertion_ctx = create_insertion_ctx(vec![]);
+
+    let result =
+        SwitchDepot::default().explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx);
+
+    assert!(result.is_none());
+}
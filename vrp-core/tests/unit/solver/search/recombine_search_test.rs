@@ -0,0 +1,88 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes_sorted;
+use crate::helpers::solver::*;
+use crate::solver::search::RecreateWithCheapest;
+
+type ColumnData = (Vec<usize>, usize, Cost);
+
+fn create_columns(data: Vec<ColumnData>) -> Vec<RouteColumn> {
+    data.into_iter().map(|(jobs, actor, cost)| RouteColumn { jobs, actor, cost }).collect()
+}
+
+parameterized_test! { can_solve_set_partitioning, (columns, total_jobs, conflicts, upper_bound, expected), {
+    can_solve_set_partitioning_impl(create_columns(columns), total_jobs, conflicts, upper_bound, expected);
+}}
+
+can_solve_set_partitioning! {
+    case01_cheapest_partition: (
+        vec![(vec![0, 1], 0, 10.), (vec![2], 1, 5.), (vec![0], 0, 3.), (vec![1, 2], 1, 4.)],
+        3, vec![vec![], vec![]], 100., Some(vec![2, 3])
+    ),
+    case02_same_actor: (
+        vec![(vec![0], 0, 1.), (vec![1], 0, 1.), (vec![0, 1], 1, 5.)],
+        2, vec![vec![], vec![]], 100., Some(vec![2])
+    ),
+    case03_conflicting_actors: (
+        vec![(vec![0], 0, 1.), (vec![1], 1, 1.), (vec![0, 1], 2, 5.)],
+        2, vec![vec![1], vec![0], vec![]], 100., Some(vec![2])
+    ),
+    case04_no_improvement: (
+        vec![(vec![0, 1], 0, 10.), (vec![2], 1, 5.), (vec![0], 0, 3.), (vec![1, 2], 1, 4.)],
+        3, vec![vec![], vec![]], 7., None
+    ),
+    case05_no_cover: (
+        vec![(vec![0], 0, 1.)],
+        2, vec![vec![]], 100., None
+    ),
+}
+
+fn can_solve_set_partitioning_impl(
+    columns: Vec<RouteColumn>,
+    total_jobs: usize,
+    conflicts: Vec<Vec<usize>>,
+    upper_bound: Cost,
+    expected: Option<Vec<usize>>,
+) {
+    let result = solve_set_partitioning(&columns, total_jobs, &conflicts, upper_bound, &|| false);
+
+    assert_eq!(
+        result.map(|mut selected| {
+            selected.sort();
+            selected
+        }),
+        expected
+    );
+}
+
+#[test]
+fn can_stop_set_partitioning_when_terminated() {
+    let columns = create_columns(vec![(vec![0], 0, 1.), (vec![1], 1, 1.)]);
+
+    let result = solve_set_partitioning(&columns, 2, &[vec![], vec![]], 100., &|| true);
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn can_recombine_routes_from_population() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, true);
+    let environment = Arc::new(Environment::default());
+    let original = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment.clone());
+    let create_insertion_ctx = |routes: &[Vec<&str>]| {
+        let mut insertion_ctx = original.deep_copy();
+        rearrange_jobs_in_routes(&mut insertion_ctx, routes);
+        insertion_ctx.restore();
+        insertion_ctx
+    };
+    let individual = create_insertion_ctx(&[vec!["c0", "c1", "c2"], vec!["c3", "c5", "c4"]]);
+    let insertion_ctx = create_insertion_ctx(&[vec!["c0", "c2", "c1"], vec!["c3", "c4", "c5"]]);
+    let mut refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    refinement_ctx.add_solution(individual);
+    let search = RecombineSearch::new(Arc::new(RecreateWithCheapest::new(environment.random.clone())), 10, 1000);
+
+    let result = search.search(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(get_customer_ids_from_routes_sorted(&result), vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]]);
+    assert!(result.solution.get_total_cost() < insertion_ctx.solution.get_total_cost());
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::models::examples::create_example_problem;
+use std::sync::Mutex;
+
+#[test]
+fn can_notify_about_new_best_solutions() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let costs = Arc::new(Mutex::new(Vec::<f64>::new()));
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let solution = Solver::new(problem, config)
+        .with_best_solution_callback({
+            let costs = costs.clone();
+            Arc::new(move |solution: &Solution| costs.lock().unwrap().push(solution.cost))
+        })
+        .solve()
+        .unwrap();
+
+    let costs = costs.lock().unwrap();
+    assert!(!costs.is_empty());
+    assert_eq!(costs.last().cloned(), Some(solution.cost));
+}
+
+#[test]
+fn can_return_final_population() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::default());
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let solutions = Solver::new(problem, config).solve_all().unwrap();
+
+    assert!(!solutions.is_empty());
+    assert!(solutions.iter().all(|solution| !solution.routes.is_empty()));
+}
@@ -8,14 +8,26 @@ use crate::format::{get_coord_index, get_job_index};
 use crate::utils::combine_error_results;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
+use std::iter::once;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::construction::enablers::{get_place_service_duration, TimeWindowToleranceDimension};
+use vrp_core::models::problem::Single;
 use vrp_core::models::solution::Place;
 use vrp_core::prelude::compare_floats;
 use vrp_core::utils::GenericError;
 
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicles(ctx), check_jobs_presence(ctx), check_jobs_match(ctx), check_groups(ctx)])
+    combine_error_results(&[
+        check_vehicles(ctx),
+        check_drivers(ctx),
+        check_depots(ctx),
+        check_calendar(ctx),
+        check_visit_frequency(ctx),
+        check_jobs_presence(ctx),
+        check_jobs_match(ctx),
+        check_groups(ctx),
+    ])
 }
 
 /// Checks that vehicles in each tour are used once per shift and they are known in problem.
@@ -38,6 +50,225 @@ fn check_vehicles(ctx: &CheckerContext) -> Result<(), GenericError> {
     Ok(())
 }
 
+/// Checks that tours start and end at one of the shift depots and depot capacities are respected.
+fn check_depots(ctx: &CheckerContext) -> Result<(), GenericError> {
+    let capacities = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Depot { id, location, capacity } => Some((id, location, *capacity)),
+            VehicleResource::Reload { .. } => None,
+        })
+        .collect::<Vec<_>>();
+    let mut used_depots = HashMap::<usize, usize>::new();
+
+    ctx.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
+        let shift = ctx
+            .get_vehicle(&tour.vehicle_id)?
+            .shifts
+            .get(tour.shift_index)
+            .ok_or_else(|| format!("cannot find shift {} of vehicle '{}'", tour.shift_index, tour.vehicle_id))?;
+        let depots = shift.depots.as_ref();
+
+        // NOTE vehicle which is already on its tour departs from its current location
+        let state = ctx.problem.state.iter().flat_map(|state| state.vehicles.iter()).find(|vehicle| {
+            vehicle.vehicle_id == tour.vehicle_id && vehicle.shift_index.unwrap_or(0) == tour.shift_index
+        });
+        let starts: Box<dyn Iterator<Item = &Location>> = if let Some(state) = state {
+            Box::new(once(&state.location))
+        } else {
+            Box::new(once(&shift.start.location).chain(depots.and_then(|d| d.start.as_ref()).into_iter().flatten()))
+        };
+        if !is_depot_stop(ctx, tour.stops.first(), "departure", starts) {
+            return Err(format!("tour of vehicle '{}' starts at unknown depot", tour.vehicle_id).into());
+        }
+
+        if let Some(end) = shift.end.as_ref() {
+            let ends = once(&end.location).chain(depots.and_then(|d| d.end.as_ref()).into_iter().flatten());
+            if !is_depot_stop(ctx, tour.stops.last(), "arrival", ends) {
+                return Err(format!("tour of vehicle '{}' ends at unknown depot", tour.vehicle_id).into());
+            }
+        }
+
+        if let Some(location) = tour.stops.first().and_then(|stop| stop.location()) {
+            *used_depots.entry(ctx.get_location_index(location)?).or_default() += 1;
+        }
+
+        Ok(())
+    })?;
+
+    capacities.into_iter().try_for_each(|(id, location, capacity)| {
+        let used = used_depots.get(&ctx.get_location_index(location)?).copied().unwrap_or_default();
+        if used > capacity {
+            Err(format!("depot '{id}' capacity violation, expected: not more than {capacity}, got: {used}").into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+fn is_depot_stop<'a>(
+    ctx: &CheckerContext,
+    stop: Option<&Stop>,
+    activity_type: &str,
+    mut locations: impl Iterator<Item = &'a Location>,
+) -> bool {
+    let stop_location = stop
+        .filter(|stop| stop.activities().iter().any(|activity| activity.activity_type == activity_type))
+        .and_then(|stop| stop.location())
+        .and_then(|location| ctx.get_location_index(location).ok());
+
+    match stop_location {
+        Some(stop_location) => locations.any(|location| ctx.get_location_index(location).ok() == Some(stop_location)),
+        None => true,
+    }
+}
+
+/// Checks that tours of vehicles with calendar have proper day and keep minimum rest between shifts.
+fn check_calendar(ctx: &CheckerContext) -> Result<(), GenericError> {
+    let mut vehicle_tours = HashMap::<&String, Vec<(f64, f64)>>::new();
+
+    ctx.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
+        let vehicle = ctx.get_vehicle(&tour.vehicle_id)?;
+        let day = get_shift_day(vehicle, tour.shift_index).map(|day| day.day);
+
+        if day != tour.day {
+            return Err(format!(
+                "tour day mismatch for vehicle '{}' shift {}: expected {:?}, got {:?}",
+                tour.vehicle_id, tour.shift_index, day, tour.day
+            )
+            .into());
+        }
+
+        if vehicle.calendar.is_some() {
+            let (first, last) = tour.stops.first().zip(tour.stops.last()).ok_or("empty tour")?;
+            vehicle_tours
+                .entry(&tour.vehicle_id)
+                .or_default()
+                .push((parse_time(&first.schedule().departure), parse_time(&last.schedule().departure)));
+        }
+
+        Ok(())
+    })?;
+
+    vehicle_tours.into_iter().try_for_each(|(vehicle_id, mut tours)| {
+        let min_rest = ctx.get_vehicle(vehicle_id)?.calendar.as_ref().and_then(|c| c.min_rest).unwrap_or_default();
+        tours.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        if tours.windows(2).any(|pair| pair[0].1 + min_rest > pair[1].0) {
+            Err(format!("tours of vehicle '{vehicle_id}' overlap or do not keep minimum rest of {min_rest}").into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Checks that visits of periodic jobs are assigned to different days respecting spacing and patterns.
+fn check_visit_frequency(ctx: &CheckerContext) -> Result<(), GenericError> {
+    let mut visit_days = HashMap::<&str, HashMap<&String, Option<usize>>>::new();
+
+    ctx.solution.tours.iter().for_each(|tour| {
+        tour.stops.iter().flat_map(|stop| stop.activities()).for_each(|activity| {
+            if ctx.get_job_by_id(&activity.job_id).is_some_and(|job| job.frequency.is_some()) {
+                visit_days.entry(get_periodic_job_id(&activity.job_id)).or_default().insert(&activity.job_id, tour.day);
+            }
+        })
+    });
+
+    visit_days.into_iter().try_for_each(|(job_id, visits)| {
+        let frequency = ctx
+            .get_job_by_id(&get_visit_job_id(job_id, 0))
+            .and_then(|job| job.frequency.as_ref())
+            .ok_or_else(|| format!("cannot find periodic job '{job_id}'"))?;
+
+        let mut days = visits
+            .into_values()
+            .map(|day| day.ok_or_else(|| format!("periodic job '{job_id}' is visited on tour without day")))
+            .collect::<Result<Vec<_>, _>>()?;
+        days.sort();
+
+        let min_spacing = frequency.min_spacing.unwrap_or(1);
+        let has_invalid_spacing = days.windows(2).any(|pair| {
+            let spacing = pair[1] - pair[0];
+            let is_max_spacing_violated =
+                days.len() == frequency.count && frequency.max_spacing.is_some_and(|max_spacing| spacing > max_spacing);
+
+            spacing < min_spacing || is_max_spacing_violated
+        });
+
+        if has_invalid_spacing {
+            return Err(format!("visits of periodic job '{job_id}' violate spacing: {days:?}").into());
+        }
+
+        let is_pattern_match = frequency
+            .patterns
+            .as_ref()
+            .is_none_or(|patterns| patterns.iter().any(|pattern| days.iter().all(|day| pattern.contains(day))));
+
+        if !is_pattern_match {
+            return Err(format!("visits of periodic job '{job_id}' do not match any pattern: {days:?}").into());
+        }
+
+        Ok(())
+    })
+}
+
+/// Checks that drivers in each tour are known, allowed to drive the vehicle and used once per their shift.
+fn check_drivers(ctx: &CheckerContext) -> Result<(), GenericError> {
+    let all_drivers = ctx
+        .problem
+        .fleet
+        .drivers
+        .iter()
+        .flatten()
+        .flat_map(|driver| driver.driver_ids.iter().map(move |driver_id| (driver_id, driver)))
+        .collect::<HashMap<_, _>>();
+    let mut used_drivers = HashSet::<(String, usize)>::new();
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let driver_id = match (&tour.driver_id, all_drivers.is_empty()) {
+            (None, true) => return Ok(()),
+            (Some(driver_id), true) => return Err(format!("driver '{driver_id}' is used, but no drivers are defined")),
+            (None, false) => return Err(format!("tour of vehicle '{}' has no driver assigned", tour.vehicle_id)),
+            (Some(driver_id), false) => driver_id,
+        };
+
+        let driver = all_drivers.get(driver_id).ok_or_else(|| format!("used driver with unknown id: '{driver_id}'"))?;
+
+        if driver.vehicle_types.as_ref().is_some_and(|types| !types.contains(&tour.type_id)) {
+            return Err(format!("driver '{driver_id}' is not allowed to drive vehicle type '{}'", tour.type_id));
+        }
+
+        let (start, end) = match (tour.stops.first(), tour.stops.last()) {
+            (Some(first), Some(last)) => {
+                (parse_time(&first.schedule().departure), parse_time(&last.schedule().arrival))
+            }
+            _ => return Err(format!("tour of vehicle '{}' has no stops", tour.vehicle_id)),
+        };
+
+        let shift_index = match driver.shifts.as_ref() {
+            Some(shifts) => shifts
+                .iter()
+                .position(|shift| parse_time(&shift.earliest) <= start && end <= parse_time(&shift.latest))
+                .ok_or_else(|| {
+                    format!("tour of vehicle '{}' is outside of driver '{driver_id}' shifts", tour.vehicle_id)
+                })?,
+            None => 0,
+        };
+
+        if !used_drivers.insert((driver_id.clone(), shift_index)) {
+            Err(format!("driver with '{driver_id}' id used more than once for shift {shift_index}"))
+        } else {
+            Ok(())
+        }
+    })?;
+
+    Ok(())
+}
+
 /// Checks job task rules.
 fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), GenericError> {
     struct JobAssignment {
@@ -177,8 +408,8 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), GenericError> {
                                                 true
                                             }
                                         }
-                                        Ok(Some(JobInfo(_, _, place, time))) => {
-                                            is_valid_job_info(ctx, stop, activity, *idx, place, time)
+                                        Ok(Some(JobInfo(_, single, place, time))) => {
+                                            is_valid_job_info(ctx, stop, activity, *idx, &single, place, time)
                                         }
                                         _ => false,
                                     }
@@ -212,10 +443,22 @@ fn is_valid_job_info(
     stop: &PointStop,
     activity: &Activity,
     activity_idx: usize,
+    single: &Single,
     place: Place,
     time: TimeWindow,
 ) -> bool {
     let not_equal = |left: f64, right: f64| compare_floats(left, right) != Ordering::Equal;
+    let service_start = time.start.max(place.time.start);
+    // NOTE with soft time window, a vehicle either starts service early or waits for original time window start
+    let earliness = single
+        .dimens
+        .get_time_window_tolerances()
+        .and_then(|tolerances| tolerances.get(place.idx))
+        .and_then(|tolerance| tolerance.as_ref())
+        .map_or(0., |tolerance| tolerance.earliness);
+    let service_starts = [service_start, time.start.max(place.time.start + earliness)];
+    let place =
+        Place { duration: get_place_service_duration(single, place.idx, place.duration, service_start), ..place };
     let parking = ctx.clustering.as_ref().map(|config| config.serving.get_parking()).unwrap_or(0.);
     let commute_profile = ctx.clustering.as_ref().map(|config| config.profile.clone());
     let domain_commute = ctx.get_commute_info(commute_profile, parking, stop, activity_idx);
@@ -224,8 +467,7 @@ fn is_valid_job_info(
     match (&ctx.clustering, &activity.commute, domain_commute) {
         (_, _, Err(_)) | (_, None, Ok(Some(_))) | (_, Some(_), Ok(None)) | (&None, &Some(_), Ok(Some(_))) => true,
         (_, None, Ok(None)) => {
-            let expected_departure = time.start.max(place.time.start) + place.duration + extra_time;
-            not_equal(time.end, expected_departure)
+            service_starts.iter().all(|service_start| not_equal(time.end, service_start + place.duration + extra_time))
         }
         (Some(config), Some(commute), Ok(Some(d_commute))) => {
             let (service_time, parking) = match config.serving {
@@ -240,13 +482,15 @@ fn is_valid_job_info(
             let service_time =
                 service_time + if a_commute.is_zero_distance() && activity_idx > 0 { parking } else { 0. };
 
-            let expected_departure =
-                time.start.max(place.time.start) + service_time + d_commute.backward.duration + extra_time;
             let actual_departure = time.end + d_commute.backward.duration;
 
-            // NOTE: a "workaroundish" approach for two clusters in the same stop
-            (not_equal(actual_departure, expected_departure)
-                && not_equal(actual_departure, expected_departure - parking))
+            service_starts.iter().all(|service_start| {
+                let expected_departure = service_start + service_time + d_commute.backward.duration + extra_time;
+
+                // NOTE: a "workaroundish" approach for two clusters in the same stop
+                not_equal(actual_departure, expected_departure)
+                    && not_equal(actual_departure, expected_departure - parking)
+            })
                 // compare commute
                 || not_equal(a_commute.forward.distance, d_commute.forward.distance)
                 || not_equal(a_commute.forward.duration, d_commute.forward.duration)
@@ -15,11 +15,15 @@ pub fn check_breaks(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
 fn check_break_assignment(context: &CheckerContext) -> Result<(), GenericError> {
     context.solution.tours.iter().try_for_each(|tour| {
         let vehicle_shift = context.get_vehicle_shift(tour)?;
+        // NOTE driving breaks are checked separately
         let actual_break_count = tour
             .stops
             .iter()
-            .flat_map(|stop| stop.activities().iter())
-            .filter(|activity| activity.activity_type == "break")
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .filter(|(_, activity)| activity.activity_type == "break")
+            .filter(|(stop, activity)| {
+                !matches!(context.get_activity_type(tour, stop, activity), Ok(ActivityType::DrivingBreak))
+            })
             .count();
         let matched_break_count = tour.stops.iter().try_fold(0, |acc, stop| {
             stop.activities()
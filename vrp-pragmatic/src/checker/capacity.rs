@@ -10,13 +10,18 @@ use vrp_core::models::common::{Load, MultiDimLoad};
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
 /// * max vehicle's capacity is not violated
 /// * load change is correct
+/// * compartment is compatible with job's product and its capacity is not violated
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicle_load_assignment(context), check_resource_consumption(context)])
+    combine_error_results(&[
+        check_vehicle_load_assignment(context),
+        check_resource_consumption(context),
+        check_compartment_loads(context),
+    ])
 }
 
 fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), GenericError> {
     context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
-        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
+        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.to_vec(context.dimensions()));
         let intervals = get_intervals(context, tour);
 
         intervals
@@ -31,7 +36,7 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), Generic
                         Ok(match demand {
                             (DemandType::StaticDelivery, demand) => (acc.0 + demand, acc.1),
                             (DemandType::StaticPickup, demand) => (acc.0, acc.1 + demand),
-                            (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand, acc.1 + demand),
+                            (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand.clone(), acc.1 + demand),
                             _ => acc,
                         })
                     },
@@ -40,8 +45,8 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), Generic
                 let end_capacity = interval.iter().try_fold::<_, _, Result<_, GenericError>>(
                     start_delivery,
                     |acc, (idx, (from, to))| {
-                        let from_load = MultiDimLoad::new(from.load().clone());
-                        let to_load = MultiDimLoad::new(to.load().clone());
+                        let from_load = MultiDimLoad::new(from.load().to_vec(context.dimensions()));
+                        let to_load = MultiDimLoad::new(to.load().to_vec(context.dimensions()));
 
                         if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
                             return Err(format!("load exceeds capacity in tour '{}'", tour.vehicle_id).into());
@@ -53,7 +58,7 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), Generic
                                 let activity_type = context.get_activity_type(tour, to, activity)?;
                                 let (demand_type, demand) =
                                     if activity.activity_type == "arrival" || activity.activity_type == "reload" {
-                                        (DemandType::StaticDelivery, end_pickup)
+                                        (DemandType::StaticDelivery, end_pickup.clone())
                                     } else {
                                         get_demand(context, activity, &activity_type)?
                                     };
@@ -67,7 +72,7 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), Generic
                         )?;
 
                         let is_from_valid = from_load == acc;
-                        let is_to_valid = to_load == from_load + change;
+                        let is_to_valid = to_load == from_load.clone() + change;
 
                         if is_from_valid && is_to_valid {
                             Ok(to_load)
@@ -96,8 +101,11 @@ fn check_resource_consumption(context: &CheckerContext) -> Result<(), GenericErr
         .resources
         .iter()
         .flat_map(|resources| resources.iter().cloned())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id, MultiDimLoad::new(capacity)),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => {
+                Some((id, MultiDimLoad::new(capacity.to_vec(context.dimensions()))))
+            }
+            VehicleResource::Depot { .. } => None,
         })
         .collect::<HashMap<_, _>>();
 
@@ -137,13 +145,13 @@ fn check_resource_consumption(context: &CheckerContext) -> Result<(), GenericErr
         })
         .fold(HashMap::default(), |mut acc, (resource_id, consumption)| {
             let entry = acc.entry(resource_id).or_insert_with(MultiDimLoad::default);
-            *entry = *entry + consumption;
+            *entry = entry.clone() + consumption;
 
             acc
         });
 
     consumption.into_iter().try_for_each(|(resource_id, consumed)| {
-        let available = *resources.get(&resource_id).ok_or_else(|| {
+        let available = resources.get(&resource_id).cloned().ok_or_else(|| {
             GenericError::from(format!("cannot find resource '{resource_id}' in list of available resources"))
         })?;
 
@@ -157,6 +165,103 @@ fn check_resource_consumption(context: &CheckerContext) -> Result<(), GenericErr
     })
 }
 
+fn check_compartment_loads(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let compartments = match context.get_vehicle(&tour.vehicle_id)?.compartments.as_ref() {
+            Some(compartments) => compartments,
+            None => return Ok(()),
+        };
+
+        let mut job_compartments = HashMap::<String, usize>::default();
+        let demands = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .map(|(stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let job = match &activity_type {
+                    ActivityType::Job(job) => job,
+                    _ => return Ok(None),
+                };
+
+                let (demand_type, demand) = get_demand(context, activity, &activity_type)?;
+                if matches!(demand_type, DemandType::None) {
+                    return Ok(None);
+                }
+
+                let compartment_id = activity.compartment.as_ref().ok_or_else(|| {
+                    GenericError::from(format!("job '{}' has no compartment in tour '{}'", job.id, tour.vehicle_id))
+                })?;
+                let compartment_idx =
+                    compartments.iter().position(|compartment| compartment.id == *compartment_id).ok_or_else(|| {
+                        GenericError::from(format!(
+                            "job '{}' has unknown compartment '{}' in tour '{}'",
+                            job.id, compartment_id, tour.vehicle_id
+                        ))
+                    })?;
+
+                let is_accepted = match (compartments[compartment_idx].products.as_ref(), job.product.as_ref()) {
+                    (None, _) => true,
+                    (Some(products), Some(product)) => products.contains(product),
+                    (Some(_), None) => false,
+                };
+                if !is_accepted {
+                    return Err(format!(
+                        "job '{}' cannot be loaded into compartment '{}' in tour '{}'",
+                        job.id, compartment_id, tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                if *job_compartments.entry(job.id.clone()).or_insert(compartment_idx) != compartment_idx {
+                    return Err(format!(
+                        "job '{}' uses more than one compartment in tour '{}'",
+                        job.id, tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                Ok(Some((compartment_idx, demand_type, demand)))
+            })
+            .collect::<Result<Vec<_>, GenericError>>()?;
+
+        let capacities = compartments
+            .iter()
+            .map(|compartment| MultiDimLoad::new(compartment.capacity.to_vec(context.dimensions())))
+            .collect::<Vec<_>>();
+        let check_load = |compartment_idx: usize, load: &MultiDimLoad| {
+            if capacities[compartment_idx].can_fit(load) {
+                Ok(())
+            } else {
+                Err(GenericError::from(format!(
+                    "load exceeds capacity of compartment '{}' in tour '{}'",
+                    compartments[compartment_idx].id, tour.vehicle_id
+                )))
+            }
+        };
+
+        // NOTE static deliveries are loaded at the tour start
+        let mut loads = vec![MultiDimLoad::default(); compartments.len()];
+        demands.iter().flatten().for_each(|(compartment_idx, demand_type, demand)| {
+            if matches!(demand_type, DemandType::StaticDelivery | DemandType::StaticPickupDelivery) {
+                loads[*compartment_idx] = loads[*compartment_idx].clone() + demand.clone();
+            }
+        });
+        loads.iter().enumerate().try_for_each(|(compartment_idx, load)| check_load(compartment_idx, load))?;
+
+        demands.into_iter().flatten().try_for_each(|(compartment_idx, demand_type, demand)| {
+            let load = loads[compartment_idx].clone();
+            loads[compartment_idx] = match demand_type {
+                DemandType::StaticDelivery | DemandType::DynamicDelivery => load - demand,
+                DemandType::StaticPickup | DemandType::DynamicPickup => load + demand,
+                DemandType::None | DemandType::StaticPickupDelivery => load,
+            };
+
+            check_load(compartment_idx, &loads[compartment_idx])
+        })
+    })
+}
+
 enum DemandType {
     None,
     StaticPickup,
@@ -177,7 +282,10 @@ fn get_demand(
         |job, task| {
             let is_dynamic = job.pickups.as_ref().map_or(false, |p| !p.is_empty())
                 && job.deliveries.as_ref().map_or(false, |p| !p.is_empty());
-            let demand = task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
+            let demand = task
+                .demand
+                .as_ref()
+                .map_or_else(MultiDimLoad::default, |demand| MultiDimLoad::new(demand.to_vec(context.dimensions())));
 
             (is_dynamic, demand)
         },
@@ -0,0 +1,79 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/driving_rules_test.rs"]
+mod driving_rules_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+
+/// NOTE to ensure duration correctness, routing check should be performed first.
+pub fn check_driving_rules(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    combine_error_results(&[check_driving_time(context)])
+}
+
+/// Checks that driving rules are not violated:
+/// * driving time between breaks
+/// * total driving time
+fn check_driving_time(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let rules = match vehicle.limits.as_ref().and_then(|limits| limits.driving_rules.as_ref()) {
+            Some(rules) => rules,
+            None => return Ok(()),
+        };
+
+        let split_break = rules.split_break.as_ref().and_then(|parts| match parts.as_slice() {
+            &[first, second] => Some((first, second)),
+            _ => None,
+        });
+
+        let (_, _, total_driving_time) = tour.stops.windows(2).try_fold::<_, _, Result<_, GenericError>>(
+            (0., false, 0.),
+            |(driving_time, has_first_part, total_driving_time), stops| {
+                let (prev, next) = match stops {
+                    [prev, next] => (prev, next),
+                    _ => unreachable!(),
+                };
+
+                let leg_driving_time = parse_time(&next.schedule().arrival) - parse_time(&prev.schedule().departure);
+                let driving_time = driving_time + leg_driving_time;
+
+                if driving_time > rules.max_driving_time {
+                    return Err(format!(
+                        "driving time limit violation, expected: not more than {} without a break, got: {}, vehicle id '{}', shift index: {}",
+                        rules.max_driving_time, driving_time, tour.vehicle_id, tour.shift_index
+                    )
+                    .into());
+                }
+
+                // NOTE any break long enough is considered, split break resets driving time only when both parts are taken
+                let (driving_time, has_first_part) = next
+                    .activities()
+                    .iter()
+                    .filter(|activity| activity.activity_type == "break")
+                    .map(|activity| context.get_activity_time(next, activity))
+                    .fold((driving_time, has_first_part), |(driving_time, has_first_part), time| {
+                        let break_duration = time.end - time.start;
+
+                        match split_break {
+                            _ if break_duration >= rules.break_duration => (0., false),
+                            Some((_, second)) if has_first_part && break_duration >= second => (0., false),
+                            Some((first, _)) if break_duration >= first => (driving_time, true),
+                            _ => (driving_time, has_first_part),
+                        }
+                    });
+
+                Ok((driving_time, has_first_part, total_driving_time + leg_driving_time))
+            },
+        )?;
+
+        if total_driving_time > rules.max_daily_driving_time {
+            Err(format!(
+                "daily driving time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                rules.max_daily_driving_time, total_driving_time, tour.vehicle_id, tour.shift_index
+            )
+            .into())
+        } else {
+            Ok(())
+        }
+    })
+}
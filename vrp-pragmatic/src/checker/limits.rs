@@ -4,10 +4,11 @@ mod limits_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use hashbrown::HashMap;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context)])
+    combine_error_results(&[check_shift_limits(context), check_shift_time(context), check_max_ride_durations(context)])
 }
 
 /// Check that shift limits are not violated:
@@ -87,3 +88,40 @@ fn check_shift_time(context: &CheckerContext) -> Result<(), GenericError> {
         }
     })
 }
+
+/// Checks that ride duration of jobs with pickups and deliveries does not exceed job's max ride duration.
+fn check_max_ride_durations(context: &CheckerContext) -> Result<(), GenericError> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
+        // NOTE keep departure from the first and service start at the last job activity
+        let rides = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .filter_map(|(stop, activity)| {
+                let job = context.get_job_by_id(&activity.job_id)?;
+                job.max_ride_duration.map(|max_ride_duration| (job, max_ride_duration, stop, activity))
+            })
+            .fold(HashMap::<_, (f64, f64, f64)>::new(), |mut acc, (job, max_ride_duration, stop, activity)| {
+                let time = context.get_activity_time(stop, activity);
+                acc.entry(job.id.as_str())
+                    .and_modify(|(_, _, service_start)| *service_start = time.start)
+                    .or_insert((max_ride_duration, time.end, time.start));
+
+                acc
+            });
+
+        rides.into_iter().try_for_each(|(job_id, (max_ride_duration, departure, service_start))| {
+            let ride_duration = service_start - departure;
+
+            // NOTE schedule times are truncated to seconds in the solution
+            if ride_duration > max_ride_duration.ceil() {
+                Err(format!(
+                    "max ride duration violation, expected: not more than {max_ride_duration}, got: {ride_duration}, job id '{job_id}'"
+                )
+                .into())
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
@@ -5,16 +5,19 @@
 #[path = "../../tests/unit/checker/checker_test.rs"]
 mod checker_test;
 
+use crate::construction::enablers::VehicleTie;
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::format::{CoordIndex, Location};
 use crate::parse_time;
+use crate::utils::get_haversine_distance;
 use hashbrown::{HashMap, HashSet};
 use std::sync::Arc;
 use vrp_core::construction::clustering::vicinity::ClusterConfig;
 use vrp_core::construction::clustering::vicinity::VisitPolicy;
 use vrp_core::models::common::{Duration, Profile, TimeWindow};
-use vrp_core::models::solution::{Commute as DomainCommute, CommuteInfo as DomainCommuteInfo};
+use vrp_core::models::problem::{TransportCost, TravelTime};
+use vrp_core::models::solution::{Commute as DomainCommute, CommuteInfo as DomainCommuteInfo, Route};
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::prelude::GenericError;
 use vrp_core::solver::processing::VicinityDimension;
@@ -31,6 +34,7 @@ pub struct CheckerContext {
     job_map: HashMap<String, Job>,
     coord_index: CoordIndex,
     profile_index: HashMap<String, usize>,
+    transport: Option<Arc<dyn TransportCost + Send + Sync>>,
     core_problem: Arc<CoreProblem>,
     clustering: Option<ClusterConfig>,
 }
@@ -38,8 +42,9 @@ pub struct CheckerContext {
 /// Represents all possible activity types.
 enum ActivityType {
     Terminal,
-    Job(Job),
+    Job(Box<Job>),
     Break(VehicleBreak),
+    DrivingBreak,
     Reload(VehicleReload),
     Recharge(VehicleRechargeStation),
 }
@@ -52,18 +57,36 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Result<Self, Vec<GenericError>> {
+        let coord_index = CoordIndex::new(&problem);
+        // NOTE use routing model of the solver without reserved times and parking as they are checked separately
+        let transport = matrices
+            .as_ref()
+            .map(|matrices| {
+                create_transport_costs(&problem, matrices.clone(), vec![], Arc::new(CoordIndex::new(&problem)))
+            })
+            .transpose()
+            .map_err(|err| vec![err])?;
+        // NOTE solution is checked against the problem with completed jobs removed, committed jobs locked
+        //      and planning calendar applied
+        let problem = apply_planning_calendar(apply_problem_state(problem));
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
         let clustering = core_problem.extras.get_cluster_config().cloned();
-        let coord_index = CoordIndex::new(&problem);
-        let profile_index = if matrices.is_none() {
-            HashMap::new()
-        } else {
-            get_matrices(&matrices)
-                .and_then(|matrices| get_profile_index(&problem, matrices.as_slice()))
-                .map_err(|err| vec![err])?
+        let profile_index = match matrices.as_ref() {
+            Some(matrices) => get_profile_index(&problem, matrices.as_slice()).map_err(|err| vec![err])?,
+            None => HashMap::new(),
         };
 
-        Ok(Self { problem, matrices, solution, job_map, coord_index, profile_index, core_problem, clustering })
+        Ok(Self {
+            problem,
+            matrices,
+            solution,
+            job_map,
+            coord_index,
+            profile_index,
+            transport,
+            core_problem,
+            clustering,
+        })
     }
 
     /// Performs solution check.
@@ -77,6 +100,7 @@ impl CheckerContext {
             .chain(check_assignment(self).err())
             .chain(check_routing(self).err())
             .chain(check_limits(self).err())
+            .chain(check_driving_rules(self).err())
             .flatten()
             .fold((HashSet::new(), Vec::default()), |(mut used, mut errors), error| {
                 if !used.contains(&error) {
@@ -104,15 +128,9 @@ impl CheckerContext {
             .ok_or_else(|| format!("cannot find vehicle with id '{vehicle_id}'").into())
     }
 
-    fn get_vehicle_profile(&self, vehicle_id: &str) -> Result<Profile, GenericError> {
-        let profile = &self.get_vehicle(vehicle_id)?.profile;
-        let index = self
-            .profile_index
-            .get(profile.matrix.as_str())
-            .cloned()
-            .ok_or(format!("cannot get matrix for '{}' profile", profile.matrix))?;
-
-        Ok(Profile { index, scale: profile.scale.unwrap_or(1.) })
+    /// Gets names of capacity dimensions, if they are declared.
+    fn dimensions(&self) -> Option<&[String]> {
+        self.problem.fleet.dimensions.as_deref()
     }
 
     /// Gets activity operation time range in seconds since Unix epoch.
@@ -177,7 +195,7 @@ impl CheckerContext {
             "pickup" | "delivery" | "service" | "replacement" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id).into()),
-                    |job| Ok(ActivityType::Job(job.clone())),
+                    |job| Ok(ActivityType::Job(Box::new(job.clone()))),
                 )
             }
 
@@ -191,6 +209,13 @@ impl CheckerContext {
                         .find(|b| get_break_time_window(tour, b).map(|tw| tw.intersects(&time)).unwrap_or(false))
                 })
                 .map(|b| ActivityType::Break(b.clone()))
+                .or_else(|| {
+                    self.get_vehicle(&tour.vehicle_id)
+                        .ok()
+                        .and_then(|vehicle| vehicle.limits.as_ref())
+                        .and_then(|limits| limits.driving_rules.as_ref())
+                        .map(|_| ActivityType::DrivingBreak)
+                })
                 .ok_or_else(|| format!("cannot find break for tour '{}'", tour.vehicle_id).into()),
 
             "reload" => shift
@@ -351,6 +376,33 @@ impl CheckerContext {
             .ok_or_else(|| format!("cannot find coordinate in coord index: {location:?}").into())
     }
 
+    /// Gets travel distance and duration between two locations departing at given time. Uses the same
+    /// routing model as the solver, so time dependent routing (e.g. speed bands or fifo) is considered.
+    fn get_travel_info(
+        &self,
+        vehicle_id: &str,
+        from_idx: usize,
+        to_idx: usize,
+        departure: f64,
+    ) -> Result<(i64, i64), GenericError> {
+        let actor = self
+            .core_problem
+            .fleet
+            .actors
+            .iter()
+            .find(|actor| actor.vehicle.dimens.get_vehicle_id().is_some_and(|id| id == vehicle_id))
+            .cloned()
+            .ok_or_else(|| format!("cannot find actor for vehicle with id '{vehicle_id}'"))?;
+        let route = Route { actor, tour: Default::default() };
+
+        let transport = self.transport.as_ref().ok_or("cannot get travel info without routing matrices")?;
+        let travel_time = TravelTime::Departure(departure);
+        let distance = transport.distance(&route, from_idx, to_idx, travel_time);
+        let duration = transport.duration(&route, from_idx, to_idx, travel_time);
+
+        Ok((distance.round() as i64, duration.round() as i64))
+    }
+
     fn get_matrix_data(&self, profile: &Profile, from_idx: usize, to_idx: usize) -> Result<(i64, i64), GenericError> {
         let matrices = get_matrices(&self.matrices)?;
         let matrix =
@@ -364,6 +416,16 @@ impl CheckerContext {
             return Ok((0, 0));
         }
 
+        if let Some(entries) = matrix.entries.as_ref() {
+            let (distance, duration) = match entries.iter().find(|entry| entry.0 == from_idx && entry.1 == to_idx) {
+                Some(&MatrixEntry(_, _, duration, distance)) => (distance, duration),
+                None => self.get_approx_matrix_data(profile, from_idx, to_idx)?,
+            };
+            let duration = (duration as f64 * profile.scale) as i64;
+
+            return Ok((distance, duration));
+        }
+
         let matrix_size = get_matrix_size(matrices.as_slice());
         let matrix_idx = from_idx * matrix_size + to_idx;
 
@@ -373,6 +435,33 @@ impl CheckerContext {
 
         Ok((distance, duration))
     }
+
+    /// Approximates a route missing in sparse routing matrix using haversine distance and profile speed.
+    fn get_approx_matrix_data(
+        &self,
+        profile: &Profile,
+        from_idx: usize,
+        to_idx: usize,
+    ) -> Result<(i64, i64), GenericError> {
+        let speed = self
+            .profile_index
+            .iter()
+            .find(|(_, &index)| index == profile.index)
+            .and_then(|(name, _)| {
+                self.problem.fleet.profiles.iter().find(|matrix_profile| &matrix_profile.name == name)
+            })
+            .map(|matrix_profile| matrix_profile.speed.unwrap_or(DEFAULT_SPEED))
+            .ok_or_else(|| format!("cannot find matrix profile with index {}", profile.index))?;
+
+        let (from, to) = self
+            .coord_index
+            .get_by_idx(from_idx)
+            .zip(self.coord_index.get_by_idx(to_idx))
+            .ok_or_else(|| format!("cannot approximate route between unknown locations: {from_idx}->{to_idx}"))?;
+        let distance = get_haversine_distance(&from, &to);
+
+        Ok((distance.round() as i64, (distance / speed).round() as i64))
+    }
 }
 
 fn job_task_size(tasks: &Option<Vec<JobTask>>) -> usize {
@@ -432,11 +521,16 @@ fn get_matrices(matrices: &Option<Vec<Matrix>>) -> Result<&Vec<Matrix>, GenericE
 
 fn get_profile_index(problem: &Problem, matrices: &[Matrix]) -> Result<HashMap<String, usize>, GenericError> {
     let profiles = problem.fleet.profiles.len();
-    if profiles != matrices.len() {
+    // NOTE time aware routing has multiple matrices per profile
+    let matrix_profiles = if matrices.iter().any(|matrix| matrix.timestamp.is_some()) {
+        matrices.iter().map(|matrix| matrix.profile.as_ref()).collect::<HashSet<_>>().len()
+    } else {
+        matrices.len()
+    };
+
+    if profiles != matrix_profiles {
         return Err(format!(
-            "precondition failed: amount of matrices supplied ({}) does not match profile specified ({})",
-            matrices.len(),
-            profiles,
+            "precondition failed: amount of matrices supplied ({matrix_profiles}) does not match profile specified ({profiles})",
         )
         .into());
     }
@@ -459,6 +553,9 @@ use crate::checker::capacity::check_vehicle_load;
 mod limits;
 use crate::checker::limits::check_limits;
 
+mod driving_rules;
+use crate::checker::driving_rules::check_driving_rules;
+
 mod breaks;
 use crate::checker::breaks::{check_breaks, get_break_time_window};
 
@@ -5,10 +5,11 @@ mod relations_test;
 use super::*;
 use crate::utils::combine_error_results;
 use hashbrown::HashSet;
+use std::iter::once;
 
 /// Checks relation rules.
 pub fn check_relations(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_relations_assignment(context)])
+    combine_error_results(&[check_relations_assignment(context), check_committed_jobs(context)])
 }
 
 fn check_relations_assignment(context: &CheckerContext) -> Result<(), GenericError> {
@@ -94,6 +95,31 @@ fn check_relations_assignment(context: &CheckerContext) -> Result<(), GenericErr
     Ok(())
 }
 
+fn check_committed_jobs(context: &CheckerContext) -> Result<(), GenericError> {
+    context
+        .problem
+        .state
+        .iter()
+        .flat_map(|state| state.vehicles.iter())
+        .filter(|vehicle| vehicle.committed.as_ref().is_some_and(|committed| !committed.is_empty()))
+        .try_for_each(|vehicle| {
+            let tour = get_tour_by_vehicle_id(&vehicle.vehicle_id, vehicle.shift_index, &context.solution)?;
+            let activity_ids = get_activity_ids(&tour);
+            let expected =
+                once("departure".to_string()).chain(vehicle.committed.iter().flatten().cloned()).collect::<Vec<_>>();
+
+            if activity_ids.starts_with(expected.as_slice()) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "tour of vehicle '{}' does not start with committed jobs: expected {:?}, got {:?}",
+                    vehicle.vehicle_id, expected, activity_ids
+                )
+                .into())
+            }
+        })
+}
+
 fn get_tour_by_vehicle_id(
     vehicle_id: &str,
     shift_index: Option<usize>,
@@ -8,7 +8,7 @@ use crate::utils::combine_error_results;
 
 /// Checks that matrix routing information is used properly.
 pub fn check_routing(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_routing_rules(context)])
+    combine_error_results(&[check_routing_rules(context), check_tardiness(context), check_parking(context)])
 }
 
 fn check_routing_rules(context: &CheckerContext) -> Result<(), GenericError> {
@@ -18,12 +18,12 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), GenericError> {
     let skip_distance_check = skip_distance_check(&context.solution);
 
     context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
-        let profile = context.get_vehicle_profile(&tour.vehicle_id)?;
-
         let get_matrix_data = |from: &PointStop, to: &PointStop| -> Result<(i64, i64), GenericError> {
             let from_idx = context.get_location_index(&from.location)?;
             let to_idx = context.get_location_index(&to.location)?;
-            context.get_matrix_data(&profile, from_idx, to_idx)
+            let departure = parse_time(&from.time.departure);
+
+            context.get_travel_info(&tour.vehicle_id, from_idx, to_idx, departure)
         };
 
         let first_stop = tour.stops.first().ok_or_else(|| "empty tour".to_string())?;
@@ -166,6 +166,165 @@ fn check_solution_statistic(solution: &Solution) -> Result<(), GenericError> {
     }
 }
 
+/// Checks that tardiness of soft time windows is reported properly.
+fn check_tardiness(context: &CheckerContext) -> Result<(), GenericError> {
+    let tardiness = context.solution.tours.iter().try_fold::<_, _, Result<_, GenericError>>(None, |acc, tour| {
+        let tardiness = tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |a| (stop, a))).try_fold(
+            None,
+            |acc: Option<i64>, (stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let time = context.get_activity_time(stop, activity);
+                let location = context.get_activity_location(stop, activity);
+                let lateness = context.visit_job(
+                    activity,
+                    &activity_type,
+                    |_, task| get_task_lateness(task, location.as_ref(), activity, time.start),
+                    || Ok(None),
+                )??;
+
+                Ok::<_, GenericError>(lateness.map(|lateness| acc.unwrap_or_default() + lateness).or(acc))
+            },
+        )?;
+
+        if tardiness != tour.statistic.tardiness {
+            return Err(format!(
+                "tardiness mismatch for tour statistic: {}, expected: '{:?}', got: '{:?}'",
+                tour.vehicle_id, tardiness, tour.statistic.tardiness,
+            )
+            .into());
+        }
+
+        Ok(tardiness.map(|tardiness| acc.unwrap_or_default() + tardiness).or(acc))
+    })?;
+
+    if tardiness != context.solution.statistic.tardiness {
+        Err(format!(
+            "tardiness mismatch for solution statistic, expected: '{:?}', got: '{:?}'",
+            tardiness, context.solution.statistic.tardiness
+        )
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that parking time at job locations is respected and reported properly.
+fn check_parking(context: &CheckerContext) -> Result<(), GenericError> {
+    // NOTE parking time of vicinity clusters is checked together with commute
+    if context.clustering.is_some() {
+        return Ok(());
+    }
+
+    let job_parking = get_job_parking(context)?;
+
+    context.solution.tours.iter().try_for_each::<_, Result<_, GenericError>>(|tour| {
+        let vehicle_parking = context.get_vehicle(&tour.vehicle_id)?.profile.parking;
+
+        tour.stops.iter().enumerate().try_fold::<_, _, Result<_, GenericError>>(
+            None,
+            |prev_location, (stop_idx, stop)| {
+                let Some(point) = stop.as_point() else { return Ok(prev_location) };
+                let location = context.get_location_index(&point.location)?;
+                let expected = match prev_location {
+                    Some(prev_location) if prev_location != location => job_parking
+                        .get(&location)
+                        .and_then(|parking| parking.or(vehicle_parking))
+                        .unwrap_or_default(),
+                    _ => 0.,
+                };
+                let actual = point
+                    .parking
+                    .as_ref()
+                    .map_or(0., |parking| parse_time(&parking.end) - parse_time(&parking.start));
+
+                if (expected - actual).abs() > 1. {
+                    return Err(format!(
+                        "parking time mismatch for {stop_idx} stop in the tour: {}, expected: '{expected}', got: '{actual}'",
+                        tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                let parking_end = point.parking.as_ref().map(|parking| parse_time(&parking.end));
+                let is_early_activity = parking_end.is_some_and(|parking_end| {
+                    point.activities.iter().any(|activity| {
+                        activity.activity_type != "break" && context.get_activity_time(stop, activity).start < parking_end
+                    })
+                });
+
+                if is_early_activity {
+                    return Err(format!(
+                        "activity starts before parking end at {stop_idx} stop in the tour: {}",
+                        tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                Ok(Some(location))
+            },
+        )?;
+
+        Ok(())
+    })
+}
+
+/// Returns parking time per job location: `None` means that vehicle's parking time is used.
+fn get_job_parking(context: &CheckerContext) -> Result<HashMap<usize, Option<f64>>, GenericError> {
+    context
+        .problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| {
+            job.pickups.iter().chain(job.deliveries.iter()).chain(job.services.iter()).chain(job.replacements.iter())
+        })
+        .flatten()
+        .flat_map(|task| task.places.iter())
+        .try_fold(HashMap::default(), |mut acc, place| {
+            let parking = acc.entry(context.get_location_index(&place.location)?).or_insert(None);
+
+            if let Some(value) = place.parking {
+                *parking = Some(parking.map_or(value, |parking: f64| parking.max(value)));
+            }
+
+            Ok(acc)
+        })
+}
+
+/// Returns lateness of the activity if it is served at the job place with time window tolerance.
+fn get_task_lateness(
+    task: &JobTask,
+    location: Option<&Location>,
+    activity: &Activity,
+    start: f64,
+) -> Result<Option<i64>, GenericError> {
+    let Some(place) = (if task.places.len() == 1 {
+        task.places.first()
+    } else {
+        task.places.iter().find(|place| {
+            place.tag == activity.job_tag && location.into_iter().all(|location| place.location == *location)
+        })
+    }) else {
+        return Ok(None);
+    };
+
+    let Some(tolerance) = place.tolerance.as_ref() else { return Ok(None) };
+    let Some(times) = place.times.as_ref() else { return Ok(Some(0)) };
+
+    // NOTE a vehicle can arrive before widened time window and wait, but it cannot start service after it
+    times
+        .iter()
+        .map(|tw| parse_time_window(tw))
+        .filter(|tw| start <= tw.end + tolerance.lateness.unwrap_or_default())
+        .map(|tw| (start - tw.end).max(0.) as i64)
+        .min()
+        .map(Some)
+        .ok_or_else(|| {
+            format!("activity of job '{}' is served outside of time windows widened by tolerance", activity.job_id)
+                .into()
+        })
+}
+
 /// A workaround method for hre format output where distance is not defined.
 fn skip_distance_check(solution: &Solution) -> bool {
     let skip_distance_check = solution
@@ -81,6 +81,67 @@ impl VehicleTie for Dimensions {
     }
 }
 
+/// Specifies driver entity.
+pub trait DriverTie {
+    /// Gets driver's id.
+    fn get_driver_id(&self) -> Option<&String>;
+    /// Sets driver's id.
+    fn set_driver_id(&mut self, id: String) -> &mut Self;
+
+    /// Gets driver's type id.
+    fn get_driver_type(&self) -> Option<&String>;
+    /// Sets driver's type id.
+    fn set_driver_type(&mut self, id: String) -> &mut Self;
+
+    /// Gets driver's skills set.
+    fn get_driver_skills(&self) -> Option<&HashSet<String>>;
+    /// Sets driver's skills set.
+    fn set_driver_skills(&mut self, skills: HashSet<String>) -> &mut Self;
+
+    /// Gets vehicle types which driver is allowed to drive.
+    fn get_driver_vehicle_types(&self) -> Option<&HashSet<String>>;
+    /// Sets vehicle types which driver is allowed to drive.
+    fn set_driver_vehicle_types(&mut self, vehicle_types: HashSet<String>) -> &mut Self;
+}
+
+impl DriverTie for Dimensions {
+    fn get_driver_id(&self) -> Option<&String> {
+        self.get_value("driver_id")
+    }
+
+    fn set_driver_id(&mut self, id: String) -> &mut Self {
+        self.set_value("driver_id", id);
+        self
+    }
+
+    fn get_driver_type(&self) -> Option<&String> {
+        self.get_value("driver_type")
+    }
+
+    fn set_driver_type(&mut self, id: String) -> &mut Self {
+        self.set_value("driver_type", id);
+        self
+    }
+
+    fn get_driver_skills(&self) -> Option<&HashSet<String>> {
+        self.get_value("driver_skills")
+    }
+
+    fn set_driver_skills(&mut self, skills: HashSet<String>) -> &mut Self {
+        self.set_value("driver_skills", skills);
+        self
+    }
+
+    fn get_driver_vehicle_types(&self) -> Option<&HashSet<String>> {
+        self.get_value("driver_vehicle_types")
+    }
+
+    fn set_driver_vehicle_types(&mut self, vehicle_types: HashSet<String>) -> &mut Self {
+        self.set_value("driver_vehicle_types", vehicle_types);
+        self
+    }
+}
+
 /// Specifies job entity.
 pub trait JobTie {
     /// Gets job id.
@@ -1,5 +1,6 @@
 use super::*;
 use crate::format::{CoordIndex, CustomLocationType, Location as ApiLocation};
+use crate::utils::get_haversine_distance;
 use vrp_core::models::common::{Distance, Duration, Location, Profile};
 use vrp_core::models::problem::TransportFallback;
 
@@ -35,3 +36,36 @@ impl TransportFallback for UnknownLocationFallback {
         self.get_default_value(from, to)
     }
 }
+
+/// A transport fallback which approximates routes missing in sparse routing matrix using haversine
+/// distance between geo coordinates and routing profile speed.
+pub struct ApproxLocationFallback {
+    coord_index: Arc<CoordIndex>,
+    speeds: Vec<f64>,
+}
+
+impl ApproxLocationFallback {
+    /// Creates a new instance of [`ApproxLocationFallback`] with speeds specified per profile index.
+    pub fn new(coord_index: Arc<CoordIndex>, speeds: Vec<f64>) -> Self {
+        Self { coord_index, speeds }
+    }
+
+    fn get_distance(&self, from: Location, to: Location) -> Distance {
+        match (self.coord_index.get_by_idx(from), self.coord_index.get_by_idx(to)) {
+            (Some(from), Some(to)) => get_haversine_distance(&from, &to),
+            _ => panic!("cannot approximate route between unknown locations: {from}->{to}"),
+        }
+    }
+}
+
+impl TransportFallback for ApproxLocationFallback {
+    fn duration(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        let speed = self.speeds.get(profile.index).copied().expect("cannot get profile speed");
+
+        (self.get_distance(from, to) / speed).round()
+    }
+
+    fn distance(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        self.get_distance(from, to).round()
+    }
+}
@@ -11,9 +11,6 @@ pub use self::entities::*;
 mod location_fallback;
 pub use self::location_fallback::*;
 
-mod only_vehicle_activity_cost;
-pub use self::only_vehicle_activity_cost::*;
-
 mod typed_actor_group_key;
 pub use self::typed_actor_group_key::*;
 
@@ -103,19 +103,15 @@ fn create_reload_route_intervals<T: LoadOps>(
             let fold_demand = |range: Range<usize>, demand_fn: fn(&Demand<T>) -> T| {
                 route_ctx.route().tour.activities_slice(range.start, range.end).iter().fold(
                     T::default(),
-                    |acc, activity| {
-                        activity
-                            .job
-                            .as_ref()
-                            .and_then(|job| job.dimens.get_demand())
-                            .map(|demand| acc + demand_fn(demand))
-                            .unwrap_or_else(|| acc)
+                    |acc, activity| match activity.job.as_ref().and_then(|job| job.dimens.get_demand()) {
+                        Some(demand) => acc + demand_fn(demand),
+                        None => acc,
                     },
                 )
             };
 
-            let left_pickup = fold_demand(left.clone(), |demand| demand.pickup.0);
-            let right_delivery = fold_demand(right.clone(), |demand| demand.delivery.0);
+            let left_pickup = fold_demand(left.clone(), |demand| demand.pickup.0.clone());
+            let right_delivery = fold_demand(right.clone(), |demand| demand.delivery.0.clone());
 
             // static delivery moved to left
             let new_max_load_left = get_load(left.start, MAX_FUTURE_CAPACITY_KEY) + right_delivery;
@@ -128,7 +124,7 @@ fn create_reload_route_intervals<T: LoadOps>(
             has_enough_vehicle_capacity
                 && place_capacity_threshold.as_ref().map_or(true, |place_capacity_threshold| {
                     // total static delivery at left
-                    let left_delivery = fold_demand(left.start..right.end, |demand| demand.delivery.0);
+                    let left_delivery = fold_demand(left.start..right.end, |demand| demand.delivery.0.clone());
 
                     (place_capacity_threshold)(route_ctx, left.start, &left_delivery)
                 })
@@ -168,7 +164,7 @@ where
                 }
             })
         }),
-        Arc::new(|single| single.dimens.get_demand().map(|demand| demand.delivery.0)),
+        Arc::new(|single| single.dimens.get_demand().map(|demand: &Demand<T>| demand.delivery.0.clone())),
     )
 }
 
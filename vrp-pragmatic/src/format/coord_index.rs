@@ -52,6 +52,10 @@ impl CoordIndex {
                     index.add(&end.location);
                 }
 
+                if let Some(depots) = &shift.depots {
+                    depots.start.iter().chain(depots.end.iter()).flatten().for_each(|location| index.add(location));
+                }
+
                 if let Some(breaks) = &shift.breaks {
                     breaks
                         .iter()
@@ -74,6 +78,9 @@ impl CoordIndex {
             });
         });
 
+        // process state
+        problem.state.iter().flat_map(|state| state.vehicles.iter()).for_each(|vehicle| index.add(&vehicle.location));
+
         index.max_matrix_index = index.direct_index.len().max(1) - 1;
 
         let start_offset = index.direct_index.len() * index.direct_index.len();
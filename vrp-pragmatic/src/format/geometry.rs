@@ -0,0 +1,80 @@
+//! A helper module for route geometries used to draw roads between stops in geojson output.
+
+#[cfg(test)]
+#[path = "../../tests/unit/format/geometry_test.rs"]
+mod geometry_test;
+
+use crate::format::problem::{Matrix, MatrixGeometry};
+use crate::utils::decode_polyline;
+use hashbrown::HashMap;
+use std::sync::Arc;
+use vrp_core::models::common::{Location, ValueDimension};
+use vrp_core::models::Extras;
+use vrp_core::prelude::*;
+
+const ROUTE_GEOMETRY_KEY: &str = "route_geometry";
+
+/// Provides road geometry of routes between locations.
+pub trait RouteGeometry {
+    /// Returns route geometry as (latitude, longitude) pairs between two locations for given routing
+    /// profile index or `None` if it is unknown.
+    fn get_geometry(&self, profile: usize, from: Location, to: Location) -> Option<Vec<(f64, f64)>>;
+}
+
+/// A trait to get or set route geometry.
+pub trait RouteGeometryDimension {
+    /// Sets route geometry.
+    fn set_route_geometry(&mut self, geometry: Arc<dyn RouteGeometry + Send + Sync>) -> &mut Self;
+
+    /// Gets route geometry.
+    fn get_route_geometry(&self) -> Option<&Arc<dyn RouteGeometry + Send + Sync>>;
+}
+
+impl RouteGeometryDimension for Extras {
+    fn set_route_geometry(&mut self, geometry: Arc<dyn RouteGeometry + Send + Sync>) -> &mut Self {
+        self.set_value(ROUTE_GEOMETRY_KEY, geometry);
+        self
+    }
+
+    fn get_route_geometry(&self) -> Option<&Arc<dyn RouteGeometry + Send + Sync>> {
+        self.get_value(ROUTE_GEOMETRY_KEY)
+    }
+}
+
+/// A route geometry which uses encoded polylines specified in routing matrices.
+pub(crate) struct MatrixRouteGeometry {
+    geometries: HashMap<(usize, Location, Location), Vec<(f64, f64)>>,
+}
+
+impl MatrixRouteGeometry {
+    /// Creates a new instance of `MatrixRouteGeometry` from routing matrices. Returns `None` if
+    /// there are no geometries specified.
+    pub fn new(
+        matrices: &[Matrix],
+        get_profile: impl Fn(&Matrix, usize) -> usize,
+    ) -> Result<Option<Self>, GenericError> {
+        if matrices.iter().all(|matrix| matrix.geometries.is_none()) {
+            return Ok(None);
+        }
+
+        let geometries = matrices
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, matrix)| {
+                let profile = get_profile(matrix, idx);
+                matrix.geometries.iter().flatten().map(move |geometry| (profile, geometry))
+            })
+            .map(|(profile, MatrixGeometry(from, to, polyline))| {
+                decode_polyline(polyline).map(|coordinates| ((profile, *from, *to), coordinates))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Some(Self { geometries }))
+    }
+}
+
+impl RouteGeometry for MatrixRouteGeometry {
+    fn get_geometry(&self, profile: usize, from: Location, to: Location) -> Option<Vec<(f64, f64)>> {
+        self.geometries.get(&(profile, from, to)).cloned()
+    }
+}
@@ -4,8 +4,9 @@
 
 extern crate serde_json;
 
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use vrp_core::construction::enablers::ReservedTimesIndex;
 use vrp_core::models::common::{Distance, Duration};
 use vrp_core::models::problem::Job as CoreJob;
@@ -15,6 +16,11 @@ use vrp_core::prelude::GenericError;
 mod coord_index;
 pub use self::coord_index::CoordIndex;
 
+mod geometry;
+pub(crate) use self::geometry::MatrixRouteGeometry;
+pub use self::geometry::{RouteGeometry, RouteGeometryDimension};
+pub use crate::utils::{decode_polyline, encode_polyline};
+
 pub mod problem;
 pub mod solution;
 
@@ -91,6 +97,67 @@ pub enum CustomLocationType {
     Unknown,
 }
 
+/// Represents a load amount: vehicle capacity, job demand or vehicle load.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum LoadAmount {
+    /// Values are specified in order of capacity dimensions.
+    Indexed(Vec<i32>),
+    /// Values are keyed by capacity dimension names declared in fleet. Omitted dimensions have zero value.
+    Named(BTreeMap<String, i32>),
+}
+
+impl LoadAmount {
+    /// Returns values ordered by given dimension names.
+    pub fn to_vec(&self, dimensions: Option<&[String]>) -> Vec<i32> {
+        match (self, dimensions) {
+            (Self::Indexed(values), _) => values.clone(),
+            (Self::Named(values), Some(dimensions)) => {
+                dimensions.iter().map(|name| values.get(name).copied().unwrap_or(0)).collect()
+            }
+            (Self::Named(values), None) => values.values().copied().collect(),
+        }
+    }
+
+    /// Creates a new instance of `LoadAmount` from values ordered by given dimension names:
+    /// values are keyed by name when dimensions are declared.
+    pub fn from_vec(values: Vec<i32>, dimensions: Option<&[String]>) -> Self {
+        match dimensions {
+            Some(dimensions) => Self::Named(
+                dimensions
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, name)| (name.clone(), values.get(idx).copied().unwrap_or(0)))
+                    .collect(),
+            ),
+            None => Self::Indexed(values),
+        }
+    }
+
+    /// Returns amount of dimensions used.
+    pub fn len(&self, dimensions: Option<&[String]>) -> usize {
+        match (self, dimensions) {
+            (Self::Indexed(values), _) => values.len(),
+            (Self::Named(_), Some(dimensions)) => dimensions.len(),
+            (Self::Named(values), None) => values.len(),
+        }
+    }
+
+    /// Returns true if no values are specified.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Indexed(values) => values.is_empty(),
+            Self::Named(values) => values.is_empty(),
+        }
+    }
+}
+
+impl From<Vec<i32>> for LoadAmount {
+    fn from(values: Vec<i32>) -> Self {
+        Self::Indexed(values)
+    }
+}
+
 /// A format error.
 #[derive(Clone, Debug, Serialize)]
 pub struct FormatError {
@@ -187,6 +254,12 @@ const GROUP_CONSTRAINT_CODE: i32 = 12;
 const COMPATIBILITY_CONSTRAINT_CODE: i32 = 13;
 const RELOAD_RESOURCE_CONSTRAINT_CODE: i32 = 14;
 const RECHARGE_CONSTRAINT_CODE: i32 = 15;
+const MAX_RIDE_DURATION_CONSTRAINT_CODE: i32 = 16;
+const DRIVING_RULES_CONSTRAINT_CODE: i32 = 17;
+const DEPOT_CAPACITY_CONSTRAINT_CODE: i32 = 18;
+const COMPARTMENT_CONSTRAINT_CODE: i32 = 19;
+const SHIFT_OVERLAP_CONSTRAINT_CODE: i32 = 20;
+const PERIODIC_CONSTRAINT_CODE: i32 = 21;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
@@ -204,3 +277,18 @@ pub fn get_job_index(problem: &CoreProblem) -> &JobIndex {
 pub fn get_coord_index(problem: &CoreProblem) -> &CoordIndex {
     problem.extras.get("coord_index").and_then(|s| s.downcast_ref::<CoordIndex>()).expect("cannot get coord index!")
 }
+
+/// Gets names of capacity dimensions, if they are declared.
+pub(crate) fn get_capacity_dimensions(problem: &CoreProblem) -> Option<&[String]> {
+    problem.extras.get("capacity_dimensions").and_then(|s| s.downcast_ref::<Vec<String>>()).map(|d| d.as_slice())
+}
+
+/// Gets ids of jobs completed according to the problem state, if it is specified.
+pub(crate) fn get_completed_jobs(problem: &CoreProblem) -> Option<&HashSet<String>> {
+    problem.extras.get("completed_jobs").and_then(|s| s.downcast_ref::<HashSet<String>>())
+}
+
+/// Gets ids of jobs which pickups are served according to the problem state, if it is specified.
+pub(crate) fn get_picked_up_jobs(problem: &CoreProblem) -> Option<&HashSet<String>> {
+    problem.extras.get("picked_up_jobs").and_then(|s| s.downcast_ref::<HashSet<String>>())
+}
@@ -0,0 +1,279 @@
+//! Provides a compact binary routing matrix format which is faster to load than json. The layout is:
+//!
+//! - header: `VRPM` magic bytes, format version, value size in bytes (4 or 8), flags and one reserved byte
+//! - optional profile name and timestamp as length prefixed utf8 strings (presence is marked by flags)
+//! - amount of locations as u64
+//! - travel times, distances and optional error codes as square matrices of little-endian signed integers
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/binary_matrix_test.rs"]
+mod binary_matrix_test;
+
+use super::Matrix;
+use crate::format::{FormatError, MultiFormatError};
+use std::io::Write;
+use vrp_core::prelude::GenericError;
+
+const MAGIC: &[u8; 4] = b"VRPM";
+const VERSION: u8 = 1;
+const HEADER_SIZE: usize = 8;
+const CHUNK_SIZE: usize = 8192;
+
+const HAS_PROFILE: u8 = 0b0001;
+const HAS_TIMESTAMP: u8 = 0b0010;
+const HAS_ERROR_CODES: u8 = 0b0100;
+
+/// Checks whether given data starts as routing matrix in binary format.
+pub fn is_binary_matrix(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+/// Serializes dense routing matrix in binary format. Values are stored using four bytes when all
+/// of them fit, eight bytes otherwise.
+pub fn serialize_matrix_binary<W: Write>(matrix: &Matrix, writer: &mut W) -> Result<(), GenericError> {
+    if matrix.entries.is_some() {
+        return Err("sparse matrix cannot be serialized in binary format".into());
+    }
+
+    if matrix.geometries.is_some() {
+        return Err("matrix with geometries cannot be serialized in binary format".into());
+    }
+
+    let size = (matrix.travel_times.len() as f64).sqrt().round() as usize;
+    let is_valid_len = |len: usize| len == size * size;
+    if !is_valid_len(matrix.travel_times.len())
+        || !is_valid_len(matrix.distances.len())
+        || matrix.error_codes.as_ref().is_some_and(|codes| !is_valid_len(codes.len()))
+    {
+        return Err("travel times, distances and error codes should be square matrices of the same size".into());
+    }
+
+    let is_compact = matrix
+        .travel_times
+        .iter()
+        .chain(matrix.distances.iter())
+        .chain(matrix.error_codes.iter().flatten())
+        .all(|&value| i32::try_from(value).is_ok());
+    let value_size: u8 = if is_compact { 4 } else { 8 };
+
+    let flags = [
+        (matrix.profile.is_some(), HAS_PROFILE),
+        (matrix.timestamp.is_some(), HAS_TIMESTAMP),
+        (matrix.error_codes.is_some(), HAS_ERROR_CODES),
+    ]
+    .iter()
+    .filter(|(is_set, _)| *is_set)
+    .fold(0, |acc, (_, flag)| acc | flag);
+
+    let mut write = |data: &[u8]| writer.write_all(data).map_err(|err| GenericError::from(err.to_string()));
+
+    write(MAGIC)?;
+    write(&[VERSION, value_size, flags, 0])?;
+
+    for text in matrix.profile.iter().chain(matrix.timestamp.iter()) {
+        write(&(text.len() as u32).to_le_bytes())?;
+        write(text.as_bytes())?;
+    }
+
+    write(&(size as u64).to_le_bytes())?;
+
+    let values = [Some(&matrix.travel_times), Some(&matrix.distances), matrix.error_codes.as_ref()];
+    for chunk in values.into_iter().flatten().flat_map(|values| values.chunks(CHUNK_SIZE)) {
+        let data = if is_compact {
+            chunk.iter().flat_map(|&value| (value as i32).to_le_bytes()).collect::<Vec<_>>()
+        } else {
+            chunk.iter().flat_map(|&value| value.to_le_bytes()).collect::<Vec<_>>()
+        };
+        write(data.as_slice())?;
+    }
+
+    Ok(())
+}
+
+/// A routing matrix decoded from binary format directly into routing data used by the solver:
+/// no intermediate integer values are kept in memory.
+pub struct BinaryMatrix {
+    /// A name of profile.
+    pub profile: Option<String>,
+    /// A date in RFC3999 for which routing info is applicable.
+    pub timestamp: Option<String>,
+    /// Amount of locations.
+    pub size: usize,
+    /// Travel durations, unreachable routes have negative values.
+    pub durations: Vec<f64>,
+    /// Travel distances, unreachable routes have negative values.
+    pub distances: Vec<f64>,
+    /// Specifies whether matrix has error codes, so some routes can be unreachable.
+    pub has_error_codes: bool,
+}
+
+/// Deserializes routing matrix from data in binary format.
+pub fn deserialize_matrix_binary(data: &[u8]) -> Result<Matrix, MultiFormatError> {
+    read_matrix(data).map_err(create_format_error)
+}
+
+/// Deserializes routing matrix from data in binary format into routing data used by the solver.
+pub fn deserialize_binary_matrix(data: &[u8]) -> Result<BinaryMatrix, MultiFormatError> {
+    read_binary_matrix(data).map_err(create_format_error)
+}
+
+fn create_format_error(err: GenericError) -> MultiFormatError {
+    vec![FormatError::new(
+        "E0001".to_string(),
+        "cannot deserialize matrix".to_string(),
+        format!("check binary matrix: '{err}'"),
+    )]
+    .into()
+}
+
+fn read_matrix(data: &[u8]) -> Result<Matrix, GenericError> {
+    let (mut reader, header) = read_header(data)?;
+    let len = header.size * header.size;
+
+    let travel_times = reader.read_values(len, header.value_size)?;
+    let distances = reader.read_values(len, header.value_size)?;
+    let error_codes = if header.has_error_codes { Some(reader.read_values(len, header.value_size)?) } else { None };
+
+    reader.finish()?;
+
+    let BinaryHeader { profile, timestamp, .. } = header;
+
+    Ok(Matrix { profile, timestamp, travel_times, distances, error_codes, entries: None, geometries: None })
+}
+
+fn read_binary_matrix(data: &[u8]) -> Result<BinaryMatrix, GenericError> {
+    let (mut reader, header) = read_header(data)?;
+    let len = header.size * header.size;
+
+    let mut durations = Vec::with_capacity(len);
+    reader.visit_values(len, header.value_size, |_, value| durations.push(value as f64))?;
+
+    let mut distances = Vec::with_capacity(len);
+    reader.visit_values(len, header.value_size, |_, value| distances.push(value as f64))?;
+
+    if header.has_error_codes {
+        reader.visit_values(len, header.value_size, |idx, error| {
+            if error > 0 {
+                durations[idx] = -1.;
+                distances[idx] = -1.;
+            }
+        })?;
+    }
+
+    reader.finish()?;
+
+    let BinaryHeader { profile, timestamp, size, has_error_codes, .. } = header;
+
+    Ok(BinaryMatrix { profile, timestamp, size, durations, distances, has_error_codes })
+}
+
+struct BinaryHeader {
+    profile: Option<String>,
+    timestamp: Option<String>,
+    size: usize,
+    value_size: usize,
+    has_error_codes: bool,
+}
+
+fn read_header(data: &[u8]) -> Result<(BinaryReader<'_>, BinaryHeader), GenericError> {
+    if data.len() < HEADER_SIZE || !is_binary_matrix(data) {
+        return Err("unknown format".into());
+    }
+
+    let (version, value_size, flags) = (data[4], data[5] as usize, data[6]);
+    if version != VERSION {
+        return Err(format!("unsupported version: {version}").into());
+    }
+
+    if value_size != 4 && value_size != 8 {
+        return Err(format!("unsupported value size: {value_size}").into());
+    }
+
+    let mut reader = BinaryReader { data, offset: HEADER_SIZE };
+
+    let profile = if flags & HAS_PROFILE > 0 { Some(reader.read_string()?) } else { None };
+    let timestamp = if flags & HAS_TIMESTAMP > 0 { Some(reader.read_string()?) } else { None };
+
+    let size = usize::try_from(reader.read_u64()?).map_err(|_| "matrix size is too big")?;
+    let has_error_codes = flags & HAS_ERROR_CODES > 0;
+
+    // NOTE check the size before values are allocated, so corrupted header cannot exhaust memory
+    let data_size = size
+        .checked_mul(size)
+        .and_then(|len| len.checked_mul(value_size))
+        .and_then(|len| len.checked_mul(if has_error_codes { 3 } else { 2 }))
+        .ok_or("matrix size is too big")?;
+    if data_size > data.len() - reader.offset {
+        return Err("unexpected end of data".into());
+    }
+
+    Ok((reader, BinaryHeader { profile, timestamp, size, value_size, has_error_codes }))
+}
+
+struct BinaryReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], GenericError> {
+        let end = self.offset.checked_add(len).filter(|&end| end <= self.data.len()).ok_or("unexpected end of data")?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+
+        Ok(bytes)
+    }
+
+    fn read_u64(&mut self) -> Result<u64, GenericError> {
+        let bytes = self.read_bytes(8)?;
+        Ok(u64::from_le_bytes(bytes.try_into().expect("eight bytes")))
+    }
+
+    fn read_string(&mut self) -> Result<String, GenericError> {
+        let bytes = self.read_bytes(4)?;
+        let len = u32::from_le_bytes(bytes.try_into().expect("four bytes")) as usize;
+
+        String::from_utf8(self.read_bytes(len)?.to_vec()).map_err(|err| err.to_string().into())
+    }
+
+    fn read_values(&mut self, len: usize, value_size: usize) -> Result<Vec<i64>, GenericError> {
+        let mut values = Vec::with_capacity(len);
+        self.visit_values(len, value_size, |_, value| values.push(value))?;
+
+        Ok(values)
+    }
+
+    /// Decodes values in place without copying them into intermediate buffer.
+    fn visit_values(
+        &mut self,
+        len: usize,
+        value_size: usize,
+        mut visit_fn: impl FnMut(usize, i64),
+    ) -> Result<(), GenericError> {
+        let bytes = self.read_bytes(len.checked_mul(value_size).ok_or("matrix size is too big")?)?;
+
+        if value_size == 4 {
+            bytes
+                .chunks_exact(4)
+                .map(|chunk| i32::from_le_bytes(chunk.try_into().expect("four bytes")) as i64)
+                .enumerate()
+                .for_each(|(idx, value)| visit_fn(idx, value));
+        } else {
+            bytes
+                .chunks_exact(8)
+                .map(|chunk| i64::from_le_bytes(chunk.try_into().expect("eight bytes")))
+                .enumerate()
+                .for_each(|(idx, value)| visit_fn(idx, value));
+        }
+
+        Ok(())
+    }
+
+    fn finish(&self) -> Result<(), GenericError> {
+        if self.offset != self.data.len() {
+            return Err("unexpected data after matrix end".into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/calendar_reader_test.rs"]
+mod calendar_reader_test;
+
+use super::*;
+use crate::format_time;
+
+/// A day duration in seconds.
+const DAY_DURATION: f64 = 86400.;
+
+/// Applies multi-day planning calendar to the problem: shifts of the vehicle with calendar are
+/// repeated for each available day (in calendar order), job place time windows are repeated for
+/// each day when the place can be served and periodic jobs are split into separate visit jobs.
+pub(crate) fn apply_planning_calendar(mut api_problem: ApiProblem) -> ApiProblem {
+    api_problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        if let Some(calendar) = vehicle.calendar.as_ref() {
+            vehicle.shifts = calendar
+                .days
+                .iter()
+                .flat_map(|day| {
+                    let offset = get_day_offset(day.day);
+                    vehicle.shifts.iter().map(move |shift| move_shift(shift, offset))
+                })
+                .collect();
+        }
+    });
+
+    api_problem.plan.jobs = std::mem::take(&mut api_problem.plan.jobs)
+        .into_iter()
+        .flat_map(|job| match job.frequency.as_ref() {
+            Some(frequency) => (0..frequency.count)
+                .map(|visit_idx| Job { id: get_visit_job_id(&job.id, visit_idx), ..job.clone() })
+                .collect(),
+            None => vec![job],
+        })
+        .collect();
+
+    api_problem
+        .plan
+        .jobs
+        .iter_mut()
+        .flat_map(|job| {
+            job.pickups
+                .iter_mut()
+                .chain(job.deliveries.iter_mut())
+                .chain(job.services.iter_mut())
+                .chain(job.replacements.iter_mut())
+                .flatten()
+        })
+        .flat_map(|task| task.places.iter_mut())
+        .for_each(|place| {
+            if let Some(days) = place.days.take() {
+                place.times = place.times.take().map(|times| get_calendar_times(&times, &days));
+                place.durations = place.durations.take().map(|durations| get_calendar_durations(&durations, &days));
+            }
+        });
+
+    api_problem
+}
+
+/// Returns a calendar day of the vehicle shift with given index. Expects that planning calendar
+/// is already applied to the problem.
+pub(crate) fn get_shift_day(vehicle: &VehicleType, shift_index: usize) -> Option<&VehicleDay> {
+    let calendar = vehicle.calendar.as_ref()?;
+    let shifts_per_day = (vehicle.shifts.len() / calendar.days.len().max(1)).max(1);
+
+    calendar.days.get(shift_index / shifts_per_day)
+}
+
+/// Returns id of the periodic job visit.
+pub(crate) fn get_visit_job_id(job_id: &str, visit_idx: usize) -> String {
+    format!("{job_id}#{visit_idx}")
+}
+
+/// Returns id of the periodic job using id of its visit.
+pub(crate) fn get_periodic_job_id(visit_id: &str) -> &str {
+    visit_id.rsplit_once('#').map_or(visit_id, |(job_id, _)| job_id)
+}
+
+/// Repeats given time windows for each of the given days.
+pub(crate) fn get_calendar_times(times: &[Vec<String>], days: &[usize]) -> Vec<Vec<String>> {
+    days.iter().flat_map(|&day| times.iter().map(move |tw| move_times(tw, get_day_offset(day)))).collect()
+}
+
+fn get_calendar_durations(durations: &[JobPlaceDuration], days: &[usize]) -> Vec<JobPlaceDuration> {
+    days.iter().flat_map(|&day| move_durations(durations, get_day_offset(day))).collect()
+}
+
+fn get_day_offset(day: usize) -> f64 {
+    day as f64 * DAY_DURATION
+}
+
+fn move_time(time: &str, offset: f64) -> String {
+    format_time(parse_time(time) + offset)
+}
+
+fn move_times(times: &[String], offset: f64) -> Vec<String> {
+    times.iter().map(|time| move_time(time, offset)).collect()
+}
+
+fn move_durations(durations: &[JobPlaceDuration], offset: f64) -> Vec<JobPlaceDuration> {
+    durations
+        .iter()
+        .map(|band| JobPlaceDuration { start: move_time(&band.start, offset), duration: band.duration })
+        .collect()
+}
+
+fn move_place(place: &JobPlace, offset: f64) -> JobPlace {
+    JobPlace {
+        times: place.times.as_ref().map(|times| times.iter().map(|tw| move_times(tw, offset)).collect()),
+        durations: place.durations.as_ref().map(|durations| move_durations(durations, offset)),
+        ..place.clone()
+    }
+}
+
+fn move_shift(shift: &VehicleShift, offset: f64) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart {
+            earliest: move_time(&shift.start.earliest, offset),
+            latest: shift.start.latest.as_ref().map(|time| move_time(time, offset)),
+            location: shift.start.location.clone(),
+        },
+        end: shift.end.as_ref().map(|end| ShiftEnd {
+            earliest: end.earliest.as_ref().map(|time| move_time(time, offset)),
+            latest: move_time(&end.latest, offset),
+            location: end.location.clone(),
+        }),
+        breaks: shift.breaks.as_ref().map(|breaks| {
+            breaks
+                .iter()
+                .map(|vehicle_break| match vehicle_break {
+                    VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeWindow(tw), places, policy } => {
+                        VehicleBreak::Optional {
+                            time: VehicleOptionalBreakTime::TimeWindow(move_times(tw, offset)),
+                            places: places.clone(),
+                            policy: policy.clone(),
+                        }
+                    }
+                    VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::ExactTime { earliest, latest },
+                        duration,
+                    } => VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::ExactTime {
+                            earliest: move_time(earliest, offset),
+                            latest: move_time(latest, offset),
+                        },
+                        duration: *duration,
+                    },
+                    // NOTE offset based breaks are relative to the shift start
+                    _ => vehicle_break.clone(),
+                })
+                .collect()
+        }),
+        reloads: shift.reloads.as_ref().map(|reloads| {
+            reloads
+                .iter()
+                .map(|reload| VehicleReload {
+                    times: reload.times.as_ref().map(|times| times.iter().map(|tw| move_times(tw, offset)).collect()),
+                    ..reload.clone()
+                })
+                .collect()
+        }),
+        recharges: shift.recharges.as_ref().map(|recharges| VehicleRecharges {
+            max_distance: recharges.max_distance,
+            stations: recharges.stations.iter().map(|station| move_place(station, offset)).collect(),
+        }),
+        depots: shift.depots.clone(),
+    }
+}
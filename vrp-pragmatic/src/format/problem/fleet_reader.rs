@@ -3,15 +3,40 @@
 mod fleet_reader_test;
 
 use super::*;
-use crate::construction::enablers::{create_typed_actor_groups, UnknownLocationFallback, VehicleTie};
+use crate::construction::enablers::{
+    create_typed_actor_groups, ApproxLocationFallback, DriverTie, UnknownLocationFallback, VehicleTie,
+};
+use crate::format::problem::state_reader::get_vehicle_state;
 use crate::get_unique_locations;
 use crate::utils::get_approx_transportation;
 use crate::Location as ApiLocation;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
+use vrp_core::construction::enablers::VehicleParkingDimension;
+use vrp_core::construction::features::{
+    Compartment, ShiftRestDimension, VehicleCompartmentsDimension, VehicleDayDimension,
+};
 use vrp_core::models::common::*;
+use vrp_core::models::problem::SpeedBand as CoreSpeedBand;
 use vrp_core::models::problem::*;
 
+/// A default speed (meters per second) used to approximate routing data.
+pub(crate) const DEFAULT_SPEED: f64 = 10.;
+
+fn get_compartments<T: LoadOps>(
+    compartments: &[VehicleCompartment],
+    capacity_map: impl Fn(&LoadAmount) -> T,
+) -> Vec<Compartment<T>> {
+    compartments
+        .iter()
+        .map(|compartment| Compartment {
+            id: compartment.id.clone(),
+            capacity: capacity_map(&compartment.capacity),
+            products: compartment.products.as_ref().map(|products| products.iter().cloned().collect()),
+        })
+        .collect()
+}
+
 pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String, usize> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if acc.get(&profile.name).is_none() {
@@ -21,9 +46,10 @@ pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String,
     })
 }
 
-pub(super) fn create_transport_costs(
+pub(crate) fn create_transport_costs(
     api_problem: &ApiProblem,
-    matrices: &[Matrix],
+    matrices: Vec<Matrix>,
+    binary_matrices: Vec<BinaryMatrix>,
     coord_index: Arc<CoordIndex>,
 ) -> Result<Arc<dyn TransportCost + Sync + Send>, GenericError> {
     if !matrices.iter().all(|m| m.profile.is_some()) && !matrices.iter().all(|m| m.profile.is_none()) {
@@ -45,39 +71,57 @@ pub(super) fn create_transport_costs(
         .into());
     }
 
-    let matrix_data = matrices
-        .iter()
-        .enumerate()
-        .map(|(idx, matrix)| {
-            let profile = matrix.profile.as_ref().and_then(|p| matrix_profiles.get(p)).cloned().unwrap_or(idx);
-            (profile, matrix.timestamp.clone(), matrix)
-        })
-        .map(|(profile, timestamp, matrix)| {
-            let (durations, distances) = if let Some(error_codes) = &matrix.error_codes {
-                let capacity = matrix.distances.len();
-
-                let mut durations: Vec<Duration> = Vec::with_capacity(capacity);
-                let mut distances: Vec<Distance> = Vec::with_capacity(capacity);
-                for (i, error) in error_codes.iter().enumerate() {
-                    if *error > 0 {
-                        durations.push(-1.);
-                        distances.push(-1.);
-                    } else {
-                        durations.push(*matrix.travel_times.get(i).unwrap() as f64);
-                        distances.push(*matrix.distances.get(i).unwrap() as f64);
+    let travel_models = get_travel_models(api_problem, &matrix_profiles);
+
+    if matrices.iter().any(|matrix| matrix.entries.is_some()) {
+        return create_sparse_transport_costs(api_problem, &matrices, &matrix_profiles, travel_models, coord_index);
+    }
+
+    let matrix_data = if binary_matrices.is_empty() {
+        // NOTE consume matrices to release memory of each one as soon as it is converted
+        matrices
+            .into_iter()
+            .enumerate()
+            .map(|(idx, matrix)| (get_matrix_profile(&matrix, idx, &matrix_profiles), matrix.timestamp.clone(), matrix))
+            .map(|(profile, timestamp, matrix)| {
+                let (durations, distances) = if let Some(error_codes) = &matrix.error_codes {
+                    let capacity = matrix.distances.len();
+
+                    let mut durations: Vec<Duration> = Vec::with_capacity(capacity);
+                    let mut distances: Vec<Distance> = Vec::with_capacity(capacity);
+                    for (i, error) in error_codes.iter().enumerate() {
+                        if *error > 0 {
+                            durations.push(-1.);
+                            distances.push(-1.);
+                        } else {
+                            durations.push(*matrix.travel_times.get(i).unwrap() as f64);
+                            distances.push(*matrix.distances.get(i).unwrap() as f64);
+                        }
                     }
-                }
-                (durations, distances)
-            } else {
-                (
-                    matrix.travel_times.iter().map(|d| *d as f64).collect(),
-                    matrix.distances.iter().map(|d| *d as f64).collect(),
-                )
-            };
+                    (durations, distances)
+                } else {
+                    (
+                        matrix.travel_times.iter().map(|d| *d as f64).collect(),
+                        matrix.distances.iter().map(|d| *d as f64).collect(),
+                    )
+                };
 
-            MatrixData::new(profile, timestamp.map(|t| parse_time(&t)), durations, distances)
-        })
-        .collect::<Vec<_>>();
+                MatrixData::new(profile, timestamp.map(|t| parse_time(&t)), durations, distances)
+            })
+            .collect::<Vec<_>>()
+    } else {
+        // NOTE binary matrices are already decoded into routing data, so it is moved without conversion
+        binary_matrices
+            .into_iter()
+            .zip(matrices.iter())
+            .enumerate()
+            .map(|(idx, (binary, matrix))| {
+                let profile = get_matrix_profile(matrix, idx, &matrix_profiles);
+
+                MatrixData::new(profile, binary.timestamp.map(|t| parse_time(&t)), binary.durations, binary.distances)
+            })
+            .collect::<Vec<_>>()
+    };
 
     let matrix_indices = matrix_data.iter().map(|data| data.index).collect::<HashSet<_>>().len();
     if matrix_profiles.len() != matrix_indices {
@@ -85,14 +129,84 @@ pub(super) fn create_transport_costs(
     }
 
     if coord_index.has_custom() {
-        create_matrix_transport_cost_with_fallback(matrix_data, UnknownLocationFallback::new(coord_index))
+        create_matrix_transport_cost_with_models(matrix_data, travel_models, UnknownLocationFallback::new(coord_index))
     } else {
-        create_matrix_transport_cost(matrix_data)
+        create_matrix_transport_cost_with_models(matrix_data, travel_models, NoFallback)
+    }
+}
+
+fn create_sparse_transport_costs(
+    api_problem: &ApiProblem,
+    matrices: &[Matrix],
+    matrix_profiles: &HashMap<String, usize>,
+    travel_models: HashMap<usize, TravelModel>,
+    coord_index: Arc<CoordIndex>,
+) -> Result<Arc<dyn TransportCost + Sync + Send>, GenericError> {
+    if matrices.iter().any(|matrix| matrix.entries.is_none() || matrix.timestamp.is_some()) {
+        return Err("sparse matrices cannot be mixed with dense or time aware matrices".into());
+    }
+
+    if coord_index.has_indices() || coord_index.has_custom() {
+        return Err("sparse matrices require geo coordinates to approximate missing routes".into());
     }
+
+    let matrix_data = matrices
+        .iter()
+        .enumerate()
+        .map(|(idx, matrix)| {
+            let entries = matrix
+                .entries
+                .iter()
+                .flatten()
+                .map(|&MatrixEntry(from, to, duration, distance)| (from, to, duration as f64, distance as f64))
+                .collect();
+
+            SparseMatrixData::new(get_matrix_profile(matrix, idx, matrix_profiles), entries)
+        })
+        .collect::<Vec<_>>();
+
+    let mut speeds = vec![DEFAULT_SPEED; matrix_profiles.len()];
+    api_problem.fleet.profiles.iter().for_each(|profile| {
+        if let Some(&index) = matrix_profiles.get(&profile.name) {
+            speeds[index] = profile.speed.unwrap_or(DEFAULT_SPEED);
+        }
+    });
+
+    create_sparse_matrix_transport_cost(matrix_data, travel_models, ApproxLocationFallback::new(coord_index, speeds))
+}
+
+pub(super) fn get_matrix_profile(matrix: &Matrix, idx: usize, matrix_profiles: &HashMap<String, usize>) -> usize {
+    matrix.profile.as_ref().and_then(|p| matrix_profiles.get(p)).cloned().unwrap_or(idx)
+}
+
+fn get_travel_models(
+    api_problem: &ApiProblem,
+    matrix_profiles: &HashMap<String, usize>,
+) -> HashMap<usize, TravelModel> {
+    api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| {
+            let model = match (&profile.speed_bands, profile.fifo) {
+                (Some(bands), _) => TravelModel::SpeedBands(
+                    bands
+                        .iter()
+                        .map(|band| CoreSpeedBand { start: parse_time(&band.start), factor: band.factor })
+                        .collect(),
+                ),
+                (None, Some(true)) => TravelModel::Interpolation { fifo: true },
+                _ => return None,
+            };
+
+            matrix_profiles.get(&profile.name).map(|&index| (index, model))
+        })
+        .collect()
 }
 
 pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> CoreFleet {
     let profile_indices = get_profile_index_map(api_problem);
+    let dimensions = api_problem.fleet.dimensions.as_deref();
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
@@ -110,6 +224,9 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
+            let day = get_shift_day(vehicle, shift_index);
+            let costs = Costs { fixed: day.and_then(|day| day.fixed).unwrap_or(costs.fixed), ..costs.clone() };
+
             let start = {
                 let location = coord_index.get_by_loc(&shift.start.location).unwrap();
                 let earliest = parse_time(&shift.start.earliest);
@@ -132,6 +249,17 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     location,
                     time: TimeInterval { earliest: None, latest: Some(time) },
                 }),
+                depots: shift.depots.as_ref().map_or_else(Default::default, |depots| {
+                    let get_locations = |locations: &Option<Vec<ApiLocation>>| {
+                        locations
+                            .iter()
+                            .flatten()
+                            .map(|location| coord_index.get_by_loc(location).unwrap())
+                            .collect::<Vec<_>>()
+                    };
+
+                    VehicleDepots { start: get_locations(&depots.start), end: get_locations(&depots.end) }
+                }),
             }];
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
@@ -146,44 +274,138 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_tour_size(tour_size);
                 }
 
+                if let Some(day) = day {
+                    dimens.set_vehicle_day(day.day);
+                }
+
+                if let Some(rest) = vehicle.calendar.as_ref().and_then(|calendar| calendar.min_rest) {
+                    dimens.set_shift_rest(rest);
+                }
+
                 if props.has_multi_dimen_capacity {
-                    dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
+                    dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.to_vec(dimensions)));
                 } else {
-                    dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
+                    dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.to_vec(dimensions).first().unwrap()));
+                }
+
+                if let Some(compartments) = vehicle.compartments.as_ref() {
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_vehicle_compartments(get_compartments(compartments, |capacity| {
+                            MultiDimLoad::new(capacity.to_vec(dimensions))
+                        }));
+                    } else {
+                        dimens.set_vehicle_compartments(get_compartments(compartments, |capacity| {
+                            SingleDimLoad::new(capacity.to_vec(dimensions).first().copied().unwrap_or_default())
+                        }));
+                    }
+                }
+
+                if let Some(parking) = vehicle.profile.parking {
+                    dimens.set_vehicle_parking(parking);
                 }
 
                 if let Some(skills) = vehicle.skills.as_ref() {
                     dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
                 }
 
-                vehicles.push(Arc::new(Vehicle {
-                    profile: profile.clone(),
-                    costs: costs.clone(),
-                    dimens,
-                    details: details.clone(),
-                }));
+                // NOTE vehicle which is already on its tour continues it from the current location
+                let details = get_vehicle_state(api_problem, vehicle_id, shift_index).map_or_else(
+                    || details.clone(),
+                    |state| {
+                        let location = coord_index.get_by_loc(&state.location).unwrap();
+                        let time = parse_time(&state.time);
+
+                        details
+                            .iter()
+                            .map(|detail| VehicleDetail {
+                                start: Some(VehiclePlace {
+                                    location,
+                                    time: TimeInterval { earliest: Some(time), latest: Some(time) },
+                                }),
+                                end: detail.end.clone(),
+                                depots: VehicleDepots { start: vec![], end: detail.depots.end.clone() },
+                            })
+                            .collect()
+                    },
+                );
+
+                vehicles.push(Arc::new(Vehicle { profile: profile.clone(), costs: costs.clone(), dimens, details }));
             });
         }
     });
 
-    let drivers = vec![Arc::new(Driver {
-        costs: Costs {
-            fixed: 0.0,
-            per_distance: 0.0,
-            per_driving_time: 0.0,
-            per_waiting_time: 0.0,
-            per_service_time: 0.0,
-        },
-        dimens: Default::default(),
-        details: vec![],
-    })];
-
-    CoreFleet::new(drivers, vehicles, Box::new(|actors| create_typed_actor_groups(actors)))
+    let drivers = read_drivers(api_problem);
+
+    CoreFleet::new_with_pairing(
+        drivers,
+        vehicles,
+        Box::new(|actors| create_typed_actor_groups(actors)),
+        Box::new(|vehicle| vehicle.dimens.get_vehicle_type().cloned()),
+        Box::new(|driver| driver.dimens.get_driver_vehicle_types().map(|types| types.iter().cloned().collect())),
+    )
+}
+
+fn read_drivers(api_problem: &ApiProblem) -> Vec<Arc<Driver>> {
+    let driver_types = api_problem.fleet.drivers.iter().flatten().collect::<Vec<_>>();
+
+    if driver_types.is_empty() {
+        return vec![Arc::new(Driver {
+            costs: Costs {
+                fixed: 0.0,
+                per_distance: 0.0,
+                per_driving_time: 0.0,
+                per_waiting_time: 0.0,
+                per_service_time: 0.0,
+            },
+            dimens: Default::default(),
+            details: vec![],
+        })];
+    }
+
+    driver_types
+        .into_iter()
+        .flat_map(|driver| {
+            let costs = Costs {
+                fixed: driver.costs.fixed.unwrap_or(0.),
+                per_distance: driver.costs.distance,
+                per_driving_time: driver.costs.time,
+                per_waiting_time: driver.costs.time,
+                per_service_time: driver.costs.time,
+            };
+
+            // NOTE a driver without shifts is available once at any time
+            let details = driver.shifts.as_ref().map_or_else(
+                || vec![DriverDetail { time: TimeWindow::max() }],
+                |shifts| {
+                    shifts
+                        .iter()
+                        .map(|shift| DriverDetail {
+                            time: TimeWindow::new(parse_time(&shift.earliest), parse_time(&shift.latest)),
+                        })
+                        .collect()
+                },
+            );
+
+            driver.driver_ids.iter().map(move |driver_id| {
+                let mut dimens: Dimensions = Default::default();
+                dimens.set_driver_type(driver.type_id.clone()).set_driver_id(driver_id.clone());
+
+                if let Some(skills) = driver.skills.as_ref() {
+                    dimens.set_driver_skills(skills.iter().cloned().collect());
+                }
+
+                if let Some(vehicle_types) = driver.vehicle_types.as_ref() {
+                    dimens.set_driver_vehicle_types(vehicle_types.iter().cloned().collect());
+                }
+
+                Arc::new(Driver { costs: costs.clone(), dimens, details: details.clone() })
+            })
+        })
+        .collect()
 }
 
 /// Creates a matrices using approximation.
 pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
-    const DEFAULT_SPEED: f64 = 10.;
     // get each speed value once
     let speeds = problem
         .fleet
@@ -217,6 +439,8 @@ pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
                 travel_times: approx_data[idx].0.clone(),
                 distances: approx_data[idx].1.clone(),
                 error_codes: None,
+                entries: None,
+                geometries: None,
             }
         })
         .collect()
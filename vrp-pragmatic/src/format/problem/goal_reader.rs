@@ -1,13 +1,14 @@
 use super::*;
+use crate::construction::enablers::{get_shift_index, get_vehicle_id_from_job, is_correct_vehicle};
 use crate::construction::enablers::{JobTie, VehicleTie};
 use crate::construction::features::*;
 use hashbrown::HashSet;
 use vrp_core::construction::clustering::vicinity::ClusterDimension;
 use vrp_core::construction::enablers::NoRouteIntervals;
 use vrp_core::construction::features::*;
-use vrp_core::models::common::{LoadOps, MultiDimLoad, SingleDimLoad};
+use vrp_core::models::common::{Load, LoadOps, MultiDimLoad, SingleDimLoad};
 use vrp_core::models::problem::{Actor, Single, TransportCost};
-use vrp_core::models::{Feature, Goal, GoalContext};
+use vrp_core::models::{Feature, Goal, GoalContext, LevelPolicy, LevelTolerance};
 
 pub(super) fn create_goal_context(
     api_problem: &ApiProblem,
@@ -18,8 +19,9 @@ pub(super) fn create_goal_context(
 
     // TODO what's about performance implications on order of features when they are evaluated?
 
-    let objective_features = get_objective_features(api_problem, blocks, props)?;
-    let (global_objective_map, local_objective_map) = extract_feature_map(objective_features.as_slice())?;
+    let (objective_features, level_policies) = get_objective_features(api_problem, blocks, props)?;
+    let (global_objective_map, local_objective_map) =
+        extract_feature_map(objective_features.as_slice(), level_policies.as_slice())?;
     features.extend(objective_features.into_iter().flat_map(|features| features.into_iter()));
 
     if props.has_unreachable_locations {
@@ -28,10 +30,46 @@ pub(super) fn create_goal_context(
 
     features.push(get_capacity_feature("capacity", api_problem, blocks, props)?);
 
+    if props.has_compartments {
+        features.push(if props.has_multi_dimen_capacity {
+            create_compartments_feature::<MultiDimLoad>("compartments", COMPARTMENT_CONSTRAINT_CODE)?
+        } else {
+            create_compartments_feature::<SingleDimLoad>("compartments", COMPARTMENT_CONSTRAINT_CODE)?
+        });
+    }
+
     if props.has_tour_travel_limits {
         features.push(get_tour_limit_feature("tour_limit", api_problem, blocks.transport.clone())?)
     }
 
+    if props.has_max_ride_durations {
+        features.push(create_max_ride_duration_feature(
+            "max_ride_duration",
+            MAX_RIDE_DURATION_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+            MAX_RIDE_DURATION_KEY,
+        )?);
+    }
+
+    if props.has_driving_rules {
+        features.push(get_driving_rules_feature("driving_rules", api_problem, blocks.transport.clone())?);
+    }
+
+    if props.has_calendar {
+        features.push(create_shift_overlap_feature(
+            "shift_overlap",
+            SHIFT_OVERLAP_CONSTRAINT_CODE,
+            blocks.fleet.actors.as_slice(),
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_depot_capacities {
+        features.push(get_depot_capacity_feature("depot_capacity", api_problem, blocks.coord_index.as_ref())?);
+    }
+
     if props.has_breaks {
         features.push(create_optional_break_feature("break", BREAK_CONSTRAINT_CODE)?)
     }
@@ -52,6 +90,15 @@ pub(super) fn create_goal_context(
         features.push(create_group_feature("group", blocks.jobs.size(), GROUP_CONSTRAINT_CODE, GROUP_KEY)?);
     }
 
+    if props.has_frequency {
+        features.push(create_periodic_feature(
+            "periodic",
+            blocks.jobs.size(),
+            PERIODIC_CONSTRAINT_CODE,
+            blocks.fleet.actors.as_slice(),
+        )?);
+    }
+
     if props.has_skills {
         features.push(create_skills_feature("skills", SKILL_CONSTRAINT_CODE)?)
     }
@@ -73,7 +120,7 @@ pub(super) fn create_goal_context(
         )?);
     }
 
-    let goal = Goal::no_alternatives(global_objective_map, local_objective_map);
+    let goal = Goal::no_alternatives(global_objective_map, local_objective_map).with_policies(level_policies);
 
     GoalContext::new(features.as_slice(), goal)
 }
@@ -82,13 +129,23 @@ fn get_objective_features(
     api_problem: &ApiProblem,
     blocks: &ProblemBlocks,
     props: &ProblemProperties,
-) -> Result<Vec<Vec<Feature>>, GenericError> {
+) -> Result<(Vec<Vec<Feature>>, Vec<LevelPolicy>), GenericError> {
     let objectives = get_objectives(api_problem, props);
 
     objectives
         .iter()
-        .map(|objectives| {
-            objectives
+        .map(|level| {
+            let tolerance = level.tolerance().map(|tolerance| LevelTolerance {
+                relative: tolerance.relative.unwrap_or(0.),
+                absolute: tolerance.absolute.unwrap_or(0.),
+            });
+
+            let (objectives, weights) = match level.objectives() {
+                [Objective::WeightedSum { objectives, weights }] => (objectives.as_slice(), Some(weights.clone())),
+                objectives => (objectives, None),
+            };
+
+            let features = objectives
                 .iter()
                 .map(|objective| match objective {
                     Objective::MinimizeCost => create_minimize_transport_costs_feature(
@@ -163,16 +220,7 @@ fn get_objective_features(
                             create_max_load_balanced_feature::<MultiDimLoad>(
                                 "max_load_balance",
                                 get_threshold(options),
-                                Arc::new(|loaded, capacity| {
-                                    let mut max_ratio = 0_f64;
-
-                                    for (idx, value) in capacity.load.iter().enumerate() {
-                                        let ratio = loaded.load[idx] as f64 / *value as f64;
-                                        max_ratio = max_ratio.max(ratio);
-                                    }
-
-                                    max_ratio
-                                }),
+                                Arc::new(|loaded, capacity| loaded.ratio(capacity)),
                             )
                         } else {
                             create_max_load_balanced_feature::<SingleDimLoad>(
@@ -207,24 +255,31 @@ fn get_objective_features(
                     Objective::FastService { tolerance } => {
                         get_fast_service_feature("fast_service", blocks, props, *tolerance)
                     }
+                    Objective::WeightedSum { .. } => {
+                        Err("weighted-sum objective should be the only objective on its level and cannot be nested"
+                            .into())
+                    }
                 })
-                .collect()
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((features, LevelPolicy { weights, tolerance }))
         })
-        .collect()
+        .collect::<Result<Vec<_>, GenericError>>()
+        .map(|levels| levels.into_iter().unzip())
 }
 
-fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Vec<Objective>> {
+fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<ObjectiveLevel> {
     if let Some(objectives) = api_problem.objectives.clone() {
         objectives
     } else {
         let mut objectives = vec![
-            vec![Objective::MinimizeUnassigned { breaks: Some(1.) }],
-            vec![Objective::MinimizeTours],
-            vec![Objective::MinimizeCost],
+            vec![Objective::MinimizeUnassigned { breaks: Some(1.) }].into(),
+            vec![Objective::MinimizeTours].into(),
+            vec![Objective::MinimizeCost].into(),
         ];
 
         if props.has_value {
-            objectives.insert(0, vec![Objective::MaximizeValue { breaks: None }])
+            objectives.insert(0, vec![Objective::MaximizeValue { breaks: None }].into())
         }
 
         objectives
@@ -246,7 +301,7 @@ fn get_capacity_feature(
                 api_problem,
                 blocks,
                 MultiDimLoad::new,
-                Box::new(move |capacity| *capacity * RELOAD_THRESHOLD),
+                Box::new(move |capacity| capacity.clone() * RELOAD_THRESHOLD),
             )
         } else {
             get_capacity_with_reload_feature::<SingleDimLoad>(
@@ -278,7 +333,7 @@ fn get_fast_service_feature(
                 transport,
                 activity,
                 create_simple_reload_route_intervals(Box::new(move |capacity: &MultiDimLoad| {
-                    *capacity * RELOAD_THRESHOLD
+                    capacity.clone() * RELOAD_THRESHOLD
                 })),
                 tolerance,
                 FAST_SERVICE_KEY,
@@ -414,6 +469,73 @@ fn get_recharge_feature(
     create_recharge_feature(name, RECHARGE_CONSTRAINT_CODE, distance_limit_fn, transport)
 }
 
+fn get_driving_rules_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) -> Result<Feature, GenericError> {
+    let driving_rules_index: HashMap<_, _> = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.driving_rules.as_ref()).map(|rules| {
+                let rules = DrivingRules {
+                    max_driving_time: rules.max_driving_time,
+                    max_total_driving_time: rules.max_daily_driving_time,
+                };
+
+                (vehicle.type_id.clone(), rules)
+            })
+        })
+        .collect();
+
+    let driving_rules_fn: DrivingRulesFn = Arc::new(move |actor: &Actor| {
+        actor.vehicle.dimens.get_vehicle_type().and_then(|type_id| driving_rules_index.get(type_id)).cloned()
+    });
+
+    create_driving_rules_feature(
+        name,
+        DRIVING_RULES_CONSTRAINT_CODE,
+        driving_rules_fn,
+        Arc::new(|single| single.dimens.get_job_type().is_some_and(|job_type| job_type == "driving_break")),
+        Box::new(|route, job| {
+            // NOTE split driving break is a multi job with the same vehicle and shift on all parts
+            let single = match job {
+                CoreJob::Single(single) => Some(single),
+                CoreJob::Multi(multi) => multi.jobs.first(),
+            };
+
+            single.is_some_and(|single| {
+                is_correct_vehicle(route, get_vehicle_id_from_job(single), get_shift_index(&single.dimens))
+            })
+        }),
+        transport,
+    )
+}
+
+fn get_depot_capacity_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    coord_index: &CoordIndex,
+) -> Result<Feature, GenericError> {
+    // NOTE depot location which is not used by any vehicle shift is ignored
+    let capacities = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Depot { location, capacity, .. } => {
+                coord_index.get_by_loc(location).map(|location| (location, *capacity))
+            }
+            VehicleResource::Reload { .. } => None,
+        })
+        .collect();
+
+    create_depot_capacity_feature(name, DEPOT_CAPACITY_CONSTRAINT_CODE, capacities)
+}
+
 fn get_reload_resources<T>(
     api_problem: &ApiProblem,
     job_index: &JobIndex,
@@ -429,8 +551,11 @@ where
         .as_ref()
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id.clone(), capacity.clone()),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => {
+                Some((id.clone(), capacity.to_vec(api_problem.fleet.dimensions.as_deref())))
+            }
+            VehicleResource::Depot { .. } => None,
         })
         .collect::<Vec<_>>();
     let total_resources_specified = available_resources.len();
@@ -478,7 +603,10 @@ where
 }
 
 #[allow(clippy::type_complexity)]
-fn extract_feature_map(features: &[Vec<Feature>]) -> Result<(Vec<Vec<String>>, Vec<Vec<String>>), GenericError> {
+fn extract_feature_map(
+    features: &[Vec<Feature>],
+    policies: &[LevelPolicy],
+) -> Result<(Vec<Vec<String>>, Vec<Vec<String>>), GenericError> {
     let global_objective_map: Vec<Vec<String>> = features
         .iter()
         .map(|features| features.iter().filter_map(|f| f.objective.as_ref().map(|_| f.name.clone())).collect())
@@ -488,17 +616,26 @@ fn extract_feature_map(features: &[Vec<Feature>]) -> Result<(Vec<Vec<String>>, V
     //       which has the same size as local_objective_map. So, we exclude some objectives which
     //       are not really needed to be present here.
     let exclusion_set = &["min_unassigned"].into_iter().collect::<HashSet<_>>();
-    let local_objective_map: Vec<Vec<String>> = features
+    let local_objective_map: Vec<Vec<String>> = global_objective_map
         .iter()
-        .flat_map(|inner| {
-            inner
-                .iter()
-                .filter_map(|f| f.objective.as_ref().map(|_| f.name.clone()))
-                .filter(|name| !exclusion_set.contains(name.as_str()))
+        .zip(policies.iter().map(Some).chain(std::iter::repeat(None)))
+        .flat_map(|(names, policy)| {
+            let names = names.iter().filter(|name| !exclusion_set.contains(name.as_str())).cloned();
+
+            if policy.is_some_and(|policy| policy.weights.is_some()) {
+                // NOTE: weighted objectives are kept together, so their estimates are summed with weights
+                let names = names.collect::<Vec<_>>();
+                if names.is_empty() {
+                    vec![]
+                } else {
+                    vec![names]
+                }
+            } else {
+                // NOTE: there is no mechanism to handle objectives on the same level yet, so simply move
+                //       them to a separate level and rely on non-determenistic behavior of ResultSelector
+                names.map(|name| vec![name]).collect()
+            }
         })
-        // NOTE: there is no mechanism to handle objectives on the same level yet, so simply move
-        //       them to a separate level and rely on non-determenistic behavior of ResultSelector
-        .map(|objective| vec![objective])
         .collect();
 
     // NOTE COST_DIMENSION variable in vrp-core is responsible for that
@@ -7,6 +7,12 @@ use crate::format::{JobIndex, Location};
 use crate::utils::VariableJobPermutation;
 use hashbrown::HashMap;
 use std::sync::Arc;
+use vrp_core::construction::enablers::{
+    ServiceDurationBand, ServiceDurationDimension, TimeWindowTolerance, TimeWindowToleranceDimension,
+};
+use vrp_core::construction::features::{
+    JobProductDimension, MaxRideDurationDimension, VisitFrequency, VisitFrequencyDimension,
+};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
@@ -106,27 +112,65 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let dimensions = api_problem.fleet.dimensions.as_deref();
 
     let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
         let absent = (empty(), empty());
-        let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
+        let capacity = task.demand.as_ref().map_or_else(empty, |demand| MultiDimLoad::new(demand.to_vec(dimensions)));
         let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
 
         let demand = match activity_type {
             "pickup" => Demand { pickup: demand, delivery: absent },
             "delivery" => Demand { pickup: absent, delivery: demand },
-            "replacement" => Demand { pickup: demand, delivery: demand },
-            "service" => Demand { pickup: absent, delivery: absent },
+            "replacement" => Demand { pickup: demand.clone(), delivery: demand },
+            "service" => Demand { pickup: absent.clone(), delivery: absent },
             _ => panic!("invalid activity type."),
         };
 
+        let tolerances = task
+            .places
+            .iter()
+            .map(|p| {
+                p.tolerance.as_ref().map(|tolerance| {
+                    TimeWindowTolerance::new(
+                        tolerance.earliness.unwrap_or_default(),
+                        tolerance.lateness.unwrap_or_default(),
+                        tolerance.penalty,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let durations =
+            task.places.iter().map(|p| p.durations.as_ref().map(|d| parse_service_durations(d))).collect::<Vec<_>>();
+
         let places = task
             .places
             .iter()
-            .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
+            .zip(tolerances.iter())
+            .map(|(p, tolerance)| {
+                let times = parse_times(&p.times);
+                let times = match tolerance {
+                    Some(tolerance) => expand_times(times, tolerance),
+                    None => times,
+                };
+
+                (Some(p.location.clone()), p.duration, times, p.tag.clone())
+            })
             .collect();
 
-        get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        let mut single =
+            get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+
+        if tolerances.iter().any(|tolerance| tolerance.is_some()) {
+            single.dimens.set_time_window_tolerances(tolerances);
+        }
+
+        if durations.iter().any(|durations| durations.is_some()) {
+            single.dimens.set_service_durations(durations);
+        }
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
@@ -189,6 +233,10 @@ fn read_conditional_jobs(
             if let Some(recharges) = &shift.recharges {
                 read_recharges(coord_index, job_index, &mut jobs, vehicle, shift_index, recharges);
             }
+
+            if let Some(driving_rules) = vehicle.limits.as_ref().and_then(|limits| limits.driving_rules.as_ref()) {
+                read_driving_breaks(coord_index, job_index, &mut jobs, vehicle, shift_index, driving_rules);
+            }
         }
     });
 
@@ -271,6 +319,10 @@ fn read_reloads(
             duration: reload.duration,
             times: reload.times.clone(),
             tag: reload.tag.clone(),
+            tolerance: None,
+            days: None,
+            durations: None,
+            parking: None,
         }),
     )
 }
@@ -294,6 +346,63 @@ fn read_recharges(
     )
 }
 
+fn read_driving_breaks(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    driving_rules: &VehicleDrivingRules,
+) {
+    // NOTE at most one break is needed per max driving time within daily driving time
+    let break_count = (driving_rules.max_daily_driving_time / driving_rules.max_driving_time).ceil() as usize;
+    let break_count = break_count.max(1) - 1;
+
+    (1..=break_count)
+        .flat_map(|break_idx| {
+            vehicle.vehicle_ids.iter().map(move |vehicle_id| {
+                let job_id = format!("{vehicle_id}_driving_break_{shift_index}_{break_idx}");
+                let get_break_part = |job_type: &str, duration: Duration| {
+                    let times = vec![TimeSpan::Window(TimeWindow::max())];
+                    get_conditional_job(
+                        coord_index,
+                        vehicle_id.clone(),
+                        &job_id,
+                        job_type,
+                        shift_index,
+                        vec![(None, duration, times, None)],
+                    )
+                };
+
+                // NOTE only the last part of split break resets driving time
+                let job = match driving_rules.split_break.as_deref() {
+                    Some(&[first, second]) => {
+                        let mut dimens = Dimensions::default();
+                        dimens
+                            .set_job_id(job_id.clone())
+                            .set_job_type("driving_break".to_string())
+                            .set_shift_index(shift_index)
+                            .set_vehicle_id(vehicle_id.clone());
+
+                        let parts = vec![
+                            Arc::new(get_break_part("driving_break_part", first)),
+                            Arc::new(get_break_part("driving_break", second)),
+                        ];
+
+                        Job::Multi(Multi::new_shared(parts, dimens))
+                    }
+                    _ => Job::Single(Arc::new(get_break_part("driving_break", driving_rules.break_duration))),
+                };
+
+                (job_id, job)
+            })
+        })
+        .for_each(|(job_id, job)| {
+            job_index.insert(job_id, job.clone());
+            jobs.push(job);
+        });
+}
+
 fn read_specific_job_places(
     job_type: &str,
     coord_index: &CoordIndex,
@@ -392,9 +501,10 @@ fn get_single_with_extras(
     if has_multi_dimens {
         dimens.set_demand(demand);
     } else {
+        let single_dim = |load: &MultiDimLoad| SingleDimLoad::new(load.load.first().copied().unwrap_or_default());
         dimens.set_demand(Demand {
-            pickup: (SingleDimLoad::new(demand.pickup.0.load[0]), SingleDimLoad::new(demand.pickup.1.load[0])),
-            delivery: (SingleDimLoad::new(demand.delivery.0.load[0]), SingleDimLoad::new(demand.delivery.1.load[0])),
+            pickup: (single_dim(&demand.pickup.0), single_dim(&demand.pickup.1)),
+            delivery: (single_dim(&demand.delivery.0), single_dim(&demand.delivery.1)),
         });
     }
     dimens.set_job_type(activity_type.to_string()).set_job_order(*order);
@@ -412,9 +522,29 @@ fn get_single_job(job: &ApiJob, single: Single) -> Job {
         .set_job_compatibility(job.compatibility.clone())
         .set_job_skills(get_skills(&job.skills));
 
+    if let Some(product) = job.product.as_ref() {
+        single.dimens.set_job_product(product.clone());
+    }
+
+    if let Some(frequency) = get_visit_frequency(job) {
+        single.dimens.set_visit_frequency(frequency);
+    }
+
     Job::Single(Arc::new(single))
 }
 
+fn get_visit_frequency(job: &ApiJob) -> Option<Arc<VisitFrequency>> {
+    job.frequency.as_ref().map(|frequency| {
+        Arc::new(VisitFrequency {
+            id: get_periodic_job_id(&job.id).to_string(),
+            count: frequency.count,
+            min_spacing: frequency.min_spacing.unwrap_or(1),
+            max_spacing: frequency.max_spacing,
+            patterns: frequency.patterns.clone(),
+        })
+    })
+}
+
 fn get_multi_job(
     job: &ApiJob,
     singles: Vec<Single>,
@@ -429,6 +559,18 @@ fn get_multi_job(
         .set_job_compatibility(job.compatibility.clone())
         .set_job_skills(get_skills(&job.skills));
 
+    if let Some(product) = job.product.as_ref() {
+        dimens.set_job_product(product.clone());
+    }
+
+    if let Some(frequency) = get_visit_frequency(job) {
+        dimens.set_visit_frequency(frequency);
+    }
+
+    if let Some(max_ride_duration) = job.max_ride_duration {
+        dimens.set_max_ride_duration(max_ride_duration);
+    }
+
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
     let multi = if singles.len() == 2 && deliveries_start_index == 1 {
@@ -450,7 +592,7 @@ fn get_multi_job(
     Job::Multi(multi)
 }
 
-fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor) -> bool + Sync + Send> {
+pub(super) fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor) -> bool + Sync + Send> {
     Arc::new(move |actor: &Actor| {
         *actor.vehicle.dimens.get_vehicle_id().unwrap() == vehicle_id
             && actor.vehicle.dimens.get_shift_index().unwrap() == shift_index
@@ -467,6 +609,26 @@ fn empty() -> MultiDimLoad {
     MultiDimLoad::default()
 }
 
+fn expand_times(times: Vec<TimeSpan>, tolerance: &TimeWindowTolerance) -> Vec<TimeSpan> {
+    times
+        .into_iter()
+        .map(|time| match time {
+            TimeSpan::Window(tw) => TimeSpan::Window(tolerance.expand(&tw)),
+            TimeSpan::Offset(_) => time,
+        })
+        .collect()
+}
+
+fn parse_service_durations(durations: &[JobPlaceDuration]) -> Vec<ServiceDurationBand> {
+    let mut bands = durations
+        .iter()
+        .map(|band| ServiceDurationBand { start: parse_time(&band.start), duration: band.duration })
+        .collect::<Vec<_>>();
+    bands.sort_by(|a, b| compare_floats(a.start, b.start));
+
+    bands
+}
+
 fn parse_times(times: &Option<Vec<Vec<String>>>) -> Vec<TimeSpan> {
     times.as_ref().map_or(vec![TimeSpan::Window(TimeWindow::max())], |tws| {
         tws.iter().map(|tw| TimeSpan::Window(parse_time_window(tw))).collect()
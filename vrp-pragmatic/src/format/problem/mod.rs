@@ -4,6 +4,7 @@ use super::*;
 use crate::parse_time;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use vrp_core::construction::enablers::LocationParkingIndex;
 use vrp_core::models::common::TimeWindow;
 use vrp_core::models::Lock;
 use vrp_core::prelude::{ActivityCost, Fleet as CoreFleet, Jobs as CoreJobs, TransportCost};
@@ -18,16 +19,29 @@ pub use self::model::*;
 #[path = "../../../tests/unit/format/problem/reader_test.rs"]
 mod reader_test;
 
+mod binary_matrix;
+pub use self::binary_matrix::*;
+
+mod calendar_reader;
+pub(crate) use self::calendar_reader::{
+    apply_planning_calendar, get_calendar_times, get_periodic_job_id, get_shift_day, get_visit_job_id,
+};
+
 mod clustering_reader;
 
 mod fleet_reader;
 pub use self::fleet_reader::create_approx_matrices;
+pub(crate) use self::fleet_reader::{create_transport_costs, DEFAULT_SPEED};
 
 mod goal_reader;
 mod job_reader;
 
 mod problem_reader;
-use self::problem_reader::{map_to_problem_with_approx, map_to_problem_with_matrices};
+mod state_reader;
+use self::problem_reader::{
+    map_to_problem_with_approx, map_to_problem_with_binary_matrices, map_to_problem_with_matrices,
+};
+pub(crate) use self::state_reader::apply_problem_state;
 
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
@@ -83,6 +97,12 @@ impl PragmaticProblem for (ApiProblem, Vec<Matrix>) {
     }
 }
 
+impl PragmaticProblem for (ApiProblem, Vec<BinaryMatrix>) {
+    fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
+        map_to_problem_with_binary_matrices(self.0, self.1)
+    }
+}
+
 impl PragmaticProblem for ApiProblem {
     fn read_pragmatic(self) -> Result<CoreProblem, MultiFormatError> {
         map_to_problem_with_approx(self)
@@ -117,6 +137,15 @@ struct ProblemProperties {
     has_compatibility: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_driving_rules: bool,
+    has_depot_capacities: bool,
+    has_time_window_tolerances: bool,
+    has_service_durations: bool,
+    has_parking: bool,
+    has_max_ride_durations: bool,
+    has_compartments: bool,
+    has_calendar: bool,
+    has_frequency: bool,
 }
 
 /// Keeps track of materialized problem building blocks.
@@ -129,6 +158,8 @@ struct ProblemBlocks {
     activity: Arc<dyn ActivityCost + Send + Sync>,
     locks: Vec<Arc<Lock>>,
     reserved_times_index: ReservedTimesIndex,
+    location_parking: Option<Arc<LocationParkingIndex>>,
+    route_geometry: Option<Arc<dyn RouteGeometry + Send + Sync>>,
 }
 
 fn parse_time_window(tw: &[String]) -> TimeWindow {
@@ -4,7 +4,7 @@ mod model_test;
 
 extern crate serde_json;
 
-use crate::format::{FormatError, Location, MultiFormatError};
+use crate::format::{FormatError, LoadAmount, Location, MultiFormatError};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
 
@@ -67,6 +67,44 @@ pub struct JobPlace {
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// Specifies how much job place time windows can be violated and at which cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<JobPlaceTolerance>,
+    /// Day indices when job place can be served in multi-day planning: time windows are repeated
+    /// for each day with 24 hours shift.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<Vec<usize>>,
+    /// Time dependent service durations: when service starts within a band, its duration is used
+    /// instead of the place duration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durations: Option<Vec<JobPlaceDuration>>,
+    /// A parking time needed before serving the job at the place location. It is shared by
+    /// consecutive activities at the same location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parking: Option<f64>,
+}
+
+/// Specifies a service duration band: it starts at given time and lasts till the start of the next one.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobPlaceDuration {
+    /// A band start time in RFC3339 format.
+    pub start: String,
+    /// A service duration (in seconds) used when service starts within the band.
+    pub duration: f64,
+}
+
+/// Specifies a tolerated violation of job place time windows. Service can be started earlier
+/// or later than time window allows within given spans, each second of violation is penalized.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobPlaceTolerance {
+    /// A max duration (in seconds) of service start before time window start.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliness: Option<f64>,
+    /// A max duration (in seconds) of service start after time window end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<f64>,
+    /// A penalty per second of earliness or lateness.
+    pub penalty: f64,
 }
 
 /// Specifies a job task.
@@ -76,7 +114,7 @@ pub struct JobTask {
     pub places: Vec<JobPlace>,
     /// Job place demand.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub demand: Option<Vec<i32>>,
+    pub demand: Option<LoadAmount>,
     /// An order, bigger value - later assignment in the route.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
@@ -87,6 +125,7 @@ pub struct JobTask {
 /// * all of them should be completed or none of them.
 /// * all pickups must be completed before any of deliveries.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Job {
     /// A job id.
     pub id: String,
@@ -122,6 +161,39 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// A product type: job's demand can be loaded only into a vehicle compartment which accepts it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+
+    /// A maximum time goods or passengers stay in the vehicle: measured from departure at the first
+    /// pickup till service start at the last delivery. Applicable only for job with pickups and deliveries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ride_duration: Option<f64>,
+
+    /// A visit frequency: job is visited multiple times on different days of planning horizon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<JobFrequency>,
+}
+
+/// Specifies visit frequency of a periodic job over multi-day planning horizon.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobFrequency {
+    /// Amount of visits.
+    pub count: usize,
+
+    /// A minimum amount of days between two consecutive visits. Default is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_spacing: Option<usize>,
+
+    /// A maximum amount of days between two consecutive visits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_spacing: Option<usize>,
+
+    /// Allowed day patterns: visit days have to match one of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patterns: Option<Vec<Vec<usize>>>,
 }
 
 // region Clustering
@@ -298,6 +370,23 @@ pub struct VehicleShift {
     /// Vehicle recharge stations information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recharges: Option<VehicleRecharges>,
+
+    /// Alternative shift start and end locations (depots).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depots: Option<ShiftDepots>,
+}
+
+/// Specifies alternative start and end locations of the vehicle shift. The solver selects
+/// one of them (or the original one) for each tour.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ShiftDepots {
+    /// Alternative shift start locations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<Vec<Location>>,
+
+    /// Alternative shift end locations. Ignored when shift has no end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<Vec<Location>>,
 }
 
 /// Specifies a place where vehicle can load or unload cargo.
@@ -356,6 +445,30 @@ pub struct VehicleLimits {
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
+
+    /// Driving time rules, such as EU drive time regulation.
+    /// No driving time restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driving_rules: Option<VehicleDrivingRules>,
+}
+
+/// Specifies driving time rules which require a break after accumulated driving time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleDrivingRules {
+    /// Max driving time before a break has to be taken, e.g. 4.5 hours.
+    pub max_driving_time: f64,
+
+    /// Break duration which resets accumulated driving time, e.g. 45 minutes.
+    pub break_duration: f64,
+
+    /// Max total driving time per shift/tour, e.g. 9 hours.
+    pub max_daily_driving_time: f64,
+
+    /// Durations of two parts which the break can be split into, e.g. 15 and 30 minutes.
+    /// Break cannot be split when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_break: Option<Vec<f64>>,
 }
 
 /// Vehicle optional break time variant.
@@ -436,6 +549,46 @@ pub enum VehicleBreak {
     },
 }
 
+/// Specifies a vehicle compartment.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleCompartment {
+    /// Compartment id.
+    pub id: String,
+
+    /// Compartment capacity.
+    pub capacity: LoadAmount,
+
+    /// Products accepted by compartment. If not set, any job can be loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products: Option<Vec<String>>,
+}
+
+/// Specifies a day when vehicle is available.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleDay {
+    /// Day index.
+    pub day: usize,
+
+    /// Fixed cost of vehicle usage on this day. If omitted, vehicle's fixed cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<f64>,
+}
+
+/// Specifies vehicle availability calendar: all vehicle shifts are repeated for each available
+/// day with 24 hours shift.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleCalendar {
+    /// Days when vehicle is available.
+    pub days: Vec<VehicleDay>,
+
+    /// Minimum rest time (in seconds) between the end of one tour and the start of the next
+    /// shift of the same vehicle. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_rest: Option<f64>,
+}
+
 /// Specifies a vehicle type.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -456,7 +609,11 @@ pub struct VehicleType {
     pub shifts: Vec<VehicleShift>,
 
     /// Vehicle capacity.
-    pub capacity: Vec<i32>,
+    pub capacity: LoadAmount,
+
+    /// Vehicle compartments: each job's demand is loaded into one of them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<VehicleCompartment>>,
 
     /// Vehicle skills.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -465,6 +622,10 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle availability calendar for multi-day planning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calendar: Option<VehicleCalendar>,
 }
 
 /// Specifies a vehicle profile.
@@ -477,18 +638,81 @@ pub struct VehicleProfile {
     /// Default value is 1.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scale: Option<f64>,
+
+    /// A default parking time used at job locations which have no parking time specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parking: Option<f64>,
 }
 
 /// Specifies routing matrix profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MatrixProfile {
     /// Profile name.
     pub name: String,
 
     /// Approximation speed (meters per second). Used only when routing matrix is not specified.
-    /// Default value is 10.
+    /// Default value is 10. When matrices are calculated using road graph, it limits road speeds.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// Keeps first-in-first-out property for time dependent routing matrices: departing later never
+    /// leads to arriving earlier. Can be set only when the profile has time dependent matrices.
+    /// Default value is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fifo: Option<bool>,
+
+    /// Piecewise constant speed bands which change free flow travel durations during the day. When set,
+    /// routing matrices should not have timestamps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed_bands: Option<Vec<SpeedBand>>,
+}
+
+/// Specifies a speed band of the routing profile.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct SpeedBand {
+    /// A time in RFC3339 when band starts. The band lasts till the start of the next one.
+    pub start: String,
+
+    /// A speed factor relative to free flow speed, e.g. 0.5 means twice slower than defined by matrix.
+    pub factor: f64,
+}
+
+/// Specifies a driver type.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriverType {
+    /// Driver type id.
+    pub type_id: String,
+
+    /// Concrete driver ids.
+    pub driver_ids: Vec<String>,
+
+    /// Driver costs. They are added to costs of the vehicle driven by the driver.
+    pub costs: VehicleCosts,
+
+    /// Driver skills. They are combined with skills of the vehicle driven by the driver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+
+    /// Driver shifts: time intervals when the driver is available for work.
+    /// If omitted, the driver is available at any time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shifts: Option<Vec<DriverShift>>,
+
+    /// Vehicle type ids the driver is allowed to drive. If omitted, any vehicle type can be driven.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_types: Option<Vec<String>>,
+}
+
+/// Specifies a driver shift.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverShift {
+    /// Earliest time in RFC3339 format when the driver can start working.
+    pub earliest: String,
+
+    /// Latest time in RFC3339 format when the driver should finish working.
+    pub latest: String,
 }
 
 /// Specifies vehicle resource type.
@@ -501,7 +725,18 @@ pub enum VehicleResource {
         /// Resource id.
         id: String,
         /// A total resource capacity.
-        capacity: Vec<i32>,
+        capacity: LoadAmount,
+    },
+
+    /// A depot resource which limits amount of tours starting at the depot location.
+    #[serde(rename(deserialize = "depot", serialize = "depot"))]
+    Depot {
+        /// Resource id.
+        id: String,
+        /// A depot location.
+        location: Location,
+        /// Max amount of tours which can start at the depot location.
+        capacity: usize,
     },
 }
 
@@ -511,12 +746,21 @@ pub struct Fleet {
     /// Vehicle types.
     pub vehicles: Vec<VehicleType>,
 
+    /// Driver types. If omitted, each vehicle is driven by an implicit driver without costs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drivers: Option<Vec<DriverType>>,
+
     /// Routing profiles.
     pub profiles: Vec<MatrixProfile>,
 
     /// Specifies vehicle resources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<VehicleResource>>,
+
+    /// Specifies names of capacity dimensions. When declared, capacity, demand and load can be
+    /// keyed by dimension name instead of position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<Vec<String>>,
 }
 
 // endregion
@@ -606,6 +850,67 @@ pub enum Objective {
         /// to consider them different. Relative distance metric is used.
         tolerance: Option<f64>,
     },
+
+    /// A composite objective which compares solutions by weighted sum of inner objectives' values.
+    /// It has to be the only objective on its level.
+    WeightedSum {
+        /// Objectives to be combined. Composite objectives cannot be nested.
+        objectives: Vec<Objective>,
+        /// Weights of objectives, one per objective in the same order.
+        weights: Vec<f64>,
+    },
+}
+
+/// Specifies a single level of hierarchical objective function.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(untagged)]
+pub enum ObjectiveLevel {
+    /// A level defined by objectives of the same priority.
+    Objectives(Vec<Objective>),
+    /// A level defined by objectives of the same priority and a tolerance of their comparison.
+    WithTolerance {
+        /// Objectives of the same priority.
+        objectives: Vec<Objective>,
+        /// A tolerance within which objective values are considered equal, so the next level
+        /// decides which solution is better.
+        tolerance: ObjectiveTolerance,
+    },
+}
+
+impl ObjectiveLevel {
+    /// Returns objectives of the level.
+    pub fn objectives(&self) -> &[Objective] {
+        match self {
+            ObjectiveLevel::Objectives(objectives) | ObjectiveLevel::WithTolerance { objectives, .. } => objectives,
+        }
+    }
+
+    /// Returns level's tolerance if specified.
+    pub fn tolerance(&self) -> Option<&ObjectiveTolerance> {
+        match self {
+            ObjectiveLevel::Objectives(_) => None,
+            ObjectiveLevel::WithTolerance { tolerance, .. } => Some(tolerance),
+        }
+    }
+}
+
+impl From<Vec<Objective>> for ObjectiveLevel {
+    fn from(objectives: Vec<Objective>) -> Self {
+        ObjectiveLevel::Objectives(objectives)
+    }
+}
+
+/// Specifies a tolerance of objective level. Two values are considered equal when their difference
+/// does not exceed either absolute or relative threshold.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ObjectiveTolerance {
+    /// A max difference relative to the biggest of two values, e.g. 0.02 means 2%.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relative: Option<f64>,
+
+    /// A max difference in absolute units.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub absolute: Option<f64>,
 }
 
 /// Specifies balance objective options. At the moment, it uses coefficient of variation as
@@ -634,6 +939,49 @@ pub struct CompactOptions {
 
 // endregion
 
+// region State
+
+/// Specifies a current state of the vehicle which is already on its tour.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleState {
+    /// Vehicle id.
+    pub vehicle_id: String,
+
+    /// Vehicle shift index. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+
+    /// A current vehicle location: the tour is continued from here.
+    pub location: Location,
+
+    /// A time when vehicle is ready to depart from its current location in RFC3339 format.
+    pub time: String,
+
+    /// Ids of jobs which are already served by the vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed: Option<Vec<String>>,
+
+    /// Ids of pickup and delivery jobs which pickups are already served by the vehicle: their goods
+    /// are on board and only deliveries are left.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picked_up: Option<Vec<String>>,
+
+    /// Ids of jobs which the vehicle is committed to serve next in the given order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committed: Option<Vec<String>>,
+}
+
+/// Specifies a current state of the plan which is already in progress.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemState {
+    /// A list of vehicle states.
+    pub vehicles: Vec<VehicleState>,
+}
+
+// endregion
+
 // region Common
 
 /// A VRP problem definition.
@@ -647,7 +995,11 @@ pub struct Problem {
 
     /// Specifies objective function hierarchy.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub objectives: Option<Vec<Vec<Objective>>>,
+    pub objectives: Option<Vec<ObjectiveLevel>>,
+
+    /// Specifies a current state of the plan which is used to reoptimize it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<ProblemState>,
 }
 
 /// A routing matrix.
@@ -661,17 +1013,53 @@ pub struct Matrix {
     pub timestamp: Option<String>,
 
     /// Travel distances (used to be in seconds).
-    #[serde(alias = "durations")]
+    #[serde(alias = "durations", default)]
     pub travel_times: Vec<i64>,
 
     /// Travel durations (use to be in meters).
+    #[serde(default)]
     pub distances: Vec<i64>,
 
     /// Error codes to mark unreachable locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_codes: Option<Vec<i64>>,
+
+    /// Sparse routing data, e.g. routes to k nearest neighbours of each location. When set, travel times
+    /// and distances should be empty. Missing routes are approximated using geo coordinates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries: Option<Vec<MatrixEntry>>,
+
+    /// Road geometries of routes between locations. They are not used by the solver, but returned in geojson
+    /// output instead of straight lines between stops.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometries: Option<Vec<MatrixGeometry>>,
 }
 
+/// A sparse routing matrix entry serialized as `[from, to, duration, distance]` array.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MatrixEntry(
+    /// A location index of the route start.
+    pub usize,
+    /// A location index of the route end.
+    pub usize,
+    /// A travel duration.
+    pub i64,
+    /// A travel distance.
+    pub i64,
+);
+
+/// A route geometry serialized as `[from, to, polyline]` array, where polyline is encoded using
+/// encoded polyline algorithm format with precision 5.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MatrixGeometry(
+    /// A location index of the route start.
+    pub usize,
+    /// A location index of the route end.
+    pub usize,
+    /// An encoded polyline.
+    pub String,
+);
+
 // endregion
 
 /// Deserializes problem in json format from `BufReader`.
@@ -688,13 +1076,22 @@ pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Mul
 
 /// Deserializes routing matrix in json format from `BufReader`.
 pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, MultiFormatError> {
-    serde_json::from_reader(reader).map_err(|err| {
+    let create_error = |err: String| -> MultiFormatError {
         vec![FormatError::new(
             "E0001".to_string(),
             "cannot deserialize matrix".to_string(),
             format!("check input json: '{err}'"),
         )]
         .into()
+    };
+
+    serde_json::from_reader(reader).map_err(|err| create_error(err.to_string())).and_then(|matrix: Matrix| {
+        // NOTE dense routing data is optional only for sparse matrix
+        if matrix.travel_times.is_empty() && matrix.entries.is_none() {
+            Err(create_error("missing field `travelTimes` or `entries`".to_string()))
+        } else {
+            Ok(matrix)
+        }
     })
 }
 
@@ -1,21 +1,25 @@
 use super::*;
-use crate::construction::enablers::{OnlyVehicleActivityCost, VehicleTie};
+use crate::construction::enablers::VehicleTie;
+use crate::format::problem::calendar_reader::apply_planning_calendar;
 use crate::format::problem::clustering_reader::create_cluster_config;
 use crate::format::problem::fleet_reader::*;
 use crate::format::problem::goal_reader::create_goal_context;
 use crate::format::problem::job_reader::{read_jobs_with_extra_locks, read_locks};
-use crate::format::{FormatError, JobIndex};
+use crate::format::problem::state_reader::{apply_problem_state, read_state_locks};
+use crate::format::{FormatError, JobIndex, MatrixRouteGeometry, RouteGeometry, RouteGeometryDimension};
 use crate::validation::ValidationContext;
 use crate::{parse_time, CoordIndex};
+use hashbrown::HashSet;
 use vrp_core::construction::enablers::*;
 use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
+use vrp_core::models::problem::SimpleActivityCost;
 use vrp_core::models::Extras;
 use vrp_core::solver::processing::{ReservedTimeDimension, VicinityDimension};
 
 pub(super) fn map_to_problem_with_approx(problem: ApiProblem) -> Result<CoreProblem, MultiFormatError> {
     let coord_index = CoordIndex::new(&problem);
     let matrices = if coord_index.has_indices() { vec![] } else { create_approx_matrices(&problem) };
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, vec![], coord_index)
 }
 
 pub(super) fn map_to_problem_with_matrices(
@@ -23,18 +27,47 @@ pub(super) fn map_to_problem_with_matrices(
     matrices: Vec<Matrix>,
 ) -> Result<CoreProblem, MultiFormatError> {
     let coord_index = CoordIndex::new(&problem);
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, vec![], coord_index)
 }
 
-pub(super) fn map_to_problem(
+pub(super) fn map_to_problem_with_binary_matrices(
+    problem: ApiProblem,
+    binary_matrices: Vec<BinaryMatrix>,
+) -> Result<CoreProblem, MultiFormatError> {
+    let coord_index = CoordIndex::new(&problem);
+    // NOTE routing data is already decoded, so matrices keep only properties used by validation and reader
+    let matrices = binary_matrices
+        .iter()
+        .map(|matrix| Matrix {
+            profile: matrix.profile.clone(),
+            timestamp: matrix.timestamp.clone(),
+            travel_times: vec![],
+            distances: vec![],
+            error_codes: matrix.has_error_codes.then(Vec::new),
+            entries: None,
+            geometries: None,
+        })
+        .collect();
+
+    map_to_problem(problem, matrices, binary_matrices, coord_index)
+}
+
+fn map_to_problem(
     api_problem: ApiProblem,
     matrices: Vec<Matrix>,
+    binary_matrices: Vec<BinaryMatrix>,
     coord_index: CoordIndex,
 ) -> Result<CoreProblem, MultiFormatError> {
-    ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate()?;
+    let validation_ctx = ValidationContext::new(&api_problem, Some(&matrices), &coord_index);
+    let validation_ctx = match binary_matrices.first() {
+        Some(matrix) => validation_ctx.with_matrix_size(matrix.size),
+        None => validation_ctx,
+    };
+    validation_ctx.validate()?;
 
+    let api_problem = apply_planning_calendar(apply_problem_state(api_problem));
     let props = get_problem_properties(&api_problem, &matrices);
-    let blocks = get_problem_blocks(&api_problem, matrices, coord_index, &props)?;
+    let blocks = get_problem_blocks(&api_problem, matrices, binary_matrices, coord_index, &props)?;
 
     let goal = Arc::new(create_goal_context(&api_problem, &blocks, &props).map_err(|err| {
         vec![FormatError::new(
@@ -44,11 +77,29 @@ pub(super) fn map_to_problem(
         )]
     })?);
 
-    let ProblemBlocks { coord_index, job_index, jobs, fleet, transport, activity, locks, reserved_times_index } =
-        blocks;
+    let ProblemBlocks {
+        coord_index,
+        job_index,
+        jobs,
+        fleet,
+        transport,
+        activity,
+        locks,
+        reserved_times_index,
+        location_parking,
+        route_geometry,
+    } = blocks;
 
     let extras = Arc::new(
-        create_extras(&api_problem, job_index.clone(), coord_index.clone(), reserved_times_index).map_err(|err| {
+        create_extras(
+            &api_problem,
+            job_index.clone(),
+            coord_index.clone(),
+            reserved_times_index,
+            location_parking,
+            route_geometry,
+        )
+        .map_err(|err| {
             // TODO make sure that error matches actual reason
             vec![FormatError::new(
                 "E0002".to_string(),
@@ -113,21 +164,68 @@ fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> Res
         .collect()
 }
 
+fn read_location_parking_index(api_problem: &ApiProblem, coord_index: &CoordIndex) -> LocationParkingIndex {
+    api_problem.plan.jobs.iter().flat_map(get_job_tasks).flat_map(|job_task| job_task.places.iter()).fold(
+        LocationParkingIndex::default(),
+        |mut acc, place| {
+            let location = coord_index.get_by_loc(&place.location).unwrap();
+            let parking = acc.entry(location).or_insert(None);
+
+            // NOTE the longest parking time is used when jobs at the same location have different ones
+            if let Some(value) = place.parking {
+                *parking = Some(parking.map_or(value, |parking| parking.max(value)));
+            }
+
+            acc
+        },
+    )
+}
+
 fn create_extras(
     api_problem: &ApiProblem,
     job_index: Arc<JobIndex>,
     coord_index: Arc<CoordIndex>,
     reserved_times_index: ReservedTimesIndex,
+    location_parking: Option<Arc<LocationParkingIndex>>,
+    route_geometry: Option<Arc<dyn RouteGeometry + Send + Sync>>,
 ) -> Result<Extras, GenericError> {
     let mut extras = Extras::default();
 
     extras.insert("coord_index".to_owned(), coord_index.clone());
     extras.insert("job_index".to_owned(), job_index.clone());
 
+    if let Some(dimensions) = api_problem.fleet.dimensions.as_ref() {
+        extras.insert("capacity_dimensions".to_owned(), Arc::new(dimensions.clone()));
+    }
+
+    if let Some(state) = api_problem.state.as_ref() {
+        let completed_jobs = state
+            .vehicles
+            .iter()
+            .flat_map(|vehicle| vehicle.completed.iter().flatten().cloned())
+            .collect::<HashSet<String>>();
+        extras.insert("completed_jobs".to_owned(), Arc::new(completed_jobs));
+
+        let picked_up_jobs = state
+            .vehicles
+            .iter()
+            .flat_map(|vehicle| vehicle.picked_up.iter().flatten().cloned())
+            .collect::<HashSet<String>>();
+        extras.insert("picked_up_jobs".to_owned(), Arc::new(picked_up_jobs));
+    }
+
     if !reserved_times_index.is_empty() {
         extras.set_reserved_times(reserved_times_index);
     }
 
+    if let Some(location_parking) = location_parking {
+        extras.set_location_parking(location_parking);
+    }
+
+    if let Some(route_geometry) = route_geometry {
+        extras.set_route_geometry(route_geometry);
+    }
+
     if let Some(config) = create_cluster_config(api_problem)? {
         extras.set_cluster_config(config);
     }
@@ -137,14 +235,17 @@ fn create_extras(
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
     let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
-    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
-        || api_problem.plan.jobs.iter().any(|job| {
-            job.pickups
-                .iter()
-                .chain(job.deliveries.iter())
-                .flat_map(|tasks| tasks.iter())
-                .any(|task| task.demand.as_ref().map_or(false, |d| d.len() > 1))
-        });
+    let dimensions = api_problem.fleet.dimensions.as_deref();
+    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| {
+        t.capacity.len(dimensions) > 1
+            || t.compartments.iter().flatten().any(|compartment| compartment.capacity.len(dimensions) > 1)
+    }) || api_problem.plan.jobs.iter().any(|job| {
+        job.pickups
+            .iter()
+            .chain(job.deliveries.iter())
+            .flat_map(|tasks| tasks.iter())
+            .any(|task| task.demand.as_ref().is_some_and(|d| d.len(dimensions) > 1))
+    });
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
 
     let shift_has_fn = |shift_has: fn(&VehicleShift) -> bool| {
@@ -175,6 +276,45 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .iter()
         .any(|v| v.limits.as_ref().map_or(false, |l| l.max_duration.or(l.max_distance).is_some()));
 
+    let has_driving_rules =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().is_some_and(|l| l.driving_rules.is_some()));
+
+    let has_depot_capacities =
+        api_problem.fleet.resources.iter().flatten().any(|resource| matches!(resource, VehicleResource::Depot { .. }));
+
+    let has_time_window_tolerances = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(get_job_tasks)
+        .flat_map(|job_task| job_task.places.iter())
+        .any(|place| place.tolerance.is_some());
+
+    let has_service_durations = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(get_job_tasks)
+        .flat_map(|job_task| job_task.places.iter())
+        .any(|place| place.durations.as_ref().is_some_and(|durations| !durations.is_empty()));
+
+    let has_parking = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.profile.parking.is_some())
+        || api_problem
+            .plan
+            .jobs
+            .iter()
+            .flat_map(get_job_tasks)
+            .flat_map(|job_task| job_task.places.iter())
+            .any(|place| place.parking.is_some());
+
+    let has_max_ride_durations = api_problem.plan.jobs.iter().any(|job| job.max_ride_duration.is_some());
+
+    let has_compartments =
+        api_problem.fleet.vehicles.iter().any(|v| v.compartments.as_ref().is_some_and(|c| !c.is_empty()));
+
+    let has_calendar = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.calendar.is_some());
+    let has_frequency = api_problem.plan.jobs.iter().any(|job| job.frequency.is_some());
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
@@ -188,12 +328,22 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_compatibility,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_driving_rules,
+        has_depot_capacities,
+        has_time_window_tolerances,
+        has_service_durations,
+        has_parking,
+        has_max_ride_durations,
+        has_compartments,
+        has_calendar,
+        has_frequency,
     }
 }
 
 fn get_problem_blocks(
     api_problem: &ApiProblem,
     matrices: Vec<Matrix>,
+    binary_matrices: Vec<BinaryMatrix>,
     coord_index: CoordIndex,
     problem_props: &ProblemProperties,
 ) -> Result<ProblemBlocks, MultiFormatError> {
@@ -201,14 +351,35 @@ fn get_problem_blocks(
     let fleet = read_fleet(api_problem, problem_props, &coord_index);
     let reserved_times_index = read_reserved_times_index(api_problem, &fleet);
 
-    let transport = create_transport_costs(api_problem, &matrices, coord_index.clone()).map_err(|err| {
-        vec![FormatError::new(
-            "E0002".to_string(),
-            "cannot create transport costs".to_string(),
-            format!("check matrix routing data: '{err}'"),
-        )]
-    })?;
-    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(OnlyVehicleActivityCost::default());
+    let matrix_profiles = get_profile_index_map(api_problem);
+    let route_geometry =
+        MatrixRouteGeometry::new(&matrices, |matrix, idx| get_matrix_profile(matrix, idx, &matrix_profiles))
+            .map_err(|err| {
+                vec![FormatError::new(
+                    "E0002".to_string(),
+                    "cannot create transport costs".to_string(),
+                    format!("check matrix geometries: '{err}'"),
+                )]
+            })?
+            .map::<Arc<dyn RouteGeometry + Send + Sync>, _>(|geometry| Arc::new(geometry));
+
+    let transport =
+        create_transport_costs(api_problem, matrices, binary_matrices, coord_index.clone()).map_err(|err| {
+            vec![FormatError::new(
+                "E0002".to_string(),
+                "cannot create transport costs".to_string(),
+                format!("check matrix routing data: '{err}'"),
+            )]
+        })?;
+
+    let location_parking =
+        problem_props.has_parking.then(|| Arc::new(read_location_parking_index(api_problem, &coord_index)));
+    let transport: Arc<dyn TransportCost + Send + Sync> = match location_parking.as_ref() {
+        Some(location_parking) => Arc::new(ParkingTransportCost::new(transport, location_parking.clone())),
+        None => transport,
+    };
+
+    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(SimpleActivityCost::default());
 
     let (transport, activity) = if reserved_times_index.is_empty() {
         (transport, activity)
@@ -229,6 +400,15 @@ fn get_problem_blocks(
             )?
     };
 
+    let activity: Arc<dyn ActivityCost + Send + Sync> =
+        if problem_props.has_service_durations { Arc::new(TimeDependentActivityCost::new(activity)) } else { activity };
+
+    let activity: Arc<dyn ActivityCost + Send + Sync> = if problem_props.has_time_window_tolerances {
+        Arc::new(SoftTimeWindowActivityCost::new(activity))
+    } else {
+        activity
+    };
+
     // TODO pass random from outside as there might be need to have it initialized with seed
     //      at the moment, this random instance is used only by multi job permutation generator
     let random: Arc<dyn Random + Send + Sync> = Arc::new(DefaultRandom::default());
@@ -242,7 +422,14 @@ fn get_problem_blocks(
         &mut job_index,
         &random,
     );
-    let locks = locks.into_iter().chain(read_locks(api_problem, &job_index)).collect::<Vec<_>>();
+    let state_locks = read_state_locks(api_problem, &job_index).map_err(|err| {
+        vec![FormatError::new(
+            "E1701".to_string(),
+            "state has job id which does not present in the plan".to_string(),
+            format!("remove from state or add jobs to the plan: '{err}'"),
+        )]
+    })?;
+    let locks = locks.into_iter().chain(read_locks(api_problem, &job_index)).chain(state_locks).collect::<Vec<_>>();
 
     Ok(ProblemBlocks {
         coord_index,
@@ -253,5 +440,7 @@ fn get_problem_blocks(
         activity,
         locks,
         reserved_times_index,
+        location_parking,
+        route_geometry,
     })
 }
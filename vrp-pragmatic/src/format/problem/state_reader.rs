@@ -0,0 +1,97 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/state_reader_test.rs"]
+mod state_reader_test;
+
+use super::*;
+use crate::format::problem::job_reader::create_condition;
+use crate::format::JobIndex;
+use crate::validation::is_reserved_job_id;
+use hashbrown::HashSet;
+use vrp_core::models::{LockDetail, LockOrder, LockPosition};
+
+/// Applies a current plan state to the problem: completed jobs are removed from the plan and
+/// from its relations, served pickups are removed from picked up jobs.
+pub(crate) fn apply_problem_state(mut api_problem: ApiProblem) -> ApiProblem {
+    let Some(state) = api_problem.state.as_ref() else { return api_problem };
+
+    let completed =
+        state.vehicles.iter().flat_map(|vehicle| vehicle.completed.iter().flatten().cloned()).collect::<HashSet<_>>();
+    let picked_up =
+        state.vehicles.iter().flat_map(|vehicle| vehicle.picked_up.iter().flatten().cloned()).collect::<HashSet<_>>();
+
+    let plan = &mut api_problem.plan;
+
+    plan.jobs.retain(|job| !completed.contains(&job.id));
+
+    // NOTE a job with deliveries only has a static demand which is loaded at the tour start, so the goods
+    // which are already on board are part of the vehicle's initial load
+    plan.jobs.iter_mut().filter(|job| picked_up.contains(&job.id)).for_each(|job| job.pickups = None);
+
+    let relations = plan
+        .relations
+        .take()
+        .into_iter()
+        .flatten()
+        .filter_map(|mut relation| {
+            relation.jobs.retain(|job_id| !completed.contains(job_id));
+            // NOTE relation which has only reserved job ids left is not needed anymore
+            if relation.jobs.iter().all(|job_id| is_reserved_job_id(job_id)) {
+                None
+            } else {
+                Some(relation)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    plan.relations = if relations.is_empty() { None } else { Some(relations) };
+
+    api_problem
+}
+
+/// Reads locks which keep committed jobs right after vehicle departure in the given order and
+/// picked up jobs in the vehicle's tour.
+pub(super) fn read_state_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Result<Vec<Arc<Lock>>, GenericError> {
+    let get_jobs = |job_ids: &mut dyn Iterator<Item = &String>| {
+        job_ids
+            .map(|job_id| job_index.get(job_id).cloned().ok_or_else(|| format!("cannot find job with id: '{job_id}'")))
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    api_problem.state.iter().flat_map(|state| state.vehicles.iter()).try_fold(Vec::default(), |mut locks, vehicle| {
+        let committed = vehicle.committed.iter().flatten().collect::<HashSet<_>>();
+        let committed_jobs = get_jobs(&mut vehicle.committed.iter().flatten())?;
+        // NOTE committed jobs are already locked to the vehicle
+        let picked_up_jobs =
+            get_jobs(&mut vehicle.picked_up.iter().flatten().filter(|job_id| !committed.contains(job_id)))?;
+
+        let details = (!committed_jobs.is_empty())
+            .then(|| LockDetail::new(LockOrder::Strict, LockPosition::Departure, committed_jobs))
+            .into_iter()
+            .chain(
+                (!picked_up_jobs.is_empty())
+                    .then(|| LockDetail::new(LockOrder::Any, LockPosition::Any, picked_up_jobs)),
+            )
+            .collect::<Vec<_>>();
+
+        if !details.is_empty() {
+            let condition = create_condition(vehicle.vehicle_id.clone(), vehicle.shift_index.unwrap_or_default());
+            locks.push(Arc::new(Lock::new(condition, details, false)));
+        }
+
+        Ok(locks)
+    })
+}
+
+/// Returns a current state of the given vehicle shift, if it is specified.
+pub(super) fn get_vehicle_state<'a>(
+    api_problem: &'a ApiProblem,
+    vehicle_id: &str,
+    shift_index: usize,
+) -> Option<&'a VehicleState> {
+    api_problem.state.as_ref().and_then(|state| {
+        state
+            .vehicles
+            .iter()
+            .find(|vehicle| vehicle.vehicle_id == vehicle_id && vehicle.shift_index.unwrap_or(0) == shift_index)
+    })
+}
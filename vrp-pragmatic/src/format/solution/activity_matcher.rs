@@ -70,11 +70,30 @@ pub(crate) fn try_match_point_job(
             Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
         }
         "break" | "reload" | "recharge" => Ok(Some(
-            (1..)
-                .map(|idx| format!("{}_{}_{}_{}", tour.vehicle_id, activity.activity_type, tour.shift_index, idx))
-                .map(|job_id| job_index.get(&job_id))
-                .take_while(|job| job.is_some())
-                .filter_map(|job| job.and_then(|job| job.as_single().map(|s| (job.clone(), s.clone()))))
+            // NOTE driving breaks are reported as regular breaks
+            once(activity.activity_type.as_str())
+                .chain(Some("driving_break").filter(|_| activity.activity_type == "break"))
+                .flat_map(|job_type| {
+                    (1..)
+                        .map(move |idx| format!("{}_{}_{}_{}", tour.vehicle_id, job_type, tour.shift_index, idx))
+                        .map(|job_id| job_index.get(&job_id))
+                        .take_while(|job| job.is_some())
+                })
+                .flatten()
+                .flat_map(|job| {
+                    let singles: Box<dyn Iterator<Item = &Arc<_>>> = match job {
+                        Job::Single(single) => Box::new(once(single)),
+                        // NOTE parts of split driving break are distinguished by their duration
+                        Job::Multi(multi) => Box::new(multi.jobs.iter().filter(|single| {
+                            single
+                                .places
+                                .iter()
+                                .any(|place| compare_floats(place.duration, ctx.time.duration()) == Ordering::Equal)
+                        })),
+                    };
+
+                    singles.map(move |single| (job.clone(), single.clone()))
+                })
                 .filter_map(|(job, single)| {
                     match_place(&single, false, &ctx).map(|place| JobInfo(job, single, place, ctx.time.clone()))
                 })
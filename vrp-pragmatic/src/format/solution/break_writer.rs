@@ -61,7 +61,8 @@ pub(super) fn insert_reserved_times_as_breaks(
             }
 
             let break_time = reserved_time.duration as i64;
-            let break_cost = break_time as f64 * route.actor.vehicle.costs.per_service_time;
+            let break_cost = break_time as f64
+                * (route.actor.driver.costs.per_service_time + route.actor.vehicle.costs.per_service_time);
 
             for (stop_idx, stop) in tour.stops.iter_mut().enumerate() {
                 let stop_tw =
@@ -137,6 +138,7 @@ fn insert_break(
             time: Some(Interval { start: format_time(activity_time.start), end: format_time(activity_time.end) }),
             job_tag: None,
             commute: None,
+            compartment: None,
         },
     );
 
@@ -163,6 +165,6 @@ fn insert_break(
 
 #[derive(Clone)]
 enum BreakInsertion {
-    TransitBreakUsed { leg_idx: usize, load: Vec<i32> },
+    TransitBreakUsed { leg_idx: usize, load: LoadAmount },
     TransitBreakMoved { leg_idx: usize, break_tw: TimeWindow },
 }
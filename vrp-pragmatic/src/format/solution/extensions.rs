@@ -1,4 +1,4 @@
-use crate::format::solution::{Statistic, Timing};
+use crate::format::solution::{DayStatistic, Statistic, Timing};
 use std::ops::Add;
 
 impl Add for Statistic {
@@ -17,6 +17,32 @@ impl Add for Statistic {
                 commuting: self.times.commuting + rhs.times.commuting,
                 parking: self.times.parking + rhs.times.parking,
             },
+            tardiness: match (self.tardiness, rhs.tardiness) {
+                (Some(left), Some(right)) => Some(left + right),
+                (left, right) => left.or(right),
+            },
+            days: match (self.days, rhs.days) {
+                (Some(left), Some(right)) => Some(merge_days(left, right)),
+                (left, right) => left.or(right),
+            },
         }
     }
 }
+
+/// Merges per-day statistics summing values of the same day.
+fn merge_days(left: Vec<DayStatistic>, right: Vec<DayStatistic>) -> Vec<DayStatistic> {
+    let mut days = left;
+
+    right.into_iter().for_each(|rhs| match days.iter_mut().find(|lhs| lhs.day == rhs.day) {
+        Some(lhs) => {
+            lhs.tours += rhs.tours;
+            lhs.cost += rhs.cost;
+            lhs.distance += rhs.distance;
+            lhs.duration += rhs.duration;
+        }
+        None => days.push(rhs),
+    });
+    days.sort_by_key(|day| day.day);
+
+    days
+}
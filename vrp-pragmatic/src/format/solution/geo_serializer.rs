@@ -3,8 +3,11 @@
 mod geo_serializer_test;
 
 use super::Solution;
+use crate::construction::enablers::VehicleTie;
 use crate::format::solution::{Activity, PointStop, Tour, UnassignedJob};
-use crate::format::{get_coord_index, get_job_index, CoordIndex, CustomLocationType, Location};
+use crate::format::{get_coord_index, get_job_index, CoordIndex, CustomLocationType, Location, RouteGeometryDimension};
+use crate::parse_time;
+use hashbrown::HashMap;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
@@ -106,6 +109,40 @@ pub fn serialize_named_locations_as_geojson<W: Write>(
     serde_json::to_writer_pretty(writer, &geo_json).map_err(Error::from)
 }
 
+/// Serializes multiple solutions of the same problem as a single geo json overlay: tours of each
+/// solution are drawn with their own color and named locations are added on top of them.
+pub fn serialize_solutions_overlay_as_geojson<W: Write>(
+    solutions: &[(&str, &Solution)],
+    locations: &[(String, Location, usize)],
+    writer: &mut BufWriter<W>,
+) -> Result<(), Error> {
+    let geo_json = create_geojson_solutions_overlay(solutions, locations)?;
+
+    serde_json::to_writer_pretty(writer, &geo_json).map_err(Error::from)
+}
+
+fn create_geojson_solutions_overlay(
+    solutions: &[(&str, &Solution)],
+    locations: &[(String, Location, usize)],
+) -> Result<FeatureCollection, Error> {
+    let tour_lines = solutions
+        .iter()
+        .enumerate()
+        .flat_map(|(solution_idx, (name, solution))| {
+            solution.tours.iter().enumerate().map(move |(tour_idx, tour)| {
+                get_tour_line(tour_idx, tour, get_color(solution_idx).as_str()).map(|mut feature| {
+                    feature.properties.insert("solution".to_string(), name.to_string());
+                    feature
+                })
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let named_locations = create_geojson_named_locations(locations)?;
+
+    Ok(FeatureCollection { features: tour_lines.into_iter().chain(named_locations.features).collect() })
+}
+
 fn create_geojson_named_locations(locations: &[(String, Location, usize)]) -> Result<FeatureCollection, Error> {
     let colors = get_more_colors();
 
@@ -288,6 +325,64 @@ fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Result<Feature, E
     })
 }
 
+fn get_tour_legs(problem: &Problem, tour_idx: usize, tour: &Tour, color: &str) -> Result<Vec<Feature>, Error> {
+    let coord_index = get_coord_index(problem);
+    let route_geometry = problem.extras.get_route_geometry();
+    let profile = problem
+        .fleet
+        .vehicles
+        .iter()
+        .find(|vehicle| vehicle.dimens.get_vehicle_id() == Some(&tour.vehicle_id))
+        .map(|vehicle| vehicle.profile.index);
+
+    let stops = tour
+        .stops
+        .iter()
+        .enumerate()
+        .filter_map(|(stop_idx, stop)| stop.as_point().map(|stop| (stop_idx, stop)))
+        .collect::<Vec<_>>();
+
+    stops
+        .windows(2)
+        .map(|pair| (pair[0], pair[1]))
+        .filter(|((_, from), (_, to))| from.location != to.location)
+        .enumerate()
+        .map(|(leg_idx, ((from_idx, from), (to_idx, to)))| {
+            let geometry = route_geometry.zip(profile).and_then(|(route_geometry, profile)| {
+                let from_location = coord_index.get_by_loc(&from.location)?;
+                let to_location = coord_index.get_by_loc(&to.location)?;
+                route_geometry.get_geometry(profile, from_location, to_location)
+            });
+
+            // NOTE use straight line when route geometry is unknown
+            let coordinates = match geometry {
+                Some(geometry) => geometry.into_iter().map(|(lat, lng)| (lng, lat)).collect(),
+                None => vec![get_lng_lat(&from.location)?, get_lng_lat(&to.location)?],
+            };
+            let duration = parse_time(&to.time.arrival) - parse_time(&from.time.departure);
+
+            Ok(Feature {
+                properties: slice_to_map(&[
+                    ("vehicle_id", tour.vehicle_id.as_str()),
+                    ("type_id", tour.type_id.as_str()),
+                    ("tour_idx", tour_idx.to_string().as_str()),
+                    ("shift_idx", tour.shift_index.to_string().as_str()),
+                    ("leg_idx", leg_idx.to_string().as_str()),
+                    ("from_stop_idx", from_idx.to_string().as_str()),
+                    ("to_stop_idx", to_idx.to_string().as_str()),
+                    ("departure", from.time.departure.as_str()),
+                    ("arrival", to.time.arrival.as_str()),
+                    ("distance", (to.distance - from.distance).to_string().as_str()),
+                    ("duration", (duration.round() as i64).to_string().as_str()),
+                    ("stroke-width", "4"),
+                    ("stroke", color),
+                ]),
+                geometry: Geometry::LineString { coordinates },
+            })
+        })
+        .collect()
+}
+
 /// Creates solution as geo json.
 pub(crate) fn create_feature_collection(problem: &Problem, solution: &Solution) -> Result<FeatureCollection, Error> {
     let stop_markers = solution
@@ -321,12 +416,26 @@ pub(crate) fn create_feature_collection(problem: &Problem, solution: &Solution)
         .into_iter()
         .flatten();
 
+    // NOTE tour legs are colored by vehicle type
+    let type_indices = problem.fleet.vehicles.iter().filter_map(|vehicle| vehicle.dimens.get_vehicle_type()).fold(
+        HashMap::<&String, usize>::default(),
+        |mut acc, type_id| {
+            let type_idx = acc.len();
+            acc.entry(type_id).or_insert(type_idx);
+            acc
+        },
+    );
     let stop_lines = solution
         .tours
         .iter()
         .enumerate()
-        .map(|(tour_idx, tour)| get_tour_line(tour_idx, tour, get_color(tour_idx).as_str()))
-        .collect::<Result<Vec<_>, _>>()?;
+        .map(|(tour_idx, tour)| {
+            let color = get_color(type_indices.get(&tour.type_id).copied().unwrap_or(tour_idx));
+            get_tour_legs(problem, tour_idx, tour, color.as_str())
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten();
 
     let job_index = get_job_index(problem);
     let coord_index = get_coord_index(problem);
@@ -2,25 +2,26 @@
 #[path = "../../../tests/unit/format/solution/initial_reader_test.rs"]
 mod initial_reader_test;
 
-use crate::construction::enablers::{JobTie, VehicleTie};
+use crate::construction::enablers::{DriverTie, JobTie, VehicleTie};
 use crate::format::solution::activity_matcher::{try_match_point_job, JobInfo};
 use crate::format::solution::Activity as FormatActivity;
+use crate::format::solution::Solution as FormatSolution;
 use crate::format::solution::Stop as FormatStop;
 use crate::format::solution::Tour as FormatTour;
-use crate::format::solution::{deserialize_solution, map_reason_code};
-use crate::format::{get_coord_index, get_job_index, CoordIndex, JobIndex};
+use crate::format::solution::{deserialize_solution, deserialize_solutions, map_reason_code};
+use crate::format::{get_completed_jobs, get_coord_index, get_job_index, get_picked_up_jobs, CoordIndex, JobIndex};
 use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Job};
+use vrp_core::models::problem::{Actor, Job, VehiclePlace};
 use vrp_core::models::solution::Tour as CoreTour;
 use vrp_core::models::solution::{Activity, Registry, Route};
 use vrp_core::prelude::*;
 
-type ActorKey = (String, String, usize);
+type ActorKey = (String, String, usize, Option<String>);
 
 /// Reads initial solution from buffer.
 /// NOTE: Solution feasibility is not checked.
@@ -31,26 +32,71 @@ pub fn read_init_solution<R: Read>(
 ) -> Result<Solution, GenericError> {
     let solution = deserialize_solution(solution).map_err(|err| format!("cannot deserialize solution: {err}"))?;
 
+    map_init_solution(solution, problem, random)
+}
+
+/// Reads one or many initial solutions from buffer, e.g. a population of the previous run
+/// written as newline-delimited json.
+/// NOTE: Solution feasibility is not checked.
+pub fn read_init_solutions<R: Read>(
+    solutions: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Vec<Solution>, GenericError> {
+    deserialize_solutions(solutions)
+        .map_err(|err| format!("cannot deserialize solutions: {err}"))?
+        .into_iter()
+        .map(|solution| map_init_solution(solution, problem.clone(), random.clone()))
+        .collect()
+}
+
+fn map_init_solution(
+    solution: FormatSolution,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Solution, GenericError> {
     let mut registry = Registry::new(&problem.fleet, random);
     let mut added_jobs = HashSet::default();
 
-    let actor_index = registry.all().map(|actor| (get_actor_key(actor.as_ref()), actor)).collect::<HashMap<_, _>>();
+    let actor_index = registry.all().fold(HashMap::<_, Vec<_>>::default(), |mut acc, actor| {
+        acc.entry(get_actor_key(actor.as_ref())).or_default().push(actor);
+        acc
+    });
     let coord_index = get_coord_index(problem.as_ref());
     let job_index = get_job_index(problem.as_ref());
+    let completed_jobs = get_completed_jobs(problem.as_ref());
+    let picked_up_jobs = get_picked_up_jobs(problem.as_ref());
 
     let routes =
         solution.tours.iter().try_fold::<_, _, Result<_, GenericError>>(Vec::<_>::default(), |mut routes, tour| {
-            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
-            let actor =
-                actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"))?.clone();
+            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index, tour.driver_id.clone());
+            let actor = find_actor(actor_index.get(&actor_key).map(|actors| actors.as_slice()), tour, coord_index)
+                .ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"))?;
             registry.use_actor(&actor);
 
             let mut core_route = create_core_route(actor, tour)?;
 
             tour.stops.iter().try_for_each(|stop| {
-                stop.activities().iter().try_for_each::<_, Result<_, GenericError>>(|activity| {
-                    try_insert_activity(&mut core_route, tour, stop, activity, job_index, coord_index, &mut added_jobs)
-                })
+                // NOTE completed jobs and served pickups are removed from the problem, so they are skipped
+                //      in the previous plan
+                stop.activities()
+                    .iter()
+                    .filter(|activity| !completed_jobs.is_some_and(|completed| completed.contains(&activity.job_id)))
+                    .filter(|activity| {
+                        activity.activity_type != "pickup"
+                            || !picked_up_jobs.is_some_and(|picked_up| picked_up.contains(&activity.job_id))
+                    })
+                    .try_for_each::<_, Result<_, GenericError>>(|activity| {
+                        try_insert_activity(
+                            &mut core_route,
+                            tour,
+                            stop,
+                            activity,
+                            job_index,
+                            coord_index,
+                            &mut added_jobs,
+                        )
+                    })
             })?;
 
             routes.push(core_route);
@@ -138,8 +184,36 @@ fn get_actor_key(actor: &Actor) -> ActorKey {
     let vehicle_id = dimens.get_vehicle_id().cloned().expect("cannot get vehicle id!");
     let type_id = dimens.get_vehicle_type().cloned().expect("cannot get type id!");
     let shift_index = dimens.get_shift_index().expect("cannot get shift index!");
+    let driver_id = actor.driver.dimens.get_driver_id().cloned();
+
+    (vehicle_id, type_id, shift_index, driver_id)
+}
+
+fn find_actor(actors: Option<&[Arc<Actor>]>, tour: &FormatTour, coord_index: &CoordIndex) -> Option<Arc<Actor>> {
+    // NOTE the same vehicle shift can be paired with different shifts of the same driver
+    let departure = tour.stops.first().map(|stop| parse_time(&stop.schedule().departure));
+
+    // NOTE alternative depots of the same shift are distinguished by tour start and end locations
+    let get_location =
+        |stop: Option<&FormatStop>| stop.and_then(|stop| stop.location()).and_then(|l| coord_index.get_by_loc(l));
+    let (start, end) = (get_location(tour.stops.first()), get_location(tour.stops.last()));
+    let is_same_place = |place: Option<&VehiclePlace>, location: Option<Location>| match (place, location) {
+        (Some(place), Some(location)) => place.location == location,
+        _ => true,
+    };
+    let is_same_depot = |actor: &&Arc<Actor>| {
+        is_same_place(actor.detail.start.as_ref(), start) && is_same_place(actor.detail.end.as_ref(), end)
+    };
 
-    (vehicle_id, type_id, shift_index)
+    actors.and_then(|actors| {
+        departure
+            .and_then(|departure| {
+                actors.iter().filter(is_same_depot).find(|actor| actor.detail.time.contains(departure))
+            })
+            .or_else(|| actors.iter().find(is_same_depot))
+            .or_else(|| actors.first())
+            .cloned()
+    })
 }
 
 fn create_core_route(actor: Arc<Actor>, format_tour: &FormatTour) -> Result<Route, GenericError> {
@@ -168,6 +242,11 @@ fn create_core_route(actor: Arc<Actor>, format_tour: &FormatTour) -> Result<Rout
 
     set_activity_time(start_stop, start_activity, core_start)?;
 
+    // NOTE vehicle cannot depart earlier than allowed, e.g. when its shift start is overridden by the state
+    if let Some(earliest) = actor.detail.start.as_ref().and_then(|start| start.time.earliest) {
+        core_start.schedule.departure = core_start.schedule.departure.max(earliest);
+    }
+
     if core_tour.end().is_some() {
         let end_stop = format_tour.stops.last().unwrap();
         let end_activity = end_stop.activities().first().ok_or_else(|| "end stop has no activities".to_string())?;
@@ -11,7 +11,7 @@ mod geo_serializer;
 pub use self::geo_serializer::*;
 
 mod initial_reader;
-pub use self::initial_reader::read_init_solution;
+pub use self::initial_reader::{read_init_solution, read_init_solutions};
 
 mod model;
 pub use self::model::*;
@@ -22,7 +22,9 @@ pub(crate) use self::solution_writer::create_solution;
 use super::*;
 use crate::{format_time, parse_time};
 use std::io::{BufWriter, Write};
+use std::sync::Arc;
 use vrp_core::prelude::GenericError;
+use vrp_core::solver::BestSolutionCallback;
 
 type ApiActivity = model::Activity;
 type ApiSolution = model::Solution;
@@ -74,6 +76,32 @@ pub fn write_pragmatic<W: Write>(
     Ok(())
 }
 
+/// Writes solutions in pragmatic format as newline-delimited json, e.g. to reuse them later as initial solutions.
+pub fn write_pragmatic_solutions<W: Write>(
+    problem: &DomainProblem,
+    solutions: &[DomainSolution],
+    writer: &mut BufWriter<W>,
+) -> Result<(), GenericError> {
+    solutions.iter().try_for_each(|solution| {
+        let solution = create_solution(problem, solution, &PragmaticOutputType::OnlyPragmatic);
+
+        serde_json::to_writer(&mut *writer, &solution)
+            .map_err(std::io::Error::from)
+            .and_then(|_| writeln!(writer))
+            .map_err(|err| err.to_string().into())
+    })
+}
+
+/// Creates a solver callback which receives each new best known solution converted to pragmatic format.
+pub fn create_best_solution_callback<F>(problem: Arc<DomainProblem>, callback: F) -> BestSolutionCallback
+where
+    F: Fn(&ApiSolution) + Send + Sync + 'static,
+{
+    Arc::new(move |solution: &DomainSolution| {
+        callback(&create_solution(problem.as_ref(), solution, &PragmaticOutputType::OnlyPragmatic))
+    })
+}
+
 fn map_code_reason(code: i32) -> (&'static str, &'static str) {
     match code {
         SKILL_CONSTRAINT_CODE => ("SKILL_CONSTRAINT", "cannot serve required skill"),
@@ -101,6 +129,24 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
         RECHARGE_CONSTRAINT_CODE => ("RECHARGE_CONSTRAINT_CODE", "cannot be assigned due to recharge constraint"),
+        MAX_RIDE_DURATION_CONSTRAINT_CODE => {
+            ("MAX_RIDE_DURATION_CONSTRAINT", "cannot be assigned due to max ride duration constraint")
+        }
+        DRIVING_RULES_CONSTRAINT_CODE => {
+            ("DRIVING_RULES_CONSTRAINT", "cannot be assigned due to driving rules constraint")
+        }
+        DEPOT_CAPACITY_CONSTRAINT_CODE => {
+            ("DEPOT_CAPACITY_CONSTRAINT", "cannot be assigned due to depot capacity constraint")
+        }
+        COMPARTMENT_CONSTRAINT_CODE => {
+            ("COMPARTMENT_CONSTRAINT", "does not fit into any compatible vehicle compartment")
+        }
+        SHIFT_OVERLAP_CONSTRAINT_CODE => {
+            ("SHIFT_OVERLAP_CONSTRAINT", "cannot be assigned due to overlap with another shift of the same vehicle")
+        }
+        PERIODIC_CONSTRAINT_CODE => {
+            ("PERIODIC_CONSTRAINT", "cannot be scheduled on a day which satisfies visit frequency spacing or patterns")
+        }
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -122,6 +168,12 @@ fn map_reason_code(reason: &str) -> i32 {
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
         "RECHARGE_CONSTRAINT_CODE" => RECHARGE_CONSTRAINT_CODE,
+        "MAX_RIDE_DURATION_CONSTRAINT" => MAX_RIDE_DURATION_CONSTRAINT_CODE,
+        "DRIVING_RULES_CONSTRAINT" => DRIVING_RULES_CONSTRAINT_CODE,
+        "DEPOT_CAPACITY_CONSTRAINT" => DEPOT_CAPACITY_CONSTRAINT_CODE,
+        "COMPARTMENT_CONSTRAINT" => COMPARTMENT_CONSTRAINT_CODE,
+        "SHIFT_OVERLAP_CONSTRAINT" => SHIFT_OVERLAP_CONSTRAINT_CODE,
+        "PERIODIC_CONSTRAINT" => PERIODIC_CONSTRAINT_CODE,
         _ => -1,
     }
 }
@@ -1,5 +1,5 @@
 use super::FeatureCollection;
-use crate::format::{CoordIndex, Location};
+use crate::format::{CoordIndex, LoadAmount, Location};
 use crate::{format_time, parse_time};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
@@ -38,6 +38,27 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Total tardiness: a sum of time window lateness of all activities with tolerated lateness.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tardiness: Option<i64>,
+    /// Per-day breakdown of the statistic, present only in multi-day planning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<Vec<DayStatistic>>,
+}
+
+/// Represents statistic of tours performed on the same day.
+#[derive(Clone, Deserialize, Default, Serialize, PartialEq, Debug)]
+pub struct DayStatistic {
+    /// Day index.
+    pub day: usize,
+    /// Amount of tours.
+    pub tours: usize,
+    /// Total cost.
+    pub cost: f64,
+    /// Total distance.
+    pub distance: i64,
+    /// Total duration.
+    pub duration: i64,
 }
 
 /// Represents a schedule.
@@ -101,6 +122,9 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// Vehicle compartment where job's demand is loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartment: Option<String>,
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
@@ -131,7 +155,7 @@ impl Stop {
     }
 
     /// Returns stop's load.
-    pub fn load(&self) -> &Vec<i32> {
+    pub fn load(&self) -> &LoadAmount {
         match self {
             Self::Transit(transit) => &transit.load,
             Self::Point(point) => &point.load,
@@ -139,7 +163,7 @@ impl Stop {
     }
 
     /// Returns stop's load as mutable.
-    pub fn load_mut(&mut self) -> &mut Vec<i32> {
+    pub fn load_mut(&mut self) -> &mut LoadAmount {
         match self {
             Self::Transit(transit) => &mut transit.load,
             Self::Point(point) => &mut point.load,
@@ -193,7 +217,7 @@ pub struct TransitStop {
     /// Stop schedule.
     pub time: Schedule,
     /// Vehicle load after departure from this stop.
-    pub load: Vec<i32>,
+    pub load: LoadAmount,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
 }
@@ -208,7 +232,7 @@ pub struct PointStop {
     /// Distance traveled since departure from start.
     pub distance: i64,
     /// Vehicle load after departure from this stop.
-    pub load: Vec<i32>,
+    pub load: LoadAmount,
     /// Parking time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parking: Option<Interval>,
@@ -227,6 +251,12 @@ pub struct Tour {
     /// Shift index.
     #[serde(default)]
     pub shift_index: usize,
+    /// Driver id, present only when drivers are specified in the fleet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_id: Option<String>,
+    /// Day index, present only when vehicle has availability calendar.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day: Option<usize>,
     /// List of stops.
     pub stops: Vec<Stop>,
     /// Tour statistic.
@@ -291,6 +321,9 @@ pub struct Metrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<Generation>,
+    /// A seed used to initialize random generator, if it was specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
 }
 
 /// Represents information about generation.
@@ -319,6 +352,9 @@ pub struct Individual {
     pub difference: f64,
     /// Objectives fitness values.
     pub fitness: Vec<f64>,
+    /// Solution gap (in percent) from target fitness, e.g. of the best known solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gap: Option<f64>,
 }
 
 /// Holds population state.
@@ -374,6 +410,12 @@ pub fn deserialize_solution<R: Read>(reader: BufReader<R>) -> Result<Solution, E
     serde_json::from_reader(reader).map_err(Error::from)
 }
 
+/// Deserializes one or many solutions in json format from `BufReader`. Multiple solutions are
+/// expected to follow each other, e.g. as in newline-delimited json.
+pub fn deserialize_solutions<R: Read>(reader: BufReader<R>) -> Result<Vec<Solution>, Error> {
+    serde_json::Deserializer::from_reader(reader).into_iter::<Solution>().collect::<Result<_, _>>().map_err(Error::from)
+}
+
 impl Interval {
     /// Returns interval's duration.
     pub fn duration(&self) -> Duration {
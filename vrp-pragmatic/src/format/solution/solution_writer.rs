@@ -2,14 +2,20 @@
 #[path = "../../../tests/unit/format/solution/writer_test.rs"]
 mod writer_test;
 
-use crate::construction::enablers::{JobTie, VehicleTie};
+use crate::construction::enablers::{DriverTie, JobTie, VehicleTie};
 use crate::format::coord_index::CoordIndex;
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::*;
-use vrp_core::construction::enablers::{get_route_intervals, ReservedTimesIndex};
-use vrp_core::construction::heuristics::UnassignmentInfo;
+use vrp_core::construction::enablers::{
+    get_location_parking_time, get_route_intervals, get_service_duration, get_time_window_deviation,
+    get_time_window_service_start, LocationParkingDimension, ReservedTimesIndex,
+};
+use vrp_core::construction::features::{
+    Compartment, VehicleCompartmentsDimension, VehicleDayDimension, COMPARTMENT_ASSIGNMENT_KEY,
+};
+use vrp_core::construction::heuristics::{RouteContext, RouteState, UnassignmentInfo};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Multi, TravelTime};
 use vrp_core::models::solution::{Activity, Route};
@@ -50,6 +56,7 @@ pub(crate) fn create_solution(
         .collect::<Vec<Tour>>();
 
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
+    let statistic = Statistic { days: create_day_statistic(tours.as_slice()), ..statistic };
 
     let unassigned = create_unassigned(solution);
     let violations = create_violations(solution);
@@ -70,17 +77,23 @@ fn create_tour(
     // TODO reduce complexity
 
     let is_multi_dimen = has_multi_dim_demand(problem);
+    let dimensions = get_capacity_dimensions(problem);
     let parking = get_parking_time(problem.extras.as_ref());
+    let location_parking = problem.extras.get_location_parking();
+    let compartments = get_compartment_ids(problem, route);
 
     let actor = route.actor.as_ref();
     let vehicle = actor.vehicle.as_ref();
+    let driver = actor.driver.as_ref();
     let transport = problem.transport.as_ref();
 
     let mut tour = Tour {
         vehicle_id: vehicle.dimens.get_vehicle_id().unwrap().clone(),
         type_id: vehicle.dimens.get_vehicle_type().unwrap().clone(),
         shift_index: vehicle.dimens.get_shift_index().unwrap(),
+        driver_id: actor.driver.dimens.get_driver_id().cloned(),
         stops: vec![],
+        day: vehicle.dimens.get_vehicle_day(),
         statistic: Statistic::default(),
     };
 
@@ -107,7 +120,7 @@ fn create_tour(
             tour.stops.push(Stop::Point(PointStop {
                 location: coord_index.get_by_idx(start.place.location).unwrap(),
                 time: format_schedule(&start.schedule),
-                load: start_delivery.as_vec(),
+                load: LoadAmount::from_vec(start_delivery.as_vec(), dimensions),
                 distance: 0,
                 activities: vec![ApiActivity {
                     job_id: "departure".to_string(),
@@ -123,6 +136,7 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    compartment: None,
                 }],
                 parking: None,
             }));
@@ -134,13 +148,17 @@ fn create_tour(
         let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
             Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
             |leg, act| {
-                let activity_type = get_activity_type(act).cloned();
+                // NOTE driving break and its parts are reported as a regular break
+                let activity_type = get_activity_type(act).map(|t| match t.as_str() {
+                    "driving_break" | "driving_break_part" => "break".to_string(),
+                    _ => t.clone(),
+                });
                 let (prev_location, prev_departure) = leg.last_detail.unwrap();
                 let prev_load = if activity_type.is_some() {
                     leg.load.unwrap()
                 } else {
                     // NOTE arrival must have zero load
-                    let dimen_size = leg.load.unwrap().size;
+                    let dimen_size = leg.load.unwrap().size();
                     MultiDimLoad::new(vec![0; dimen_size])
                 };
 
@@ -163,15 +181,18 @@ fn create_tour(
                 let commute = act.commute.clone().unwrap_or_default();
                 let commuting = commute.duration();
 
-                let (driving, transport_cost) = if commute.is_zero_distance() {
+                let (driving, location_parking, transport_cost) = if commute.is_zero_distance() {
                     // NOTE: use original cost traits to adapt time-based costs (except waiting/commuting)
                     let prev_departure = TravelTime::Departure(prev_departure);
                     let duration = transport.duration(route, prev_location, act.place.location, prev_departure);
                     let transport_cost = transport.cost(route, prev_location, act.place.location, prev_departure);
-                    (duration, transport_cost)
+                    // NOTE: transport duration includes parking time at job location
+                    let location_parking = location_parking
+                        .map_or(0., |index| get_location_parking_time(index, actor, prev_location, act.place.location));
+                    (duration - location_parking, location_parking, transport_cost)
                 } else {
                     // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
-                    (0., commuting * vehicle.costs.per_service_time)
+                    (0., 0., commuting * (driver.costs.per_service_time + vehicle.costs.per_service_time))
                 };
 
                 // NOTE two clusters at the same stop location
@@ -182,15 +203,18 @@ fn create_tour(
                     };
 
                 let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
-                let service_start = activity_arrival.max(act.place.time.start);
+                let service_start = get_time_window_service_start(route, act, activity_arrival);
                 let waiting = service_start - activity_arrival;
-                let serving = act.place.duration - parking;
+                let serving = get_service_duration(act, service_start) - parking;
                 let service_end = service_start + serving;
                 let activity_departure = service_end;
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
+                let tardiness = get_time_window_deviation(act, service_start).map(|(_, lateness)| lateness as i64);
+                let total_cost = serving_cost
+                    + transport_cost
+                    + waiting * (driver.costs.per_waiting_time + vehicle.costs.per_waiting_time);
 
                 let location_distance =
                     transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
@@ -206,14 +230,22 @@ fn create_tour(
                 if is_new_stop {
                     tour.stops.push(Stop::Point(PointStop {
                         location: coord_index.get_by_idx(act.place.location).unwrap(),
-                        time: format_schedule(&act.schedule),
-                        load: prev_load.as_vec(),
+                        time: format_schedule(&DomainSchedule::new(
+                            act.schedule.arrival - location_parking,
+                            act.schedule.departure,
+                        )),
+                        load: LoadAmount::from_vec(prev_load.as_vec(), dimensions),
                         distance,
                         parking: if parking > 0. {
                             Some(Interval {
                                 start: format_time(act.schedule.arrival),
                                 end: format_time(act.schedule.arrival + parking),
                             })
+                        } else if location_parking > 0. {
+                            Some(Interval {
+                                start: format_time(act.schedule.arrival - location_parking),
+                                end: format_time(act.schedule.arrival),
+                            })
                         } else {
                             None
                         },
@@ -230,7 +262,7 @@ fn create_tour(
                 };
 
                 last.time.departure = format_time(act.schedule.departure);
-                last.load = load.as_vec();
+                last.load = LoadAmount::from_vec(load.as_vec(), dimensions);
                 last.activities.push(ApiActivity {
                     job_id,
                     activity_type: activity_type.clone(),
@@ -244,6 +276,7 @@ fn create_tour(
                         .commute
                         .as_ref()
                         .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    compartment: act.retrieve_job().and_then(|job| compartments.get(&job)).cloned(),
                 });
 
                 // NOTE detect when vehicle returns after activity to stop point
@@ -269,8 +302,12 @@ fn create_tour(
                             waiting: leg.statistic.times.waiting + waiting as i64,
                             break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
                             commuting: leg.statistic.times.commuting + commuting as i64,
-                            parking: leg.statistic.times.parking + parking as i64,
+                            parking: leg.statistic.times.parking + (parking + location_parking) as i64,
                         },
+                        tardiness: tardiness
+                            .map(|tardiness| leg.statistic.tardiness.unwrap_or_default() + tardiness)
+                            .or(leg.statistic.tardiness),
+                        days: None,
                     },
                     load: Some(load),
                 }
@@ -282,7 +319,7 @@ fn create_tour(
         leg
     });
 
-    leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.cost += driver.costs.fixed + vehicle.costs.fixed;
     tour.statistic = leg.statistic;
 
     insert_reserved_times_as_breaks(route, &mut tour, reserved_times_index);
@@ -315,6 +352,29 @@ fn create_tour(
     tour
 }
 
+fn create_day_statistic(tours: &[Tour]) -> Option<Vec<DayStatistic>> {
+    let mut days = tours
+        .iter()
+        .filter_map(|tour| tour.day.map(|day| (day, &tour.statistic)))
+        .collect_group_by_key(|(day, _)| *day)
+        .into_iter()
+        .map(|(day, statistics)| DayStatistic {
+            day,
+            tours: statistics.len(),
+            cost: statistics.iter().map(|(_, statistic)| statistic.cost).sum(),
+            distance: statistics.iter().map(|(_, statistic)| statistic.distance).sum(),
+            duration: statistics.iter().map(|(_, statistic)| statistic.duration).sum(),
+        })
+        .collect::<Vec<_>>();
+
+    if days.is_empty() {
+        None
+    } else {
+        days.sort_by_key(|day| day.day);
+        Some(days)
+    }
+}
+
 fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
     ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
 }
@@ -425,6 +485,29 @@ fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<Mult
     }
 }
 
+fn get_compartment_ids(problem: &DomainProblem, route: &Route) -> HashMap<CoreJob, String> {
+    fn get_ids<T: LoadOps>(route_ctx: &RouteContext) -> Option<HashMap<CoreJob, String>> {
+        let compartments: &Vec<Compartment<T>> = route_ctx.route().actor.vehicle.dimens.get_vehicle_compartments()?;
+        let assignment = route_ctx.state().get_route_state::<HashMap<CoreJob, usize>>(COMPARTMENT_ASSIGNMENT_KEY)?;
+
+        Some(assignment.iter().map(|(job, idx)| (job.clone(), compartments[*idx].id.clone())).collect())
+    }
+
+    let dimens = &route.actor.vehicle.dimens;
+    let multi_dim: Option<&Vec<Compartment<MultiDimLoad>>> = dimens.get_vehicle_compartments();
+    let single_dim: Option<&Vec<Compartment<SingleDimLoad>>> = dimens.get_vehicle_compartments();
+    if multi_dim.is_none() && single_dim.is_none() {
+        return HashMap::default();
+    }
+
+    // NOTE assignment is kept in the route state by the compartments feature, so it is the same one
+    //      which is used by the feature's constraint
+    let mut route_ctx = RouteContext::new_with_state(route.deep_copy(), RouteState::default());
+    problem.goal.accept_route_state(&mut route_ctx);
+
+    get_ids::<MultiDimLoad>(&route_ctx).or_else(|| get_ids::<SingleDimLoad>(&route_ctx)).unwrap_or_default()
+}
+
 fn get_parking_time(extras: &DomainExtras) -> f64 {
     extras.get_cluster_config().map_or(0., |config| config.serving.get_parking())
 }
@@ -469,10 +552,11 @@ fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
                         .population
                         .individuals
                         .iter()
-                        .map(|i| ApiIndividual { difference: i.difference, fitness: i.fitness.clone() })
+                        .map(|i| ApiIndividual { difference: i.difference, fitness: i.fitness.clone(), gap: i.gap })
                         .collect(),
                 },
             })
             .collect(),
+        seed: metrics.seed,
     })
 }
@@ -8,3 +8,6 @@ pub use self::collections::*;
 
 mod permutations;
 pub use self::permutations::VariableJobPermutation;
+
+mod polyline;
+pub use self::polyline::*;
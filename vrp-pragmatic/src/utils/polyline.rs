@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/polyline_test.rs"]
+mod polyline_test;
+
+use vrp_core::prelude::GenericError;
+
+/// A coordinate precision of encoded polyline: five decimal places.
+const PRECISION: f64 = 1E5;
+
+/// Decodes polyline in encoded polyline algorithm format with precision 5 into
+/// (latitude, longitude) pairs.
+pub fn decode_polyline(encoded: &str) -> Result<Vec<(f64, f64)>, GenericError> {
+    let mut bytes = encoded.bytes();
+    let mut next_value = || -> Result<Option<i64>, GenericError> {
+        let (mut result, mut shift) = (0_i64, 0);
+
+        loop {
+            let Some(byte) = bytes.next() else {
+                return if shift == 0 { Ok(None) } else { Err("unexpected end of encoded polyline".into()) };
+            };
+
+            if !(63..=126).contains(&byte) || shift > 30 {
+                return Err(format!("invalid encoded polyline character: '{}'", byte as char).into());
+            }
+
+            let chunk = (byte - 63) as i64;
+            result |= (chunk & 0x1f) << shift;
+            shift += 5;
+
+            if chunk < 0x20 {
+                return Ok(Some(if result & 1 == 1 { !(result >> 1) } else { result >> 1 }));
+            }
+        }
+    };
+
+    let (mut lat, mut lng) = (0_i64, 0_i64);
+    let mut coordinates = Vec::new();
+
+    while let Some(lat_delta) = next_value()? {
+        let lng_delta = next_value()?.ok_or("unexpected end of encoded polyline")?;
+        lat += lat_delta;
+        lng += lng_delta;
+        coordinates.push((lat as f64 / PRECISION, lng as f64 / PRECISION));
+    }
+
+    Ok(coordinates)
+}
+
+/// Encodes (latitude, longitude) pairs into polyline in encoded polyline algorithm format with precision 5.
+pub fn encode_polyline(coordinates: &[(f64, f64)]) -> String {
+    let encode_value = |value: i64, encoded: &mut String| {
+        let mut value = if value < 0 { !(value << 1) } else { value << 1 };
+        while value >= 0x20 {
+            encoded.push((((value & 0x1f) | 0x20) as u8 + 63) as char);
+            value >>= 5;
+        }
+        encoded.push((value as u8 + 63) as char);
+    };
+
+    let mut encoded = String::new();
+    coordinates.iter().fold((0_i64, 0_i64), |(prev_lat, prev_lng), &(lat, lng)| {
+        let (lat, lng) = ((lat * PRECISION).round() as i64, (lng * PRECISION).round() as i64);
+        encode_value(lat - prev_lat, &mut encoded);
+        encode_value(lng - prev_lng, &mut encoded);
+
+        (lat, lng)
+    });
+
+    encoded
+}
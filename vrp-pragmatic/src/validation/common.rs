@@ -1,3 +1,4 @@
+use crate::format::LoadAmount;
 use crate::parse_time_safe;
 use hashbrown::HashSet;
 use std::cmp::Ordering::Less;
@@ -69,3 +70,33 @@ pub fn get_duplicates<'a>(items: impl Iterator<Item = &'a String>) -> Option<Vec
         Some(duplicates)
     }
 }
+
+/// Returns load values paired with their dimension names. When name is not known, value position is used.
+pub fn get_dimension_values(amount: &LoadAmount, dimensions: Option<&[String]>) -> Vec<(String, i32)> {
+    match amount {
+        LoadAmount::Indexed(values) => values
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| {
+                let name = dimensions.and_then(|dimensions| dimensions.get(idx)).cloned();
+                (name.unwrap_or_else(|| format!("#{idx}")), *value)
+            })
+            .collect(),
+        LoadAmount::Named(values) => values.iter().map(|(name, value)| (name.clone(), *value)).collect(),
+    }
+}
+
+/// Returns dimensions used by load amount which are not declared in the fleet.
+pub fn get_unknown_dimensions(amount: &LoadAmount, dimensions: Option<&[String]>) -> Vec<String> {
+    match (amount, dimensions) {
+        (LoadAmount::Indexed(_), None) => vec![],
+        (LoadAmount::Indexed(values), Some(dimensions)) => {
+            (dimensions.len()..values.len()).map(|idx| format!("#{idx}")).collect()
+        }
+        (LoadAmount::Named(values), dimensions) => values
+            .keys()
+            .filter(|name| !dimensions.is_some_and(|dimensions| dimensions.contains(name)))
+            .cloned()
+            .collect(),
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/drivers_test.rs"]
+mod drivers_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+use hashbrown::HashSet;
+
+/// Checks that fleet has no driver with duplicate type ids.
+fn check_e1400_no_driver_types_with_duplicate_type_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
+    get_duplicates(ctx.drivers().map(|driver| &driver.type_id)).map_or(Ok(()), |ids| {
+        Err(FormatError::new(
+            "E1400".to_string(),
+            "duplicated driver type ids".to_string(),
+            format!("remove duplicated driver type ids: {}", ids.join(", ")),
+        ))
+    })
+}
+
+/// Checks that fleet has no driver with duplicate ids.
+fn check_e1401_no_driver_types_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
+    get_duplicates(ctx.drivers().flat_map(|driver| driver.driver_ids.iter())).map_or(Ok(()), |ids| {
+        Err(FormatError::new(
+            "E1401".to_string(),
+            "duplicated driver ids".to_string(),
+            format!("remove duplicated driver ids: {}", ids.join(", ")),
+        ))
+    })
+}
+
+/// Checks that driver shift time is correct.
+fn check_e1402_driver_shift_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .drivers()
+        .filter(|driver| {
+            driver.shifts.as_ref().is_some_and(|shifts| {
+                let tws =
+                    shifts.iter().map(|shift| vec![shift.earliest.clone(), shift.latest.clone()]).collect::<Vec<_>>();
+                !check_raw_time_windows(&tws, false)
+            })
+        })
+        .map(|driver| driver.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1402".to_string(),
+            "invalid driver shift time".to_string(),
+            format!(
+                "ensure that driver shifts are not empty, have correct time windows and do not intersect, \
+                 driver type ids: {}",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that drivers refer to known vehicle types.
+fn check_e1403_driver_vehicle_types_are_known(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let vehicle_types = ctx.vehicles().map(|vehicle| &vehicle.type_id).collect::<HashSet<_>>();
+
+    let type_ids =
+        ctx.drivers()
+            .filter(|driver| {
+                driver.vehicle_types.as_ref().is_some_and(|types| {
+                    types.is_empty() || types.iter().any(|type_id| !vehicle_types.contains(type_id))
+                })
+            })
+            .map(|driver| driver.type_id.to_string())
+            .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1403".to_string(),
+            "unknown or empty vehicle types in driver definition".to_string(),
+            format!(
+                "ensure that driver's vehicle types are not empty and refer to existing vehicle types, \
+                 driver type ids: {}",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Validates drivers from the fleet.
+pub fn validate_drivers(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    combine_error_results(&[
+        check_e1400_no_driver_types_with_duplicate_type_ids(ctx),
+        check_e1401_no_driver_types_with_duplicate_ids(ctx),
+        check_e1402_driver_shift_time(ctx),
+        check_e1403_driver_vehicle_types_are_known(ctx),
+    ])
+    .map_err(|errors| errors.into())
+}
@@ -3,7 +3,10 @@
 mod jobs_test;
 
 use super::*;
+use crate::format::LoadAmount;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
+use hashbrown::HashSet;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -49,7 +52,14 @@ fn check_e1102_multiple_pickups_deliveries_demand(ctx: &ValidationContext) -> Re
     let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(false, |tasks| !tasks.is_empty());
     let get_demand = |tasks: &Option<Vec<JobTask>>| {
         if let Some(tasks) = tasks {
-            tasks.iter().map(|task| task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new)).sum()
+            tasks
+                .iter()
+                .map(|task| {
+                    task.demand
+                        .as_ref()
+                        .map_or_else(MultiDimLoad::default, |demand| MultiDimLoad::new(demand.to_vec(ctx.dimensions())))
+                })
+                .sum()
         } else {
             MultiDimLoad::default()
         }
@@ -158,12 +168,38 @@ fn check_e1106_negative_duration(ctx: &ValidationContext) -> Result<(), FormatEr
 
 /// Checks that job has no negative demand in any of dimensions.
 fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = get_job_demand_issues(ctx, |demand| {
+        get_dimension_values(demand, ctx.dimensions())
+            .into_iter()
+            .filter(|(_, value)| *value < 0)
+            .map(|(name, _)| name)
+            .collect()
+    });
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1107".to_string(),
+            "job has negative demand".to_string(),
+            format!("fix negative demand in jobs with ids (dimensions): '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job place time window tolerance is defined correctly.
+fn check_e1108_invalid_time_window_tolerance(ctx: &ValidationContext) -> Result<(), FormatError> {
     let ids = ctx
         .jobs()
         .filter(|job| {
-            ctx.tasks(job)
-                .iter()
-                .any(|task| task.demand.as_ref().map_or(false, |demand| demand.iter().any(|&dim| dim < 0)))
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).any(|place| {
+                place.tolerance.as_ref().is_some_and(|tolerance| {
+                    place.times.is_none()
+                        || tolerance.penalty.is_sign_negative()
+                        || tolerance.earliness.is_some_and(|earliness| earliness.is_sign_negative())
+                        || tolerance.lateness.is_some_and(|lateness| lateness.is_sign_negative())
+                })
+            })
         })
         .map(|job| job.id.clone())
         .collect::<Vec<_>>();
@@ -172,13 +208,205 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
         Ok(())
     } else {
         Err(FormatError::new(
-            "E1107".to_string(),
-            "job has negative demand".to_string(),
-            format!("fix negative demand in jobs with ids: '{}'", ids.join(", ")),
+            "E1108".to_string(),
+            "invalid time window tolerance".to_string(),
+            format!(
+                "specify time windows and use non negative tolerance values in jobs with ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that max ride duration is positive and specified only for job with pickups and deliveries.
+fn check_e1109_invalid_max_ride_duration(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().is_some_and(|tasks| !tasks.is_empty());
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.max_ride_duration.is_some_and(|max_ride_duration| {
+                max_ride_duration <= 0. || !has_tasks(&job.pickups) || !has_tasks(&job.deliveries)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "invalid max ride duration".to_string(),
+            format!(
+                "use positive max ride duration only for jobs with pickups and deliveries, ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job demand uses only declared capacity dimensions.
+fn check_e1110_unknown_demand_dimensions(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = get_job_demand_issues(ctx, |demand| get_unknown_dimensions(demand, ctx.dimensions()));
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "job demand has unknown dimension".to_string(),
+            format!(
+                "declare dimensions in fleet or remove them from demand, job ids (dimensions): '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job place days are defined correctly.
+fn check_e1111_invalid_job_place_days(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).any(|place| {
+                place.days.as_ref().is_some_and(|days| {
+                    let mut unique_days = days.clone();
+                    unique_days.sort();
+                    unique_days.dedup();
+
+                    days.is_empty()
+                        || unique_days.len() != days.len()
+                        || place.times.as_ref().is_none_or(|times| {
+                            times.is_empty()
+                                || !check_raw_time_windows(times, false)
+                                || !check_raw_time_windows(&get_calendar_times(times, days), false)
+                        })
+                })
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid job place days".to_string(),
+            format!(
+                "specify unique days together with time windows which do not intersect across days, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job visit frequency is defined correctly.
+fn check_e1112_invalid_job_frequency(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_calendar = ctx.vehicles().any(|vehicle| vehicle.calendar.is_some());
+    let relation_ids =
+        ctx.problem.plan.relations.iter().flatten().flat_map(|relation| relation.jobs.iter()).collect::<HashSet<_>>();
+    let job_ids = ctx.jobs().map(|job| &job.id).collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.frequency.as_ref().is_some_and(|frequency| {
+                let min_spacing = frequency.min_spacing.unwrap_or(1);
+                let has_invalid_patterns = frequency.patterns.as_ref().is_some_and(|patterns| {
+                    patterns.is_empty()
+                        || patterns.iter().any(|pattern| {
+                            let mut days = pattern.clone();
+                            days.sort();
+                            days.dedup();
+
+                            days.len() != pattern.len() || days.len() != frequency.count
+                        })
+                });
+                let has_id_clash = (0..frequency.count).any(|idx| job_ids.contains(&get_visit_job_id(&job.id, idx)));
+
+                !has_calendar
+                    || frequency.count == 0
+                    || min_spacing == 0
+                    || frequency.max_spacing.is_some_and(|max_spacing| max_spacing < min_spacing)
+                    || has_invalid_patterns
+                    || has_id_clash
+                    || relation_ids.contains(&job.id)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid job frequency".to_string(),
+            format!(
+                "specify vehicle calendar, positive visit count, consistent spacing and patterns, and do not use \
+                 periodic jobs in relations, job ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job place service durations and parking time are defined correctly.
+fn check_e1113_invalid_job_place_service_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).any(|place| {
+                let has_invalid_durations = place.durations.as_ref().is_some_and(|durations| {
+                    let starts = durations.iter().map(|band| parse_time_safe(&band.start).ok()).collect::<Vec<_>>();
+                    let mut unique_starts = starts.iter().flatten().map(|start| *start as i64).collect::<Vec<_>>();
+                    unique_starts.sort();
+                    unique_starts.dedup();
+
+                    durations.is_empty()
+                        || unique_starts.len() != durations.len()
+                        || durations.iter().any(|band| band.duration.is_sign_negative())
+                });
+
+                has_invalid_durations || place.parking.is_some_and(|parking| parking.is_sign_negative())
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid job place service time".to_string(),
+            format!(
+                "specify non empty service durations with unique valid start times and non negative durations, \
+                 and non negative parking time, job ids: '{}'",
+                ids.join(", ")
+            ),
         ))
     }
 }
 
+/// Returns job ids with dimension names which have an issue detected by the given function.
+fn get_job_demand_issues<F>(ctx: &ValidationContext, issues_fn: F) -> Vec<String>
+where
+    F: Fn(&LoadAmount) -> Vec<String>,
+{
+    ctx.jobs()
+        .filter_map(|job| {
+            let mut dimensions =
+                ctx.tasks(job).iter().filter_map(|task| task.demand.as_ref()).flat_map(&issues_fn).collect::<Vec<_>>();
+            dimensions.sort();
+            dimensions.dedup();
+
+            (!dimensions.is_empty()).then(|| format!("{} ({})", job.id, dimensions.join(", ")))
+        })
+        .collect()
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -190,6 +418,12 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_invalid_time_window_tolerance(ctx),
+        check_e1109_invalid_max_ride_duration(ctx),
+        check_e1110_unknown_demand_dimensions(ctx),
+        check_e1111_invalid_job_place_days(ctx),
+        check_e1112_invalid_job_frequency(ctx),
+        check_e1113_invalid_job_place_service_time(ctx),
     ])
     .map_err(|errors| errors.into())
 }
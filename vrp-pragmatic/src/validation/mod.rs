@@ -12,6 +12,7 @@ pub struct ValidationContext<'a> {
 
     coord_index: &'a CoordIndex,
     job_index: HashMap<String, Job>,
+    matrix_size: Option<usize>,
 }
 
 mod common;
@@ -26,11 +27,17 @@ use self::objectives::validate_objectives;
 mod vehicles;
 use self::vehicles::validate_vehicles;
 
+mod drivers;
+use self::drivers::validate_drivers;
+
 mod relations;
 use self::relations::validate_relations;
 
 mod routing;
 use self::routing::validate_routing;
+
+mod state;
+use self::state::validate_state;
 use hashbrown::HashMap;
 
 impl<'a> ValidationContext<'a> {
@@ -41,18 +48,31 @@ impl<'a> ValidationContext<'a> {
             matrices,
             coord_index,
             job_index: problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect(),
+            matrix_size: matrices
+                .and_then(|matrices| matrices.first())
+                .filter(|matrix| matrix.entries.is_none())
+                .map(|matrix| (matrix.distances.len() as f64).sqrt().round() as usize),
         }
     }
 
+    /// Sets size of dense routing matrices which values are not kept in matrices, e.g. they are
+    /// decoded from binary format.
+    pub fn with_matrix_size(mut self, matrix_size: usize) -> Self {
+        self.matrix_size = Some(matrix_size);
+        self
+    }
+
     /// Validates problem on set of rules.
     pub fn validate(&self) -> Result<(), MultiFormatError> {
         let multi_err: MultiFormatError = validate_jobs(self)
             .err()
             .into_iter()
             .chain(validate_vehicles(self).err())
+            .chain(validate_drivers(self).err())
             .chain(validate_objectives(self).err())
             .chain(validate_routing(self).err())
             .chain(validate_relations(self).err())
+            .chain(validate_state(self).err())
             .flatten()
             .collect::<Vec<_>>()
             .into();
@@ -74,6 +94,16 @@ impl<'a> ValidationContext<'a> {
         self.problem.fleet.vehicles.iter()
     }
 
+    /// Gets list of drivers from the problem.
+    fn drivers(&self) -> impl Iterator<Item = &DriverType> {
+        self.problem.fleet.drivers.iter().flatten()
+    }
+
+    /// Gets names of capacity dimensions, if they are declared.
+    fn dimensions(&self) -> Option<&[String]> {
+        self.problem.fleet.dimensions.as_deref()
+    }
+
     /// Gets a flat list of job tasks from the job.
     fn tasks(&self, job: &'a Job) -> Vec<&'a JobTask> {
         job.pickups
@@ -87,6 +117,6 @@ impl<'a> ValidationContext<'a> {
     }
 }
 
-fn is_reserved_job_id(job_id: &str) -> bool {
+pub(crate) fn is_reserved_job_id(job_id: &str) -> bool {
     job_id == "departure" || job_id == "arrival" || job_id == "break" || job_id == "reload"
 }
@@ -40,6 +40,7 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 CompactTour { .. } => acc.entry("compact-tour"),
                 TourOrder => acc.entry("tour-order"),
                 FastService { .. } => acc.entry("fast-service"),
+                WeightedSum { .. } => acc.entry("weighted-sum"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -186,12 +187,69 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that weighted-sum objective is the only one on its level, not nested and has valid weights.
+fn check_e1608_invalid_weighted_sum_objective(levels: &[ObjectiveLevel]) -> Result<(), FormatError> {
+    let has_invalid = levels.iter().map(|level| level.objectives()).any(|objectives| {
+        objectives.iter().any(|objective| match objective {
+            WeightedSum { objectives: inner, weights } => {
+                objectives.len() > 1
+                    || inner.is_empty()
+                    || inner.len() != weights.len()
+                    || inner.iter().any(|objective| matches!(objective, WeightedSum { .. }))
+                    || weights.iter().any(|weight| !weight.is_finite() || *weight <= 0.)
+            }
+            _ => false,
+        })
+    });
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "invalid weighted-sum objective".to_string(),
+            "keep weighted-sum objective alone on its level, do not nest it and specify one positive weight per \
+             inner objective"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that objective level tolerance is specified by non-negative values.
+fn check_e1609_invalid_objective_tolerance(levels: &[ObjectiveLevel]) -> Result<(), FormatError> {
+    let has_invalid = levels.iter().filter_map(|level| level.tolerance()).any(|tolerance| {
+        let values = [tolerance.relative, tolerance.absolute];
+
+        values.iter().all(|value| value.is_none())
+            || values.iter().flatten().any(|value| !value.is_finite() || *value < 0.)
+    });
+
+    if has_invalid {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "invalid objective tolerance".to_string(),
+            "specify relative and/or absolute tolerance as non-negative number".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
-    ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().flatten().collect())
+    ctx.problem.objectives.as_ref().map(|objectives| {
+        objectives
+            .iter()
+            .flat_map(|level| level.objectives())
+            .flat_map(|objective| match objective {
+                WeightedSum { objectives, .. } => objectives.as_slice(),
+                objective => std::slice::from_ref(objective),
+            })
+            .collect()
+    })
 }
 
 pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
-    if let Some(objectives) = get_objectives(ctx) {
+    if let Some((levels, objectives)) = ctx.problem.objectives.as_deref().zip(get_objectives(ctx)) {
         combine_error_results(&[
             check_e1600_empty_objective(&objectives),
             check_e1601_duplicate_objectives(&objectives),
@@ -201,6 +259,8 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_invalid_weighted_sum_objective(levels),
+            check_e1609_invalid_objective_tolerance(levels),
         ])
         .map_err(|errors| errors.into())
     } else {
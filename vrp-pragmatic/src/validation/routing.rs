@@ -3,7 +3,8 @@
 mod routing_test;
 
 use super::*;
-use crate::utils::combine_error_results;
+use crate::parse_time_safe;
+use crate::utils::{combine_error_results, decode_polyline};
 use hashbrown::HashSet;
 
 /// Checks that no duplicated profile names specified.
@@ -67,11 +68,8 @@ fn check_e1503_no_matrix_when_indices_used(
 fn check_e1504_index_size_mismatch(ctx: &ValidationContext) -> Result<(), FormatError> {
     let max_index = ctx.coord_index.max_matrix_index();
 
-    let (matrix_size, is_correct_index) = ctx
-        .matrices
-        .and_then(|matrices| matrices.first())
-        .map(|matrix| (matrix.distances.len() as f64).sqrt().round() as usize)
-        .map_or((0_usize, true), |matrix_size| (matrix_size, max_index + 1 == matrix_size));
+    let (matrix_size, is_correct_index) =
+        ctx.matrix_size.map_or((0_usize, true), |matrix_size| (matrix_size, max_index + 1 == matrix_size));
 
     if !is_correct_index {
         Err(FormatError::new(
@@ -114,6 +112,152 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that speed bands are properly defined.
+fn check_e1506_speed_bands(ctx: &ValidationContext) -> Result<(), FormatError> {
+    // NOTE time aware and time agnostic matrices cannot be mixed, so speed bands cannot be used with any time aware one
+    let has_timestamps =
+        ctx.matrices.iter().flat_map(|matrices| matrices.iter()).any(|matrix| matrix.timestamp.is_some());
+
+    let profile_names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.speed_bands.as_ref().map(|bands| (&profile.name, bands)))
+        .filter(|(_, bands)| {
+            let has_invalid_starts = bands
+                .iter()
+                .map(|band| parse_time_safe(&band.start))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|starts| starts.windows(2).any(|pair| pair[0] >= pair[1]))
+                .unwrap_or(true);
+
+            bands.is_empty()
+                || bands.iter().any(|band| !band.factor.is_finite() || band.factor <= 0.)
+                || has_invalid_starts
+                || has_timestamps
+        })
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+
+    if profile_names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid speed bands in matrix profile".to_string(),
+            format!(
+                "ensure that speed bands are not empty, have positive factor, sorted by valid start time and \
+                 not used when any matrix has timestamp, profiles: '{}'",
+                profile_names.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that sparse routing matrices are properly defined.
+fn check_e1507_sparse_matrices(ctx: &ValidationContext, location_types: (bool, bool)) -> Result<(), FormatError> {
+    let (_, has_indices) = location_types;
+    let matrices = ctx.matrices.map_or(&[][..], |matrices| matrices.as_slice());
+
+    if matrices.iter().all(|matrix| matrix.entries.is_none()) {
+        return Ok(());
+    }
+
+    let max_index = ctx.coord_index.max_matrix_index();
+    let is_valid_matrix = |matrix: &Matrix| {
+        matrix.entries.as_ref().is_some_and(|entries| {
+            matrix.timestamp.is_none()
+                && matrix.travel_times.is_empty()
+                && matrix.distances.is_empty()
+                && matrix.error_codes.is_none()
+                && entries.iter().all(|entry| entry.0 <= max_index && entry.1 <= max_index)
+        })
+    };
+
+    if !has_indices && matrices.iter().all(is_valid_matrix) {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid sparse routing matrix".to_string(),
+            "ensure that all matrices are sparse without timestamps, travel times, distances and error codes, \
+             entries refer to known locations and geo coordinates are used for all locations"
+                .to_string(),
+        ))
+    }
+}
+
+/// Checks that route geometries of routing matrices are properly defined.
+fn check_e1508_matrix_geometries(ctx: &ValidationContext, location_types: (bool, bool)) -> Result<(), FormatError> {
+    let (_, has_indices) = location_types;
+    let geometries = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter())
+        .filter_map(|matrix| matrix.geometries.as_ref())
+        .collect::<Vec<_>>();
+
+    if geometries.is_empty() {
+        return Ok(());
+    }
+
+    let max_index = ctx.coord_index.max_matrix_index();
+    let invalid_routes = geometries
+        .iter()
+        .flat_map(|geometries| geometries.iter())
+        .filter(|MatrixGeometry(from, to, polyline)| {
+            *from > max_index || *to > max_index || !decode_polyline(polyline).is_ok_and(|coords| coords.len() > 1)
+        })
+        .map(|MatrixGeometry(from, to, _)| format!("{from}->{to}"))
+        .collect::<Vec<_>>();
+
+    if !has_indices && invalid_routes.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1508".to_string(),
+            "invalid route geometries in routing matrix".to_string(),
+            format!(
+                "ensure that geo coordinates are used for all locations, geometries refer to known locations and \
+                 have valid encoded polylines with at least two points, routes: '{}'",
+                invalid_routes.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that fifo property is set only for profiles with time dependent matrices.
+fn check_e1509_fifo(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profiles = &ctx.problem.fleet.profiles;
+    let has_timestamps = |name: &String| {
+        ctx.matrices.iter().flat_map(|matrices| matrices.iter()).enumerate().any(|(idx, matrix)| {
+            let profile = matrix.profile.as_ref().or_else(|| profiles.get(idx).map(|profile| &profile.name));
+            matrix.timestamp.is_some() && profile == Some(name)
+        })
+    };
+
+    let profile_names = profiles
+        .iter()
+        .filter(|profile| profile.fifo == Some(true) && !has_timestamps(&profile.name))
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if profile_names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1509".to_string(),
+            "fifo is set for matrix profile without time dependent matrices".to_string(),
+            format!(
+                "ensure that routing matrices with timestamp are specified for profiles with fifo, or remove fifo \
+                 property, profiles: '{}'",
+                profile_names.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     let location_types = (ctx.coord_index.has_coordinates(), ctx.coord_index.has_indices());
@@ -125,6 +269,10 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_speed_bands(ctx),
+        check_e1507_sparse_matrices(ctx, location_types),
+        check_e1508_matrix_geometries(ctx, location_types),
+        check_e1509_fifo(ctx),
     ])
     .map_err(|errors| errors.into())
 }
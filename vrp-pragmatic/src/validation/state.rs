@@ -0,0 +1,217 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/state_test.rs"]
+mod state_test;
+
+use super::*;
+use crate::parse_time_safe;
+use crate::utils::combine_error_results;
+
+/// Checks that state vehicle ids are defined in fleet and have the corresponding shift.
+fn check_e1700_vehicle_existence(ctx: &ValidationContext, state: &ProblemState) -> Result<(), FormatError> {
+    let vehicle_map = ctx
+        .vehicles()
+        .flat_map(|v_type| v_type.vehicle_ids.iter().map(move |id| (id.clone(), v_type)))
+        .collect::<HashMap<_, _>>();
+
+    let vehicle_ids = state
+        .vehicles
+        .iter()
+        .filter(|vehicle| {
+            vehicle_map
+                .get(&vehicle.vehicle_id)
+                .and_then(|v_type| v_type.shifts.get(vehicle.shift_index.unwrap_or(0)))
+                .is_none()
+        })
+        .map(|vehicle| vehicle.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1700".to_string(),
+            "state has vehicle id or shift index which does not present in the fleet".to_string(),
+            format!("remove from state or add vehicles (shifts) to the fleet, ids: '{}'", vehicle_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that state job ids are defined in plan.
+fn check_e1701_job_existence(ctx: &ValidationContext, state: &ProblemState) -> Result<(), FormatError> {
+    let job_ids = get_state_job_ids(state)
+        .chain(get_picked_up_job_ids(state))
+        .filter(|job_id| !ctx.job_index.contains_key(*job_id))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1701".to_string(),
+            "state has job id which does not present in the plan".to_string(),
+            format!("remove from state or add jobs to the plan, ids: '{}'", job_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that state has no duplicated vehicle shifts or job ids.
+fn check_e1702_no_duplicates(state: &ProblemState) -> Result<(), FormatError> {
+    let vehicle_keys = state
+        .vehicles
+        .iter()
+        .map(|vehicle| format!("{}:{}", vehicle.vehicle_id, vehicle.shift_index.unwrap_or(0)))
+        .collect::<Vec<_>>();
+
+    let duplicates = get_duplicates(vehicle_keys.iter())
+        .into_iter()
+        .chain(get_duplicates(get_state_job_ids(state)))
+        .chain(get_duplicates(
+            state
+                .vehicles
+                .iter()
+                .flat_map(|vehicle| vehicle.completed.iter().flatten())
+                .chain(get_picked_up_job_ids(state)),
+        ))
+        .flatten()
+        .collect::<Vec<_>>();
+
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1702".to_string(),
+            "state has duplicated vehicle shifts or job ids".to_string(),
+            format!(
+                "specify vehicle shift state once, job can be either completed or committed once, ids: '{}'",
+                duplicates.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that committed jobs do not conflict with relations.
+fn check_e1703_no_relation_conflicts(ctx: &ValidationContext, state: &ProblemState) -> Result<(), FormatError> {
+    let relations = ctx.problem.plan.relations.iter().flatten().collect::<Vec<_>>();
+
+    let ids = state
+        .vehicles
+        .iter()
+        .filter(|vehicle| vehicle.committed.as_ref().is_some_and(|committed| !committed.is_empty()))
+        .flat_map(|vehicle| {
+            let has_ordered_relation = relations.iter().any(|relation| {
+                relation.vehicle_id == vehicle.vehicle_id
+                    && relation.shift_index.unwrap_or(0) == vehicle.shift_index.unwrap_or(0)
+                    && !matches!(relation.type_field, RelationType::Any)
+            });
+
+            let conflicting = vehicle
+                .committed
+                .iter()
+                .flatten()
+                .filter(|&job_id| relations.iter().any(|relation| relation.jobs.contains(job_id)))
+                .cloned();
+
+            has_ordered_relation.then(|| vehicle.vehicle_id.clone()).into_iter().chain(conflicting)
+        })
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1703".to_string(),
+            "state has committed jobs which conflict with relations".to_string(),
+            format!(
+                "remove committed jobs from relations and strict or sequence relations of vehicles with \
+                 committed jobs, ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that state time is specified in proper format.
+fn check_e1704_time_format(state: &ProblemState) -> Result<(), FormatError> {
+    let vehicle_ids = state
+        .vehicles
+        .iter()
+        .filter(|vehicle| parse_time_safe(&vehicle.time).is_err())
+        .map(|vehicle| vehicle.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1704".to_string(),
+            "state has invalid time".to_string(),
+            format!("use RFC3339 format for state time, vehicle ids: '{}'", vehicle_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that picked up jobs have both pickups and deliveries and are picked up by the vehicle which
+/// is committed to serve them.
+fn check_e1705_picked_up_jobs(ctx: &ValidationContext, state: &ProblemState) -> Result<(), FormatError> {
+    let job_ids = state
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            vehicle.picked_up.iter().flatten().filter(move |&job_id| {
+                let is_pickup_delivery = ctx.job_index.get(job_id).is_none_or(|job| {
+                    job.pickups.as_ref().is_some_and(|tasks| !tasks.is_empty())
+                        && job.deliveries.as_ref().is_some_and(|tasks| !tasks.is_empty())
+                });
+                let is_committed_by_other = state.vehicles.iter().any(|other| {
+                    !std::ptr::eq(other, vehicle) && other.committed.iter().flatten().any(|id| id == job_id)
+                });
+
+                !is_pickup_delivery || is_committed_by_other
+            })
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1705".to_string(),
+            "state has invalid picked up jobs".to_string(),
+            format!(
+                "picked up job should have pickups and deliveries and can be committed only to the same vehicle, \
+                 ids: '{}'",
+                job_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn get_picked_up_job_ids(state: &ProblemState) -> impl Iterator<Item = &String> {
+    state.vehicles.iter().flat_map(|vehicle| vehicle.picked_up.iter().flatten())
+}
+
+fn get_state_job_ids(state: &ProblemState) -> impl Iterator<Item = &String> {
+    state
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.completed.iter().flatten().chain(vehicle.committed.iter().flatten()))
+}
+
+/// Validates current state of the plan.
+pub fn validate_state(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    if let Some(state) = ctx.problem.state.as_ref() {
+        combine_error_results(&[
+            check_e1700_vehicle_existence(ctx, state),
+            check_e1701_job_existence(ctx, state),
+            check_e1702_no_duplicates(state),
+            check_e1703_no_relation_conflicts(ctx, state),
+            check_e1704_time_format(state),
+            check_e1705_picked_up_jobs(ctx, state),
+        ])
+        .map_err(|errors| errors.into())
+    } else {
+        Ok(())
+    }
+}
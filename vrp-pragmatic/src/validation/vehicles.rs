@@ -4,10 +4,12 @@ mod vehicles_test;
 
 use super::*;
 use crate::utils::combine_error_results;
-use crate::validation::common::get_time_windows;
+use crate::validation::common::{get_time_windows, get_unknown_dimensions};
+use crate::Location as ApiLocation;
 use crate::{parse_time, parse_time_safe};
 use hashbrown::HashSet;
 use std::cmp::Ordering;
+use std::iter::once;
 use vrp_core::models::common::TimeWindow;
 use vrp_core::utils::compare_floats;
 
@@ -222,8 +224,9 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
         .resources
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, .. } => id.to_string(),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, .. } => Some(id.to_string()),
+            VehicleResource::Depot { .. } => None,
         })
         .collect::<Vec<_>>();
 
@@ -264,6 +267,261 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that vehicle driving rules are valid.
+fn check_e1309_vehicle_driving_rules(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.driving_rules.as_ref()).is_some_and(|rules| {
+                let is_invalid_split = rules.split_break.as_ref().is_some_and(|parts| {
+                    parts.len() != 2
+                        || parts.iter().any(|&part| part <= 0.)
+                        || parts.iter().sum::<f64>() < rules.break_duration
+                });
+
+                rules.max_driving_time <= 0.
+                    || rules.break_duration <= 0.
+                    || rules.max_daily_driving_time < rules.max_driving_time
+                    || is_invalid_split
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle driving rules".to_string(),
+            format!(
+                "ensure that driving times and break duration are positive, daily driving time is not less than \
+                 driving time, and split break has two parts which sum is not less than break duration, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1310_vehicle_depots(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            let is_empty = |locations: &Option<Vec<ApiLocation>>| locations.as_ref().is_some_and(|l| l.is_empty());
+
+            match shift.depots.as_ref() {
+                Some(depots) => {
+                    !is_empty(&depots.start) && !is_empty(&depots.end) && (depots.end.is_none() || shift.end.is_some())
+                }
+                None => true,
+            }
+        }),
+    );
+
+    if !type_ids.is_empty() {
+        return Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid vehicle depots".to_string(),
+            format!(
+                "ensure that depot lists are not empty and end depots are specified only for shifts with end, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ));
+    }
+
+    let depot_locations = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .flat_map(|shift| {
+            once(&shift.start.location).chain(shift.depots.iter().flat_map(|depots| depots.start.iter().flatten()))
+        })
+        .filter_map(|location| ctx.coord_index.get_by_loc(location))
+        .collect::<HashSet<_>>();
+
+    let resource_ids = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Depot { id, location, .. } => Some((id, location)),
+            VehicleResource::Reload { .. } => None,
+        })
+        .filter(|(_, location)| {
+            !ctx.coord_index.get_by_loc(location).is_some_and(|location| depot_locations.contains(&location))
+        })
+        .map(|(id, _)| id.to_string())
+        .collect::<Vec<_>>();
+
+    if resource_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid vehicle depots".to_string(),
+            format!(
+                "ensure that depot resource location is used as shift start location, resource ids: '{}'",
+                resource_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that capacity dimensions are declared properly and used by vehicle capacities.
+fn check_e1311_vehicle_capacity_dimensions(ctx: &ValidationContext) -> Result<(), FormatError> {
+    if let Some(dimensions) = ctx.problem.fleet.dimensions.as_ref() {
+        let invalid = get_duplicates(dimensions.iter())
+            .into_iter()
+            .flatten()
+            .chain(dimensions.iter().filter(|name| name.is_empty()).cloned())
+            .collect::<Vec<_>>();
+
+        if !invalid.is_empty() || dimensions.is_empty() {
+            return Err(FormatError::new(
+                "E1311".to_string(),
+                "invalid capacity dimensions".to_string(),
+                format!("declare unique and non empty dimension names in fleet, dimensions: '{}'", invalid.join(", ")),
+            ));
+        }
+    }
+
+    let ids = ctx
+        .vehicles()
+        .map(|vehicle| (&vehicle.type_id, &vehicle.capacity))
+        .chain(ctx.problem.fleet.resources.iter().flatten().filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id, capacity)),
+            VehicleResource::Depot { .. } => None,
+        }))
+        .filter_map(|(id, capacity)| {
+            let dimensions = get_unknown_dimensions(capacity, ctx.dimensions());
+            (!dimensions.is_empty()).then(|| format!("{id} ({})", dimensions.join(", ")))
+        })
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid capacity dimensions".to_string(),
+            format!(
+                "declare dimensions in fleet or remove them from capacity, vehicle type or resource ids \
+                 (dimensions): '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1312_vehicle_compartments(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.compartments.as_ref().is_some_and(|compartments| {
+                let has_reloads = vehicle
+                    .shifts
+                    .iter()
+                    .any(|shift| shift.reloads.as_ref().is_some_and(|reloads| !reloads.is_empty()));
+
+                compartments.is_empty()
+                    || has_reloads
+                    || get_duplicates(compartments.iter().map(|compartment| &compartment.id)).is_some()
+                    || compartments
+                        .iter()
+                        .any(|compartment| !get_unknown_dimensions(&compartment.capacity, ctx.dimensions()).is_empty())
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle compartments".to_string(),
+            format!(
+                "ensure that compartments list is not empty, has unique ids and known capacity dimensions, \
+                 and vehicle has no reloads, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle calendar is defined correctly.
+fn check_e1313_vehicle_calendar(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.calendar.as_ref().is_some_and(|calendar| {
+                let days = calendar.days.iter().map(|day| day.day).collect::<Vec<_>>();
+                let mut unique_days = days.clone();
+                unique_days.sort();
+                unique_days.dedup();
+
+                let tws = vehicle
+                    .shifts
+                    .iter()
+                    .map(|shift| {
+                        vec![
+                            shift.start.earliest.clone(),
+                            shift.end.as_ref().map_or_else(|| shift.start.earliest.clone(), |end| end.latest.clone()),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+
+                days.is_empty()
+                    || unique_days.len() != days.len()
+                    || calendar.min_rest.is_some_and(|min_rest| min_rest.is_sign_negative())
+                    || calendar.days.iter().any(|day| day.fixed.is_some_and(|fixed| fixed.is_sign_negative()))
+                    || (check_raw_time_windows(&tws, false)
+                        && !check_raw_time_windows(&get_calendar_times(&tws, &days), false))
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle calendar".to_string(),
+            format!(
+                "ensure that calendar has unique days, non negative costs and rest time, and shifts repeated \
+                 for each day do not intersect, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle profile has no negative parking time.
+fn check_e1314_vehicle_parking(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.profile.parking.is_some_and(|parking| parking.is_sign_negative()))
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid vehicle parking".to_string(),
+            format!(
+                "ensure that vehicle profile parking time is not negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -311,6 +569,12 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_offset_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_driving_rules(ctx),
+        check_e1310_vehicle_depots(ctx),
+        check_e1311_vehicle_capacity_dimensions(ctx),
+        check_e1312_vehicle_compartments(ctx),
+        check_e1313_vehicle_calendar(ctx),
+        check_e1314_vehicle_parking(ctx),
     ])
     .map_err(|errors| errors.into())
 }
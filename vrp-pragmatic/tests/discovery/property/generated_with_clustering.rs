@@ -42,7 +42,7 @@ prop_compose! {
         generate_vehicles(
              generate_vehicle(
                 2..4,
-                Just(VehicleProfile { matrix: "car".to_string(), scale: None }),
+                Just(VehicleProfile { matrix: "car".to_string(), scale: None, parking: None }),
                 generate_simple_capacity(5..20),
                 default_costs_prototype(),
                 generate_no_vehicle_skills(),
@@ -57,7 +57,7 @@ prop_compose! {
         Problem {
             plan: Plan {
                 clustering: Some(Clustering::Vicinity {
-                    profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+                    profile: VehicleProfile { matrix: "car".to_string(), scale: None, parking: None },
                     threshold: VicinityThresholdPolicy {
                         duration,
                         distance,
@@ -72,8 +72,7 @@ prop_compose! {
                 ..plan
             },
             fleet,
-            objectives: None,
-        }
+            objectives: None, state: None, }
     }
 }
 
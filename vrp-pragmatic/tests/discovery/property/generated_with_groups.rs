@@ -49,8 +49,7 @@ prop_compose! {
         Problem {
             plan,
             fleet,
-            objectives: None,
-        }
+            objectives: None, state: None, }
     }
 }
 
@@ -68,8 +68,7 @@ prop_compose! {
                 ..plan
             },
             fleet,
-            objectives: None,
-        }
+            objectives: None, state: None, }
     }
 }
 
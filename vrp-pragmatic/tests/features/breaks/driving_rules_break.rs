@@ -0,0 +1,116 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn get_break_count(solution: &Solution) -> usize {
+    get_break_durations(solution).len()
+}
+
+fn get_break_durations(solution: &Solution) -> Vec<f64> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type == "break")
+        .map(|(stop, activity)| match activity.time.as_ref() {
+            Some(time) => parse_time(&time.end) - parse_time(&time.start),
+            None => parse_time(&stop.schedule().departure) - parse_time(&stop.schedule().arrival),
+        })
+        .collect()
+}
+
+fn create_test_problem(max_driving_time: f64, max_daily_driving_time: f64) -> Problem {
+    create_test_problem_with_split(max_driving_time, max_daily_driving_time, None)
+}
+
+fn create_test_problem_with_split(
+    max_driving_time: f64,
+    max_daily_driving_time: f64,
+    split_break: Option<Vec<f64>>,
+) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job("job2", (10., 0.)),
+                create_delivery_job("job3", (15., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    driving_rules: Some(VehicleDrivingRules {
+                        max_driving_time,
+                        break_duration: 5.,
+                        max_daily_driving_time,
+                        split_break,
+                    }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_insert_driving_break_when_driving_time_limit_is_reached() {
+    let problem = create_test_problem(20., 100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_break_count(&solution), 1);
+}
+
+#[test]
+fn can_insert_split_driving_break_when_driving_time_limit_is_reached() {
+    let problem = create_test_problem_with_split(20., 100., Some(vec![2., 3.]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_seed(problem, Some(vec![matrix]), 42);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_break_durations(&solution), vec![2., 3.]);
+}
+
+#[test]
+fn can_skip_driving_break_when_it_is_not_needed() {
+    let problem = create_test_problem(30., 100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_break_count(&solution), 0);
+}
+
+#[test]
+fn can_unassign_job_when_daily_driving_time_is_exceeded() {
+    let problem = create_test_problem(20., 25.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job3".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "DRIVING_RULES_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to driving rules constraint".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+            }]
+        }])
+    );
+}
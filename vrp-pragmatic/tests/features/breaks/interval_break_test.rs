@@ -3,8 +3,8 @@ use crate::format::problem::*;
 use crate::format_time;
 use crate::helpers::*;
 
-fn create_test_objectives() -> Option<Vec<Vec<Objective>>> {
-    Some(vec![vec![MinimizeUnassigned { breaks: Some(10.) }], vec![MinimizeCost]])
+fn create_test_objectives() -> Option<Vec<ObjectiveLevel>> {
+    Some(vec![vec![MinimizeUnassigned { breaks: Some(10.) }].into(), vec![MinimizeCost].into()])
 }
 
 #[test]
@@ -34,6 +34,7 @@ fn can_assign_interval_break_between_jobs() {
             ..create_default_fleet()
         },
         objectives: create_test_objectives(),
+        state: None,
     };
     let matrix = create_matrix_from_problem(&problem);
 
@@ -117,8 +118,9 @@ fn can_assign_interval_break_with_reload() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    depots: None,
                 }],
-                capacity: vec![2],
+                capacity: vec![2].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -0,0 +1,100 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_job_with_product(id: &str, location: f64, demand: i32, product: &str) -> Job {
+    Job { product: Some(product.to_string()), ..create_delivery_job_with_demand(id, (location, 0.), vec![demand]) }
+}
+
+fn create_compartment(id: &str, capacity: i32, products: &[&str]) -> VehicleCompartment {
+    VehicleCompartment {
+        id: id.to_string(),
+        capacity: vec![capacity].into(),
+        products: Some(products.iter().map(|product| product.to_string()).collect()),
+    }
+}
+
+fn create_problem(jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![10].into(),
+                compartments: Some(vec![
+                    create_compartment("chilled", 2, &["chilled"]),
+                    create_compartment("mixed", 2, &["chilled", "frozen"]),
+                ]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_compartments(solution: &Solution) -> Vec<(String, String)> {
+    let mut compartments = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter_map(|activity| activity.compartment.clone().map(|compartment| (activity.job_id.clone(), compartment)))
+        .collect::<Vec<_>>();
+    compartments.sort();
+
+    compartments
+}
+
+#[test]
+fn can_assign_jobs_to_compartments() {
+    let problem = create_problem(vec![
+        create_job_with_product("job1", 1., 1, "frozen"),
+        create_job_with_product("job2", 2., 1, "chilled"),
+        create_job_with_product("job3", 3., 1, "chilled"),
+        create_job_with_product("job4", 4., 1, "chilled"),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let compartments = get_compartments(&solution);
+    assert_eq!(compartments.len(), 4);
+    assert!(compartments.contains(&("job1".to_string(), "mixed".to_string())));
+    assert_eq!(compartments.iter().filter(|(_, compartment)| compartment == "chilled").count(), 2);
+}
+
+#[test]
+fn can_unassign_jobs_which_do_not_fit_into_compartments() {
+    let problem = create_problem(vec![
+        create_job_with_product("job1", 1., 1, "frozen"),
+        create_job_with_product("job2", 2., 2, "frozen"),
+        create_job_with_product("job3", 3., 1, "dry"),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 2);
+    assert!(unassigned.iter().any(|job| job.job_id == "job3"));
+    assert!(unassigned.iter().flat_map(|job| job.reasons.iter()).all(|reason| reason.code == "COMPARTMENT_CONSTRAINT"));
+}
+
+#[test]
+fn can_keep_pickup_and_delivery_in_the_same_compartment() {
+    let create_job = |id: &str, pickup: f64, delivery: f64| Job {
+        product: Some("frozen".to_string()),
+        ..create_pickup_delivery_job(id, (pickup, 0.), (delivery, 0.))
+    };
+    let problem =
+        create_problem(vec![create_job("job1", 1., 4.), create_job("job2", 2., 5.), create_job("job3", 3., 6.)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let compartments = get_compartments(&solution);
+    assert_eq!(compartments.len(), 6);
+    assert!(compartments.iter().all(|(_, compartment)| compartment == "mixed"));
+}
@@ -1 +1,2 @@
+mod compartments_test;
 mod simple_capacity_test;
@@ -45,6 +45,7 @@ impl From<ActivityData> for Activity {
                 forward: convert_expected_commute_info(fwd),
                 backward: convert_expected_commute_info(bak),
             }),
+            compartment: None,
         }
     }
 }
@@ -82,7 +83,7 @@ impl From<StopData> for Stop {
             } else {
                 None
             },
-            load: vec![stop.load],
+            load: vec![stop.load].into(),
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
         })
     }
@@ -100,6 +101,8 @@ fn create_statistic(data: (f64, i64, i64, (i64, i64, i64, i64))) -> Statistic {
             parking: data.3 .3,
             ..Timing::default()
         },
+        tardiness: None,
+        days: None,
     }
 }
 
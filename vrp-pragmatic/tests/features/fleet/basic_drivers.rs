@@ -0,0 +1,120 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_driver_type(type_id: &str, time_cost: f64) -> DriverType {
+    DriverType {
+        costs: VehicleCosts { fixed: None, distance: 0., time: time_cost },
+        ..create_default_driver_type(type_id)
+    }
+}
+
+#[test]
+fn can_pick_cheaper_driver() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            drivers: Some(vec![create_driver_type("expensive", 10.), create_driver_type("cheap", 1.)]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.driver_id.as_deref(), Some("cheap_1"));
+    // NOTE vehicle fixed, distance and time costs plus driver time costs
+    assert_eq!(tour.statistic.cost, 10. + 20. + 21. + 21.);
+    assert_eq!(solution.statistic.cost, tour.statistic.cost);
+}
+
+#[test]
+fn can_use_only_eligible_vehicle_types() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("car"),
+                VehicleType {
+                    costs: VehicleCosts { fixed: Some(100.), distance: 2., time: 2. },
+                    ..create_default_vehicle("truck")
+                },
+            ],
+            drivers: Some(vec![DriverType {
+                vehicle_types: Some(vec!["truck".to_string()]),
+                ..create_default_driver_type("trucker")
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.vehicle_id, "truck_1");
+    assert_eq!(tour.driver_id.as_deref(), Some("trucker_1"));
+}
+
+#[test]
+fn can_use_driver_skills() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills("job1", (10., 0.), all_of_skills(vec!["license".to_string()]))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            drivers: Some(vec![
+                create_driver_type("unskilled", 1.),
+                DriverType { skills: Some(vec!["license".to_string()]), ..create_driver_type("skilled", 10.) },
+            ]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().driver_id.as_deref(), Some("skilled_1"));
+}
+
+#[test]
+fn can_use_vehicle_only_once_with_single_driver() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 20)], 1.),
+                create_delivery_job_with_times("job2", (-10., 0.), vec![(0, 20)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            drivers: Some(vec![DriverType {
+                shifts: Some(vec![DriverShift { earliest: format_time(0.), latest: format_time(100.) }]),
+                ..create_default_driver_type("driver")
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+}
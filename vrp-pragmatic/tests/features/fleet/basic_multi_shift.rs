@@ -27,7 +27,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         ..create_default_vehicle_shift()
                     },
                 ],
-                capacity: vec![1],
+                capacity: vec![1].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -102,9 +102,9 @@ fn can_prefer_first_days_with_minimize_arrival_time_objective() {
             ..create_empty_plan()
         },
         objectives: Some(vec![
-            vec![Objective::MinimizeUnassigned { breaks: None }],
-            vec![Objective::MinimizeArrivalTime],
-            vec![Objective::MinimizeCost],
+            vec![Objective::MinimizeUnassigned { breaks: None }].into(),
+            vec![Objective::MinimizeArrivalTime].into(),
+            vec![Objective::MinimizeCost].into(),
         ]),
         fleet: Fleet {
             vehicles: vec![VehicleType {
@@ -120,7 +120,7 @@ fn can_prefer_first_days_with_minimize_arrival_time_objective() {
                         ..create_default_vehicle_shift()
                     })
                     .collect(),
-                capacity: vec![1],
+                capacity: vec![1].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -1,5 +1,10 @@
+mod basic_drivers;
 mod basic_multi_shift;
 mod basic_open_end;
+mod multi_day;
+mod multi_depot;
 mod multi_dimens;
+mod periodic_visits;
 mod profile_variation;
 mod unreachable_jobs;
+mod vehicle_state;
@@ -0,0 +1,81 @@
+use crate::format::problem::*;
+use crate::format::solution::Solution;
+use crate::helpers::*;
+
+fn create_job_with_days(id: &str, location: (f64, f64), days: Option<Vec<usize>>) -> Job {
+    let mut job = create_delivery_job_with_times(id, location, vec![(0, 100)], 1.);
+    job.deliveries.as_mut().unwrap()[0].places[0].days = days;
+
+    job
+}
+
+fn create_problem_with_calendar(jobs: Vec<Job>, days: Vec<(usize, Option<f64>)>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                calendar: Some(VehicleCalendar {
+                    days: days.into_iter().map(|(day, fixed)| VehicleDay { day, fixed }).collect(),
+                    min_rest: Some(3600.),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_tour_days(solution: &Solution) -> Vec<(Option<usize>, Vec<String>)> {
+    let mut days = solution
+        .tours
+        .iter()
+        .map(|tour| (tour.day, get_ids_from_tour(tour).into_iter().flatten().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    days.sort();
+
+    days
+}
+
+#[test]
+fn can_serve_jobs_on_their_days() {
+    let problem = create_problem_with_calendar(
+        vec![
+            create_job_with_days("job1", (1., 0.), Some(vec![0])),
+            create_job_with_days("job2", (2., 0.), Some(vec![2])),
+        ],
+        vec![(0, None), (1, None), (2, None)],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_tour_days(&solution),
+        vec![
+            (Some(0), vec!["departure".to_string(), "job1".to_string(), "arrival".to_string()]),
+            (Some(2), vec!["departure".to_string(), "job2".to_string(), "arrival".to_string()]),
+        ]
+    );
+    let days = solution.statistic.days.as_ref().expect("no day statistic");
+    assert_eq!(days.iter().map(|day| (day.day, day.tours)).collect::<Vec<_>>(), vec![(0, 1), (2, 1)]);
+}
+
+#[test]
+fn can_use_day_with_lower_fixed_cost() {
+    let problem = create_problem_with_calendar(
+        vec![create_job_with_days("job1", (1., 0.), Some(vec![0, 1]))],
+        vec![(0, Some(100.)), (1, Some(10.))],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_tour_days(&solution),
+        vec![(Some(1), vec!["departure".to_string(), "job1".to_string(), "arrival".to_string()])]
+    );
+    assert_eq!(solution.statistic.cost, solution.statistic.days.as_ref().unwrap()[0].cost);
+}
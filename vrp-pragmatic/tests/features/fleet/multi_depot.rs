@@ -0,0 +1,75 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_depots(vehicle_ids: Vec<&str>, is_open: bool) -> VehicleType {
+    VehicleType {
+        vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+        shifts: vec![VehicleShift {
+            depots: Some(ShiftDepots {
+                start: Some(vec![(100., 0.).to_loc()]),
+                end: if is_open { None } else { Some(vec![(100., 0.).to_loc()]) },
+            }),
+            ..if is_open { create_default_open_vehicle_shift() } else { create_default_vehicle_shift() }
+        }],
+        capacity: vec![1].into(),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn get_depot_locations(tour: &Tour) -> (Option<f64>, Option<f64>) {
+    let get_lat = |stop: Option<&Stop>| match stop.and_then(|stop| stop.location()) {
+        Some(Location::Coordinate { lat, .. }) => Some(*lat),
+        _ => None,
+    };
+
+    (get_lat(tour.stops.first()), get_lat(tour.stops.last()))
+}
+
+#[test]
+fn can_select_closest_depot() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (101., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_depots(vec!["my_vehicle_1"], false)],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_depot_locations(&solution.tours[0]), (Some(100.), Some(100.)));
+}
+
+#[test]
+fn can_limit_tours_per_depot() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (101., 0.)), create_delivery_job("job2", (102., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_depots(vec!["my_vehicle_1", "my_vehicle_2"], true)],
+            resources: Some(vec![VehicleResource::Depot {
+                id: "depot".to_string(),
+                location: (100., 0.).to_loc(),
+                capacity: 1,
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let mut starts = solution.tours.iter().map(|tour| get_depot_locations(tour).0).collect::<Vec<_>>();
+    starts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(starts, vec![Some(0.), Some(100.)]);
+}
@@ -1,5 +1,6 @@
 use crate::format::problem::*;
 use crate::format::solution::*;
+use crate::format::LoadAmount;
 use crate::helpers::*;
 
 #[test]
@@ -15,7 +16,7 @@ fn can_use_two_dimensions() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![1, 1],
+                capacity: vec![1, 1].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -64,7 +65,7 @@ fn can_unassign_due_to_dimension_mismatch() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![1],
+                capacity: vec![1].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -89,3 +90,58 @@ fn can_unassign_due_to_dimension_mismatch() {
             .build()
     );
 }
+
+#[test]
+fn can_use_named_dimensions_beyond_inline_size() {
+    let dimensions = (0..10).map(|idx| format!("dimen_{idx}")).collect::<Vec<_>>();
+    let create_job = |id: &str, location: (f64, f64), dimension: &str| {
+        let job = create_delivery_job(id, location);
+        Job {
+            deliveries: job.deliveries.map(|tasks| {
+                tasks
+                    .into_iter()
+                    .map(|task| JobTask { demand: Some(create_named_load(&[(dimension, 1)])), ..task })
+                    .collect()
+            }),
+            ..job
+        }
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job("job1", (1., 0.), "dimen_0"),
+                create_job("job2", (2., 0.), "dimen_9"),
+                create_job("job3", (3., 0.), "dimen_5"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: create_named_load(&[("dimen_0", 1), ("dimen_9", 1)]),
+                ..create_default_vehicle_type()
+            }],
+            dimensions: Some(dimensions.clone()),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]).concat(), vec!["departure", "job1", "job2"]);
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job3"]);
+    let loads = solution.tours[0]
+        .stops
+        .iter()
+        .map(|stop| stop.load().to_vec(Some(dimensions.as_slice())))
+        .map(|load| (load[0], load[9]))
+        .collect::<Vec<_>>();
+    assert_eq!(loads, vec![(1, 1), (0, 1), (0, 0)]);
+    assert!(solution.tours[0].stops.iter().all(|stop| match stop.load() {
+        LoadAmount::Named(load) => load.len() == dimensions.len(),
+        LoadAmount::Indexed(_) => false,
+    }));
+}
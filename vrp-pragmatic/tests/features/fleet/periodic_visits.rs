@@ -0,0 +1,79 @@
+use crate::format::problem::*;
+use crate::format::solution::Solution;
+use crate::helpers::*;
+
+fn create_problem_with_periodic_job(frequency: JobFrequency, days: usize) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![Job { frequency: Some(frequency), ..create_delivery_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                calendar: Some(VehicleCalendar {
+                    days: (0..days).map(|day| VehicleDay { day, fixed: None }).collect(),
+                    min_rest: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_visit_days(solution: &Solution) -> Vec<usize> {
+    let mut days = solution.tours.iter().filter_map(|tour| tour.day).collect::<Vec<_>>();
+    days.sort();
+
+    days
+}
+
+#[test]
+fn can_schedule_visits_with_min_spacing() {
+    let problem = create_problem_with_periodic_job(
+        JobFrequency { count: 3, min_spacing: Some(2), max_spacing: None, patterns: None },
+        6,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let days = get_visit_days(&solution);
+    assert_eq!(days.len(), 3);
+    assert!(days.windows(2).all(|pair| pair[1] - pair[0] >= 2));
+}
+
+#[test]
+fn can_schedule_visits_using_day_pattern() {
+    let problem = create_problem_with_periodic_job(
+        JobFrequency { count: 3, min_spacing: None, max_spacing: None, patterns: Some(vec![vec![1, 3, 5]]) },
+        6,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_visit_days(&solution), vec![1, 3, 5]);
+}
+
+#[test]
+fn can_report_visits_which_do_not_fit_into_horizon() {
+    let mut problem = create_problem_with_periodic_job(
+        JobFrequency { count: 3, min_spacing: Some(3), max_spacing: None, patterns: None },
+        4,
+    );
+    problem.plan.jobs.push(create_delivery_job("job2", (2., 0.)));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unassigned = solution.unassigned.expect("no unassigned visits");
+    assert_eq!(unassigned.len(), 3);
+    assert!(unassigned
+        .iter()
+        .all(|job| job.job_id.starts_with("job1#") && job.reasons[0].code == "PERIODIC_CONSTRAINT"));
+}
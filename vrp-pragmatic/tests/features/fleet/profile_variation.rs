@@ -4,7 +4,7 @@ use crate::helpers::*;
 fn create_vehicle_type(type_id: &str, scale: Option<f64>) -> VehicleType {
     VehicleType {
         type_id: type_id.to_string(),
-        profile: VehicleProfile { matrix: "car".to_string(), scale },
+        profile: VehicleProfile { matrix: "car".to_string(), scale, parking: None },
         vehicle_ids: vec![format!("{type_id}_1")],
         ..create_default_vehicle_type()
     }
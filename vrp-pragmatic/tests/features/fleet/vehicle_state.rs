@@ -0,0 +1,93 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_continue_tour_from_current_vehicle_state() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (8., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        state: Some(ProblemState {
+            vehicles: vec![VehicleState {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                location: (5., 0.).to_loc(),
+                time: format_time(5.),
+                completed: Some(vec!["job1".to_string()]),
+                picked_up: None,
+                committed: Some(vec!["job2".to_string()]),
+            }],
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    assert_eq!(
+        get_ids_from_tour(tour),
+        vec![vec!["departure"], vec!["job2"], vec!["job3"], vec!["arrival"]]
+            .into_iter()
+            .map(|ids| ids.into_iter().map(|id| id.to_string()).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    );
+    let departure = tour.stops.first().unwrap();
+    assert!(matches!(departure.location(), Some(Location::Coordinate { lat, .. }) if *lat == 5.));
+    assert_eq!(departure.schedule().departure, format_time(5.));
+}
+
+#[test]
+fn can_keep_picked_up_goods_on_board() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (3., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![2].into(), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        state: Some(ProblemState {
+            vehicles: vec![VehicleState {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                location: (1., 0.).to_loc(),
+                time: format_time(1.),
+                completed: None,
+                picked_up: Some(vec!["job1".to_string()]),
+                committed: None,
+            }],
+        }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.iter().flatten().count(), 1);
+    let tour = &solution.tours[0];
+    assert_eq!(tour.vehicle_id, "my_vehicle_1");
+    assert!(tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities())
+        .any(|a| a.job_id == "job1" && a.activity_type == "delivery"));
+    assert!(tour.stops.iter().flat_map(|stop| stop.activities()).all(|a| a.activity_type != "pickup"));
+    assert_eq!(tour.stops.first().unwrap().load().to_vec(None), vec![2]);
+}
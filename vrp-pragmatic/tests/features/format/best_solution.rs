@@ -0,0 +1,101 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::sync::{Arc, Mutex};
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::{create_default_config_builder, Solver};
+use vrp_core::utils::Environment;
+
+#[test]
+fn can_receive_best_solutions_in_pragmatic_format() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let environment = Arc::new(Environment::default());
+    let snapshots = Arc::new(Mutex::new(Vec::<Solution>::new()));
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let solution = Solver::new(problem.clone(), config)
+        .with_best_solution_callback(create_best_solution_callback(problem.clone(), {
+            let snapshots = snapshots.clone();
+            move |solution| snapshots.lock().unwrap().push(solution.clone())
+        }))
+        .solve()
+        .unwrap();
+
+    let snapshots = snapshots.lock().unwrap();
+    assert!(!snapshots.is_empty());
+    assert_eq!(snapshots.last(), Some(&create_solution(problem.as_ref(), &solution, &Default::default())));
+}
+
+#[test]
+fn can_receive_best_solutions_with_vicinity_clustering() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (10., 0.)),
+            ],
+            clustering: Some(Clustering::Vicinity {
+                profile: VehicleProfile { matrix: "car".to_string(), scale: None, parking: None },
+                threshold: VicinityThresholdPolicy {
+                    duration: 3.,
+                    distance: 3.,
+                    min_shared_time: None,
+                    smallest_time_window: None,
+                    max_jobs_per_cluster: None,
+                },
+                visiting: VicinityVisitPolicy::Continue,
+                serving: VicinityServingPolicy::Original { parking: 0. },
+                filtering: None,
+            }),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let environment = Arc::new(Environment::default());
+    let snapshots = Arc::new(Mutex::new(Vec::<Solution>::new()));
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .unwrap();
+
+    let solution = Solver::new(problem.clone(), config)
+        .with_best_solution_callback(create_best_solution_callback(problem.clone(), {
+            let snapshots = snapshots.clone();
+            move |solution| snapshots.lock().unwrap().push(solution.clone())
+        }))
+        .solve()
+        .unwrap();
+
+    let snapshots = snapshots.lock().unwrap();
+    assert!(!snapshots.is_empty());
+    snapshots.iter().for_each(|snapshot| {
+        let mut job_ids = snapshot
+            .tours
+            .iter()
+            .flat_map(|tour| tour.stops.iter())
+            .flat_map(|stop| stop.activities().iter())
+            .map(|activity| activity.job_id.as_str())
+            .filter(|job_id| job_id.starts_with("job"))
+            .collect::<Vec<_>>();
+        job_ids.sort();
+        assert_eq!(job_ids, vec!["job1", "job2", "job3", "job4"]);
+    });
+    assert_eq!(snapshots.last(), Some(&create_solution(problem.as_ref(), &solution, &Default::default())));
+}
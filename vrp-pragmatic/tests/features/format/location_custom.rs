@@ -35,6 +35,8 @@ fn can_use_unknown_location() {
         travel_times: vec![0, 5, 5, 0],
         distances: vec![0, 5, 5, 0],
         error_codes: None,
+        entries: None,
+        geometries: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -32,6 +32,8 @@ fn can_use_location_index() {
         travel_times: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         distances: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         error_codes: None,
+        entries: None,
+        geometries: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
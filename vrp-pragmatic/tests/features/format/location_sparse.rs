@@ -0,0 +1,104 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::{CustomLocationType, Location};
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_use_sparse_matrix_with_approximation_fallback() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (0.001, 0.)), create_delivery_job("job2", (0.002, 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![],
+        distances: vec![],
+        error_codes: None,
+        entries: Some(vec![MatrixEntry(2, 0, 7, 5), MatrixEntry(0, 2, 7, 5)]),
+        geometries: None,
+    };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        SolutionBuilder::default()
+            .tour(
+                TourBuilder::default()
+                    .stops(vec![
+                        StopBuilder::default()
+                            .coordinate((0., 0.))
+                            .schedule_stamp(0., 0.)
+                            .load(vec![2])
+                            .build_departure(),
+                        StopBuilder::default()
+                            .coordinate((0.001, 0.))
+                            .schedule_stamp(7., 8.)
+                            .load(vec![1])
+                            .distance(5)
+                            .build_single("job1", "delivery"),
+                        StopBuilder::default()
+                            .coordinate((0.002, 0.))
+                            .schedule_stamp(19., 20.)
+                            .load(vec![0])
+                            .distance(116)
+                            .build_single("job2", "delivery"),
+                    ])
+                    .statistic(Statistic {
+                        // NOTE job1->job2 route is approximated: 111 meters with default speed
+                        cost: 146.,
+                        distance: 116,
+                        ..StatisticBuilder::default().driving(18).serving(2).build()
+                    })
+                    .build()
+            )
+            .build()
+    );
+}
+
+#[test]
+fn can_reject_sparse_matrix_with_unknown_location() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (0.001, 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: Location::Custom { r#type: CustomLocationType::Unknown },
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![],
+        distances: vec![],
+        error_codes: None,
+        entries: Some(vec![]),
+        geometries: None,
+    };
+
+    let result = (problem, vec![matrix]).read_pragmatic();
+
+    assert!(result.is_err());
+}
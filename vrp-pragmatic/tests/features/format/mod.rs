@@ -1,2 +1,4 @@
+mod best_solution;
 mod location_custom;
 mod location_index;
+mod location_sparse;
@@ -4,7 +4,12 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_max_duration_limit(max_duration: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, max_duration: Some(max_duration), tour_size: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            max_duration: Some(max_duration),
+            tour_size: None,
+            driving_rules: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -22,6 +27,8 @@ fn can_limit_one_job_by_max_duration() {
         travel_times: vec![1, 100, 100, 1],
         distances: vec![1, 1, 1, 1],
         error_codes: None,
+        entries: None,
+        geometries: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
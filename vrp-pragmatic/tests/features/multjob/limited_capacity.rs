@@ -34,6 +34,8 @@ fn can_handle_limited_capacity() {
             distance: 36,
             duration: 42,
             times: Timing { driving: 36, serving: 6, ..Timing::default() },
+            tardiness: None,
+            days: None,
         }
     );
     assert!(solution.unassigned.is_none());
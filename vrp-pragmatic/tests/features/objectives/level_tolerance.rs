@@ -0,0 +1,53 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_accept_worse_cost_for_fewer_tours_within_tolerance, (tolerance, expected_tours), {
+    can_accept_worse_cost_for_fewer_tours_within_tolerance_impl(tolerance, expected_tours);
+}}
+
+can_accept_worse_cost_for_fewer_tours_within_tolerance! {
+    case01_no_tolerance: (None, 2),
+    case02_relative_too_small: (Some(ObjectiveTolerance { relative: Some(0.1), absolute: None }), 2),
+    case03_relative_enough: (Some(ObjectiveTolerance { relative: Some(0.5), absolute: None }), 1),
+    case04_absolute_too_small: (Some(ObjectiveTolerance { relative: None, absolute: Some(5.) }), 2),
+    case05_absolute_enough: (Some(ObjectiveTolerance { relative: None, absolute: Some(15.) }), 1),
+}
+
+fn can_accept_worse_cost_for_fewer_tours_within_tolerance_impl(
+    tolerance: Option<ObjectiveTolerance>,
+    expected_tours: usize,
+) {
+    // NOTE one tour costs 10 + 30 + 32 = 72, two tours cost 2 * (10 + 10 + 11) = 62
+    let cost_level = if let Some(tolerance) = tolerance {
+        ObjectiveLevel::WithTolerance { objectives: vec![MinimizeCost], tolerance }
+    } else {
+        vec![MinimizeCost].into()
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (-10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            vec![MinimizeUnassigned { breaks: None }].into(),
+            cost_level,
+            vec![MinimizeTours].into(),
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+}
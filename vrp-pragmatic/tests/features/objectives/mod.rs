@@ -0,0 +1,2 @@
+mod level_tolerance;
+mod weighted_sum;
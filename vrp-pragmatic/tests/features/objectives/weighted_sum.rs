@@ -0,0 +1,59 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(objectives: Vec<ObjectiveLevel>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (-10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_trade_cost_for_tours_using_weighted_sum, (tours_weight, expected_tours), {
+    can_trade_cost_for_tours_using_weighted_sum_impl(tours_weight, expected_tours);
+}}
+
+can_trade_cost_for_tours_using_weighted_sum! {
+    case01_cheap_tours: (5., 2),
+    case02_expensive_tours: (50., 1),
+}
+
+fn can_trade_cost_for_tours_using_weighted_sum_impl(tours_weight: f64, expected_tours: usize) {
+    // NOTE one tour costs 10 + 30 + 32 = 72, two tours cost 2 * (10 + 10 + 11) = 62
+    let problem = create_problem(vec![
+        vec![MinimizeUnassigned { breaks: None }].into(),
+        vec![WeightedSum { objectives: vec![MinimizeCost, MinimizeTours], weights: vec![1., tours_weight] }].into(),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+}
+
+#[test]
+fn can_detect_weighted_sum_with_other_objectives_on_the_same_level() {
+    let problem = create_problem(vec![
+        vec![MinimizeUnassigned { breaks: None }].into(),
+        vec![WeightedSum { objectives: vec![MinimizeCost, MinimizeTours], weights: vec![1., 1.] }, MaximizeTours]
+            .into(),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let result = (problem, vec![matrix]).read_pragmatic();
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_test_problem(max_ride_duration: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    max_ride_duration: Some(max_ride_duration),
+                    ..create_pickup_delivery_job("job1", (1., 0.), (3., 0.))
+                },
+                create_pickup_delivery_job("job2", (2., 0.), (20., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_deliver_directly_to_follow_max_ride_duration() {
+    let problem = create_test_problem(2.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![
+            vec!["departure".to_string()],
+            vec!["job1".to_string()],
+            vec!["job1".to_string()],
+            vec!["job2".to_string()],
+            vec!["job2".to_string()],
+            vec!["arrival".to_string()],
+        ]
+    );
+}
+
+#[test]
+fn can_unassign_job_when_max_ride_duration_cannot_be_met() {
+    let problem = create_test_problem(1.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "MAX_RIDE_DURATION_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to max ride duration constraint".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+            }]
+        }])
+    );
+}
@@ -15,22 +15,22 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None })
+    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None, driving_rules: None })
 }
 
-fn create_order_objective(is_constrained: bool) -> Vec<Vec<Objective>> {
+fn create_order_objective(is_constrained: bool) -> Vec<ObjectiveLevel> {
     if is_constrained {
         vec![
-            vec![Objective::MinimizeUnassigned { breaks: None }],
-            vec![Objective::MinimizeTours],
-            vec![Objective::MinimizeCost],
+            vec![Objective::MinimizeUnassigned { breaks: None }].into(),
+            vec![Objective::MinimizeTours].into(),
+            vec![Objective::MinimizeCost].into(),
         ]
     } else {
         vec![
-            vec![Objective::MinimizeUnassigned { breaks: None }],
-            vec![Objective::MinimizeTours],
-            vec![Objective::TourOrder],
-            vec![Objective::MinimizeCost],
+            vec![Objective::MinimizeUnassigned { breaks: None }].into(),
+            vec![Objective::MinimizeTours].into(),
+            vec![Objective::TourOrder].into(),
+            vec![Objective::MinimizeCost].into(),
         ]
     }
 }
@@ -147,8 +147,17 @@ fn can_follow_order_when_prioritized_property_set() {
 fn can_handle_order_between_special_activities() {
     let create_test_job = |id: &str, location: (f64, f64), order: i32| Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: location.to_loc(), duration: 100., tag: None }],
-            demand: Some(vec![1]),
+            places: vec![JobPlace {
+                times: None,
+                location: location.to_loc(),
+                duration: 100.,
+                tag: None,
+                tolerance: None,
+                days: None,
+                durations: None,
+                parking: None,
+            }],
+            demand: Some(vec![1].into()),
             order: Some(order),
         }]),
         ..create_job(id)
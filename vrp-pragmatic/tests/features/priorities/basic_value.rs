@@ -9,21 +9,21 @@ parameterized_test! {can_prefer_jobs_with_more_value, objectives, {
 
 can_prefer_jobs_with_more_value! {
     case01: Some(vec![
-        vec![MinimizeUnassigned { breaks: None }],
-        vec![MaximizeValue { breaks: None }],
-        vec![MinimizeCost],
+        vec![MinimizeUnassigned { breaks: None }].into(),
+        vec![MaximizeValue { breaks: None }].into(),
+        vec![MinimizeCost].into(),
     ]),
     case02: None,
 }
 
-fn can_prefer_jobs_with_more_value_impl(objectives: Option<Vec<Vec<Objective>>>) {
+fn can_prefer_jobs_with_more_value_impl(objectives: Option<Vec<ObjectiveLevel>>) {
     let problem = Problem {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job_with_value("job2", (2., 0.), 100.)],
             ..create_empty_plan()
         },
         fleet: Fleet {
-            vehicles: vec![VehicleType { capacity: vec![1], ..create_default_vehicle_type() }],
+            vehicles: vec![VehicleType { capacity: vec![1].into(), ..create_default_vehicle_type() }],
             ..create_default_fleet()
         },
         objectives,
@@ -19,6 +19,10 @@ fn can_use_recharge_trivial_case() {
                             duration: 0.0,
                             times: None,
                             tag: None,
+                            tolerance: None,
+                            days: None,
+                            durations: None,
+                            parking: None,
                         }],
                     }),
                     ..create_default_vehicle_shift_with_locations((0., 0.), (100., 0.))
@@ -66,6 +70,10 @@ fn can_still_skip_jobs_with_recharge() {
                             duration: 900.,
                             times: None,
                             tag: None,
+                            tolerance: None,
+                            days: None,
+                            durations: None,
+                            parking: None,
                         }],
                     }),
                     ..create_default_vehicle_shift_with_locations((52.5189, 13.4011), (52.5189, 13.4011))
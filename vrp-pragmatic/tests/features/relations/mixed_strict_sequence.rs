@@ -150,7 +150,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![5],
+                capacity: vec![5].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
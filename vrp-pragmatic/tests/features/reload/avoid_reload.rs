@@ -36,8 +36,9 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    depots: None,
                 }],
-                capacity: vec![2],
+                capacity: vec![2].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
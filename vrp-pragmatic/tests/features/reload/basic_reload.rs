@@ -44,8 +44,9 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    depots: None,
                 }],
-                capacity: vec![1],
+                capacity: vec![1].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
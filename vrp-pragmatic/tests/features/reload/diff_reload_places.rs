@@ -36,8 +36,9 @@ fn can_use_reloads_with_different_locations() {
                         },
                     ]),
                     recharges: None,
+                    depots: None,
                 }],
-                capacity: vec![2],
+                capacity: vec![2].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -31,8 +31,9 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    depots: None,
                 }],
-                capacity: vec![2],
+                capacity: vec![2].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -82,7 +83,7 @@ fn can_properly_handle_load_without_capacity_violation() {
                     ]),
                     ..create_default_vehicle_shift()
                 }],
-                capacity: vec![2],
+                capacity: vec![2].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -27,8 +27,9 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    depots: None,
                 }],
-                capacity: vec![1],
+                capacity: vec![1].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
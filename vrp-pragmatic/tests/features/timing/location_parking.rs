@@ -0,0 +1,80 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+fn create_problem_with_parking(job_parking: Option<f64>, vehicle_parking: Option<f64>) -> Problem {
+    let mut job1 = create_delivery_job("job1", (10., 0.));
+    job1.deliveries.as_mut().unwrap()[0].places[0].parking = job_parking;
+
+    Problem {
+        plan: Plan {
+            jobs: vec![job1, create_delivery_job("job2", (10., 0.)), create_delivery_job("job3", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: VehicleProfile { parking: vehicle_parking, ..create_default_vehicle_profile() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_parking(tour: &Tour, location: (f64, f64)) -> Option<i64> {
+    tour.stops
+        .iter()
+        .filter_map(|stop| stop.as_point())
+        .find(|stop| stop.location == location.to_loc())
+        .and_then(|stop| stop.parking.as_ref())
+        .map(|parking| (parse_time(&parking.end) - parse_time(&parking.start)) as i64)
+}
+
+parameterized_test! {can_share_parking_time_at_the_same_location, (job_parking, vehicle_parking, expected), {
+    can_share_parking_time_at_the_same_location_impl(job_parking, vehicle_parking, expected);
+}}
+
+can_share_parking_time_at_the_same_location! {
+    case01_job_parking: (Some(5.), None, (Some(5), None, 5)),
+    case02_vehicle_parking: (None, Some(3.), (Some(3), Some(3), 6)),
+    case03_job_and_vehicle_parking: (Some(5.), Some(3.), (Some(5), Some(3), 8)),
+}
+
+fn can_share_parking_time_at_the_same_location_impl(
+    job_parking: Option<f64>,
+    vehicle_parking: Option<f64>,
+    expected: (Option<i64>, Option<i64>, i64),
+) {
+    let problem = create_problem_with_parking(job_parking, vehicle_parking);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.stops.len(), 4);
+    assert_eq!(get_parking(tour, (10., 0.)), expected.0);
+    assert_eq!(get_parking(tour, (20., 0.)), expected.1);
+    assert_eq!(get_parking(tour, (0., 0.)), None);
+    assert_eq!(tour.statistic.times.parking, expected.2);
+    assert_eq!(tour.statistic.times.driving, 40);
+    assert_eq!(tour.statistic.duration, 43 + expected.2);
+}
+
+#[test]
+fn can_report_stop_arrival_before_parking() {
+    let mut problem = create_problem_with_parking(Some(5.), None);
+    problem.plan.jobs.truncate(1);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let stop = solution.tours.first().unwrap().stops[1].as_point().unwrap();
+    assert_eq!(stop.time.arrival, format_time(10.));
+    assert_eq!(stop.time.departure, format_time(16.));
+    assert_eq!(stop.parking, Some(Interval { start: format_time(10.), end: format_time(15.) }));
+    assert_eq!(stop.activities[0].time, Some(Interval { start: format_time(15.), end: format_time(16.) }));
+}
@@ -1,4 +1,8 @@
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod location_parking;
+mod service_durations;
+mod soft_time_windows;
+mod speed_bands;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
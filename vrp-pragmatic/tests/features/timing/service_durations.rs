@@ -0,0 +1,58 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_service_durations(times: Vec<(i32, i32)>, durations: Option<Vec<(f64, f64)>>) -> Problem {
+    let mut job = create_delivery_job_with_times("job1", (10., 0.), times, 5.);
+    job.deliveries.as_mut().unwrap()[0].places[0].durations = durations.map(|durations| {
+        durations
+            .into_iter()
+            .map(|(start, duration)| JobPlaceDuration { start: format_time(start), duration })
+            .collect()
+    });
+
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_service_duration_of_band_where_service_starts() {
+    let problem = create_problem_with_service_durations(vec![(30, 100)], Some(vec![(0., 10.), (20., 15.)]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.stops[1].schedule().departure, format_time(45.));
+    assert_eq!(tour.statistic.times.serving, 15);
+    // NOTE departure is shifted to avoid waiting
+    assert_eq!(tour.statistic.duration, 35);
+}
+
+#[test]
+fn can_use_place_duration_before_first_band() {
+    let problem = create_problem_with_service_durations(vec![(0, 100)], Some(vec![(50., 15.)]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.stops[1].schedule().departure, format_time(15.));
+    assert_eq!(tour.statistic.times.serving, 5);
+    assert_eq!(tour.statistic.duration, 25);
+}
+
+#[test]
+fn can_skip_job_when_service_duration_violates_shift_time() {
+    let problem = create_problem_with_service_durations(vec![(0, 100)], Some(vec![(0., 2000.)]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1"]);
+}
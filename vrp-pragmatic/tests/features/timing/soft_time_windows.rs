@@ -0,0 +1,132 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem(lateness: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 10)], 0.),
+                create_delivery_job_with_times("job2", (20., 0.), vec![(10, 20)], 0.),
+                create_delivery_job_with_times("job3", (30., 0.), vec![(20, 30)], 0.),
+                create_delivery_job_with_times("job4", (40., 0.), vec![(30, 40)], 0.),
+                create_delivery_job_with_tolerance("job5", (50., 0.), vec![(0, 10)], (None, Some(lateness), 1.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_serve_job_late_within_tolerance() {
+    let problem = create_test_problem(100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        SolutionBuilder::default()
+            .tour(
+                TourBuilder::default()
+                    .stops(vec![
+                        StopBuilder::default()
+                            .coordinate((0., 0.))
+                            .schedule_stamp(0., 0.)
+                            .load(vec![5])
+                            .build_departure(),
+                        StopBuilder::default()
+                            .coordinate((10., 0.))
+                            .schedule_stamp(10., 10.)
+                            .load(vec![4])
+                            .distance(10)
+                            .build_single("job1", "delivery"),
+                        StopBuilder::default()
+                            .coordinate((20., 0.))
+                            .schedule_stamp(20., 20.)
+                            .load(vec![3])
+                            .distance(20)
+                            .build_single("job2", "delivery"),
+                        StopBuilder::default()
+                            .coordinate((30., 0.))
+                            .schedule_stamp(30., 30.)
+                            .load(vec![2])
+                            .distance(30)
+                            .build_single("job3", "delivery"),
+                        StopBuilder::default()
+                            .coordinate((40., 0.))
+                            .schedule_stamp(40., 40.)
+                            .load(vec![1])
+                            .distance(40)
+                            .build_single("job4", "delivery"),
+                        StopBuilder::default()
+                            .coordinate((50., 0.))
+                            .schedule_stamp(50., 51.)
+                            .load(vec![0])
+                            .distance(50)
+                            .build_single("job5", "delivery"),
+                        StopBuilder::default()
+                            .coordinate((0., 0.))
+                            .schedule_stamp(101., 101.)
+                            .load(vec![0])
+                            .distance(100)
+                            .build_arrival(),
+                    ])
+                    .statistic(Statistic {
+                        // NOTE 40 seconds of lateness are penalized with 1 per second
+                        cost: 251.,
+                        ..StatisticBuilder::default().driving(100).serving(1).tardiness(40).build()
+                    })
+                    .build()
+            )
+            .build()
+    );
+}
+
+#[test]
+fn can_have_unassigned_job_when_lateness_exceeds_tolerance() {
+    let problem = create_test_problem(20.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.tardiness, None);
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job5"]);
+}
+
+parameterized_test! {can_wait_or_serve_job_early_within_tolerance, (penalty, expected_departure), {
+    can_wait_or_serve_job_early_within_tolerance_impl(penalty, expected_departure);
+}}
+
+can_wait_or_serve_job_early_within_tolerance! {
+    case01_waits_when_earliness_is_expensive: (2., 51.),
+    case02_serves_early_when_earliness_is_cheap: (0.5, 21.),
+}
+
+fn can_wait_or_serve_job_early_within_tolerance_impl(penalty: f64, expected_departure: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_tolerance(
+                "job1",
+                (10., 0.),
+                vec![(50, 60)],
+                (Some(30.), None, penalty),
+            )],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let stop = solution.tours[0].stops[1].as_point().unwrap();
+    assert_eq!(stop.time.departure, format_time(expected_departure));
+    assert_eq!(solution.statistic.tardiness, Some(0));
+}
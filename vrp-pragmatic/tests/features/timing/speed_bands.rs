@@ -0,0 +1,48 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_speed_bands(speed_bands: Option<Vec<(f64, f64)>>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                fifo: None,
+                speed_bands: speed_bands.map(|bands| {
+                    bands.into_iter().map(|(start, factor)| SpeedBand { start: format_time(start), factor }).collect()
+                }),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_speed_bands_crossing_band_boundary() {
+    let problem = create_problem_with_speed_bands(Some(vec![(0., 0.5), (10., 1.)]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.stops[1].schedule().arrival, format_time(15.));
+    assert_eq!(tour.statistic.distance, 20);
+    assert_eq!(tour.statistic.duration, 26);
+}
+
+#[test]
+fn can_use_free_flow_without_speed_bands() {
+    let problem = create_problem_with_speed_bands(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.stops[1].schedule().arrival, format_time(10.));
+    assert_eq!(tour.statistic.duration, 21);
+}
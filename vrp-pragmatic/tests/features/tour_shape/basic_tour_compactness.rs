@@ -37,10 +37,10 @@ fn can_compact_tour() {
             ..create_default_fleet()
         },
         objectives: Some(vec![
-            vec![MinimizeUnassigned { breaks: None }],
-            vec![MinimizeTours],
-            vec![CompactTour { options: CompactOptions { job_radius: 2, threshold: 2, distance: 0. } }],
-            vec![MinimizeCost],
+            vec![MinimizeUnassigned { breaks: None }].into(),
+            vec![MinimizeTours].into(),
+            vec![CompactTour { options: CompactOptions { job_radius: 2, threshold: 2, distance: 0. } }].into(),
+            vec![MinimizeCost].into(),
         ]),
         ..create_empty_problem()
     };
@@ -38,23 +38,23 @@ fn can_balance_activities_with_threshold_impl(threshold: Option<f64>, expected_l
                 VehicleType {
                     vehicle_ids: vec!["my_vehicle1".to_string()],
                     shifts: vec![create_default_open_vehicle_shift()],
-                    capacity: vec![4],
+                    capacity: vec![4].into(),
                     ..create_default_vehicle_type()
                 },
                 VehicleType {
                     type_id: "my_vehicle2".to_string(),
                     vehicle_ids: vec!["my_vehicle2".to_string()],
                     shifts: vec![create_default_vehicle_shift_with_locations((3., 0.), (3., 0.))],
-                    capacity: vec![4],
+                    capacity: vec![4].into(),
                     ..create_default_vehicle_type()
                 },
             ],
             ..create_default_fleet()
         },
         objectives: Some(vec![
-            vec![MinimizeUnassigned { breaks: None }],
-            vec![BalanceActivities { options: Some(BalanceOptions { threshold }) }],
-            vec![MinimizeCost],
+            vec![MinimizeUnassigned { breaks: None }].into(),
+            vec![BalanceActivities { options: Some(BalanceOptions { threshold }) }].into(),
+            vec![MinimizeCost].into(),
         ]),
         ..create_empty_problem()
     };
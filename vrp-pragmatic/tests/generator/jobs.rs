@@ -75,7 +75,7 @@ prop_compose! {
                         tag: Some("p1".to_owned()),
                         ..pickup
                     }
-                ], demand: demand.clone(), order }
+                ], demand: demand.clone().map(|demand| demand.into()), order }
             ]),
             deliveries: Some(vec![
              JobTask { places: vec![
@@ -83,15 +83,15 @@ prop_compose! {
                         tag: Some("d1".to_owned()),
                         ..delivery
                     }
-                ], demand, order: None }
+                ], demand: demand.map(|demand| demand.into()), order: None }
             ]),
             replacements: None,
             services: None,
             skills,
             value,
             group,
-            compatibility
-        }
+            compatibility,
+            max_ride_duration: None, product: None, frequency: None, }
     }
 }
 
@@ -137,7 +137,7 @@ prop_compose! {
             value,
             group,
             compatibility,
-        }
+            max_ride_duration: None, product: None, frequency: None, }
     }
 }
 
@@ -152,7 +152,7 @@ prop_compose! {
      demand in demand_proto,
      order in order_proto,
     ) -> JobTask {
-       JobTask { places: vec![place], demand, order }
+       JobTask { places: vec![place], demand: demand.map(|demand| demand.into()), order }
     }
 }
 
@@ -169,7 +169,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, tolerance: None, days: None, durations: None, parking: None, }
     }
 }
 
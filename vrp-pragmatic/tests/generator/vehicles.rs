@@ -30,10 +30,9 @@ prop_compose! {
             profile,
             costs,
             shifts,
-            capacity,
+            capacity: capacity.into(),
             skills,
-            limits,
-        }
+            limits, compartments: None, calendar: None, }
     }
 }
 
@@ -86,8 +85,7 @@ prop_compose! {
           end: places.1,
           breaks,
           reloads,
-          recharges,
-        }
+          recharges, depots: None, }
     }
 }
 
@@ -107,7 +105,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None }
+        Fleet { vehicles, profiles, drivers: None, resources: None, dimensions: None, }
     }
 }
 
@@ -39,6 +39,7 @@ fn test_vehicle_impl(id: &str, has_open_end: bool) -> Vehicle {
         details: vec![VehicleDetail {
             start: Some(VehiclePlace { location: 0, time: Default::default() }),
             end: if has_open_end { None } else { Some(VehiclePlace { location: 0, time: Default::default() }) },
+            depots: Default::default(),
         }],
     }
 }
@@ -1,6 +1,6 @@
 use crate::format::problem::Objective::{MinimizeCost, MinimizeUnassigned};
 use crate::format::problem::*;
-use crate::format::{CoordIndex, Location};
+use crate::format::{CoordIndex, LoadAmount, Location};
 use crate::format_time;
 use crate::helpers::ToLocation;
 use std::sync::Arc;
@@ -9,11 +9,20 @@ use vrp_core::models::problem::{TransportCost, TravelTime};
 use vrp_core::models::solution::Route;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace {
+        times: None,
+        location: location.to_loc(),
+        duration: 1.,
+        tag,
+        tolerance: None,
+        days: None,
+        durations: None,
+        parking: None,
+    }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
-    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None }
+    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1].into()), order: None }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -27,6 +36,9 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        max_ride_duration: None,
+        product: None,
+        frequency: None,
     }
 }
 
@@ -38,7 +50,7 @@ pub fn create_delivery_job_with_order(id: &str, location: (f64, f64), order: i32
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![create_job_place(location, None)],
-            demand: Some(vec![1]),
+            demand: Some(vec![1].into()),
             order: Some(order),
         }]),
         ..create_job(id)
@@ -49,7 +61,7 @@ pub fn create_delivery_job_with_group(id: &str, location: (f64, f64), group: &st
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![create_job_place(location, None)],
-            demand: Some(vec![1]),
+            demand: Some(vec![1].into()),
             order: None,
         }]),
         group: Some(group.to_string()),
@@ -61,7 +73,7 @@ pub fn create_delivery_job_with_compatibility(id: &str, location: (f64, f64), co
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![create_job_place(location, None)],
-            demand: Some(vec![1]),
+            demand: Some(vec![1].into()),
             order: None,
         }]),
         compatibility: Some(compatibility.to_string()),
@@ -74,14 +86,17 @@ pub fn create_delivery_job_with_skills(id: &str, location: (f64, f64), skills: J
 }
 
 pub fn create_delivery_job_with_demand(id: &str, location: (f64, f64), demand: Vec<i32>) -> Job {
-    Job { deliveries: Some(vec![JobTask { demand: Some(demand), ..create_task(location, None) }]), ..create_job(id) }
+    Job {
+        deliveries: Some(vec![JobTask { demand: Some(demand.into()), ..create_task(location, None) }]),
+        ..create_job(id)
+    }
 }
 
 pub fn create_delivery_job_with_duration(id: &str, location: (f64, f64), duration: f64) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace { duration, ..create_job_place(location, None) }],
-            demand: Some(vec![1]),
+            demand: Some(vec![1].into()),
             order: None,
         }]),
         ..create_job(id)
@@ -92,13 +107,26 @@ pub fn create_delivery_job_with_times(id: &str, location: (f64, f64), times: Vec
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location, None) }],
-            demand: Some(vec![1]),
+            demand: Some(vec![1].into()),
             order: None,
         }]),
         ..create_job(id)
     }
 }
 
+pub fn create_delivery_job_with_tolerance(
+    id: &str,
+    location: (f64, f64),
+    times: Vec<(i32, i32)>,
+    tolerance: (Option<f64>, Option<f64>, f64),
+) -> Job {
+    let (earliness, lateness, penalty) = tolerance;
+    let mut job = create_delivery_job_with_times(id, location, times, 1.);
+    job.deliveries.as_mut().unwrap()[0].places[0].tolerance = Some(JobPlaceTolerance { earliness, lateness, penalty });
+
+    job
+}
+
 pub fn create_delivery_job_with_value(id: &str, location: (f64, f64), value: f64) -> Job {
     Job { deliveries: Some(vec![create_task(location, None)]), value: Some(value), ..create_job(id) }
 }
@@ -108,7 +136,10 @@ pub fn create_pickup_job(id: &str, location: (f64, f64)) -> Job {
 }
 
 pub fn create_pickup_job_with_demand(id: &str, location: (f64, f64), demand: Vec<i32>) -> Job {
-    Job { pickups: Some(vec![JobTask { demand: Some(demand), ..create_task(location, None) }]), ..create_job(id) }
+    Job {
+        pickups: Some(vec![JobTask { demand: Some(demand.into()), ..create_task(location, None) }]),
+        ..create_job(id)
+    }
 }
 
 pub fn create_replacement_job(id: &str, location: (f64, f64)) -> Job {
@@ -140,7 +171,7 @@ pub fn create_pickup_delivery_job_with_params(
                 times: convert_times(&pickup.2),
                 ..create_job_place(pickup.0, Some("p1".to_string()))
             }],
-            demand: Some(demand.clone()),
+            demand: Some(demand.clone().into()),
             order: None,
         }]),
         deliveries: Some(vec![JobTask {
@@ -149,7 +180,7 @@ pub fn create_pickup_delivery_job_with_params(
                 times: convert_times(&delivery.2),
                 ..create_job_place(delivery.0, Some("d1".to_string()))
             }],
-            demand: Some(demand),
+            demand: Some(demand.into()),
             order: None,
         }]),
 
@@ -160,8 +191,17 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
-            demand: Some(vec![1]),
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                tolerance: None,
+                days: None,
+                durations: None,
+                parking: None,
+            }],
+            demand: Some(vec![1].into()),
             order: None,
         }]),
         ..create_job(id)
@@ -182,7 +222,7 @@ pub fn create_multi_job(
                     duration,
                     ..create_job_place((location.0, location.1), Some(format!("{}{}", prefix, i + 1)))
                 }],
-                demand: Some(demand),
+                demand: Some(demand.into()),
                 order: None,
             })
             .collect::<Vec<_>>();
@@ -212,6 +252,7 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
         breaks: None,
         reloads: None,
         recharges: None,
+        depots: None,
     }
 }
 
@@ -222,6 +263,7 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
         breaks: None,
         reloads: None,
         recharges: None,
+        depots: None,
     }
 }
 
@@ -230,11 +272,15 @@ pub fn create_default_vehicle_costs() -> VehicleCosts {
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
-    VehicleProfile { matrix: "car".to_string(), scale: None }
+    VehicleProfile { matrix: "car".to_string(), scale: None, parking: None }
 }
 
 pub fn create_vehicle_profile_with_name(name: &str) -> VehicleProfile {
-    VehicleProfile { matrix: name.to_string(), scale: None }
+    VehicleProfile { matrix: name.to_string(), scale: None, parking: None }
+}
+
+pub fn create_named_load(values: &[(&str, i32)]) -> LoadAmount {
+    LoadAmount::Named(values.iter().map(|(name, value)| (name.to_string(), *value)).collect())
 }
 
 pub fn create_default_vehicle_type() -> VehicleType {
@@ -252,22 +298,41 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         profile: create_default_vehicle_profile(),
         costs: create_default_vehicle_costs(),
         shifts: vec![create_default_vehicle_shift()],
-        capacity,
+        capacity: capacity.into(),
         skills: None,
         limits: None,
+        compartments: None,
+        calendar: None,
+    }
+}
+
+pub fn create_default_driver_type(id: &str) -> DriverType {
+    DriverType {
+        type_id: id.to_string(),
+        driver_ids: vec![format!("{id}_1")],
+        costs: VehicleCosts { fixed: None, distance: 0., time: 1. },
+        skills: None,
+        shifts: None,
+        vehicle_types: None,
     }
 }
 
 pub fn create_default_fleet() -> Fleet {
-    Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles(), resources: None }
+    Fleet {
+        vehicles: vec![create_default_vehicle_type()],
+        profiles: create_default_matrix_profiles(),
+        drivers: None,
+        resources: None,
+        dimensions: None,
+    }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile { name: "car".to_string(), speed: None, fifo: None, speed_bands: None }]
 }
 
-pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
-    Some(vec![vec![MinimizeUnassigned { breaks: None }], vec![MinimizeCost]])
+pub fn create_min_jobs_cost_objective() -> Option<Vec<ObjectiveLevel>> {
+    Some(vec![vec![MinimizeUnassigned { breaks: None }].into(), vec![MinimizeCost].into()])
 }
 
 pub fn create_empty_plan() -> Plan {
@@ -277,8 +342,9 @@ pub fn create_empty_plan() -> Plan {
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: create_empty_plan(),
-        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], drivers: None, resources: None, dimensions: None },
         objectives: None,
+        state: None,
     }
 }
 
@@ -293,6 +359,8 @@ pub fn create_matrix(data: Vec<i64>) -> Matrix {
         travel_times: data.clone(),
         distances: data,
         error_codes: None,
+        entries: None,
+        geometries: None,
     }
 }
 
@@ -24,7 +24,7 @@ impl StopBuilder {
         Self {
             stop: Stop::Transit(TransitStop {
                 time: Schedule { arrival: format_time(0.), departure: format_time(0.) },
-                load: vec![],
+                load: vec![].into(),
                 activities: vec![],
             }),
         }
@@ -63,7 +63,7 @@ impl StopBuilder {
     }
 
     pub fn load(mut self, load: Vec<i32>) -> Self {
-        *self.stop.load_mut() = load;
+        *self.stop.load_mut() = load.into();
 
         self
     }
@@ -168,7 +168,7 @@ impl Default for StopBuilder {
                 location: Location::Coordinate { lat: 0., lng: 0. },
                 time: Schedule { arrival: format_time(0.), departure: format_time(0.) },
                 distance: 0,
-                load: vec![],
+                load: vec![].into(),
                 parking: None,
                 activities: vec![],
             }),
@@ -239,6 +239,12 @@ impl ActivityBuilder {
         self
     }
 
+    pub fn compartment(mut self, compartment: &str) -> Self {
+        self.activity.compartment = Some(compartment.to_string());
+
+        self
+    }
+
     pub fn build(self) -> Activity {
         if self.activity.activity_type.is_empty() {
             panic!("missing activity type");
@@ -262,6 +268,7 @@ impl Default for ActivityBuilder {
                 time: None,
                 job_tag: None,
                 commute: None,
+                compartment: None,
             },
         }
     }
@@ -298,6 +305,12 @@ impl StatisticBuilder {
         self
     }
 
+    pub fn tardiness(mut self, tardiness: i64) -> Self {
+        self.statistic.tardiness = Some(tardiness);
+
+        self
+    }
+
     pub fn build(self) -> Statistic {
         let mut statistic = self.statistic;
         let (per_distance, per_time) = self.costs;
@@ -341,6 +354,18 @@ impl TourBuilder {
         self
     }
 
+    pub fn driver_id(mut self, id: &str) -> Self {
+        self.tour.driver_id = Some(id.to_string());
+
+        self
+    }
+
+    pub fn day(mut self, day: usize) -> Self {
+        self.tour.day = Some(day);
+
+        self
+    }
+
     pub fn stops(mut self, stops: Vec<Stop>) -> Self {
         self.tour.stops = stops;
 
@@ -369,8 +394,10 @@ impl Default for TourBuilder {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![],
                 statistic: Default::default(),
+                day: None,
             },
         }
     }
@@ -10,7 +10,7 @@ use vrp_core::rosomaxa::evolution::TelemetryMode;
 use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
 use vrp_core::solver::{create_default_config_builder, create_elitism_population, Solver};
 use vrp_core::solver::{get_default_telemetry_mode, RefinementContext};
-use vrp_core::utils::{Environment, GenericError, Parallelism};
+use vrp_core::utils::{DefaultRandom, Environment, GenericError, Parallelism, Random};
 
 /// Runs solver with cheapest insertion heuristic.
 pub fn solve_with_cheapest_insertion(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
@@ -50,13 +50,31 @@ pub fn solve_with_metaheuristic_and_iterations_without_check(
     solve(problem, matrices, generations, false)
 }
 
+/// Runs solver with default metaheuristic and random generator initialized from the given seed.
+pub fn solve_with_metaheuristic_and_seed(problem: Problem, matrices: Option<Vec<Matrix>>, seed: u64) -> Solution {
+    solve_with_random(problem, matrices, 200, true, Arc::new(DefaultRandom::new_with_seed(seed)))
+}
+
 pub fn solve(problem: Problem, matrices: Option<Vec<Matrix>>, generations: usize, perform_check: bool) -> Solution {
+    solve_with_random(problem, matrices, generations, perform_check, Environment::default().random)
+}
+
+fn solve_with_random(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    generations: usize,
+    perform_check: bool,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Solution {
     // NOTE: hardcode cpus to guarantee rosomaxa population algorithm is used
     const AVAILABLE_CPUS: usize = 4;
 
     get_core_solution(problem, matrices, perform_check, |problem: Arc<CoreProblem>| {
-        let environment =
-            Arc::new(Environment { parallelism: Parallelism::new_with_cpus(AVAILABLE_CPUS), ..Environment::default() });
+        let environment = Arc::new(Environment {
+            random,
+            parallelism: Parallelism::new_with_cpus(AVAILABLE_CPUS),
+            ..Environment::default()
+        });
         let telemetry_mode = get_default_telemetry_mode(environment.logger.clone());
 
         create_default_config_builder(problem.clone(), environment, telemetry_mode)
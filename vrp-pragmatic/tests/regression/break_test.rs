@@ -22,8 +22,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                tolerance: None,
+                                days: None,
+                                durations: None,
+                                parking: None,
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1].into()),
                             order: None,
                         }]),
                         ..create_job("job1")
@@ -38,8 +42,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                tolerance: None,
+                                days: None,
+                                durations: None,
+                                parking: None,
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1].into()),
                             order: None,
                         }]),
                         ..create_job("job2")
@@ -54,8 +62,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                tolerance: None,
+                                days: None,
+                                durations: None,
+                                parking: None,
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1].into()),
                             order: None,
                         }]),
                         ..create_job("job3")
@@ -70,8 +82,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                tolerance: None,
+                                days: None,
+                                durations: None,
+                                parking: None,
                             }],
-                            demand: Some(vec![2]),
+                            demand: Some(vec![2].into()),
                             order: None,
                         }]),
                         ..create_job("job4")
@@ -86,8 +102,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                tolerance: None,
+                                days: None,
+                                durations: None,
+                                parking: None,
                             }],
-                            demand: Some(vec![3]),
+                            demand: Some(vec![3].into()),
                             order: None,
                         }]),
                         ..create_job("job5")
@@ -102,8 +122,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                tolerance: None,
+                                days: None,
+                                durations: None,
+                                parking: None,
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1].into()),
                             order: None,
                         }]),
                         ..create_job("job6")
@@ -115,7 +139,7 @@ fn can_handle_properly_invalid_break_removal() {
                 vehicles: vec![VehicleType {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
-                    profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+                    profile: VehicleProfile { matrix: "car".to_string(), scale: None, parking: None },
                     costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
@@ -138,10 +162,13 @@ fn can_handle_properly_invalid_break_removal() {
                         }]),
                         reloads: None,
                         recharges: None,
+                        depots: None,
                     }],
-                    capacity: vec![5],
+                    capacity: vec![5].into(),
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    calendar: None,
                 }],
                 ..create_default_fleet()
             },
@@ -13,8 +13,12 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
             duration: 0.,
             times: None,
             tag: Some(tag.to_string()),
+            tolerance: None,
+            days: None,
+            durations: None,
+            parking: None,
         }],
-        demand: Some(vec![1]),
+        demand: Some(vec![1].into()),
         order: None,
     };
 
@@ -63,6 +67,8 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
         travel_times: vec![0, 220, 2045, 152, 0, 2198, 2069, 2290, 0],
         distances: vec![0, 1612, 19774, 1155, 0, 20929, 20609, 22221, 0],
         error_codes: None,
+        entries: None,
+        geometries: None,
     }];
 
     let solution = solve_with_metaheuristic_and_iterations(problem, Some(matrices), 1000);
@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 use vrp_core::models::examples::create_example_problem;
 
@@ -31,8 +32,10 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 vehicle_id: id.to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index,
+                driver_id: None,
                 stops: vec![],
                 statistic: Statistic::default(),
+                day: None,
             })
             .collect(),
         ..SolutionBuilder::default().build()
@@ -44,6 +47,189 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
     assert_eq!(result.map_err(|_| ()), expected_result);
 }
 
+parameterized_test! {check_drivers, (tours, expected_result), {
+    check_drivers_impl(tours, expected_result);
+}}
+
+check_drivers! {
+    case_01: (vec![("my_vehicle_1", Some("driver_1"), (0., 10.))], Ok(())),
+    case_02: (vec![("my_vehicle_1", None, (0., 10.))], Err(())),
+    case_03: (vec![("my_vehicle_1", Some("driver_2"), (0., 10.))], Err(())),
+    case_04: (vec![("my_vehicle_1", Some("driver_1"), (90., 110.))], Err(())),
+    case_05: (vec![("my_vehicle_1", Some("driver_1"), (0., 10.)), ("my_vehicle_2", Some("driver_1"), (20., 30.))], Err(())),
+    case_06: (vec![("my_vehicle_1", Some("driver_1"), (0., 10.)), ("my_vehicle_2", Some("driver_1"), (200., 210.))], Ok(())),
+}
+
+fn check_drivers_impl(tours: Vec<(&str, Option<&str>, (f64, f64))>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            drivers: Some(vec![DriverType {
+                shifts: Some(vec![
+                    DriverShift { earliest: format_time(0.), latest: format_time(100.) },
+                    DriverShift { earliest: format_time(200.), latest: format_time(300.) },
+                ]),
+                ..create_default_driver_type("driver")
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: tours
+            .into_iter()
+            .map(|(vehicle_id, driver_id, (start, end))| {
+                let tour = TourBuilder::default().vehicle_id(vehicle_id).stops(vec![
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(start, start)
+                        .load(vec![0])
+                        .build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(end, end).load(vec![0]).build_arrival(),
+                ]);
+                let tour = if let Some(driver_id) = driver_id { tour.driver_id(driver_id) } else { tour };
+
+                tour.build()
+            })
+            .collect(),
+        ..SolutionBuilder::default().build()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_drivers(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {check_calendar, (tours, expected_result), {
+    check_calendar_impl(tours, expected_result);
+}}
+
+check_calendar! {
+    case_01: (vec![(0, Some(0), (0., 100.)), (1, Some(1), (86400., 86500.))], Ok(())),
+    case_02: (vec![(0, None, (0., 100.))], Err(())),
+    case_03: (vec![(1, Some(0), (86400., 86500.))], Err(())),
+    case_04: (vec![(0, Some(0), (0., 86000.)), (1, Some(1), (86400., 86500.))], Err(())),
+}
+
+fn check_calendar_impl(tours: Vec<(usize, Option<usize>, (f64, f64))>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                calendar: Some(VehicleCalendar {
+                    days: vec![VehicleDay { day: 0, fixed: None }, VehicleDay { day: 1, fixed: None }],
+                    min_rest: Some(3600.),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: tours
+            .into_iter()
+            .map(|(shift_index, day, (start, end))| {
+                let tour = TourBuilder::default().shift_index(shift_index).stops(vec![
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(start, start)
+                        .load(vec![0])
+                        .build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(end, end).load(vec![0]).build_arrival(),
+                ]);
+                let tour = if let Some(day) = day { tour.day(day) } else { tour };
+
+                tour.build()
+            })
+            .collect(),
+        ..SolutionBuilder::default().build()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_calendar(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
+parameterized_test! {check_visit_frequency, (patterns, visit_days, expected_result), {
+    check_visit_frequency_impl(patterns, visit_days, expected_result);
+}}
+
+check_visit_frequency! {
+    case_01: (None, vec![0, 2], Ok(())),
+    case_02: (None, vec![0, 1], Err(())),
+    case_03: (None, vec![3], Ok(())),
+    case_04: (Some(vec![vec![0, 2]]), vec![1, 3], Err(())),
+    case_05: (Some(vec![vec![0, 2], vec![1, 3]]), vec![1, 3], Ok(())),
+}
+
+fn check_visit_frequency_impl(
+    patterns: Option<Vec<Vec<usize>>>,
+    visit_days: Vec<usize>,
+    expected_result: Result<(), ()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                frequency: Some(JobFrequency { count: 2, min_spacing: Some(2), max_spacing: None, patterns }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                calendar: Some(VehicleCalendar {
+                    days: (0..4).map(|day| VehicleDay { day, fixed: None }).collect(),
+                    min_rest: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: visit_days
+            .into_iter()
+            .enumerate()
+            .map(|(visit_idx, day)| {
+                let offset = day as f64 * 86400.;
+                TourBuilder::default()
+                    .shift_index(day)
+                    .day(day)
+                    .stops(vec![
+                        StopBuilder::default()
+                            .coordinate((0., 0.))
+                            .schedule_stamp(offset, offset)
+                            .load(vec![1])
+                            .build_departure(),
+                        StopBuilder::default()
+                            .coordinate((1., 0.))
+                            .schedule_stamp(offset + 1., offset + 2.)
+                            .load(vec![0])
+                            .build_single(&format!("job1#{visit_idx}"), "delivery"),
+                        StopBuilder::default()
+                            .coordinate((0., 0.))
+                            .schedule_stamp(offset + 3., offset + 3.)
+                            .load(vec![0])
+                            .build_arrival(),
+                    ])
+                    .build()
+            })
+            .collect(),
+        ..SolutionBuilder::default().build()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_visit_frequency(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
 parameterized_test! {check_jobs, (jobs, tours, unassigned, expected_result), {
     check_jobs_impl(jobs, tours, unassigned, expected_result);
 }}
@@ -131,8 +317,12 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{tgt}{idx}")),
+                    tolerance: None,
+                    days: None,
+                    durations: None,
+                    parking: None,
                 }],
-                demand: if tgt != "service" { Some(vec![1]) } else { None },
+                demand: if tgt != "service" { Some(vec![1].into()) } else { None },
                 order: None,
             })
             .collect()
@@ -164,8 +354,10 @@ fn check_jobs_impl(
                 vehicle_id: id.to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index,
+                driver_id: None,
                 stops: stops.into_iter().map(create_stop).collect(),
                 statistic: Statistic::default(),
+                day: None,
             })
             .collect(),
         unassigned: Some(
@@ -86,8 +86,9 @@ fn can_check_breaks_impl(
                     }]),
                     reloads: None,
                     recharges: None,
+                    depots: None,
                 }],
-                capacity: vec![5],
+                capacity: vec![5].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -102,6 +103,7 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        compartment: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -111,6 +113,7 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            compartment: None,
         });
     }
 
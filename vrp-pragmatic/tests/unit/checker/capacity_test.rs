@@ -45,8 +45,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Gen
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    depots: None,
                 }],
-                capacity: vec![5],
+                capacity: vec![5].into(),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -185,7 +186,7 @@ fn can_check_resource_consumption() {
                 }],
                 ..create_vehicle_with_capacity("my_vehicle", vec![2])
             }],
-            resources: Some(vec![VehicleResource::Reload { id: "resource_1".to_string(), capacity: vec![1] }]),
+            resources: Some(vec![VehicleResource::Reload { id: "resource_1".to_string(), capacity: vec![1].into() }]),
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -228,9 +229,100 @@ fn can_check_resource_consumption() {
 
     let result = check_resource_consumption(&ctx);
 
-    assert_eq!(
-        result,
-        Err("consumed more resource 'resource_1' than available: [2, 0, 0, 0, 0, 0, 0, 0] vs [1, 0, 0, 0, 0, 0, 0, 0]"
-            .into())
-    );
+    assert_eq!(result, Err("consumed more resource 'resource_1' than available: [2] vs [1]".into()));
+}
+
+parameterized_test! {can_check_compartment_loads, (compartments, expected_result), {
+    can_check_compartment_loads_impl(compartments, expected_result);
+}}
+
+can_check_compartment_loads! {
+    case01_valid: (vec![Some("chilled"), Some("mixed"), Some("mixed")], Ok(())),
+    case02_exceeds: (vec![Some("chilled"), Some("chilled"), Some("mixed")],
+        Err(vec!["load exceeds capacity of compartment 'chilled' in tour 'my_vehicle_1'".into()])),
+    case03_wrong_product: (vec![Some("chilled"), Some("mixed"), Some("chilled")],
+        Err(vec!["job 'job3' cannot be loaded into compartment 'chilled' in tour 'my_vehicle_1'".into()])),
+    case04_missing: (vec![None, Some("mixed"), Some("mixed")],
+        Err(vec!["job 'job1' has no compartment in tour 'my_vehicle_1'".into()])),
+    case05_unknown: (vec![Some("frozen"), Some("mixed"), Some("mixed")],
+        Err(vec!["job 'job1' has unknown compartment 'frozen' in tour 'my_vehicle_1'".into()])),
+}
+
+fn can_check_compartment_loads_impl(compartments: Vec<Option<&str>>, expected_result: Result<(), Vec<GenericError>>) {
+    let create_compartment = |id: &str, products: &[&str]| VehicleCompartment {
+        id: id.to_string(),
+        capacity: vec![1].into(),
+        products: Some(products.iter().map(|product| product.to_string()).collect()),
+    };
+    let create_activity = |activity: ActivityBuilder, job_id: &str, compartment: Option<&str>| {
+        let activity = activity.job_id(job_id);
+        match compartment {
+            Some(compartment) => activity.compartment(compartment).build(),
+            None => activity.build(),
+        }
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { product: Some("chilled".to_string()), ..create_delivery_job("job1", (1., 0.)) },
+                Job { product: Some("chilled".to_string()), ..create_delivery_job("job2", (2., 0.)) },
+                Job { product: Some("frozen".to_string()), ..create_pickup_job("job3", (3., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![5].into(),
+                compartments: Some(vec![
+                    create_compartment("chilled", &["chilled"]),
+                    create_compartment("mixed", &["chilled", "frozen"]),
+                ]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1])
+                        .distance(1)
+                        .activity(create_activity(ActivityBuilder::delivery(), "job1", compartments[0]))
+                        .build(),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(3., 4.)
+                        .load(vec![0])
+                        .distance(2)
+                        .activity(create_activity(ActivityBuilder::delivery(), "job2", compartments[1]))
+                        .build(),
+                    StopBuilder::default()
+                        .coordinate((3., 0.))
+                        .schedule_stamp(5., 6.)
+                        .load(vec![1])
+                        .distance(3)
+                        .activity(create_activity(ActivityBuilder::pickup(), "job3", compartments[2]))
+                        .build(),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(9., 9.)
+                        .load(vec![0])
+                        .distance(6)
+                        .build_arrival(),
+                ])
+                .statistic(StatisticBuilder::default().driving(6).serving(3).build())
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_vehicle_load(&ctx);
+
+    assert_eq!(result, expected_result);
 }
@@ -0,0 +1,81 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+const DRIVING_TIME_ERROR: &str = "driving time limit violation, expected: not more than 20 without a break, got: 30, \
+                                  vehicle id 'some_real_vehicle', shift index: 0";
+const DAILY_DRIVING_TIME_ERROR: &str =
+    "daily driving time limit violation, expected: not more than 25, got: 30, vehicle id 'some_real_vehicle', shift index: 0";
+
+fn create_test_problem(max_daily_driving_time: f64) -> Problem {
+    Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["some_real_vehicle".to_string()],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    driving_rules: Some(VehicleDrivingRules {
+                        max_driving_time: 20.,
+                        break_duration: 45.,
+                        max_daily_driving_time,
+                        split_break: Some(vec![15., 30.]),
+                    }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_stop(job_id: &str, location: f64, arrival: f64, break_duration: f64) -> Stop {
+    let builder = StopBuilder::default().coordinate((location, 0.)).schedule_stamp(arrival, arrival + break_duration);
+
+    if break_duration > 0. {
+        builder.build_single_time("break", "break", (arrival, arrival + break_duration))
+    } else {
+        builder.build_single(job_id, "delivery")
+    }
+}
+
+parameterized_test! {can_check_driving_time, (max_daily_driving_time, breaks, expected), {
+    can_check_driving_time_impl(max_daily_driving_time, breaks, expected);
+}}
+
+can_check_driving_time! {
+    case01_full_break: (40., (0., 45.), None),
+    case02_no_break: (40., (0., 0.), Some(DRIVING_TIME_ERROR)),
+    case03_short_break: (40., (0., 15.), Some(DRIVING_TIME_ERROR)),
+    case04_split_break: (40., (15., 30.), None),
+    case05_split_break_wrong_order: (40., (30., 15.), Some(DRIVING_TIME_ERROR)),
+    case06_daily_driving_time: (25., (0., 45.), Some(DAILY_DRIVING_TIME_ERROR)),
+}
+
+fn can_check_driving_time_impl(max_daily_driving_time: f64, breaks: (f64, f64), expected: Option<&str>) {
+    let (first, second) = breaks;
+    let problem = create_test_problem(max_daily_driving_time);
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .vehicle_id("some_real_vehicle")
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).build_departure(),
+                    create_stop("job1", 10., 10., first),
+                    create_stop("job2", 20., 20. + first, second),
+                    StopBuilder::default()
+                        .coordinate((30., 0.))
+                        .schedule_stamp(30. + first + second, 30. + first + second)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_driving_time(&ctx);
+
+    assert_eq!(result, expected.map_or(Ok(()), |err| Err(err.into())));
+}
@@ -23,8 +23,10 @@ fn create_test_solution(statistic: Statistic, stops: Vec<Stop>) -> Solution {
             vehicle_id: "some_real_vehicle".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops,
             statistic,
+            day: None,
         })
         .build()
 }
@@ -59,7 +61,8 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), GenericError>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None }));
+    let problem =
+        create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None, driving_rules: None }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -71,8 +74,12 @@ pub fn can_check_shift_and_distance_limit_impl(
 
 #[test]
 pub fn can_check_tour_size_limit() {
-    let problem =
-        create_test_problem(Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: Some(2),
+        driving_rules: None,
+    }));
     let solution = create_test_solution(
         Statistic::default(),
         vec![
@@ -164,3 +171,57 @@ fn can_check_shift_time() {
 
     assert_eq!(result, Err("tour time is outside shift time, vehicle id 'my_vehicle_1', shift index: 0".into()));
 }
+
+parameterized_test! {can_check_max_ride_duration, (delivery_start, expected), {
+    can_check_max_ride_duration_impl(delivery_start, expected);
+}}
+
+can_check_max_ride_duration! {
+    case01_within_limit: (4., Ok(())),
+    case02_at_limit: (6., Ok(())),
+    case03_exceeds_limit: (7., Err("max ride duration violation, expected: not more than 5, got: 6, job id 'job1'".into())),
+}
+
+fn can_check_max_ride_duration_impl(delivery_start: f64, expected: Result<(), GenericError>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { max_ride_duration: Some(5.), ..create_pickup_delivery_job("job1", (1., 0.), (2., 0.)) }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 1.)
+                        .load(vec![1])
+                        .distance(1)
+                        .build_single_time("job1", "pickup", (1., 1.)),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(2., delivery_start)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_single_time("job1", "delivery", (delivery_start, delivery_start)),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(delivery_start + 2., delivery_start + 2.)
+                        .load(vec![0])
+                        .distance(4)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_max_ride_durations(&ctx);
+
+    assert_eq!(result, expected);
+}
@@ -67,6 +67,42 @@ mod single {
     }
 
     fn can_check_relations_impl(relations: Option<Vec<Relation>>, expected_result: Result<(), ()>) {
+        let result = check_relations_with_state(relations, None);
+
+        assert_eq!(result, expected_result);
+    }
+
+    parameterized_test! {can_check_committed_jobs, (committed, expected_result), {
+        can_check_committed_jobs_impl(committed, expected_result);
+    }}
+
+    can_check_committed_jobs! {
+        case01: (vec!["job1"], Ok(())),
+        case02: (vec!["job1", "job2"], Ok(())),
+        case03: (vec!["job2"], Err(())),
+        case04: (vec!["job2", "job1"], Err(())),
+        case05: (vec!["job1", "job3"], Err(())),
+    }
+
+    fn can_check_committed_jobs_impl(committed: Vec<&str>, expected_result: Result<(), ()>) {
+        let state = ProblemState {
+            vehicles: vec![VehicleState {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                location: (0., 0.).to_loc(),
+                time: format_time(0.),
+                completed: None,
+                picked_up: None,
+                committed: Some(committed.into_iter().map(|id| id.to_string()).collect()),
+            }],
+        };
+
+        let result = check_relations_with_state(None, Some(state));
+
+        assert_eq!(result, expected_result);
+    }
+
+    fn check_relations_with_state(relations: Option<Vec<Relation>>, state: Option<ProblemState>) -> Result<(), ()> {
         let problem = Problem {
             plan: Plan {
                 jobs: vec![
@@ -99,13 +135,17 @@ mod single {
                             ..create_default_reload()
                         }]),
                         recharges: None,
+                        depots: None,
                     }],
-                    capacity: vec![5],
+                    capacity: vec![5].into(),
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    calendar: None,
                 }],
                 ..create_default_fleet()
             },
+            state,
             ..create_empty_problem()
         };
         let solution = SolutionBuilder::default()
@@ -168,8 +208,6 @@ mod single {
             .build();
         let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
 
-        let result = check_relations(&ctx).map_err(|_| ());
-
-        assert_eq!(result, expected_result);
+        check_relations(&ctx).map_err(|_| ())
     }
 }
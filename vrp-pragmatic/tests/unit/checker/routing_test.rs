@@ -1,6 +1,5 @@
 use super::*;
 use crate::helpers::*;
-use vrp_core::models::examples::create_example_problem;
 
 fn create_test_problem() -> Problem {
     Problem {
@@ -14,7 +13,14 @@ fn create_test_problem() -> Problem {
 }
 
 fn create_test_statistic() -> Statistic {
-    Statistic { cost: 10., distance: 4, duration: 6, times: Timing { driving: 4, serving: 2, ..Timing::default() } }
+    Statistic {
+        cost: 10.,
+        distance: 4,
+        duration: 6,
+        times: Timing { driving: 4, serving: 2, ..Timing::default() },
+        tardiness: None,
+        days: None,
+    }
 }
 
 fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> Solution {
@@ -49,6 +55,12 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
         .build()
 }
 
+fn create_checker_context(problem: Problem, matrices: Vec<Matrix>, solution: Solution) -> CheckerContext {
+    let core_problem = Arc::new((problem.clone(), matrices.clone()).read_pragmatic().unwrap());
+
+    CheckerContext::new(core_problem, problem, Some(matrices), solution).unwrap()
+}
+
 fn duration_error(stop_idx: usize, actual: usize, expected: usize) -> GenericError {
     format!("arrival time mismatch for {stop_idx} stop in the tour: my_vehicle_1, expected: '1970-01-01T00:00:0{expected}Z', got: '1970-01-01T00:00:0{actual}Z'").into()
 }
@@ -82,7 +94,40 @@ fn can_check_stop_impl(stop_data: &[(f64, i64); 3], expected_result: Result<(),
     let problem = create_test_problem();
     let matrix = create_matrix_from_problem(&problem);
     let solution = create_test_solution(create_test_statistic(), stop_data);
-    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution).unwrap();
+    let ctx = create_checker_context(problem, vec![matrix], solution);
+
+    let result = check_routing(&ctx);
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_parking, (job_parking, stop_parking, expected_result), {
+    can_check_parking_impl(job_parking, stop_parking, expected_result);
+}}
+
+can_check_parking! {
+    case_01: (None, None, Ok(())),
+    case_02: (Some(2.), None,
+              Err(vec!["parking time mismatch for 1 stop in the tour: my_vehicle_1, expected: '2', got: '0'".into()])),
+    case_03: (None, Some((1., 3.)),
+              Err(vec!["parking time mismatch for 1 stop in the tour: my_vehicle_1, expected: '0', got: '2'".into()])),
+    case_04: (Some(1.), Some((1., 2.)),
+              Err(vec!["activity starts before parking end at 1 stop in the tour: my_vehicle_1".into()])),
+}
+
+fn can_check_parking_impl(
+    job_parking: Option<f64>,
+    stop_parking: Option<(f64, f64)>,
+    expected_result: Result<(), Vec<GenericError>>,
+) {
+    let mut problem = create_test_problem();
+    problem.plan.jobs[0].deliveries.as_mut().unwrap()[0].places[0].parking = job_parking;
+    let matrix = create_matrix_from_problem(&problem);
+    let mut solution = create_test_solution(create_test_statistic(), &[(1., 1), (3., 2), (6., 4)]);
+    if let Stop::Point(stop) = &mut solution.tours[0].stops[1] {
+        stop.parking = stop_parking.map(|(start, end)| Interval { start: format_time(start), end: format_time(end) });
+    }
+    let ctx = create_checker_context(problem, vec![matrix], solution);
 
     let result = check_routing(&ctx);
 
@@ -111,7 +156,7 @@ fn can_check_tour_statistic_impl(statistic: Statistic, expected_result: Result<(
     let problem = create_test_problem();
     let matrix = create_matrix_from_problem(&problem);
     let solution = create_test_solution(statistic, &[(1., 1), (3., 2), (6., 4)]);
-    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution).unwrap();
+    let ctx = create_checker_context(problem, vec![matrix], solution);
 
     let result = check_routing(&ctx);
 
@@ -125,7 +170,7 @@ fn can_check_solution_statistic() {
     let solution = create_test_solution(create_test_statistic(), &[(1., 1), (3., 2), (6., 4)]);
     let wrong_statistic = Statistic { duration: 1, ..create_test_statistic() };
     let solution = Solution { statistic: wrong_statistic.clone(), ..solution };
-    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution).unwrap();
+    let ctx = create_checker_context(problem, vec![matrix], solution);
 
     let result = check_routing(&ctx);
 
@@ -139,3 +184,105 @@ fn can_check_solution_statistic() {
         .into()])
     );
 }
+
+parameterized_test! {can_check_tardiness, (lateness, tardiness, expected_result), {
+    can_check_tardiness_impl(lateness, tardiness, expected_result);
+}}
+
+can_check_tardiness! {
+    case_01: (10., Some(1), Ok(())),
+    case_02: (10., None, Err(vec!["tardiness mismatch for tour statistic: my_vehicle_1, expected: 'Some(1)', got: 'None'".into()])),
+    case_03: (10., Some(2), Err(vec!["tardiness mismatch for tour statistic: my_vehicle_1, expected: 'Some(1)', got: 'Some(2)'".into()])),
+    case_04: (0.5, Some(1), Err(vec!["activity of job 'job1' is served outside of time windows widened by tolerance".into()])),
+}
+
+fn can_check_tardiness_impl(lateness: f64, tardiness: Option<i64>, expected_result: Result<(), Vec<GenericError>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_tolerance("job1", (1., 0.), vec![(0, 0)], (None, Some(lateness), 1.)),
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        ..create_test_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution =
+        create_test_solution(Statistic { tardiness, ..create_test_statistic() }, &[(1., 1), (3., 2), (6., 4)]);
+    let ctx = create_checker_context(problem, vec![matrix], solution);
+
+    let result = check_routing(&ctx);
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_stop_with_time_aware_matrices, (fifo, expected_result), {
+    can_check_stop_with_time_aware_matrices_impl(fifo, expected_result);
+}}
+
+can_check_stop_with_time_aware_matrices! {
+    case_01_fifo: (true, Ok(())),
+    case_02_no_fifo: (false, Err(vec![
+        "arrival time mismatch for 1 stop in the tour: my_vehicle_1, expected: '1970-01-01T00:00:10Z', got: '1970-01-01T00:00:06Z'".into()
+    ])),
+}
+
+fn can_check_stop_with_time_aware_matrices_impl(fifo: bool, expected_result: Result<(), Vec<GenericError>>) {
+    let problem = create_test_problem();
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, fifo: Some(fifo), speed_bands: None }],
+            ..problem.fleet
+        },
+        ..problem
+    };
+    // NOTE with fifo, it is faster to wait till the second matrix timestamp before departing from the depot
+    let create_time_aware_matrix = |timestamp: &str, slowdown: i64| {
+        let matrix = create_matrix_from_problem(&problem);
+        Matrix {
+            timestamp: Some(timestamp.to_string()),
+            travel_times: matrix.travel_times.iter().map(|duration| duration * slowdown).collect(),
+            ..matrix
+        }
+    };
+    let matrices =
+        vec![create_time_aware_matrix("1970-01-01T00:00:00Z", 10), create_time_aware_matrix("1970-01-01T00:00:05Z", 1)];
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(6., 7.)
+                        .load(vec![1])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(8., 9.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_single("job2", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(11., 11.)
+                        .load(vec![0])
+                        .distance(4)
+                        .build_arrival(),
+                ])
+                .statistic(Statistic {
+                    duration: 11,
+                    times: Timing { driving: 4, serving: 2, waiting: 5, ..Timing::default() },
+                    ..create_test_statistic()
+                })
+                .build(),
+        )
+        .build();
+    let ctx = create_checker_context(problem, matrices, solution);
+
+    let result = check_routing(&ctx);
+
+    assert_eq!(result, expected_result);
+}
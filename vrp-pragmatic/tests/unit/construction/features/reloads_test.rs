@@ -195,7 +195,7 @@ fn can_remove_trivial_reloads_when_used_from_capacity_constraint_impl(
         Box::new(|name, route_intervals| {
             create_capacity_limit_with_multi_trip_feature::<MultiDimLoad>(name, VIOLATION_CODE, route_intervals)
         }),
-        Box::new(move |capacity| *capacity * threshold),
+        Box::new(move |capacity| capacity.clone() * threshold),
     )
     .unwrap();
     let goal = Goal::no_alternatives([], []);
@@ -237,7 +237,7 @@ fn can_handle_new_interval_needed_for_multi_dim_load_impl(
 ) {
     let threshold = 1.;
     let route_intervals =
-        create_reload_route_intervals::<MultiDimLoad>(Box::new(move |capacity| *capacity * threshold), None);
+        create_reload_route_intervals::<MultiDimLoad>(Box::new(move |capacity| capacity.clone() * threshold), None);
     let (mut route_ctx, _) = create_route_context_with_fleet(vehicle_capacity, Vec::default());
     let (route, state) = route_ctx.as_mut();
     state.put_activity_state(MAX_PAST_CAPACITY_KEY, route.tour.end_idx().unwrap(), MultiDimLoad::new(current_capacity));
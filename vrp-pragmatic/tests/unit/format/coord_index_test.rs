@@ -17,6 +17,10 @@ fn can_use_index_with_coordinate_an_unknown_location_types() {
                             duration: 0.,
                             times: None,
                             tag: None,
+                            tolerance: None,
+                            days: None,
+                            durations: None,
+                            parking: None,
                         }],
                         demand: None,
                         order: None,
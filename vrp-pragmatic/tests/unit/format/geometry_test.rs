@@ -0,0 +1,65 @@
+use super::*;
+use crate::utils::encode_polyline;
+
+fn create_matrix(profile: &str, geometries: Option<Vec<MatrixGeometry>>) -> Matrix {
+    Matrix {
+        profile: Some(profile.to_string()),
+        timestamp: None,
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0, 1, 1, 0],
+        error_codes: None,
+        entries: None,
+        geometries,
+    }
+}
+
+fn get_profile(matrix: &Matrix, _: usize) -> usize {
+    if matrix.profile.as_deref() == Some("car") {
+        0
+    } else {
+        1
+    }
+}
+
+#[test]
+fn can_get_route_geometry_from_matrices() {
+    let car_geometry = vec![(52.52, 13.4), (52.53, 13.41)];
+    let truck_geometry = vec![(52.53, 13.41), (52.525, 13.405), (52.52, 13.4)];
+    let matrices = vec![
+        create_matrix("car", Some(vec![MatrixGeometry(0, 1, encode_polyline(&car_geometry))])),
+        create_matrix("truck", Some(vec![MatrixGeometry(1, 0, encode_polyline(&truck_geometry))])),
+    ];
+
+    let route_geometry = MatrixRouteGeometry::new(&matrices, get_profile).unwrap().unwrap();
+
+    assert_eq!(route_geometry.get_geometry(0, 0, 1), Some(car_geometry));
+    assert_eq!(route_geometry.get_geometry(1, 1, 0), Some(truck_geometry));
+    assert_eq!(route_geometry.get_geometry(0, 1, 0), None);
+    assert_eq!(route_geometry.get_geometry(1, 0, 1), None);
+}
+
+#[test]
+fn can_handle_matrices_without_geometries() {
+    let matrices = vec![create_matrix("car", None)];
+
+    assert!(MatrixRouteGeometry::new(&matrices, get_profile).unwrap().is_none());
+}
+
+#[test]
+fn can_return_error_for_invalid_polyline() {
+    let matrices = vec![create_matrix("car", Some(vec![MatrixGeometry(0, 1, "_p~iF".to_string())]))];
+
+    assert!(MatrixRouteGeometry::new(&matrices, get_profile).is_err());
+}
+
+#[test]
+fn can_set_and_get_route_geometry_in_extras() {
+    let matrices = vec![create_matrix("car", Some(vec![MatrixGeometry(0, 1, encode_polyline(&[(1., 0.), (2., 0.)]))]))];
+    let route_geometry = MatrixRouteGeometry::new(&matrices, get_profile).unwrap().unwrap();
+    let mut extras = Extras::default();
+
+    extras.set_route_geometry(Arc::new(route_geometry));
+
+    let route_geometry = extras.get_route_geometry().expect("no route geometry");
+    assert_eq!(route_geometry.get_geometry(0, 0, 1), Some(vec![(1., 0.), (2., 0.)]));
+}
@@ -0,0 +1,123 @@
+use super::*;
+use crate::format::problem::{MatrixEntry, MatrixGeometry};
+
+fn create_matrix(values: Vec<i64>, profile: Option<&str>, timestamp: Option<&str>, has_error_codes: bool) -> Matrix {
+    Matrix {
+        profile: profile.map(|profile| profile.to_string()),
+        timestamp: timestamp.map(|timestamp| timestamp.to_string()),
+        travel_times: values.clone(),
+        distances: values.iter().map(|value| value * 10).collect(),
+        error_codes: if has_error_codes { Some(values.iter().map(|value| value % 2).collect()) } else { None },
+        entries: None,
+        geometries: None,
+    }
+}
+
+fn serialize(matrix: &Matrix) -> Vec<u8> {
+    let mut data = Vec::new();
+    serialize_matrix_binary(matrix, &mut data).expect("cannot serialize matrix");
+
+    data
+}
+
+parameterized_test! {can_serialize_and_deserialize_matrix, (values, profile, timestamp, has_error_codes, value_size), {
+    can_serialize_and_deserialize_matrix_impl(values, profile, timestamp, has_error_codes, value_size);
+}}
+
+can_serialize_and_deserialize_matrix! {
+    case01_compact: (vec![0, 1, 2, 0], Some("car"), None, false, 4),
+    case02_with_timestamp: (vec![0, 1, 2, 0], Some("car"), Some("2020-07-04T08:00:00Z"), false, 4),
+    case03_with_error_codes: (vec![0, 1, 2, 0], None, None, true, 4),
+    case04_wide: (vec![0, i32::MAX as i64 + 1, -1, 0], Some("truck"), None, true, 8),
+    case05_negative: (vec![0, -1, -2, 0, 1, 2, 3, 4, 5], Some("car"), None, false, 4),
+    case06_empty: (vec![], None, None, false, 4),
+}
+
+fn can_serialize_and_deserialize_matrix_impl(
+    values: Vec<i64>,
+    profile: Option<&str>,
+    timestamp: Option<&str>,
+    has_error_codes: bool,
+    value_size: u8,
+) {
+    let matrix = create_matrix(values, profile, timestamp, has_error_codes);
+
+    let data = serialize(&matrix);
+    let result = deserialize_matrix_binary(data.as_slice()).expect("cannot deserialize matrix");
+
+    assert!(is_binary_matrix(data.as_slice()));
+    assert_eq!(data[5], value_size);
+    assert_eq!(serde_json::to_string(&result).unwrap(), serde_json::to_string(&matrix).unwrap());
+}
+
+#[test]
+fn can_deserialize_binary_matrix_into_routing_data() {
+    let matrix = create_matrix(vec![0, 1, 2, 0], Some("car"), Some("2020-07-04T08:00:00Z"), true);
+
+    let result = deserialize_binary_matrix(serialize(&matrix).as_slice()).expect("cannot deserialize matrix");
+
+    assert_eq!(result.profile, matrix.profile);
+    assert_eq!(result.timestamp, matrix.timestamp);
+    assert_eq!(result.size, 2);
+    assert_eq!(result.durations, vec![0., -1., 2., 0.]);
+    assert_eq!(result.distances, vec![0., -1., 20., 0.]);
+    assert!(result.has_error_codes);
+}
+
+#[test]
+fn can_detect_json_matrix() {
+    assert!(!is_binary_matrix(r#"{"travelTimes":[0]}"#.as_bytes()));
+}
+
+parameterized_test! {can_detect_invalid_data, (modify_fn, expected), {
+    can_detect_invalid_data_impl(modify_fn, expected);
+}}
+
+can_detect_invalid_data! {
+    case01_unknown_format: (|data: &mut Vec<u8>| data[0] = b'X', "unknown format"),
+    case02_unsupported_version: (|data: &mut Vec<u8>| data[4] = 2, "unsupported version: 2"),
+    case03_unsupported_value_size: (|data: &mut Vec<u8>| data[5] = 2, "unsupported value size: 2"),
+    case04_truncated: (|data: &mut Vec<u8>| data.truncate(data.len() - 1), "unexpected end of data"),
+    case05_trailing_data: (|data: &mut Vec<u8>| data.push(0), "unexpected data after matrix end"),
+}
+
+fn can_detect_invalid_data_impl(modify_fn: fn(&mut Vec<u8>), expected: &str) {
+    let mut data = serialize(&create_matrix(vec![0, 1, 2, 0], Some("car"), None, false));
+    modify_fn(&mut data);
+
+    let result = deserialize_matrix_binary(data.as_slice()).map(|_| ());
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        format!("E0001, cause: 'cannot deserialize matrix', action: 'check binary matrix: '{expected}''.")
+    );
+}
+
+#[test]
+fn can_return_error_for_invalid_matrix() {
+    let mut sparse = create_matrix(vec![], None, None, false);
+    sparse.entries = Some(vec![MatrixEntry(0, 1, 1, 1)]);
+    let mut mismatch = create_matrix(vec![0, 1, 2, 0], None, None, false);
+    mismatch.distances.pop();
+    let mut geometries = create_matrix(vec![0, 1, 2, 0], None, None, false);
+    geometries.geometries = Some(vec![MatrixGeometry(0, 1, "??".to_string())]);
+
+    let mut data = Vec::new();
+    assert!(serialize_matrix_binary(&sparse, &mut data).is_err());
+    assert!(serialize_matrix_binary(&mismatch, &mut data).is_err());
+    assert!(serialize_matrix_binary(&geometries, &mut data).is_err());
+}
+
+#[test]
+fn can_return_error_for_too_big_size_with_short_data() {
+    let mut data = serialize(&create_matrix(vec![0, 1, 2, 0], None, None, false));
+    data[HEADER_SIZE..HEADER_SIZE + 8].copy_from_slice(&(1_u64 << 20).to_le_bytes());
+    let expected =
+        "E0001, cause: 'cannot deserialize matrix', action: 'check binary matrix: 'unexpected end of data''.";
+
+    let matrix_result = deserialize_matrix_binary(data.as_slice()).map(|_| ());
+    let binary_result = deserialize_binary_matrix(data.as_slice()).map(|_| ());
+
+    assert_eq!(matrix_result.unwrap_err().to_string(), expected);
+    assert_eq!(binary_result.unwrap_err().to_string(), expected);
+}
@@ -0,0 +1,87 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_vehicle_day(day: usize, fixed: Option<f64>) -> VehicleDay {
+    VehicleDay { day, fixed }
+}
+
+fn create_problem_with_calendar(days: Vec<VehicleDay>, job_days: Option<Vec<usize>>) -> Problem {
+    let mut job = create_delivery_job_with_times("job1", (1., 0.), vec![(10, 20)], 1.);
+    job.deliveries.as_mut().unwrap()[0].places[0].days = job_days;
+
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![
+                    create_default_vehicle_shift(),
+                    create_default_vehicle_shift_with_locations((1., 0.), (1., 0.)),
+                ],
+                calendar: Some(VehicleCalendar { days, min_rest: None }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_shift_times(vehicle: &VehicleType) -> Vec<(f64, f64)> {
+    vehicle
+        .shifts
+        .iter()
+        .map(|shift| (parse_time(&shift.start.earliest), parse_time(&shift.end.as_ref().unwrap().latest)))
+        .collect()
+}
+
+#[test]
+fn can_repeat_vehicle_shifts_for_each_day() {
+    let problem = apply_planning_calendar(create_problem_with_calendar(
+        vec![create_vehicle_day(0, None), create_vehicle_day(2, Some(50.))],
+        None,
+    ));
+
+    let vehicle = &problem.fleet.vehicles[0];
+    assert_eq!(get_shift_times(vehicle), vec![(0., 1000.), (0., 1000.), (172800., 173800.), (172800., 173800.)]);
+    assert_eq!(vehicle.shifts[3].start.location, (1., 0.).to_loc());
+    assert_eq!(
+        (0..4).map(|idx| get_shift_day(vehicle, idx).map(|day| (day.day, day.fixed))).collect::<Vec<_>>(),
+        vec![Some((0, None)), Some((0, None)), Some((2, Some(50.))), Some((2, Some(50.)))]
+    );
+}
+
+parameterized_test! {can_repeat_job_place_times_for_each_day, (days, expected), {
+    can_repeat_job_place_times_for_each_day_impl(days, expected);
+}}
+
+can_repeat_job_place_times_for_each_day! {
+    case01_no_days: (None, vec![(10., 20.)]),
+    case02_single_day: (Some(vec![1]), vec![(86410., 86420.)]),
+    case03_many_days: (Some(vec![0, 2]), vec![(10., 20.), (172810., 172820.)]),
+}
+
+fn can_repeat_job_place_times_for_each_day_impl(days: Option<Vec<usize>>, expected: Vec<(f64, f64)>) {
+    let problem = apply_planning_calendar(create_problem_with_calendar(vec![create_vehicle_day(0, None)], days));
+
+    let place = &problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].places[0];
+    assert!(place.days.is_none());
+    assert_eq!(
+        place.times.iter().flatten().map(|tw| (parse_time(&tw[0]), parse_time(&tw[1]))).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn can_split_periodic_job_into_visits() {
+    let mut problem = create_problem_with_calendar(vec![create_vehicle_day(0, None)], None);
+    problem.plan.jobs.push(Job {
+        frequency: Some(JobFrequency { count: 3, min_spacing: None, max_spacing: None, patterns: None }),
+        ..create_delivery_job("job2", (2., 0.))
+    });
+
+    let problem = apply_planning_calendar(problem);
+
+    let ids = problem.plan.jobs.iter().map(|job| job.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids, vec!["job1", "job2#0", "job2#1", "job2#2"]);
+    assert!(ids.iter().skip(1).all(|id| get_periodic_job_id(id) == "job2"));
+}
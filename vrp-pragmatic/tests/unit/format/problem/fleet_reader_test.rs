@@ -15,6 +15,8 @@ fn matrix(profile: Option<&str>, timestamp: Option<f64>, fill_value: i64, size:
         travel_times: vec![fill_value; size],
         distances: vec![fill_value; size],
         error_codes: None,
+        entries: None,
+        geometries: None,
     }
 }
 
@@ -25,13 +27,18 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
         travel_times: vec![1; 4],
         distances: vec![2; 3],
         error_codes: None,
+        entries: None,
+        geometries: None,
     }
 }
 
 fn create_problem(profiles: &[&str]) -> Problem {
     Problem {
         fleet: Fleet {
-            profiles: profiles.iter().map(|p| MatrixProfile { name: p.to_string(), speed: None }).collect(),
+            profiles: profiles
+                .iter()
+                .map(|p| MatrixProfile { name: p.to_string(), speed: None, fifo: None, speed_bands: None })
+                .collect(),
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -99,7 +106,7 @@ fn can_create_transport_costs_negative_cases_impl(profiles: &[&str], matrices: &
     let problem = create_problem(profiles);
     let coord_index = Arc::new(CoordIndex::new(&problem));
 
-    let result = create_transport_costs(&problem, matrices, coord_index);
+    let result = create_transport_costs(&problem, matrices.to_vec(), vec![], coord_index);
 
     assert_eq!(result.err(), Some(res_err.into()));
 }
@@ -157,7 +164,7 @@ fn can_create_transport_costs_positive_cases_impl(
     let problem = create_problem(profiles);
     let coord_index = Arc::new(CoordIndex::new(&problem));
 
-    let transport = create_transport_costs(&problem, matrices, coord_index).unwrap();
+    let transport = create_transport_costs(&problem, matrices.to_vec(), vec![], coord_index).unwrap();
 
     probes.iter().for_each(|&(profile_idx, timestamp, distance)| {
         let route = Route {
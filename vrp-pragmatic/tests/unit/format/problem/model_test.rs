@@ -1,5 +1,6 @@
 use super::*;
-use crate::helpers::{SIMPLE_MATRIX, SIMPLE_PROBLEM};
+use crate::format::LoadAmount;
+use crate::helpers::{create_named_load, SIMPLE_MATRIX, SIMPLE_PROBLEM};
 use std::io::BufReader;
 
 fn assert_time_windows(actual: &Option<Vec<Vec<String>>>, expected: (&str, &str)) {
@@ -17,8 +18,8 @@ fn assert_location(actual: &Location, expected: (f64, f64)) {
     assert_eq!(lng, expected.1);
 }
 
-fn assert_demand(actual: &Option<Vec<i32>>, expected: i32) {
-    let actual = actual.as_ref().expect("Empty demand!");
+fn assert_demand(actual: &Option<LoadAmount>, expected: i32) {
+    let actual = actual.as_ref().expect("Empty demand!").to_vec(None);
     assert_eq!(actual.len(), 1);
     assert_eq!(*actual.first().unwrap(), expected);
 }
@@ -64,3 +65,51 @@ fn can_deserialize_matrix() {
     assert_eq!(matrix.distances.len(), 16);
     assert_eq!(matrix.travel_times.len(), 16);
 }
+
+#[test]
+fn can_deserialize_named_load() {
+    let indexed: LoadAmount = serde_json::from_str("[1, 2]").unwrap();
+    let named: LoadAmount = serde_json::from_str(r#"{ "volume": 2, "weight": 1 }"#).unwrap();
+    let dimensions = vec!["weight".to_string(), "pallets".to_string(), "volume".to_string()];
+
+    assert_eq!(indexed.to_vec(Some(dimensions.as_slice())), vec![1, 2]);
+    assert_eq!(named.to_vec(Some(dimensions.as_slice())), vec![1, 0, 2]);
+    assert_eq!(
+        LoadAmount::from_vec(vec![1, 0, 2], Some(dimensions.as_slice())),
+        create_named_load(&[("weight", 1), ("pallets", 0), ("volume", 2)])
+    );
+    assert_eq!(serde_json::to_string(&LoadAmount::from_vec(vec![1, 2], None)).unwrap(), "[1,2]");
+}
+
+#[test]
+fn can_deserialize_objective_levels() {
+    let levels: Vec<ObjectiveLevel> = serde_json::from_str(
+        r#"[
+            [{ "type": "minimize-unassigned" }],
+            { "objectives": [{ "type": "minimize-cost" }], "tolerance": { "relative": 0.02 } },
+            [{
+                "type": "weighted-sum",
+                "objectives": [{ "type": "minimize-tours" }, { "type": "minimize-distance" }],
+                "weights": [500, 1]
+            }]
+        ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(levels.len(), 3);
+    assert!(matches!(levels[0].objectives(), [Objective::MinimizeUnassigned { breaks: None }]));
+    assert!(levels[0].tolerance().is_none());
+    assert!(matches!(levels[1].objectives(), [Objective::MinimizeCost]));
+    assert!(matches!(levels[1].tolerance(), Some(ObjectiveTolerance { relative: Some(_), absolute: None })));
+    match levels[2].objectives() {
+        [Objective::WeightedSum { objectives, weights }] => {
+            assert!(matches!(objectives.as_slice(), [Objective::MinimizeTours, Objective::MinimizeDistance]));
+            assert_eq!(weights, &vec![500., 1.]);
+        }
+        _ => unreachable!("unexpected objectives"),
+    }
+    assert_eq!(
+        serde_json::to_string(&levels[1]).unwrap(),
+        r#"{"objectives":[{"type":"minimize-cost"}],"tolerance":{"relative":0.02}}"#
+    );
+}
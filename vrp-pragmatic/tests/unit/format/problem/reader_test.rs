@@ -77,8 +77,12 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            tolerance: None,
+                            days: None,
+                            durations: None,
+                            parking: None,
                         }],
-                        demand: Some(vec![0, 1]),
+                        demand: Some(vec![0, 1].into()),
                         order: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique".to_string()])),
@@ -94,8 +98,12 @@ fn can_read_complex_problem() {
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
                             tag: None,
+                            tolerance: None,
+                            days: None,
+                            durations: None,
+                            parking: None,
                         }],
-                        demand: Some(vec![2]),
+                        demand: Some(vec![2].into()),
                         order: None,
                     }]),
                     deliveries: Some(vec![JobTask {
@@ -107,8 +115,12 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
                             tag: None,
+                            tolerance: None,
+                            days: None,
+                            durations: None,
+                            parking: None,
                         }],
-                        demand: Some(vec![2]),
+                        demand: Some(vec![2].into()),
                         order: None,
                     }]),
                     ..create_job("pickup_delivery_job")
@@ -123,8 +135,12 @@ fn can_read_complex_problem() {
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
                             tag: None,
+                            tolerance: None,
+                            days: None,
+                            durations: None,
+                            parking: None,
                         }],
-                        demand: Some(vec![3]),
+                        demand: Some(vec![3].into()),
                         order: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique2".to_string()])),
@@ -164,14 +180,23 @@ fn can_read_complex_problem() {
                     }]),
                     reloads: None,
                     recharges: None,
+                    depots: None,
                 }],
-                capacity: vec![10, 1],
+                capacity: vec![10, 1].into(),
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), tour_size: Some(3) }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    max_duration: Some(100.),
+                    tour_size: Some(3),
+                    driving_rules: None,
+                }),
+                compartments: None,
+                calendar: None,
             }],
             ..create_default_fleet()
         },
         objectives: None,
+        state: None,
     };
     let matrix = Matrix {
         profile: Some("car".to_owned()),
@@ -179,6 +204,8 @@ fn can_read_complex_problem() {
         travel_times: vec![1; 25],
         distances: vec![2; 25],
         error_codes: None,
+        entries: None,
+        geometries: None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
@@ -292,10 +319,10 @@ fn can_create_approximation_matrices() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile { name: "car1".to_string(), speed: Some(8.), fifo: None, speed_bands: None },
+                MatrixProfile { name: "car2".to_string(), speed: Some(10.), fifo: None, speed_bands: None },
+                MatrixProfile { name: "car3".to_string(), speed: Some(5.), fifo: None, speed_bands: None },
+                MatrixProfile { name: "car4".to_string(), speed: None, fifo: None, speed_bands: None },
             ],
             ..create_default_fleet()
         },
@@ -0,0 +1,127 @@
+use super::*;
+use crate::format::get_job_index;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_state(relations: Option<Vec<Relation>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            relations,
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        state: Some(ProblemState {
+            vehicles: vec![VehicleState {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                location: (1., 0.).to_loc(),
+                time: format_time(10.),
+                completed: Some(vec!["job1".to_string()]),
+                picked_up: None,
+                committed: Some(vec!["job2".to_string()]),
+            }],
+        }),
+        ..create_empty_problem()
+    }
+}
+
+fn get_relations(problem: &Problem) -> Vec<(Vec<&str>, &str, Option<usize>)> {
+    problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .map(|relation| {
+            (relation.jobs.iter().map(|id| id.as_str()).collect(), relation.vehicle_id.as_str(), relation.shift_index)
+        })
+        .collect()
+}
+
+#[test]
+fn can_apply_problem_state() {
+    let problem = apply_problem_state(create_problem_with_state(None));
+
+    assert_eq!(problem.plan.jobs.iter().map(|job| job.id.as_str()).collect::<Vec<_>>(), vec!["job2", "job3"]);
+    assert!(problem.plan.relations.is_none());
+}
+
+#[test]
+fn can_read_committed_jobs_as_locks() {
+    let problem = create_problem_with_state(None);
+    let core_problem = problem.clone().read_pragmatic().expect("cannot read core problem");
+    let job_index = get_job_index(&core_problem);
+
+    let locks = read_state_locks(&problem, job_index).expect("cannot read locks");
+
+    assert_eq!(locks.len(), 1);
+    assert_eq!(locks[0].details.len(), 1);
+    let detail = &locks[0].details[0];
+    assert!(matches!((&detail.order, &detail.position), (LockOrder::Strict, LockPosition::Departure)));
+    assert!(detail.jobs.len() == 1 && detail.jobs[0] == job_index["job2"]);
+}
+
+#[test]
+fn can_remove_completed_jobs_from_relations() {
+    let create_relation = |jobs: Vec<&str>| Relation {
+        type_field: RelationType::Any,
+        jobs: jobs.into_iter().map(|id| id.to_string()).collect(),
+        vehicle_id: "my_vehicle_2".to_string(),
+        shift_index: None,
+    };
+    let relations = vec![create_relation(vec!["departure", "job1"]), create_relation(vec!["job1", "job3"])];
+
+    let problem = apply_problem_state(create_problem_with_state(Some(relations)));
+
+    assert_eq!(get_relations(&problem), vec![(vec!["job3"], "my_vehicle_2", None)]);
+}
+
+#[test]
+fn can_keep_problem_without_state() {
+    let problem = apply_problem_state(Problem { state: None, ..create_problem_with_state(None) });
+
+    assert_eq!(problem.plan.jobs.len(), 3);
+    assert!(problem.plan.relations.is_none());
+}
+
+#[test]
+fn can_apply_picked_up_jobs() {
+    let mut problem = create_problem_with_state(None);
+    problem.plan.jobs.push(create_pickup_delivery_job("job4", (1., 0.), (4., 0.)));
+    problem.state.iter_mut().flat_map(|state| state.vehicles.iter_mut()).for_each(|vehicle| {
+        vehicle.picked_up = Some(vec!["job4".to_string()]);
+    });
+    let core_problem = problem.clone().read_pragmatic().expect("cannot read core problem");
+    let job_index = get_job_index(&core_problem);
+
+    let locks = read_state_locks(&problem, job_index).expect("cannot read locks");
+    let problem = apply_problem_state(problem);
+
+    let job = problem.plan.jobs.iter().find(|job| job.id == "job4").expect("cannot find job");
+    assert!(job.pickups.is_none());
+    assert_eq!(job.deliveries.as_ref().map(|tasks| tasks.len()), Some(1));
+    assert_eq!(locks.len(), 1);
+    assert_eq!(locks[0].details.len(), 2);
+    let detail = &locks[0].details[1];
+    assert!(matches!((&detail.order, &detail.position), (LockOrder::Any, LockPosition::Any)));
+    assert!(detail.jobs.len() == 1 && detail.jobs[0] == job_index["job4"]);
+}
+
+#[test]
+fn can_return_error_for_unknown_committed_job() {
+    let problem = create_problem_with_state(None);
+    let core_problem = problem.clone().read_pragmatic().expect("cannot read core problem");
+    let job_index = get_job_index(&core_problem);
+    let mut problem = problem;
+    problem.state.iter_mut().flat_map(|state| state.vehicles.iter_mut()).for_each(|vehicle| {
+        vehicle.committed = Some(vec!["job5".to_string()]);
+    });
+
+    let result = read_state_locks(&problem, job_index);
+
+    assert_eq!(result.err().map(|err| err.to_string()), Some("cannot find job with id: 'job5'".to_string()));
+}
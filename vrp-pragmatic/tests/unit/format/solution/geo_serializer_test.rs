@@ -2,8 +2,10 @@ use super::*;
 use crate::format::problem::Problem as FormatProblem;
 use crate::format::problem::*;
 use crate::format::solution::*;
+use crate::format::CoordIndex;
 use crate::format_time;
 use crate::helpers::*;
+use crate::utils::encode_polyline;
 
 #[test]
 fn can_create_geo_json_from_solution() {
@@ -24,7 +26,53 @@ fn can_create_geo_json_from_solution() {
     let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
     let geo_json = create_feature_collection(&core_problem, &solution).unwrap();
 
-    assert_eq!(geo_json.features.len(), 6);
+    assert_eq!(geo_json.features.len(), 8);
+    assert_eq!(geo_json.features.iter().filter(|f| matches!(f.geometry, Geometry::LineString { .. })).count(), 3);
+}
+
+#[test]
+fn can_create_geo_json_with_route_geometry() {
+    let problem = FormatProblem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let (depot, job) =
+        (coord_index.get_by_loc(&(0., 0.).to_loc()).unwrap(), coord_index.get_by_loc(&(1., 0.).to_loc()).unwrap());
+    let mut matrix = create_matrix_from_problem(&problem);
+    matrix.geometries = Some(vec![MatrixGeometry(depot, job, encode_polyline(&[(0., 0.), (0.5, 0.5), (1., 0.)]))]);
+    let core_problem = (problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap();
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    let geo_json = create_feature_collection(&core_problem, &solution).unwrap();
+
+    let legs = geo_json
+        .features
+        .iter()
+        .filter(|feature| feature.properties.contains_key("leg_idx"))
+        .map(|feature| {
+            let get_property = |key: &str| feature.properties.get(key).cloned().unwrap();
+            (get_property("leg_idx"), get_property("distance"), get_property("duration"), feature.geometry.clone())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        legs,
+        vec![
+            (
+                "0".to_string(),
+                "1".to_string(),
+                "1".to_string(),
+                Geometry::LineString { coordinates: vec![(0., 0.), (0.5, 0.5), (0., 1.)] }
+            ),
+            (
+                "1".to_string(),
+                "1".to_string(),
+                "1".to_string(),
+                Geometry::LineString { coordinates: vec![(0., 1.), (0., 0.)] }
+            ),
+        ]
+    );
 }
 
 #[test]
@@ -64,7 +112,7 @@ fn can_create_geo_json_for_cluster_geometry() {
         location: Location::Coordinate { lat: 1., lng: 0. },
         time: Schedule { arrival: format_time(0.), departure: format_time(10.) },
         distance: 0,
-        load: vec![],
+        load: vec![].into(),
         parking: None,
         activities: vec![
             Activity {
@@ -74,6 +122,7 @@ fn can_create_geo_json_for_cluster_geometry() {
                 time: Some(Interval { start: format_time(0.), end: format_time(1.) }),
                 job_tag: None,
                 commute: Some(Commute { forward: None, backward: None }),
+                compartment: None,
             },
             Activity {
                 job_id: "job2".to_string(),
@@ -93,6 +142,7 @@ fn can_create_geo_json_for_cluster_geometry() {
                         time: Interval { start: format_time(3.), end: format_time(4.) },
                     }),
                 }),
+                compartment: None,
             },
         ],
     };
@@ -103,3 +153,26 @@ fn can_create_geo_json_for_cluster_geometry() {
     assert_eq!(features.iter().filter(|f| matches!(f.geometry, Geometry::Point { .. })).count(), 2);
     assert_eq!(features.iter().filter(|f| matches!(f.geometry, Geometry::LineString { .. })).count(), 2);
 }
+
+#[test]
+fn can_create_geo_json_overlay_from_solutions() {
+    let problem = FormatProblem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+    let locations = vec![("job1".to_string(), Location::Coordinate { lat: 1.0, lng: 0.0 }, 0)];
+
+    let geo_json =
+        create_geojson_solutions_overlay(&[("left", &solution), ("right", &solution)], locations.as_slice()).unwrap();
+
+    assert_eq!(geo_json.features.len(), 3);
+    let solution_names =
+        geo_json.features.iter().filter_map(|feature| feature.properties.get("solution").cloned()).collect::<Vec<_>>();
+    assert_eq!(solution_names, vec!["left".to_string(), "right".to_string()]);
+}
@@ -148,8 +148,10 @@ fn can_handle_empty_tour_error_in_init_solution() {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: Default::default(),
             statistic: Default::default(),
+            day: None,
         })
         .build();
 
@@ -189,3 +191,68 @@ fn can_handle_commute_error_in_init_solution() {
 
     assert_eq!(result_solution, Err("commute property in initial solution is not supported".into()));
 }
+
+#[test]
+fn can_read_init_solutions_with_problem_state() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        state: Some(ProblemState {
+            vehicles: vec![VehicleState {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                location: (1., 0.).to_loc(),
+                time: format_time(2.),
+                completed: Some(vec!["job1".to_string()]),
+                picked_up: None,
+                committed: None,
+            }],
+        }),
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(3., 4.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_single("job2", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(6., 6.)
+                        .load(vec![0])
+                        .distance(4)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let solution = serde_json::to_string(&solution).unwrap();
+    let solutions = format!("{solution}\n{solution}\n");
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read core problem"));
+
+    let solutions = read_init_solutions(BufReader::new(solutions.as_bytes()), core_problem, create_random())
+        .expect("cannot read init solutions");
+
+    assert_eq!(solutions.len(), 2);
+    solutions.iter().for_each(|solution| {
+        assert!(solution.unassigned.is_empty());
+        assert_eq!(solution.routes.len(), 1);
+        assert_eq!(solution.routes[0].tour.job_count(), 1);
+        assert_eq!(solution.routes[0].tour.start().unwrap().schedule.departure, 2.);
+    });
+}
@@ -99,6 +99,8 @@ fn can_merge_activities_with_same_location_in_one_stop() {
             distance: 10,
             duration: 12,
             times: Timing { driving: 10, serving: 2, ..Timing::default() },
+            tardiness: None,
+            days: None,
         }
     );
     assert_eq!(solution.tours.len(), 1);
@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn can_decode_polyline() {
+    let coordinates = decode_polyline("_p~iF~ps|U_ulLnnqC_mqNvxq`@").unwrap();
+
+    assert_eq!(coordinates, vec![(38.5, -120.2), (40.7, -120.95), (43.252, -126.453)]);
+}
+
+#[test]
+fn can_encode_polyline() {
+    let encoded = encode_polyline(&[(38.5, -120.2), (40.7, -120.95), (43.252, -126.453)]);
+
+    assert_eq!(encoded, "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+}
+
+parameterized_test! {can_detect_invalid_polyline, encoded, {
+    can_detect_invalid_polyline_impl(encoded);
+}}
+
+can_detect_invalid_polyline! {
+    case01_missing_longitude: "_p~iF",
+    case02_unfinished_value: "_p~iF~",
+    case03_invalid_character: "_p~iF ~ps|U",
+}
+
+fn can_detect_invalid_polyline_impl(encoded: &str) {
+    assert!(decode_polyline(encoded).is_err());
+}
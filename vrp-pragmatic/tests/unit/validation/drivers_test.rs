@@ -0,0 +1,86 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_drivers(drivers: Vec<DriverType>) -> Problem {
+    Problem { fleet: Fleet { drivers: Some(drivers), ..create_default_fleet() }, ..create_empty_problem() }
+}
+
+#[test]
+fn can_detect_duplicated_driver_type_ids() {
+    let problem =
+        create_problem_with_drivers(vec![create_default_driver_type("driver"), create_default_driver_type("driver")]);
+
+    let result = check_e1400_no_driver_types_with_duplicate_type_ids(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1400".to_string()));
+}
+
+#[test]
+fn can_detect_duplicated_driver_ids() {
+    let problem = create_problem_with_drivers(vec![
+        create_default_driver_type("driver"),
+        DriverType { type_id: "other".to_string(), ..create_default_driver_type("driver") },
+    ]);
+
+    let result = check_e1401_no_driver_types_with_duplicate_ids(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1401".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_driver_shift_time, (shifts, expected), {
+    can_detect_invalid_driver_shift_time_impl(shifts, expected);
+}}
+
+can_detect_invalid_driver_shift_time! {
+    case01: (None, None),
+    case02: (Some(vec![(0., 100.)]), None),
+    case03: (Some(vec![(0., 100.), (200., 300.)]), None),
+    case04: (Some(vec![(100., 0.)]), Some("E1402".to_string())),
+    case05: (Some(vec![(0., 100.), (50., 300.)]), Some("E1402".to_string())),
+    case06: (Some(vec![]), Some("E1402".to_string())),
+}
+
+fn can_detect_invalid_driver_shift_time_impl(shifts: Option<Vec<(f64, f64)>>, expected: Option<String>) {
+    let shifts = shifts.map(|shifts| {
+        shifts
+            .into_iter()
+            .map(|(earliest, latest)| DriverShift { earliest: format_time(earliest), latest: format_time(latest) })
+            .collect()
+    });
+    let problem = create_problem_with_drivers(vec![DriverType { shifts, ..create_default_driver_type("driver") }]);
+
+    let result = check_e1402_driver_shift_time(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_unknown_vehicle_types, (vehicle_types, expected), {
+    can_detect_unknown_vehicle_types_impl(vehicle_types, expected);
+}}
+
+can_detect_unknown_vehicle_types! {
+    case01: (None, None),
+    case02: (Some(vec!["my_vehicle"]), None),
+    case03: (Some(vec!["my_vehicle", "unknown"]), Some("E1403".to_string())),
+    case04: (Some(vec![]), Some("E1403".to_string())),
+}
+
+fn can_detect_unknown_vehicle_types_impl(vehicle_types: Option<Vec<&str>>, expected: Option<String>) {
+    let vehicle_types = vehicle_types.map(|types| types.into_iter().map(|t| t.to_string()).collect());
+    let problem =
+        create_problem_with_drivers(vec![DriverType { vehicle_types, ..create_default_driver_type("driver") }]);
+
+    let result =
+        check_e1403_driver_vehicle_types_are_known(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
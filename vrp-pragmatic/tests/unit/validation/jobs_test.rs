@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 
 fn assert_result(code: &str, action: &str, result: Option<FormatError>) {
@@ -73,3 +74,257 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+#[test]
+fn can_detect_negative_demand_with_dimension_name() {
+    let job = create_delivery_job("job1", (1., 0.));
+    let job = Job {
+        deliveries: job.deliveries.map(|tasks| {
+            tasks
+                .into_iter()
+                .map(|task| JobTask { demand: Some(create_named_load(&[("volume", -1)])), ..task })
+                .collect()
+        }),
+        ..job
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet { dimensions: Some(vec!["weight".to_string(), "volume".to_string()]), ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1107_negative_demand(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1107", "job1 (volume)", result);
+}
+
+parameterized_test! {can_detect_invalid_time_window_tolerance, (times, tolerance, expected), {
+    can_detect_invalid_time_window_tolerance_impl(times, tolerance, expected);
+}}
+
+can_detect_invalid_time_window_tolerance! {
+    case01_valid: (vec![(0, 100)], (Some(10.), Some(20.), 1.), false),
+    case02_valid_lateness_only: (vec![(0, 100)], (None, Some(20.), 1.), false),
+    case03_negative_earliness: (vec![(0, 100)], (Some(-10.), None, 1.), true),
+    case04_negative_lateness: (vec![(0, 100)], (None, Some(-10.), 1.), true),
+    case05_negative_penalty: (vec![(0, 100)], (None, Some(10.), -1.), true),
+    case06_no_times: (vec![], (None, Some(10.), 1.), true),
+}
+
+fn can_detect_invalid_time_window_tolerance_impl(
+    times: Vec<(i32, i32)>,
+    tolerance: (Option<f64>, Option<f64>, f64),
+    expected: bool,
+) {
+    let job = create_delivery_job_with_tolerance("job1", (1., 0.), times, tolerance);
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1108_invalid_time_window_tolerance(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if expected {
+        assert_result("E1108", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_max_ride_duration, (is_pickup_delivery, max_ride_duration, expected), {
+    can_detect_invalid_max_ride_duration_impl(is_pickup_delivery, max_ride_duration, expected);
+}}
+
+can_detect_invalid_max_ride_duration! {
+    case01_valid: (true, 100., false),
+    case02_zero: (true, 0., true),
+    case03_negative: (true, -10., true),
+    case04_not_pickup_delivery: (false, 100., true),
+}
+
+fn can_detect_invalid_max_ride_duration_impl(is_pickup_delivery: bool, max_ride_duration: f64, expected: bool) {
+    let job = if is_pickup_delivery {
+        create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+    } else {
+        create_delivery_job("job1", (1., 0.))
+    };
+    let job = Job { max_ride_duration: Some(max_ride_duration), ..job };
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1109_invalid_max_ride_duration(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if expected {
+        assert_result("E1109", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_unknown_demand_dimensions, (dimensions, demand, expected), {
+    can_detect_unknown_demand_dimensions_impl(dimensions, demand, expected);
+}}
+
+can_detect_unknown_demand_dimensions! {
+    case01_indexed_without_names: (None, vec![1, 2].into(), None),
+    case02_indexed_with_names: (Some(vec!["weight", "volume"]), vec![1, 2].into(), None),
+    case03_named: (Some(vec!["weight", "volume"]), create_named_load(&[("volume", 1)]), None),
+    case04_named_unknown: (Some(vec!["weight"]), create_named_load(&[("cold", 1)]), Some("job1 (cold)")),
+    case05_named_without_names: (None, create_named_load(&[("weight", 1)]), Some("job1 (weight)")),
+    case06_indexed_too_long: (Some(vec!["weight"]), vec![1, 2].into(), Some("job1 (#1)")),
+}
+
+fn can_detect_unknown_demand_dimensions_impl(
+    dimensions: Option<Vec<&str>>,
+    demand: LoadAmount,
+    expected: Option<&str>,
+) {
+    let job = create_delivery_job("job1", (1., 0.));
+    let job = Job {
+        deliveries: job
+            .deliveries
+            .map(|tasks| tasks.into_iter().map(|task| JobTask { demand: Some(demand.clone()), ..task }).collect()),
+        ..job
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            dimensions: dimensions.map(|names| names.into_iter().map(|name| name.to_string()).collect()),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1110_unknown_demand_dimensions(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(expected) = expected {
+        assert_result("E1110", expected, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_place_days, (times, days, expected), {
+    can_detect_invalid_job_place_days_impl(times, days, expected);
+}}
+
+can_detect_invalid_job_place_days! {
+    case01_valid: (vec![(0, 100)], Some(vec![0, 2]), false),
+    case02_no_days: (vec![(0, 100)], None, false),
+    case03_empty_days: (vec![(0, 100)], Some(vec![]), true),
+    case04_duplicate_days: (vec![(0, 100)], Some(vec![1, 1]), true),
+    case05_no_times: (vec![], Some(vec![1]), true),
+    case06_intersect_across_days: (vec![(0, 100_000)], Some(vec![0, 1]), true),
+}
+
+fn can_detect_invalid_job_place_days_impl(times: Vec<(i32, i32)>, days: Option<Vec<usize>>, expected: bool) {
+    let mut job = create_delivery_job_with_times("job1", (1., 0.), times, 1.);
+    job.deliveries.as_mut().unwrap()[0].places[0].days = days;
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1111_invalid_job_place_days(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected {
+        assert_result("E1111", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+type TestFrequency = (usize, Option<usize>, Option<usize>, Option<Vec<Vec<usize>>>);
+
+parameterized_test! {can_detect_invalid_job_frequency, (frequency, has_calendar, has_relation, expected), {
+    can_detect_invalid_job_frequency_impl(frequency, has_calendar, has_relation, expected);
+}}
+
+can_detect_invalid_job_frequency! {
+    case01_valid: ((2, None, None, None), true, false, false),
+    case02_no_calendar: ((2, None, None, None), false, false, true),
+    case03_zero_count: ((0, None, None, None), true, false, true),
+    case04_zero_min_spacing: ((2, Some(0), None, None), true, false, true),
+    case05_max_less_than_min: ((2, Some(3), Some(2), None), true, false, true),
+    case06_valid_patterns: ((2, None, None, Some(vec![vec![0, 2], vec![1, 3]])), true, false, false),
+    case07_pattern_size_mismatch: ((2, None, None, Some(vec![vec![0, 2, 4]])), true, false, true),
+    case08_pattern_duplicates: ((2, None, None, Some(vec![vec![1, 1]])), true, false, true),
+    case09_in_relation: ((2, None, None, None), true, true, true),
+}
+
+fn can_detect_invalid_job_frequency_impl(
+    frequency: TestFrequency,
+    has_calendar: bool,
+    has_relation: bool,
+    expected: bool,
+) {
+    let (count, min_spacing, max_spacing, patterns) = frequency;
+    let job = Job {
+        frequency: Some(JobFrequency { count, min_spacing, max_spacing, patterns }),
+        ..create_delivery_job("job1", (1., 0.))
+    };
+    let calendar = VehicleCalendar { days: vec![VehicleDay { day: 0, fixed: None }], min_rest: None };
+    let relations = vec![Relation {
+        type_field: RelationType::Any,
+        jobs: vec!["job1".to_string()],
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: None,
+    }];
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: has_relation.then_some(relations), ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { calendar: has_calendar.then_some(calendar), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1112_invalid_job_frequency(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected {
+        assert_result("E1112", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_place_service_time, (durations, parking, expected), {
+    can_detect_invalid_job_place_service_time_impl(durations, parking, expected);
+}}
+
+can_detect_invalid_job_place_service_time! {
+    case01_valid: (Some(vec![(0, 10.), (100, 20.)]), Some(5.), false),
+    case02_none: (None, None, false),
+    case03_empty_durations: (Some(vec![]), None, true),
+    case04_duplicate_starts: (Some(vec![(0, 10.), (0, 20.)]), None, true),
+    case05_negative_duration: (Some(vec![(0, -1.)]), None, true),
+    case06_negative_parking: (None, Some(-1.), true),
+}
+
+fn can_detect_invalid_job_place_service_time_impl(
+    durations: Option<Vec<(i32, f64)>>,
+    parking: Option<f64>,
+    expected: bool,
+) {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    let place = &mut job.deliveries.as_mut().unwrap()[0].places[0];
+    place.durations = durations.map(|durations| {
+        durations
+            .into_iter()
+            .map(|(start, duration)| JobPlaceDuration { start: format_time(start as f64), duration })
+            .collect()
+    });
+    place.parking = parking;
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1113_invalid_job_place_service_time(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if expected {
+        assert_result("E1113", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
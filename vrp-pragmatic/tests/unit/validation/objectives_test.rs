@@ -11,6 +11,10 @@ fn balance_dist() -> Objective {
     BalanceDistance { options: None }
 }
 
+fn to_levels(objectives: Option<Vec<Vec<Objective>>>) -> Option<Vec<ObjectiveLevel>> {
+    objectives.map(|objectives| objectives.into_iter().map(ObjectiveLevel::from).collect())
+}
+
 #[test]
 fn can_fallback_to_default() {
     let problem = Problem { objectives: None, ..create_empty_problem() };
@@ -34,7 +38,7 @@ can_detect_empty_objective! {
 }
 
 fn can_detect_empty_objective_impl(objectives: Option<Vec<Vec<Objective>>>, expected: Option<()>) {
-    let problem = Problem { objectives, ..create_empty_problem() };
+    let problem = Problem { objectives: to_levels(objectives), ..create_empty_problem() };
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, None, &coord_index);
     let objectives = get_objectives(&ctx).unwrap();
@@ -59,7 +63,7 @@ can_detect_duplicates! {
 }
 
 fn can_detect_duplicates_impl(objectives: Option<Vec<Vec<Objective>>>, expected: Option<String>) {
-    let problem = Problem { objectives, ..create_empty_problem() };
+    let problem = Problem { objectives: to_levels(objectives), ..create_empty_problem() };
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, None, &coord_index);
     let objectives = get_objectives(&ctx).unwrap();
@@ -82,7 +86,7 @@ can_detect_missing_cost_objective! {
 }
 
 fn can_detect_missing_cost_objective_impl(objectives: Option<Vec<Vec<Objective>>>, expected: Option<()>) {
-    let problem = Problem { objectives, ..create_empty_problem() };
+    let problem = Problem { objectives: to_levels(objectives), ..create_empty_problem() };
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, None, &coord_index);
     let objectives = get_objectives(&ctx).unwrap();
@@ -95,11 +99,11 @@ fn can_detect_missing_cost_objective_impl(objectives: Option<Vec<Vec<Objective>>
 #[test]
 fn can_detect_missing_value_jobs() {
     let problem = Problem {
-        objectives: Some(vec![
+        objectives: to_levels(Some(vec![
             vec![MinimizeUnassigned { breaks: None }],
             vec![MaximizeValue { breaks: None }],
             vec![MinimizeCost],
-        ]),
+        ])),
         ..create_empty_problem()
     };
     let coord_index = CoordIndex::new(&problem);
@@ -114,7 +118,11 @@ fn can_detect_missing_value_jobs() {
 #[test]
 fn can_detect_missing_order_jobs() {
     let problem = Problem {
-        objectives: Some(vec![vec![MinimizeUnassigned { breaks: None }], vec![TourOrder], vec![MinimizeCost]]),
+        objectives: to_levels(Some(vec![
+            vec![MinimizeUnassigned { breaks: None }],
+            vec![TourOrder],
+            vec![MinimizeCost],
+        ])),
         ..create_empty_problem()
     };
     let coord_index = CoordIndex::new(&problem);
@@ -170,7 +178,7 @@ can_detect_multiple_cost_objective! {
 }
 
 fn can_detect_multiple_cost_objective_impl(objectives: Option<Vec<Vec<Objective>>>, expected: Option<()>) {
-    let problem = Problem { objectives, ..create_empty_problem() };
+    let problem = Problem { objectives: to_levels(objectives), ..create_empty_problem() };
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, None, &coord_index);
     let objectives = get_objectives(&ctx).unwrap();
@@ -207,7 +215,7 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Vec<Objective>
             }],
             ..create_empty_plan()
         },
-        objectives,
+        objectives: to_levels(objectives),
         ..create_empty_problem()
     };
     let coord_index = CoordIndex::new(&problem);
@@ -218,3 +226,74 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Vec<Objective>
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+fn weighted_sum(objectives: Vec<Objective>, weights: Vec<f64>) -> Objective {
+    WeightedSum { objectives, weights }
+}
+
+parameterized_test! {can_detect_invalid_weighted_sum, (objectives, expected), {
+    can_detect_invalid_weighted_sum_impl(objectives, expected);
+}}
+
+can_detect_invalid_weighted_sum! {
+    case01_valid: (vec![vec![weighted_sum(vec![min_cost(), MinimizeTours], vec![1., 100.])]], None),
+    case02_not_alone: (vec![vec![weighted_sum(vec![min_cost()], vec![1.]), MinimizeTours]], Some(())),
+    case03_empty: (vec![vec![weighted_sum(vec![], vec![])]], Some(())),
+    case04_weights_mismatch: (vec![vec![weighted_sum(vec![min_cost(), MinimizeTours], vec![1.])]], Some(())),
+    case05_zero_weight: (vec![vec![weighted_sum(vec![min_cost(), MinimizeTours], vec![1., 0.])]], Some(())),
+    case06_nested: (
+        vec![vec![weighted_sum(vec![min_cost(), weighted_sum(vec![MinimizeTours], vec![1.])], vec![1., 1.])]],
+        Some(()),
+    ),
+}
+
+fn can_detect_invalid_weighted_sum_impl(objectives: Vec<Vec<Objective>>, expected: Option<()>) {
+    let levels = to_levels(Some(objectives)).unwrap();
+
+    let result = check_e1608_invalid_weighted_sum_objective(&levels);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1608".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_objective_tolerance, (relative, absolute, expected), {
+    can_detect_invalid_objective_tolerance_impl(relative, absolute, expected);
+}}
+
+can_detect_invalid_objective_tolerance! {
+    case01_relative: (Some(0.02), None, None),
+    case02_absolute: (None, Some(100.), None),
+    case03_both: (Some(0.02), Some(100.), None),
+    case04_none: (None, None, Some(())),
+    case05_negative_relative: (Some(-0.02), None, Some(())),
+    case06_negative_absolute: (Some(0.02), Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_objective_tolerance_impl(relative: Option<f64>, absolute: Option<f64>, expected: Option<()>) {
+    let levels = vec![ObjectiveLevel::WithTolerance {
+        objectives: vec![min_cost()],
+        tolerance: ObjectiveTolerance { relative, absolute },
+    }];
+
+    let result = check_e1609_invalid_objective_tolerance(&levels);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1609".to_string()));
+}
+
+#[test]
+fn can_check_objectives_inside_weighted_sum() {
+    let problem = Problem {
+        objectives: to_levels(Some(vec![
+            vec![MinimizeUnassigned { breaks: None }],
+            vec![weighted_sum(vec![min_cost(), MinimizeDistance], vec![1., 1.])],
+        ])),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1606_check_multiple_cost_objectives(&objectives);
+
+    assert_eq!(objectives.len(), 3);
+    assert_eq!(result.err().map(|err| err.code), Some("E1606".to_string()));
+}
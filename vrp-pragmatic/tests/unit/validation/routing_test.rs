@@ -6,8 +6,8 @@ fn can_detect_duplicates() {
     let problem = Problem {
         fleet: Fleet {
             profiles: vec![
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, fifo: None, speed_bands: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, fifo: None, speed_bands: None },
             ],
             ..create_default_fleet()
         },
@@ -85,6 +85,8 @@ fn can_detect_index_mismatch() {
         travel_times: vec![1; 4],
         distances: vec![1; 4],
         error_codes: None,
+        entries: None,
+        geometries: None,
     }];
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
@@ -102,7 +104,7 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle_type() },
                 VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle_type() },
             ],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, fifo: None, speed_bands: None }],
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -114,3 +116,210 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_speed_bands, (bands, timestamp, expected), {
+    can_detect_invalid_speed_bands_impl(bands, timestamp, expected);
+}}
+
+can_detect_invalid_speed_bands! {
+    case01_valid: (vec![("2020-07-04T08:00:00Z", 0.5), ("2020-07-04T10:00:00Z", 1.)], None, None),
+    case02_empty: (vec![], None, Some("E1506")),
+    case03_zero_factor: (vec![("2020-07-04T08:00:00Z", 0.)], None, Some("E1506")),
+    case04_unsorted: (vec![("2020-07-04T10:00:00Z", 0.5), ("2020-07-04T08:00:00Z", 1.)], None, Some("E1506")),
+    case05_invalid_time: (vec![("08:00", 0.5)], None, Some("E1506")),
+    case06_with_timestamps: (vec![("2020-07-04T08:00:00Z", 0.5)], Some("2020-07-04T08:00:00Z"), Some("E1506")),
+}
+
+fn can_detect_invalid_speed_bands_impl(bands: Vec<(&str, f64)>, timestamp: Option<&str>, expected: Option<&str>) {
+    let speed_bands = bands.into_iter().map(|(start, factor)| SpeedBand { start: start.to_string(), factor }).collect();
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                fifo: None,
+                speed_bands: Some(speed_bands),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: timestamp.map(|timestamp| timestamp.to_string()),
+        travel_times: vec![1; 4],
+        distances: vec![1; 4],
+        error_codes: None,
+        entries: None,
+        geometries: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1506_speed_bands(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+#[test]
+fn can_detect_speed_bands_with_time_aware_matrix_of_other_profile() {
+    let create_profile =
+        |name: &str, speed_bands| MatrixProfile { name: name.to_string(), speed: None, fifo: None, speed_bands };
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![
+                create_profile("car", Some(vec![SpeedBand { start: "2020-07-04T08:00:00Z".to_string(), factor: 0.5 }])),
+                create_profile("truck", None),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix {
+        profile: Some("truck".to_owned()),
+        timestamp: Some("2020-07-04T08:00:00Z".to_string()),
+        travel_times: vec![1; 4],
+        distances: vec![1; 4],
+        error_codes: None,
+        entries: None,
+        geometries: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1506_speed_bands(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1506".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_sparse_matrices, (use_indices, timestamp, travel_times, entry, expected), {
+    can_detect_invalid_sparse_matrices_impl(use_indices, timestamp, travel_times, entry, expected);
+}}
+
+can_detect_invalid_sparse_matrices! {
+    case01_valid: (false, None, vec![], (0, 1), None),
+    case02_indices: (true, None, vec![], (0, 1), Some("E1507")),
+    case03_timestamp: (false, Some("2020-07-04T08:00:00Z"), vec![], (0, 1), Some("E1507")),
+    case04_dense_data: (false, None, vec![1; 4], (0, 1), Some("E1507")),
+    case05_unknown_location: (false, None, vec![], (0, 2), Some("E1507")),
+}
+
+fn can_detect_invalid_sparse_matrices_impl(
+    use_indices: bool,
+    timestamp: Option<&str>,
+    travel_times: Vec<i64>,
+    entry: (usize, usize),
+    expected: Option<&str>,
+) {
+    let jobs = if use_indices {
+        vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)]
+    } else {
+        vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))]
+    };
+    let problem = Problem { plan: Plan { jobs, ..create_empty_plan() }, ..create_empty_problem() };
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: timestamp.map(|timestamp| timestamp.to_string()),
+        travel_times,
+        distances: vec![],
+        error_codes: None,
+        entries: Some(vec![MatrixEntry(entry.0, entry.1, 1, 1)]),
+        geometries: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+    let location_types = (ctx.coord_index.has_coordinates(), ctx.coord_index.has_indices());
+
+    let result = check_e1507_sparse_matrices(&ctx, location_types);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_matrix_geometries, (use_indices, from, to, polyline, expected), {
+    can_detect_invalid_matrix_geometries_impl(use_indices, from, to, polyline, expected);
+}}
+
+can_detect_invalid_matrix_geometries! {
+    case01_valid: (false, 0, 1, "??_ibE?", None),
+    case02_indices: (true, 0, 1, "??_ibE?", Some("E1508")),
+    case03_unknown_location: (false, 0, 3, "??_ibE?", Some("E1508")),
+    case04_single_point: (false, 0, 1, "??", Some("E1508")),
+    case05_invalid_polyline: (false, 0, 1, "?", Some("E1508")),
+}
+
+fn can_detect_invalid_matrix_geometries_impl(
+    use_indices: bool,
+    from: usize,
+    to: usize,
+    polyline: &str,
+    expected: Option<&str>,
+) {
+    let jobs = if use_indices {
+        vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)]
+    } else {
+        vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))]
+    };
+    let problem = Problem { plan: Plan { jobs, ..create_empty_plan() }, ..create_empty_problem() };
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times: vec![1; 4],
+        distances: vec![1; 4],
+        error_codes: None,
+        entries: None,
+        geometries: Some(vec![MatrixGeometry(from, to, polyline.to_string())]),
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+    let location_types = (ctx.coord_index.has_coordinates(), ctx.coord_index.has_indices());
+
+    let result = check_e1508_matrix_geometries(&ctx, location_types);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_fifo, (fifo, matrix_profile, timestamp, expected), {
+    can_detect_invalid_fifo_impl(fifo, matrix_profile, timestamp, expected);
+}}
+
+can_detect_invalid_fifo! {
+    case01_time_aware: (Some(true), Some("car"), Some("2020-07-04T08:00:00Z"), None),
+    case02_time_aware_no_profile: (Some(true), None, Some("2020-07-04T08:00:00Z"), None),
+    case03_time_agnostic: (Some(true), Some("car"), None, Some("E1509")),
+    case04_other_profile: (Some(true), Some("truck"), Some("2020-07-04T08:00:00Z"), Some("E1509")),
+    case05_no_fifo: (Some(false), Some("car"), None, None),
+    case06_default: (None, Some("car"), None, None),
+}
+
+fn can_detect_invalid_fifo_impl(
+    fifo: Option<bool>,
+    matrix_profile: Option<&str>,
+    timestamp: Option<&str>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![
+                MatrixProfile { name: "car".to_string(), speed: None, fifo, speed_bands: None },
+                MatrixProfile { name: "truck".to_string(), speed: None, fifo: None, speed_bands: None },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix {
+        profile: matrix_profile.map(|profile| profile.to_string()),
+        timestamp: timestamp.map(|timestamp| timestamp.to_string()),
+        travel_times: vec![1; 4],
+        distances: vec![1; 4],
+        error_codes: None,
+        entries: None,
+        geometries: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1509_fifo(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
@@ -0,0 +1,95 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_state(vehicle_id: &str, shift_index: Option<usize>, completed: &[&str], committed: &[&str]) -> VehicleState {
+    let to_ids = |ids: &[&str]| Some(ids.iter().map(|id| id.to_string()).collect::<Vec<_>>());
+
+    VehicleState {
+        vehicle_id: vehicle_id.to_string(),
+        shift_index,
+        location: (1., 0.).to_loc(),
+        time: format_time(10.),
+        completed: to_ids(completed),
+        picked_up: None,
+        committed: to_ids(committed),
+    }
+}
+
+parameterized_test! {can_detect_state_errors, (vehicles, relation, expected), {
+    can_detect_state_errors_impl(vehicles, relation, expected);
+}}
+
+can_detect_state_errors! {
+    case01: (vec![create_state("my_vehicle_1", None, &["job1"], &["job2"])], None, None),
+    case02: (vec![create_state("my_vehicle_2", None, &["job1"], &[])], None, Some(("E1700", "my_vehicle_2"))),
+    case03: (vec![create_state("my_vehicle_1", Some(1), &[], &["job1"])], None, Some(("E1700", "my_vehicle_1"))),
+    case04: (vec![create_state("my_vehicle_1", None, &["job4"], &["job1"])], None, Some(("E1701", "job4"))),
+    case05: (vec![create_state("my_vehicle_1", None, &["job1"], &["job1"])], None, Some(("E1702", "job1"))),
+    case06: (
+        vec![create_state("my_vehicle_1", None, &["job1"], &[]), create_state("my_vehicle_1", Some(0), &[], &[])],
+        None,
+        Some(("E1702", "my_vehicle_1:0"))
+    ),
+    case07: (
+        vec![create_state("my_vehicle_1", None, &[], &["job1"])],
+        Some((RelationType::Any, "job1")),
+        Some(("E1703", "job1"))
+    ),
+    case08: (
+        vec![create_state("my_vehicle_1", None, &[], &["job1"])],
+        Some((RelationType::Sequence, "job2")),
+        Some(("E1703", "my_vehicle_1"))
+    ),
+    case09: (vec![create_state("my_vehicle_1", None, &[], &["job1"])], Some((RelationType::Any, "job2")), None),
+    case10: (
+        vec![VehicleState { time: "10:00".to_string(), ..create_state("my_vehicle_1", None, &[], &[]) }],
+        None,
+        Some(("E1704", "my_vehicle_1"))
+    ),
+    case11: (
+        vec![VehicleState { picked_up: Some(vec!["job1".to_string()]), ..create_state("my_vehicle_1", None, &[], &[]) }],
+        None,
+        Some(("E1705", "job1"))
+    ),
+    case12: (
+        vec![VehicleState { picked_up: Some(vec!["job4".to_string()]), ..create_state("my_vehicle_1", None, &[], &[]) }],
+        None,
+        Some(("E1701", "job4"))
+    ),
+}
+
+fn can_detect_state_errors_impl(
+    vehicles: Vec<VehicleState>,
+    relation: Option<(RelationType, &str)>,
+    expected: Option<(&str, &str)>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            relations: relation.map(|(type_field, job_id)| {
+                vec![Relation {
+                    type_field,
+                    jobs: vec![job_id.to_string()],
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    shift_index: None,
+                }]
+            }),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        state: Some(ProblemState { vehicles }),
+        ..create_empty_problem()
+    };
+
+    let result = validate_state(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    if let Some((code, action)) = expected {
+        let errors = result.expect_err("expected error").errors;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, code);
+        assert!(errors[0].action.contains(action));
+    } else {
+        assert!(result.is_ok());
+    }
+}
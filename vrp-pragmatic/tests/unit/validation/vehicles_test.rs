@@ -1,4 +1,5 @@
 use super::*;
+use crate::format::LoadAmount;
 use crate::format_time;
 use crate::helpers::*;
 
@@ -124,7 +125,7 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
                 ..create_default_vehicle_type()
             }],
             resources: resources.map(|ids| {
-                ids.iter().map(|id| VehicleResource::Reload { id: id.to_string(), capacity: vec![2] }).collect()
+                ids.iter().map(|id| VehicleResource::Reload { id: id.to_string(), capacity: vec![2].into() }).collect()
             }),
             ..create_default_fleet()
         },
@@ -136,3 +137,264 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_handle_driving_rules, (driving_times, break_duration, split_break, expected), {
+    can_handle_driving_rules_impl(driving_times, break_duration, split_break, expected);
+}}
+
+can_handle_driving_rules! {
+    case01_valid: ((16200., 32400.), 2700., None, None),
+    case02_valid_split: ((16200., 32400.), 2700., Some(vec![900., 1800.]), None),
+    case03_zero_driving_time: ((0., 32400.), 2700., None, Some("E1309".to_string())),
+    case04_zero_break_duration: ((16200., 32400.), 0., None, Some("E1309".to_string())),
+    case05_low_daily_driving_time: ((16200., 10000.), 2700., None, Some("E1309".to_string())),
+    case06_short_split: ((16200., 32400.), 2700., Some(vec![900., 900.]), Some("E1309".to_string())),
+    case07_three_splits: ((16200., 32400.), 2700., Some(vec![900., 900., 900.]), Some("E1309".to_string())),
+}
+
+fn can_handle_driving_rules_impl(
+    driving_times: (f64, f64),
+    break_duration: f64,
+    split_break: Option<Vec<f64>>,
+    expected: Option<String>,
+) {
+    let (max_driving_time, max_daily_driving_time) = driving_times;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    driving_rules: Some(VehicleDrivingRules {
+                        max_driving_time,
+                        break_duration,
+                        max_daily_driving_time,
+                        split_break,
+                    }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_driving_rules(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_depots, (starts, ends, is_open, resource_location, expected), {
+    can_handle_depots_impl(starts, ends, is_open, resource_location, expected);
+}}
+
+can_handle_depots! {
+    case01_valid: (Some(vec![(1., 0.)]), Some(vec![(2., 0.)]), false, None, None),
+    case02_valid_resource: (Some(vec![(1., 0.)]), None, true, Some((1., 0.)), None),
+    case03_empty_start: (Some(vec![]), None, false, None, Some("E1310".to_string())),
+    case04_empty_end: (None, Some(vec![]), false, None, Some("E1310".to_string())),
+    case05_end_without_shift_end: (None, Some(vec![(2., 0.)]), true, None, Some("E1310".to_string())),
+    case06_unknown_resource_location: (Some(vec![(1., 0.)]), None, false, Some((2., 0.)), Some("E1310".to_string())),
+}
+
+fn can_handle_depots_impl(
+    starts: Option<Vec<(f64, f64)>>,
+    ends: Option<Vec<(f64, f64)>>,
+    is_open: bool,
+    resource_location: Option<(f64, f64)>,
+    expected: Option<String>,
+) {
+    let to_locations = |locations: Option<Vec<(f64, f64)>>| {
+        locations.map(|locations| locations.into_iter().map(|location| location.to_loc()).collect())
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    depots: Some(ShiftDepots { start: to_locations(starts), end: to_locations(ends) }),
+                    ..if is_open { create_default_open_vehicle_shift() } else { create_default_vehicle_shift() }
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: resource_location.map(|location| {
+                vec![VehicleResource::Depot { id: "depot".to_string(), location: location.to_loc(), capacity: 1 }]
+            }),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_depots(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_capacity_dimensions, (dimensions, capacity, resource_capacity, expected), {
+    can_handle_capacity_dimensions_impl(dimensions, capacity, resource_capacity, expected);
+}}
+
+can_handle_capacity_dimensions! {
+    case01_indexed: (None, vec![10, 5].into(), None, None),
+    case02_named: (Some(vec!["weight", "volume"]), create_named_load(&[("weight", 10)]), None, None),
+    case03_named_resource: (Some(vec!["weight"]), vec![10].into(), Some(create_named_load(&[("weight", 5)])), None),
+    case04_duplicate_names: (Some(vec!["weight", "weight"]), vec![10].into(), None, Some("weight")),
+    case05_empty_names: (Some(vec![]), vec![10].into(), None, Some("E1311")),
+    case06_unknown_name: (Some(vec!["weight"]), create_named_load(&[("pallets", 1)]), None, Some("my_vehicle (pallets)")),
+    case07_unknown_resource_name: (None, vec![10].into(), Some(create_named_load(&[("weight", 5)])), Some("resource (weight)")),
+}
+
+fn can_handle_capacity_dimensions_impl(
+    dimensions: Option<Vec<&str>>,
+    capacity: LoadAmount,
+    resource_capacity: Option<LoadAmount>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity, ..create_default_vehicle_type() }],
+            resources: resource_capacity
+                .map(|capacity| vec![VehicleResource::Reload { id: "resource".to_string(), capacity }]),
+            dimensions: dimensions.map(|names| names.into_iter().map(|name| name.to_string()).collect()),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1311_vehicle_capacity_dimensions(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(expected) = expected {
+        let result = result.expect("expected error");
+        assert_eq!(result.code, "E1311");
+        assert!(expected == "E1311" || result.action.contains(expected));
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_compartments, (compartment_ids, dimension, has_reloads, expected), {
+    can_detect_invalid_compartments_impl(compartment_ids, dimension, has_reloads, expected);
+}}
+
+can_detect_invalid_compartments! {
+    case01_valid: (vec!["c1", "c2"], None, false, None),
+    case02_empty: (vec![], None, false, Some("E1312")),
+    case03_duplicates: (vec!["c1", "c1"], None, false, Some("E1312")),
+    case04_unknown_dimension: (vec!["c1"], Some("pallets"), false, Some("E1312")),
+    case05_with_reloads: (vec!["c1"], None, true, Some("E1312")),
+}
+
+fn can_detect_invalid_compartments_impl(
+    compartment_ids: Vec<&str>,
+    dimension: Option<&str>,
+    has_reloads: bool,
+    expected: Option<&str>,
+) {
+    let capacity = dimension.map_or_else(|| vec![5].into(), |name| create_named_load(&[(name, 5)]));
+    let reloads = if has_reloads {
+        Some(vec![VehicleReload {
+            location: (0., 0.).to_loc(),
+            duration: 2.0,
+            times: None,
+            tag: None,
+            resource_id: None,
+        }])
+    } else {
+        None
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { reloads, ..create_default_vehicle_shift() }],
+                compartments: Some(
+                    compartment_ids
+                        .into_iter()
+                        .map(|id| VehicleCompartment { id: id.to_string(), capacity: capacity.clone(), products: None })
+                        .collect(),
+                ),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1312_vehicle_compartments(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_calendar, (days, fixed, min_rest, shift_end, expected), {
+    can_detect_invalid_calendar_impl(days, fixed, min_rest, shift_end, expected);
+}}
+
+can_detect_invalid_calendar! {
+    case01_valid: (vec![0, 1], None, Some(3600.), 1000., None),
+    case02_empty: (vec![], None, None, 1000., Some("E1313")),
+    case03_duplicates: (vec![1, 1], None, None, 1000., Some("E1313")),
+    case04_negative_rest: (vec![0], None, Some(-1.), 1000., Some("E1313")),
+    case05_negative_fixed: (vec![0], Some(-1.), None, 1000., Some("E1313")),
+    case06_intersect_across_days: (vec![0, 1], None, None, 100_000., Some("E1313")),
+}
+
+fn can_detect_invalid_calendar_impl(
+    days: Vec<usize>,
+    fixed: Option<f64>,
+    min_rest: Option<f64>,
+    shift_end: f64,
+    expected: Option<&str>,
+) {
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: shift.end.clone().map(|end| ShiftEnd { latest: format_time(shift_end), ..end }),
+                    ..shift
+                }],
+                calendar: Some(VehicleCalendar {
+                    days: days.into_iter().map(|day| VehicleDay { day, fixed }).collect(),
+                    min_rest,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1313_vehicle_calendar(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_vehicle_parking, (parking, expected), {
+    can_detect_invalid_vehicle_parking_impl(parking, expected);
+}}
+
+can_detect_invalid_vehicle_parking! {
+    case01_valid: (Some(60.), None),
+    case02_none: (None, None),
+    case03_negative: (Some(-1.), Some("E1314")),
+}
+
+fn can_detect_invalid_vehicle_parking_impl(parking: Option<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: VehicleProfile { parking, ..create_default_vehicle_profile() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1314_vehicle_parking(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|code| code.to_string()));
+}
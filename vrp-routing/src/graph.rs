@@ -0,0 +1,230 @@
+//! Contains road network graph model and logic to read it from json.
+
+#[cfg(test)]
+#[path = "../tests/unit/graph_test.rs"]
+mod graph_test;
+
+use hashbrown::HashMap;
+use serde::Deserialize;
+use std::collections::HashMap as StdHashMap;
+use std::io::{BufReader, Read};
+use vrp_core::prelude::GenericError;
+
+/// A size of spatial index cell in degrees.
+const CELL_SIZE: f64 = 0.01;
+
+/// A mean Earth radius in meters.
+const EARTH_RADIUS: f64 = 6_371_000.;
+
+/// A road class which defines travel speed on roads of this class.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RoadClass {
+    /// A road class name, e.g. `motorway` or `residential`.
+    pub name: String,
+    /// A default speed in meters per second.
+    pub speed: f64,
+    /// Speeds in meters per second for specific routing profiles.
+    #[serde(default)]
+    pub profiles: StdHashMap<String, f64>,
+}
+
+/// A directed road segment between two graph nodes.
+#[derive(Clone, Debug)]
+pub struct RoadEdge {
+    /// A start node index.
+    pub from: usize,
+    /// An end node index.
+    pub to: usize,
+    /// A road segment length in meters.
+    pub distance: f64,
+    /// A road class index.
+    pub class: usize,
+}
+
+/// A routing profile used to calculate travel durations on the road graph.
+#[derive(Clone, Debug)]
+pub struct RoadProfile {
+    /// A profile name which is used to get profile specific road class speeds.
+    pub name: String,
+    /// A max speed in meters per second, if specified, it limits road class speeds.
+    pub max_speed: Option<f64>,
+}
+
+/// A road network graph: nodes with geo coordinates connected by directed road segments.
+pub struct RoadGraph {
+    coordinates: Vec<(f64, f64)>,
+    edges: Vec<RoadEdge>,
+    classes: Vec<RoadClass>,
+    outgoing: Vec<Vec<usize>>,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    bounds: ((i64, i64), (i64, i64)),
+    distance_ratio: f64,
+}
+
+impl RoadGraph {
+    /// Creates a new instance of `RoadGraph` from nodes coordinates as (latitude, longitude) pairs,
+    /// road segments and road classes.
+    pub fn new(
+        coordinates: Vec<(f64, f64)>,
+        edges: Vec<RoadEdge>,
+        classes: Vec<RoadClass>,
+    ) -> Result<Self, GenericError> {
+        if coordinates.is_empty() {
+            return Err("road graph has no nodes".into());
+        }
+
+        if let Some(idx) = coordinates.iter().position(|(lat, lng)| !lat.is_finite() || !lng.is_finite()) {
+            return Err(format!("node {idx} has invalid coordinates").into());
+        }
+
+        if let Some(class) = classes.iter().find(|class| {
+            class.speed <= 0. || !class.speed.is_finite() || class.profiles.values().any(|&speed| speed <= 0.)
+        }) {
+            return Err(format!("road class '{}' has non-positive speed", class.name).into());
+        }
+
+        if let Some((idx, _)) = edges.iter().enumerate().find(|(_, edge)| {
+            edge.from >= coordinates.len()
+                || edge.to >= coordinates.len()
+                || edge.class >= classes.len()
+                || edge.distance < 0.
+                || !edge.distance.is_finite()
+        }) {
+            return Err(format!("edge {idx} has invalid node, road class or distance").into());
+        }
+
+        let mut outgoing = vec![Vec::new(); coordinates.len()];
+        edges.iter().enumerate().for_each(|(idx, edge)| outgoing[edge.from].push(idx));
+
+        let mut cells: HashMap<(i64, i64), Vec<usize>> = HashMap::default();
+        coordinates
+            .iter()
+            .enumerate()
+            .for_each(|(idx, &(lat, lng))| cells.entry(get_cell(lat, lng)).or_default().push(idx));
+
+        let bounds = cells.keys().fold(((i64::MAX, i64::MAX), (i64::MIN, i64::MIN)), |(min, max), &(lat, lng)| {
+            ((min.0.min(lat), min.1.min(lng)), (max.0.max(lat), max.1.max(lng)))
+        });
+
+        // NOTE road segment length is not expected to be shorter than straight line, but it is not enforced
+        let distance_ratio = edges
+            .iter()
+            .map(|edge| (edge.distance, get_straight_distance(coordinates[edge.from], coordinates[edge.to])))
+            .filter(|&(_, straight)| straight > 0.)
+            .map(|(distance, straight)| distance / straight)
+            .fold(1_f64, f64::min);
+
+        Ok(Self { coordinates, edges, classes, outgoing, cells, bounds, distance_ratio })
+    }
+
+    /// Returns nodes coordinates as (latitude, longitude) pairs.
+    pub fn coordinates(&self) -> &[(f64, f64)] {
+        self.coordinates.as_slice()
+    }
+
+    /// Returns road segments.
+    pub fn edges(&self) -> &[RoadEdge] {
+        self.edges.as_slice()
+    }
+
+    /// Returns road segments which start at given node.
+    pub fn outgoing(&self, node: usize) -> impl Iterator<Item = &RoadEdge> + '_ {
+        self.outgoing[node].iter().map(move |&idx| &self.edges[idx])
+    }
+
+    /// Returns a lower bound of road distance between two nodes: straight line distance scaled
+    /// by the smallest ratio between road segment length and distance between its nodes.
+    pub fn get_distance_bound(&self, from: usize, to: usize) -> f64 {
+        get_straight_distance(self.coordinates[from], self.coordinates[to]) * self.distance_ratio
+    }
+
+    /// Returns road classes.
+    pub fn classes(&self) -> &[RoadClass] {
+        self.classes.as_slice()
+    }
+
+    /// Returns speed of each road class for given profile.
+    pub fn get_speeds(&self, profile: &RoadProfile) -> Vec<f64> {
+        self.classes
+            .iter()
+            .map(|class| class.profiles.get(&profile.name).copied().unwrap_or(class.speed))
+            .map(|speed| profile.max_speed.map_or(speed, |max_speed| speed.min(max_speed)))
+            .collect()
+    }
+
+    /// Finds the nearest graph node to given location.
+    pub fn find_nearest(&self, lat: f64, lng: f64) -> usize {
+        let (lat_cell, lng_cell) = get_cell(lat, lng);
+        let ((min_lat, min_lng), (max_lat, max_lng)) = self.bounds;
+        let max_ring = [lat_cell - min_lat, max_lat - lat_cell, lng_cell - min_lng, max_lng - lng_cell]
+            .into_iter()
+            .fold(0, i64::max);
+
+        // NOTE use equirectangular approximation: it keeps order of distances at small scale
+        let lng_scale = lat.to_radians().cos().abs();
+        let get_distance = |idx: usize| {
+            let (node_lat, node_lng) = self.coordinates[idx];
+            ((node_lat - lat).powi(2) + ((node_lng - lng) * lng_scale).powi(2)).sqrt()
+        };
+
+        let mut nearest: Option<(f64, usize)> = None;
+        for ring in 0..=max_ring {
+            // NOTE nodes in the ring cells are not closer than this bound
+            let bound = (ring - 1).max(0) as f64 * CELL_SIZE * lng_scale.min(1.);
+            if nearest.is_some_and(|(distance, _)| distance <= bound) {
+                break;
+            }
+
+            get_ring_cells(lat_cell, lng_cell, ring)
+                .filter_map(|cell| self.cells.get(&cell))
+                .flat_map(|nodes| nodes.iter())
+                .for_each(|&idx| {
+                    let distance = get_distance(idx);
+                    if nearest.is_none_or(|(best, best_idx)| distance < best || (distance == best && idx < best_idx)) {
+                        nearest = Some((distance, idx));
+                    }
+                });
+        }
+
+        nearest.map(|(_, idx)| idx).expect("road graph has no nodes")
+    }
+}
+
+/// Reads road graph from json. Nodes are specified as `[lat, lng]` arrays and edges as
+/// `[from, to, distance, roadClass]` arrays, where `from` and `to` are node indices and
+/// `roadClass` is an index in road classes collection.
+pub fn read_road_graph<R: Read>(reader: BufReader<R>) -> Result<RoadGraph, GenericError> {
+    let graph: GraphModel = serde_json::from_reader(reader).map_err(|err| format!("cannot read road graph: {err}"))?;
+
+    let edges =
+        graph.edges.into_iter().map(|(from, to, distance, class)| RoadEdge { from, to, distance, class }).collect();
+
+    RoadGraph::new(graph.nodes, edges, graph.road_classes)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphModel {
+    nodes: Vec<(f64, f64)>,
+    road_classes: Vec<RoadClass>,
+    edges: Vec<(usize, usize, f64, usize)>,
+}
+
+/// Gets great circle distance in meters between two locations using haversine formula.
+fn get_straight_distance((lat1, lng1): (f64, f64), (lat2, lng2): (f64, f64)) -> f64 {
+    let (d_lat, d_lng) = ((lat2 - lat1).to_radians(), (lng2 - lng1).to_radians());
+    let a = (d_lat / 2.).sin().powi(2) + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lng / 2.).sin().powi(2);
+
+    2. * EARTH_RADIUS * a.sqrt().atan2((1. - a).sqrt())
+}
+
+fn get_cell(lat: f64, lng: f64) -> (i64, i64) {
+    ((lat / CELL_SIZE).floor() as i64, (lng / CELL_SIZE).floor() as i64)
+}
+
+fn get_ring_cells(lat_cell: i64, lng_cell: i64, ring: i64) -> impl Iterator<Item = (i64, i64)> {
+    (-ring..=ring).flat_map(move |lat_offset| {
+        let lng_offsets = if lat_offset.abs() == ring { (-ring..=ring).collect() } else { vec![-ring, ring] };
+        lng_offsets.into_iter().map(move |lng_offset| (lat_cell + lat_offset, lng_cell + lng_offset))
+    })
+}
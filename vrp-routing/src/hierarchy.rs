@@ -0,0 +1,308 @@
+//! Provides contraction hierarchy: a speed-up technique for shortest path queries. The graph is
+//! preprocessed by contracting nodes one by one in order of their importance and adding shortcut
+//! edges which preserve shortest paths between remaining nodes. Then, a query needs to explore only
+//! edges which lead to more important nodes from both source and target. Many-to-many queries use
+//! bucket based approach: backward searches from targets put their results into node buckets which
+//! are scanned by forward searches from sources.
+
+#[cfg(test)]
+#[path = "../tests/unit/hierarchy_test.rs"]
+mod hierarchy_test;
+
+use hashbrown::HashMap;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use vrp_core::utils::parallel_collect;
+
+/// Max amount of settled nodes in witness search. When it is reached, a shortcut is added even if
+/// it is not needed, so the limit affects only preprocessing time and hierarchy size.
+const WITNESS_SETTLED_LIMIT: usize = 500;
+
+/// A contraction hierarchy of a directed graph with non-negative edge weights. Each edge also has
+/// a distance which is accumulated along the shortest path.
+pub struct ContractionHierarchy {
+    forward: UpwardGraph,
+    backward: UpwardGraph,
+}
+
+impl ContractionHierarchy {
+    /// Creates a new instance of `ContractionHierarchy` for a graph with given amount of nodes and
+    /// edges specified as (from, to, weight, distance).
+    pub fn new(size: usize, edges: impl Iterator<Item = (usize, usize, f64, f64)>) -> Self {
+        let mut graph = ContractionGraph::new(size);
+        edges.for_each(|(from, to, weight, distance)| graph.add_edge(from, to, Edge { node: to, weight, distance }));
+
+        let mut search = WitnessSearch::new(size);
+        let mut deleted_neighbours = vec![0_i64; size];
+        let mut forward = vec![Vec::new(); size];
+        let mut backward = vec![Vec::new(); size];
+
+        let get_priority = |graph: &ContractionGraph, search: &mut WitnessSearch, deleted: &[i64], node: usize| {
+            let shortcuts = graph.find_shortcuts(search, node).len() as i64;
+            let removed = (graph.outgoing[node].len() + graph.incoming[node].len()) as i64;
+
+            shortcuts - removed + deleted[node]
+        };
+
+        let mut queue = (0..size)
+            .map(|node| Reverse((get_priority(&graph, &mut search, &deleted_neighbours, node), node)))
+            .collect::<BinaryHeap<_>>();
+
+        while let Some(Reverse((_, node))) = queue.pop() {
+            // NOTE lazy update: priority can only be changed by contraction of neighbours
+            let priority = get_priority(&graph, &mut search, &deleted_neighbours, node);
+            if queue.peek().is_some_and(|Reverse((top, _))| priority > *top) {
+                queue.push(Reverse((priority, node)));
+                continue;
+            }
+
+            let shortcuts = graph.find_shortcuts(&mut search, node);
+            let (outgoing, incoming) = graph.remove_node(node);
+
+            outgoing.iter().chain(incoming.iter()).for_each(|arc| deleted_neighbours[arc.node] += 1);
+            shortcuts.into_iter().for_each(|(from, arc)| graph.add_edge(from, arc.node, arc));
+
+            // NOTE all remaining neighbours are contracted later, so they have higher rank
+            forward[node] = outgoing;
+            backward[node] = incoming;
+        }
+
+        Self { forward: UpwardGraph::new(forward), backward: UpwardGraph::new(backward) }
+    }
+
+    /// Calculates shortest paths between all sources and targets. Returns (weight, distance) pairs
+    /// in row-major order, unreachable targets have `None` value.
+    pub fn many_to_many(&self, sources: &[usize], targets: &[usize]) -> Vec<Option<(f64, f64)>> {
+        let mut buckets: HashMap<usize, Vec<(usize, f64, f64)>> = HashMap::default();
+        parallel_collect(targets, |&target| self.backward.search(target)).into_iter().enumerate().for_each(
+            |(target_idx, settled)| {
+                settled.into_iter().for_each(|(node, weight, distance)| {
+                    buckets.entry(node).or_default().push((target_idx, weight, distance))
+                })
+            },
+        );
+
+        parallel_collect(sources, |&source| {
+            let mut row: Vec<Option<(f64, f64)>> = vec![None; targets.len()];
+
+            self.forward.search(source).into_iter().for_each(|(node, weight, distance)| {
+                buckets.get(&node).iter().flat_map(|bucket| bucket.iter()).for_each(
+                    |&(target_idx, target_weight, target_distance)| {
+                        let total = weight + target_weight;
+                        if row[target_idx].is_none_or(|(best, _)| total < best) {
+                            row[target_idx] = Some((total, distance + target_distance));
+                        }
+                    },
+                );
+            });
+
+            row
+        })
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// A graph edge which keeps an adjacent node.
+#[derive(Clone, Copy, Debug)]
+struct Edge {
+    node: usize,
+    weight: f64,
+    distance: f64,
+}
+
+/// A mutable graph used to contract nodes.
+struct ContractionGraph {
+    outgoing: Vec<Vec<Edge>>,
+    incoming: Vec<Vec<Edge>>,
+}
+
+impl ContractionGraph {
+    fn new(size: usize) -> Self {
+        Self { outgoing: vec![Vec::new(); size], incoming: vec![Vec::new(); size] }
+    }
+
+    /// Adds an edge keeping only the one with the smallest weight between the same nodes.
+    fn add_edge(&mut self, from: usize, to: usize, arc: Edge) {
+        if from == to {
+            return;
+        }
+
+        match self.outgoing[from].iter_mut().find(|existing| existing.node == to) {
+            Some(existing) if existing.weight <= arc.weight => {}
+            Some(existing) => {
+                *existing = arc;
+                let reverse = self.incoming[to].iter_mut().find(|existing| existing.node == from).expect("no edge");
+                *reverse = Edge { node: from, ..arc };
+            }
+            None => {
+                self.outgoing[from].push(arc);
+                self.incoming[to].push(Edge { node: from, ..arc });
+            }
+        }
+    }
+
+    /// Removes the node from the graph and returns its outgoing and incoming edges.
+    fn remove_node(&mut self, node: usize) -> (Vec<Edge>, Vec<Edge>) {
+        let outgoing = std::mem::take(&mut self.outgoing[node]);
+        let incoming = std::mem::take(&mut self.incoming[node]);
+
+        outgoing.iter().for_each(|arc| self.incoming[arc.node].retain(|other| other.node != node));
+        incoming.iter().for_each(|arc| self.outgoing[arc.node].retain(|other| other.node != node));
+
+        (outgoing, incoming)
+    }
+
+    /// Finds shortcuts needed to preserve shortest paths when the node is contracted.
+    fn find_shortcuts(&self, search: &mut WitnessSearch, node: usize) -> Vec<(usize, Edge)> {
+        self.incoming[node]
+            .iter()
+            .flat_map(|incoming| {
+                let max_weight = self.outgoing[node]
+                    .iter()
+                    .filter(|outgoing| outgoing.node != incoming.node)
+                    .map(|outgoing| incoming.weight + outgoing.weight)
+                    .fold(f64::NEG_INFINITY, f64::max);
+
+                if max_weight.is_finite() {
+                    search.run(self, incoming.node, node, max_weight);
+                }
+
+                self.outgoing[node]
+                    .iter()
+                    .filter(|outgoing| outgoing.node != incoming.node)
+                    .filter(|outgoing| search.get_weight(outgoing.node) > incoming.weight + outgoing.weight)
+                    .map(|outgoing| {
+                        let weight = incoming.weight + outgoing.weight;
+                        let distance = incoming.distance + outgoing.distance;
+                        (incoming.node, Edge { node: outgoing.node, weight, distance })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+/// A limited local search which looks for a path avoiding the contracted node (aka witness).
+struct WitnessSearch {
+    weights: Vec<f64>,
+    touched: Vec<usize>,
+}
+
+impl WitnessSearch {
+    fn new(size: usize) -> Self {
+        Self { weights: vec![f64::INFINITY; size], touched: Vec::new() }
+    }
+
+    fn run(&mut self, graph: &ContractionGraph, source: usize, ignored: usize, max_weight: f64) {
+        self.touched.drain(..).for_each(|node| self.weights[node] = f64::INFINITY);
+
+        let mut queue = BinaryHeap::new();
+        let mut settled = 0;
+
+        self.weights[source] = 0.;
+        self.touched.push(source);
+        queue.push(QueueItem { weight: 0., distance: 0., node: source });
+
+        while let Some(QueueItem { weight, node, .. }) = queue.pop() {
+            if weight > self.weights[node] {
+                continue;
+            }
+
+            settled += 1;
+            if weight > max_weight || settled > WITNESS_SETTLED_LIMIT {
+                break;
+            }
+
+            graph.outgoing[node].iter().filter(|arc| arc.node != ignored).for_each(|arc| {
+                let next_weight = weight + arc.weight;
+                if next_weight < self.weights[arc.node] {
+                    if self.weights[arc.node].is_infinite() {
+                        self.touched.push(arc.node);
+                    }
+                    self.weights[arc.node] = next_weight;
+                    queue.push(QueueItem { weight: next_weight, distance: 0., node: arc.node });
+                }
+            });
+        }
+    }
+
+    fn get_weight(&self, node: usize) -> f64 {
+        self.weights[node]
+    }
+}
+
+/// A graph which keeps only edges to higher ranked nodes in compressed sparse row layout.
+struct UpwardGraph {
+    offsets: Vec<usize>,
+    arcs: Vec<Edge>,
+}
+
+impl UpwardGraph {
+    fn new(adjacency: Vec<Vec<Edge>>) -> Self {
+        let offsets = std::iter::once(0)
+            .chain(adjacency.iter().scan(0, |offset, arcs| {
+                *offset += arcs.len();
+                Some(*offset)
+            }))
+            .collect();
+
+        Self { offsets, arcs: adjacency.into_iter().flatten().collect() }
+    }
+
+    /// Runs Dijkstra search in upward graph and returns all settled nodes with their weights and distances.
+    fn search(&self, source: usize) -> Vec<(usize, f64, f64)> {
+        let mut best: HashMap<usize, f64> = HashMap::default();
+        let mut settled = Vec::new();
+        let mut queue = BinaryHeap::new();
+
+        best.insert(source, 0.);
+        queue.push(QueueItem { weight: 0., distance: 0., node: source });
+
+        while let Some(QueueItem { weight, distance, node }) = queue.pop() {
+            if best.get(&node).is_some_and(|&node_weight| weight > node_weight) {
+                continue;
+            }
+
+            settled.push((node, weight, distance));
+
+            self.arcs[self.offsets[node]..self.offsets[node + 1]].iter().for_each(|arc| {
+                let next_weight = weight + arc.weight;
+                if best.get(&arc.node).is_none_or(|&node_weight| next_weight < node_weight) {
+                    best.insert(arc.node, next_weight);
+                    queue.push(QueueItem { weight: next_weight, distance: distance + arc.distance, node: arc.node });
+                }
+            });
+        }
+
+        settled
+    }
+}
+
+/// A priority queue item ordered by weight in reverse, so binary heap acts as min heap.
+pub(crate) struct QueueItem {
+    pub weight: f64,
+    pub distance: f64,
+    pub node: usize,
+}
+
+impl PartialEq for QueueItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueueItem {}
+
+impl PartialOrd for QueueItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueueItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.weight.total_cmp(&self.weight).then_with(|| other.node.cmp(&self.node))
+    }
+}
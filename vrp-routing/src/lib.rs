@@ -0,0 +1,31 @@
+//! Routing crate contains logic to calculate routing matrices using a preprocessed road network
+//! graph stored in a local file, so real road distances and durations can be used without an
+//! external routing service.
+//!
+//!
+//! # Road graph
+//!
+//! A road graph is a set of nodes with geo coordinates connected by directed road segments. Each
+//! segment has a length and a road class which defines travel speed, optionally, per routing
+//! profile. Such graph can be derived from OpenStreetMap data, e.g. from a PBF file.
+//!
+//! # Routing
+//!
+//! Locations are snapped to the nearest graph node and routing matrices are calculated using
+//! contraction hierarchy built for each distinct set of profile speeds. Road geometry of a single
+//! route is found using A* search which reuses its buffers between routes.
+
+#![warn(missing_docs)]
+#![forbid(unsafe_code)]
+
+#[cfg(test)]
+#[path = "../tests/helpers/mod.rs"]
+#[macro_use]
+mod helpers;
+
+pub use vrp_core as core;
+
+pub mod graph;
+pub mod hierarchy;
+pub mod matrix;
+pub mod path;
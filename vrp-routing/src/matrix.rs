@@ -0,0 +1,50 @@
+//! Provides functionality to calculate routing matrices using road network graph.
+
+#[cfg(test)]
+#[path = "../tests/unit/matrix_test.rs"]
+mod matrix_test;
+
+use crate::graph::{RoadGraph, RoadProfile};
+use crate::hierarchy::ContractionHierarchy;
+use vrp_core::models::common::{Distance, Duration};
+use vrp_core::models::problem::MatrixData;
+
+type RoutingData = (Vec<Duration>, Vec<Distance>);
+
+/// Creates routing matrix data for each profile. Locations are specified as (latitude, longitude)
+/// pairs and snapped to the nearest graph node. Unreachable routes have negative duration and distance.
+/// Profile index in returned data corresponds to profile position in given collection.
+pub fn create_matrix_data(graph: &RoadGraph, profiles: &[RoadProfile], locations: &[(f64, f64)]) -> Vec<MatrixData> {
+    let nodes = locations.iter().map(|&(lat, lng)| graph.find_nearest(lat, lng)).collect::<Vec<_>>();
+
+    // NOTE preprocessing is the most expensive part, so do it only once for profiles with the same speeds
+    let mut hierarchies: Vec<(Vec<u64>, RoutingData)> = Vec::new();
+
+    profiles
+        .iter()
+        .enumerate()
+        .map(|(index, profile)| {
+            let speeds = graph.get_speeds(profile);
+            let key = speeds.iter().map(|speed| speed.to_bits()).collect::<Vec<_>>();
+
+            let (durations, distances) = match hierarchies.iter().find(|(other, _)| *other == key) {
+                Some((_, data)) => data.clone(),
+                None => {
+                    let data = get_routing_data(graph, speeds.as_slice(), nodes.as_slice());
+                    hierarchies.push((key, data.clone()));
+                    data
+                }
+            };
+
+            MatrixData::new(index, None, durations, distances)
+        })
+        .collect()
+}
+
+fn get_routing_data(graph: &RoadGraph, speeds: &[f64], nodes: &[usize]) -> RoutingData {
+    let edges =
+        graph.edges().iter().map(|edge| (edge.from, edge.to, edge.distance / speeds[edge.class], edge.distance));
+    let hierarchy = ContractionHierarchy::new(graph.coordinates().len(), edges);
+
+    hierarchy.many_to_many(nodes, nodes).into_iter().map(|route| route.unwrap_or((-1., -1.))).unzip()
+}
@@ -0,0 +1,146 @@
+//! Provides shortest path search on the road graph which is used to get road geometry of routes.
+
+#[cfg(test)]
+#[path = "../tests/unit/path_test.rs"]
+mod path_test;
+
+use crate::graph::RoadGraph;
+use crate::hierarchy::QueueItem;
+use std::collections::BinaryHeap;
+
+/// A shortest path search which keeps its buffers between queries, so they are not allocated for
+/// each route. It uses A* search guided by a lower bound of travel duration to the end node.
+pub struct PathSearch {
+    weights: Vec<f64>,
+    previous: Vec<usize>,
+    touched: Vec<usize>,
+    queue: BinaryHeap<QueueItem>,
+}
+
+impl PathSearch {
+    /// Creates a new instance of `PathSearch` for given graph.
+    pub fn new(graph: &RoadGraph) -> Self {
+        let size = graph.coordinates().len();
+
+        Self {
+            weights: vec![f64::INFINITY; size],
+            previous: vec![usize::MAX; size],
+            touched: vec![],
+            queue: Default::default(),
+        }
+    }
+
+    /// Finds the fastest path between two graph nodes for given road class speeds. Returns visited
+    /// nodes including both start and end or `None` if the end node is unreachable or the path
+    /// duration exceeds `max_weight`.
+    pub fn find_path(
+        &mut self,
+        graph: &RoadGraph,
+        speeds: &[f64],
+        from: usize,
+        to: usize,
+        max_weight: f64,
+    ) -> Option<Vec<usize>> {
+        self.reset();
+
+        // NOTE lower bound is consistent: no road segment is faster than its straight line at max speed
+        let max_speed = speeds.iter().copied().fold(0_f64, f64::max);
+        let get_lower_bound = |node: usize| graph.get_distance_bound(node, to) / max_speed;
+
+        self.visit(from, usize::MAX, 0.);
+        self.queue.push(QueueItem { weight: get_lower_bound(from), distance: 0., node: from });
+
+        // NOTE distance field keeps a duration from the start node, weight is used for ordering
+        while let Some(QueueItem { weight: estimate, distance: weight, node }) = self.queue.pop() {
+            if node == to || estimate > max_weight {
+                break;
+            }
+
+            if weight > self.weights[node] {
+                continue;
+            }
+
+            graph.outgoing(node).for_each(|edge| {
+                let next_weight = weight + edge.distance / speeds[edge.class];
+                if next_weight < self.weights[edge.to] {
+                    self.visit(edge.to, node, next_weight);
+                    self.queue.push(QueueItem {
+                        weight: next_weight + get_lower_bound(edge.to),
+                        distance: next_weight,
+                        node: edge.to,
+                    });
+                }
+            });
+        }
+
+        if self.weights[to].is_infinite() || self.weights[to] > max_weight {
+            return None;
+        }
+
+        let mut path = vec![to];
+        while let Some(&node) = path.last().filter(|&&node| node != from) {
+            path.push(self.previous[node]);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Returns road geometry of the fastest route between two locations specified as (latitude, longitude)
+    /// pairs. Locations are snapped to the nearest graph nodes, returned geometry starts and ends with
+    /// the locations themselves. Returns `None` if the route is unreachable or its duration exceeds `max_weight`.
+    pub fn get_route_geometry(
+        &mut self,
+        graph: &RoadGraph,
+        speeds: &[f64],
+        from: (f64, f64),
+        to: (f64, f64),
+        max_weight: f64,
+    ) -> Option<Vec<(f64, f64)>> {
+        let (from_node, to_node) = (graph.find_nearest(from.0, from.1), graph.find_nearest(to.0, to.1));
+        let path = self.find_path(graph, speeds, from_node, to_node, max_weight)?;
+
+        let mut geometry = std::iter::once(from)
+            .chain(path.into_iter().map(|node| graph.coordinates()[node]))
+            .chain(std::iter::once(to))
+            .collect::<Vec<_>>();
+        geometry.dedup();
+
+        Some(geometry)
+    }
+
+    fn visit(&mut self, node: usize, previous: usize, weight: f64) {
+        if self.weights[node].is_infinite() {
+            self.touched.push(node);
+        }
+
+        self.weights[node] = weight;
+        self.previous[node] = previous;
+    }
+
+    fn reset(&mut self) {
+        self.touched.drain(..).for_each(|node| {
+            self.weights[node] = f64::INFINITY;
+            self.previous[node] = usize::MAX;
+        });
+        self.queue.clear();
+    }
+}
+
+/// Finds the fastest path between two graph nodes for given road class speeds. Returns visited
+/// nodes including both start and end or `None` if the end node is unreachable.
+pub fn find_path(graph: &RoadGraph, speeds: &[f64], from: usize, to: usize) -> Option<Vec<usize>> {
+    PathSearch::new(graph).find_path(graph, speeds, from, to, f64::INFINITY)
+}
+
+/// Returns road geometry of the fastest route between two locations specified as (latitude, longitude)
+/// pairs. Locations are snapped to the nearest graph nodes, returned geometry starts and ends with
+/// the locations themselves. Returns `None` if the route is unreachable.
+pub fn get_route_geometry(
+    graph: &RoadGraph,
+    speeds: &[f64],
+    from: (f64, f64),
+    to: (f64, f64),
+) -> Option<Vec<(f64, f64)>> {
+    PathSearch::new(graph).get_route_geometry(graph, speeds, from, to, f64::INFINITY)
+}
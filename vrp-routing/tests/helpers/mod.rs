@@ -0,0 +1,36 @@
+#[cfg(test)]
+#[path = "../../../vrp-core/tests/helpers/macros.rs"]
+#[macro_use]
+mod macros;
+
+use crate::graph::{RoadClass, RoadEdge, RoadGraph};
+
+/// Creates a road graph as a grid of `size` x `size` nodes with two way roads between neighbours.
+/// Horizontal roads have the first road class, vertical ones the second.
+pub fn create_grid_graph(size: usize, step: f64) -> RoadGraph {
+    let coordinates = (0..size * size)
+        .map(|idx| (52. + (idx / size) as f64 * step, 13. + (idx % size) as f64 * step))
+        .collect::<Vec<_>>();
+
+    let edges = (0..size * size)
+        .flat_map(|idx| {
+            let right = (idx % size + 1 < size).then_some((idx + 1, 0));
+            let down = (idx / size + 1 < size).then_some((idx + size, 1));
+
+            right.into_iter().chain(down).flat_map(move |(other, class)| {
+                let distance = 100. + (idx * 7 + other * 13) as f64 % 50.;
+                vec![
+                    RoadEdge { from: idx, to: other, distance, class },
+                    RoadEdge { from: other, to: idx, distance, class },
+                ]
+            })
+        })
+        .collect();
+
+    let classes = vec![
+        RoadClass { name: "primary".to_string(), speed: 20., profiles: Default::default() },
+        RoadClass { name: "residential".to_string(), speed: 10., profiles: Default::default() },
+    ];
+
+    RoadGraph::new(coordinates, edges, classes).expect("cannot create grid graph")
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::helpers::create_grid_graph;
+
+const GRAPH_JSON: &str = r#"
+{
+  "nodes": [[52.52, 13.40], [52.53, 13.41], [52.54, 13.42]],
+  "roadClasses": [
+    { "name": "primary", "speed": 20, "profiles": { "truck": 15 } },
+    { "name": "residential", "speed": 8 }
+  ],
+  "edges": [[0, 1, 1300, 0], [1, 0, 1300, 0], [1, 2, 1250.5, 1]]
+}
+"#;
+
+fn create_profile(name: &str, max_speed: Option<f64>) -> RoadProfile {
+    RoadProfile { name: name.to_string(), max_speed }
+}
+
+#[test]
+fn can_read_road_graph() {
+    let graph = read_road_graph(BufReader::new(GRAPH_JSON.as_bytes())).expect("cannot read graph");
+
+    assert_eq!(graph.coordinates().len(), 3);
+    assert_eq!(graph.classes().len(), 2);
+    assert_eq!(graph.edges().len(), 3);
+    assert_eq!((graph.edges()[2].from, graph.edges()[2].to, graph.edges()[2].class), (1, 2, 1));
+    assert_eq!(graph.edges()[2].distance, 1250.5);
+}
+
+parameterized_test! {can_detect_invalid_graph, (nodes, classes, edges, expected), {
+    can_detect_invalid_graph_impl(nodes, classes, edges, expected);
+}}
+
+can_detect_invalid_graph! {
+    case01_no_nodes: ("[]", r#"[{"name":"a","speed":10}]"#, "[]", "road graph has no nodes"),
+    case02_wrong_node: ("[[0,0],[0,1]]", r#"[{"name":"a","speed":10}]"#, "[[0,2,10,0]]", "edge 0 has invalid node, road class or distance"),
+    case03_wrong_class: ("[[0,0],[0,1]]", r#"[{"name":"a","speed":10}]"#, "[[0,1,10,0],[1,0,10,1]]", "edge 1 has invalid node, road class or distance"),
+    case04_negative_distance: ("[[0,0],[0,1]]", r#"[{"name":"a","speed":10}]"#, "[[0,1,-1,0]]", "edge 0 has invalid node, road class or distance"),
+    case05_zero_speed: ("[[0,0]]", r#"[{"name":"a","speed":0}]"#, "[]", "road class 'a' has non-positive speed"),
+    case06_zero_profile_speed: ("[[0,0]]", r#"[{"name":"a","speed":10,"profiles":{"car":0}}]"#, "[]", "road class 'a' has non-positive speed"),
+}
+
+fn can_detect_invalid_graph_impl(nodes: &str, classes: &str, edges: &str, expected: &str) {
+    let json = format!(r#"{{"nodes":{nodes},"roadClasses":{classes},"edges":{edges}}}"#);
+
+    let result = read_road_graph(BufReader::new(json.as_bytes()));
+
+    assert_eq!(result.err().map(|err| err.to_string()), Some(expected.to_string()));
+}
+
+#[test]
+fn can_detect_invalid_json() {
+    let result = read_road_graph(BufReader::new(r#"{"nodes":[]}"#.as_bytes()));
+
+    assert!(result.err().map(|err| err.to_string()).is_some_and(|err| err.starts_with("cannot read road graph")));
+}
+
+parameterized_test! {can_get_speeds, (profile, max_speed, expected), {
+    can_get_speeds_impl(profile, max_speed, expected);
+}}
+
+can_get_speeds! {
+    case01_default: ("car", None, vec![20., 8.]),
+    case02_profile: ("truck", None, vec![15., 8.]),
+    case03_max_speed: ("car", Some(10.), vec![10., 8.]),
+    case04_profile_and_max_speed: ("truck", Some(12.), vec![12., 8.]),
+}
+
+fn can_get_speeds_impl(profile: &str, max_speed: Option<f64>, expected: Vec<f64>) {
+    let graph = read_road_graph(BufReader::new(GRAPH_JSON.as_bytes())).expect("cannot read graph");
+
+    let speeds = graph.get_speeds(&create_profile(profile, max_speed));
+
+    assert_eq!(speeds, expected);
+}
+
+parameterized_test! {can_find_nearest_node, (lat, lng, expected), {
+    can_find_nearest_node_impl(lat, lng, expected);
+}}
+
+can_find_nearest_node! {
+    case01_exact: (52., 13., 0),
+    case02_close: (52.001, 13.0015, 0),
+    case03_between: (52.011, 13.026, 13),
+    case04_outside_far: (53., 14., 99),
+    case05_outside_near: (51.9, 13.05, 5),
+}
+
+fn can_find_nearest_node_impl(lat: f64, lng: f64, expected: usize) {
+    let graph = create_grid_graph(10, 0.01);
+
+    let node = graph.find_nearest(lat, lng);
+
+    assert_eq!(node, expected);
+}
+
+#[test]
+fn can_find_nearest_node_as_brute_force() {
+    let graph = create_grid_graph(10, 0.003);
+    let locations = (0..50).map(|idx| (51.995 + idx as f64 * 0.0007, 12.99 + (idx * 17 % 50) as f64 * 0.0011));
+
+    locations.for_each(|(lat, lng)| {
+        let scale = lat.to_radians().cos();
+        let expected = graph
+            .coordinates()
+            .iter()
+            .enumerate()
+            .map(|(idx, (node_lat, node_lng))| (((node_lat - lat).powi(2) + ((node_lng - lng) * scale).powi(2)), idx))
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, idx)| idx)
+            .unwrap();
+
+        assert_eq!(graph.find_nearest(lat, lng), expected);
+    });
+}
@@ -0,0 +1,98 @@
+use super::*;
+use crate::helpers::create_grid_graph;
+
+type TestEdge = (usize, usize, f64, f64);
+
+fn get_shortest_paths(size: usize, edges: &[TestEdge], source: usize) -> Vec<Option<(f64, f64)>> {
+    let mut best: Vec<Option<(f64, f64)>> = vec![None; size];
+    let mut settled = vec![false; size];
+    best[source] = Some((0., 0.));
+
+    while let Some(node) = (0..size)
+        .filter(|&node| !settled[node] && best[node].is_some())
+        .min_by(|&a, &b| best[a].unwrap().0.total_cmp(&best[b].unwrap().0))
+    {
+        settled[node] = true;
+        let (weight, distance) = best[node].unwrap();
+
+        edges.iter().filter(|(from, ..)| *from == node).for_each(|&(_, to, edge_weight, edge_distance)| {
+            if best[to].is_none_or(|(other, _)| weight + edge_weight < other) {
+                best[to] = Some((weight + edge_weight, distance + edge_distance));
+            }
+        });
+    }
+
+    best
+}
+
+fn assert_routes(actual: &[Option<(f64, f64)>], expected: &[Option<(f64, f64)>]) {
+    assert_eq!(actual.len(), expected.len());
+    actual.iter().zip(expected.iter()).for_each(|(actual, expected)| match (actual, expected) {
+        (Some((actual_weight, _)), Some((expected_weight, _))) => {
+            assert!((actual_weight - expected_weight).abs() < 1E-6, "{actual_weight} != {expected_weight}")
+        }
+        _ => assert_eq!(actual.is_some(), expected.is_some()),
+    });
+}
+
+#[test]
+fn can_find_shortest_paths_in_small_graph() {
+    let edges = [(0, 1, 1., 10.), (1, 2, 1., 20.), (0, 2, 3., 5.), (2, 3, 1., 1.), (3, 0, 10., 1.), (4, 0, 1., 1.)];
+    let hierarchy = ContractionHierarchy::new(5, edges.iter().cloned());
+
+    let routes = hierarchy.many_to_many(&[0, 3, 4], &[0, 2, 3, 4]);
+
+    assert_eq!(
+        routes,
+        vec![
+            Some((0., 0.)),
+            Some((2., 30.)),
+            Some((3., 31.)),
+            None,
+            Some((10., 1.)),
+            Some((12., 31.)),
+            Some((0., 0.)),
+            None,
+            Some((1., 1.)),
+            Some((3., 31.)),
+            Some((4., 32.)),
+            Some((0., 0.)),
+        ]
+    );
+}
+
+parameterized_test! {can_find_same_paths_as_dijkstra, (size, one_way_step), {
+    can_find_same_paths_as_dijkstra_impl(size, one_way_step);
+}}
+
+can_find_same_paths_as_dijkstra! {
+    case01_two_way: (6, None),
+    case02_one_way: (7, Some(3)),
+    case03_one_way_dense: (8, Some(2)),
+}
+
+fn can_find_same_paths_as_dijkstra_impl(size: usize, one_way_step: Option<usize>) {
+    let graph = create_grid_graph(size, 0.01);
+    let nodes = size * size;
+    // NOTE remove some reverse edges to get one way roads and speed up some roads
+    let edges = graph
+        .edges()
+        .iter()
+        .enumerate()
+        .filter(|(idx, edge)| one_way_step.is_none_or(|step| edge.from < edge.to || idx % step != 0))
+        .map(|(idx, edge)| (edge.from, edge.to, edge.distance / (5. + (idx % 4) as f64), edge.distance))
+        .collect::<Vec<_>>();
+    let hierarchy = ContractionHierarchy::new(nodes, edges.iter().cloned());
+    let locations = (0..nodes).step_by(3).collect::<Vec<_>>();
+
+    let routes = hierarchy.many_to_many(locations.as_slice(), locations.as_slice());
+
+    let expected = locations
+        .iter()
+        .flat_map(|&source| {
+            let paths = get_shortest_paths(nodes, edges.as_slice(), source);
+            locations.iter().map(move |&target| paths[target])
+        })
+        .collect::<Vec<_>>();
+    assert_routes(routes.as_slice(), expected.as_slice());
+}
@@ -0,0 +1,56 @@
+use super::*;
+use crate::graph::{read_road_graph, RoadProfile};
+use crate::helpers::create_grid_graph;
+use std::io::BufReader;
+
+fn create_profile(name: &str, max_speed: Option<f64>) -> RoadProfile {
+    RoadProfile { name: name.to_string(), max_speed }
+}
+
+#[test]
+fn can_create_matrix_data_for_locations() {
+    let graph = read_road_graph(BufReader::new(
+        r#"{
+          "nodes": [[52.52, 13.40], [52.53, 13.41], [52.54, 13.42], [52.60, 13.50]],
+          "roadClasses": [{ "name": "primary", "speed": 20, "profiles": { "truck": 8 } }, { "name": "local", "speed": 5 }],
+          "edges": [[0, 1, 1000, 0], [1, 0, 1000, 0], [1, 2, 500, 1], [2, 1, 500, 1], [0, 2, 2000, 0]]
+        }"#
+        .as_bytes(),
+    ))
+    .unwrap();
+    let locations = vec![(52.5201, 13.4001), (52.54, 13.42), (52.6, 13.5)];
+    let profiles = vec![create_profile("car", None), create_profile("truck", None)];
+
+    let matrices = create_matrix_data(&graph, profiles.as_slice(), locations.as_slice());
+
+    assert_eq!(matrices.len(), 2);
+    assert_eq!(matrices[0].index, 0);
+    assert_eq!(matrices[0].durations, vec![0., 100., -1., 150., 0., -1., -1., -1., 0.]);
+    assert_eq!(matrices[0].distances, vec![0., 2000., -1., 1500., 0., -1., -1., -1., 0.]);
+    assert_eq!(matrices[1].index, 1);
+    assert_eq!(matrices[1].durations, vec![0., 225., -1., 225., 0., -1., -1., -1., 0.]);
+    assert_eq!(matrices[1].distances, vec![0., 1500., -1., 1500., 0., -1., -1., -1., 0.]);
+}
+
+parameterized_test! {can_reuse_routing_data_for_same_speeds, (max_speeds, expected_durations), {
+    can_reuse_routing_data_for_same_speeds_impl(max_speeds, expected_durations);
+}}
+
+can_reuse_routing_data_for_same_speeds! {
+    case01_same: (vec![None, Some(25.)], vec![10., 10.]),
+    case02_different: (vec![None, Some(5.)], vec![10., 40.]),
+}
+
+fn can_reuse_routing_data_for_same_speeds_impl(max_speeds: Vec<Option<f64>>, expected_durations: Vec<f64>) {
+    let graph = create_grid_graph(2, 0.01);
+    let profiles = max_speeds.into_iter().map(|max_speed| create_profile("car", max_speed)).collect::<Vec<_>>();
+    let locations = graph.coordinates()[..2].to_vec();
+
+    let matrices = create_matrix_data(&graph, profiles.as_slice(), locations.as_slice());
+
+    let distance = matrices[0].distances[1];
+    assert_eq!(
+        matrices.iter().map(|matrix| matrix.durations[1] * 200. / distance).collect::<Vec<_>>(),
+        expected_durations
+    );
+}
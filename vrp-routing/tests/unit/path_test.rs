@@ -0,0 +1,116 @@
+use super::*;
+use crate::graph::{read_road_graph, RoadProfile};
+use crate::helpers::create_grid_graph;
+use crate::matrix::create_matrix_data;
+use std::io::BufReader;
+
+fn create_graph() -> RoadGraph {
+    read_road_graph(BufReader::new(
+        r#"{
+          "nodes": [[52.52, 13.40], [52.53, 13.41], [52.54, 13.42], [52.60, 13.50]],
+          "roadClasses": [{ "name": "primary", "speed": 20 }, { "name": "local", "speed": 5 }],
+          "edges": [[0, 1, 1000, 0], [1, 0, 1000, 0], [1, 2, 500, 0], [2, 1, 500, 0], [0, 2, 2000, 1]]
+        }"#
+        .as_bytes(),
+    ))
+    .unwrap()
+}
+
+parameterized_test! {can_find_path, (speeds, from, to, expected), {
+    can_find_path_impl(speeds, from, to, expected);
+}}
+
+can_find_path! {
+    case01_fast_detour: (vec![20., 5.], 0, 2, Some(vec![0, 1, 2])),
+    case02_fast_direct: (vec![20., 40.], 0, 2, Some(vec![0, 2])),
+    case03_same_node: (vec![20., 5.], 1, 1, Some(vec![1])),
+    case04_reverse: (vec![20., 40.], 2, 0, Some(vec![2, 1, 0])),
+    case05_unreachable: (vec![20., 5.], 0, 3, None),
+}
+
+fn can_find_path_impl(speeds: Vec<f64>, from: usize, to: usize, expected: Option<Vec<usize>>) {
+    let graph = create_graph();
+
+    let path = find_path(&graph, speeds.as_slice(), from, to);
+
+    assert_eq!(path, expected);
+}
+
+#[test]
+fn can_get_route_geometry_between_locations() {
+    let graph = create_graph();
+
+    let geometry = get_route_geometry(&graph, &[20., 5.], (52.5201, 13.4001), (52.54, 13.42));
+
+    assert_eq!(geometry, Some(vec![(52.5201, 13.4001), (52.52, 13.40), (52.53, 13.41), (52.54, 13.42)]));
+}
+
+#[test]
+fn can_find_path_with_same_duration_as_matrix() {
+    let graph = create_grid_graph(5, 0.01);
+    let speeds = graph.get_speeds(&RoadProfile { name: "car".to_string(), max_speed: None });
+    let (from, to) = (0, 24);
+
+    let path = find_path(&graph, speeds.as_slice(), from, to).expect("no path");
+    let duration = path
+        .windows(2)
+        .map(|pair| {
+            let edge = graph.outgoing(pair[0]).find(|edge| edge.to == pair[1]).expect("no edge");
+            edge.distance / speeds[edge.class]
+        })
+        .sum::<f64>();
+
+    let locations = vec![graph.coordinates()[from], graph.coordinates()[to]];
+    let profiles = vec![RoadProfile { name: "car".to_string(), max_speed: None }];
+    let matrix = create_matrix_data(&graph, profiles.as_slice(), locations.as_slice());
+    assert!((matrix[0].durations[1] - duration).abs() < 1E-6);
+}
+
+fn get_path_duration(graph: &RoadGraph, speeds: &[f64], path: &[usize]) -> f64 {
+    path.windows(2)
+        .map(|pair| {
+            let edge = graph.outgoing(pair[0]).find(|edge| edge.to == pair[1]).expect("no edge");
+            edge.distance / speeds[edge.class]
+        })
+        .sum()
+}
+
+#[test]
+fn can_reuse_path_search_between_queries() {
+    let graph = create_grid_graph(5, 0.01);
+    let profile = RoadProfile { name: "car".to_string(), max_speed: None };
+    let speeds = graph.get_speeds(&profile);
+    let matrix = create_matrix_data(&graph, &[profile], graph.coordinates());
+    let size = graph.coordinates().len();
+    let mut search = PathSearch::new(&graph);
+
+    (0..size).flat_map(|from| (0..size).map(move |to| (from, to))).for_each(|(from, to)| {
+        let path = search.find_path(&graph, speeds.as_slice(), from, to, f64::INFINITY).expect("no path");
+
+        assert_eq!((path.first(), path.last()), (Some(&from), Some(&to)));
+        assert!(
+            (get_path_duration(&graph, speeds.as_slice(), path.as_slice()) - matrix[0].durations[from * size + to])
+                .abs()
+                < 1E-6
+        );
+    });
+}
+
+parameterized_test! {can_stop_path_search_with_cutoff, (max_weight, expected), {
+    can_stop_path_search_with_cutoff_impl(max_weight, expected);
+}}
+
+can_stop_path_search_with_cutoff! {
+    case01_above_duration: (76., Some(vec![0, 1, 2])),
+    case02_below_duration: (74., None),
+    case03_unreachable: (-1., None),
+}
+
+fn can_stop_path_search_with_cutoff_impl(max_weight: f64, expected: Option<Vec<usize>>) {
+    let graph = create_graph();
+    let mut search = PathSearch::new(&graph);
+
+    let path = search.find_path(&graph, &[20., 5.], 0, 2, max_weight);
+
+    assert_eq!(path, expected);
+}
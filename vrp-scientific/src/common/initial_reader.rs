@@ -6,12 +6,16 @@ use crate::common::read_line;
 use std::collections::HashMap;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 use vrp_core::models::solution::{Activity, Registry, Route, Tour};
 use vrp_core::prelude::*;
 
-/// Reads initial solution from a buffer.
+/// Reads initial solution from a buffer. Expects a text format used by best known solutions of
+/// scientific benchmarks (e.g. CVRPLIB or SINTEF), where each route is defined on a separate line
+/// as `Route #1: 1 2 3` or `Route 1 : 1 2 3`. Other lines, such as `Cost 27591`, are ignored.
+/// Solution cost is calculated from read routes, so the solution can be written back.
 /// NOTE: Solution feasibility is not checked.
 pub fn read_init_solution<R: Read>(
     mut reader: BufReader<R>,
@@ -22,45 +26,53 @@ pub fn read_init_solution<R: Read>(
 
     let mut solution = Solution {
         cost: Cost::default(),
-        registry: Registry::new(&problem.fleet, random),
+        registry: Registry::new(&problem.fleet, random.clone()),
         routes: vec![],
         unassigned: Default::default(),
         telemetry: None,
     };
 
+    // NOTE sub jobs of multi jobs are referenced by their own ids
+    let id_map = problem
+        .jobs
+        .all()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single],
+            Job::Multi(multi) => multi.jobs.clone(),
+        })
+        .filter_map(|single| single.dimens.get_id().cloned().map(|id| (id, single)))
+        .collect::<HashMap<_, _>>();
+
     loop {
         match read_line(&mut reader, &mut buffer) {
             Ok(read) if read > 0 => {
-                let route: Vec<_> = buffer.split(':').collect();
-                if route.len() != 2 {
-                    continue;
-                }
+                let Some(customers) = get_route_customers(buffer.as_str()) else { continue };
 
-                let id_map = problem.jobs.all().fold(HashMap::<String, Arc<Single>>::new(), |mut acc, job| {
-                    let single = job.to_single().clone();
-                    acc.insert(single.dimens.get_id().unwrap().to_string(), single);
-                    acc
-                });
-
-                let actor = solution.registry.next().next().unwrap();
+                let actor = solution.registry.next().next().ok_or("not enough vehicles to read solution")?;
                 let mut tour = Tour::new(&actor);
 
-                route.last().unwrap().split_whitespace().for_each(|id| {
-                    let single = id_map.get(id).unwrap();
+                customers.split_whitespace().try_for_each(|id| {
+                    let single = id_map.get(id).ok_or_else(|| format!("cannot find job with id: '{id}'"))?;
                     let place_idx = 0;
                     let place = &single.places[place_idx];
                     tour.insert_last(Activity {
                         place: vrp_core::models::solution::Place {
                             idx: place_idx,
-                            location: place.location.unwrap(),
+                            location: place.location.ok_or_else(|| format!("job '{id}' has no location"))?,
                             duration: place.duration,
-                            time: place.times.first().and_then(|span| span.as_time_window()).unwrap(),
+                            time: place
+                                .times
+                                .first()
+                                .and_then(|span| span.as_time_window())
+                                .ok_or_else(|| format!("job '{id}' has no time window"))?,
                         },
                         schedule: Schedule::new(0.0, 0.0),
                         job: Some(single.clone()),
                         commute: None,
                     });
-                });
+
+                    Ok::<_, GenericError>(())
+                })?;
 
                 solution.registry.use_actor(&actor);
                 solution.routes.push(Route { actor, tour });
@@ -76,5 +88,16 @@ pub fn read_init_solution<R: Read>(
         }
     }
 
-    Ok(solution)
+    // NOTE route costs are known only when route states are updated
+    let environment = Arc::new(Environment { random, ..Environment::default() });
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+    Ok(insertion_ctx.solution.into())
+}
+
+/// Returns customers part of the line if it defines a route.
+fn get_route_customers(line: &str) -> Option<&str> {
+    let (name, customers) = line.split_once(':')?;
+
+    name.trim_start().to_lowercase().starts_with("route").then_some(customers)
 }
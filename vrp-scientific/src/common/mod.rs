@@ -12,6 +12,7 @@ mod initial_reader;
 pub use self::initial_reader::read_init_solution;
 
 mod routing;
+pub(crate) use self::routing::create_euclidean_transport;
 pub use self::routing::CoordIndex;
 
 use vrp_core::models::Extras;
@@ -28,26 +28,35 @@ impl CoordIndex {
 
     /// Creates transport.
     pub fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, GenericError> {
-        let matrix_values = self
-            .locations
-            .iter()
-            .flat_map(|&(x1, y1)| {
-                self.locations.iter().map(move |&(x2, y2)| {
-                    let x = x1 as f64 - x2 as f64;
-                    let y = y1 as f64 - y2 as f64;
-                    let value = (x * x + y * y).sqrt();
-
-                    if is_rounded {
-                        value.round()
-                    } else {
-                        value
-                    }
-                })
+        let locations = self.locations.iter().map(|&(x, y)| (x as f64, y as f64)).collect::<Vec<_>>();
+
+        create_euclidean_transport(locations.as_slice(), is_rounded)
+    }
+}
+
+/// Creates transport with euclidean distances between given locations used as distance and duration.
+pub(crate) fn create_euclidean_transport(
+    locations: &[(f64, f64)],
+    is_rounded: bool,
+) -> Result<Arc<dyn TransportCost + Send + Sync>, GenericError> {
+    let matrix_values = locations
+        .iter()
+        .flat_map(|&(x1, y1)| {
+            locations.iter().map(move |&(x2, y2)| {
+                let x = x1 - x2;
+                let y = y1 - y2;
+                let value = (x * x + y * y).sqrt();
+
+                if is_rounded {
+                    value.round()
+                } else {
+                    value
+                }
             })
-            .collect::<Vec<f64>>();
+        })
+        .collect::<Vec<f64>>();
 
-        let matrix_data = MatrixData::new(0, None, matrix_values.clone(), matrix_values);
+    let matrix_data = MatrixData::new(0, None, matrix_values.clone(), matrix_values);
 
-        create_matrix_transport_cost(vec![matrix_data])
-    }
+    create_matrix_transport_cost(vec![matrix_data])
 }
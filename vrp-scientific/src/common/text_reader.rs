@@ -80,6 +80,7 @@ pub(crate) fn create_fleet_with_distance_costs(
                             location,
                             time: TimeInterval { earliest: None, latest: Some(time.end) },
                         }),
+                        depots: Default::default(),
                     }],
                 })
             })
@@ -115,11 +116,13 @@ pub(crate) fn create_goal_context_prefer_min_tours(
     GoalContext::new(features.as_slice(), goal)
 }
 
-pub(crate) fn create_goal_context_distance_only(
+pub(crate) fn create_goal_context_distance_only_with_features(
     activity: Arc<SimpleActivityCost>,
     transport: Arc<dyn TransportCost + Send + Sync>,
+    extra_features: Vec<Feature>,
 ) -> Result<GoalContext, GenericError> {
-    let features = get_essential_features(activity, transport)?;
+    let mut features = get_essential_features(activity, transport)?;
+    features.extend(extra_features);
 
     let goal = Goal::with_alternatives(
         vec![vec!["min_unassigned".to_string()], vec!["min_distance".to_string()]],
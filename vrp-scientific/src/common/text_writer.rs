@@ -18,8 +18,11 @@ pub(crate) fn write_text_solution<W: Write>(solution: &Solution, writer: &mut Bu
             .tour
             .all_activities()
             .filter(|a| a.job.is_some())
-            .map(|a| a.retrieve_job().unwrap())
-            .map(|job| job.dimens().get_id().unwrap().clone())
+            // NOTE prefer ids of sub jobs, so the solution can be read back
+            .map(|a| match a.job.as_ref().and_then(|single| single.dimens.get_id()) {
+                Some(id) => id.clone(),
+                None => a.retrieve_job().unwrap().dimens().get_id().unwrap().clone(),
+            })
             .collect::<Vec<String>>()
             .join(" ");
         writer.write_all(format!("Route {i}: {customers}\n").as_bytes()).unwrap();
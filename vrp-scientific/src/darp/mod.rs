@@ -0,0 +1,7 @@
+//! Contains functionality to read dial-a-ride problem and write its solution.
+
+mod reader;
+pub use self::reader::DarpProblem;
+
+mod writer;
+pub use self::writer::DarpSolution;
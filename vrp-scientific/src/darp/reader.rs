@@ -0,0 +1,223 @@
+#[cfg(test)]
+#[path = "../../tests/unit/darp/reader_test.rs"]
+mod reader_test;
+
+use crate::common::*;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::features::*;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::*;
+use vrp_core::prelude::GenericError;
+
+/// A trait to read dial-a-ride problem in Cordeau's format.
+pub trait DarpProblem {
+    /// Reads dial-a-ride problem.
+    fn read_darp(self, is_rounded: bool) -> Result<Problem, GenericError>;
+}
+
+impl<R: Read> DarpProblem for BufReader<R> {
+    fn read_darp(self, is_rounded: bool) -> Result<Problem, GenericError> {
+        DarpReader { buffer: String::new(), reader: self, locations: vec![], header: None }.read_problem(is_rounded)
+    }
+}
+
+impl DarpProblem for String {
+    fn read_darp(self, is_rounded: bool) -> Result<Problem, GenericError> {
+        BufReader::new(self.as_bytes()).read_darp(is_rounded)
+    }
+}
+
+struct HeaderLine {
+    vehicles: usize,
+    max_route_duration: Duration,
+    capacity: usize,
+    max_ride_duration: Duration,
+}
+
+struct NodeLine {
+    id: usize,
+    location: (f64, f64),
+    service: Duration,
+    demand: i32,
+    tw: TimeWindow,
+}
+
+struct DarpReader<R: Read> {
+    buffer: String,
+    reader: BufReader<R>,
+    locations: Vec<(f64, f64)>,
+    header: Option<HeaderLine>,
+}
+
+impl<R: Read> TextReader for DarpReader<R> {
+    fn create_goal_context(
+        &self,
+        activity: Arc<SimpleActivityCost>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+    ) -> Result<GoalContext, GenericError> {
+        let header = self.header.as_ref().ok_or("cannot create goal context without header")?;
+        let max_route_duration = header.max_route_duration;
+
+        let features = vec![
+            create_travel_limit_feature(
+                "max_route_duration",
+                transport.clone(),
+                Arc::new(|_| None),
+                Arc::new(move |_| Some(max_route_duration)),
+                3,
+                4,
+            )?,
+            create_max_ride_duration_feature(
+                "max_ride_duration",
+                5,
+                transport.clone(),
+                activity.clone(),
+                MAX_RIDE_DURATION_KEY,
+            )?,
+        ];
+
+        create_goal_context_distance_only_with_features(activity, transport, features)
+    }
+
+    fn read_definitions(&mut self) -> Result<(Vec<Job>, Fleet), GenericError> {
+        let header = self.read_header()?;
+        let nodes = self.read_nodes()?;
+
+        let (depot, nodes) = nodes.split_first().ok_or("cannot find depot")?;
+        // NOTE some instances have an extra end depot node, it is assumed to have the same location as start depot
+        let nodes = if nodes.len() % 2 == 1 { &nodes[..nodes.len() - 1] } else { nodes };
+        let (pickups, deliveries) = nodes.split_at(nodes.len() / 2);
+
+        let fleet = create_fleet_with_distance_costs(
+            header.vehicles,
+            header.capacity,
+            self.collect_location(depot.location),
+            depot.tw.clone(),
+        );
+
+        let jobs = pickups
+            .iter()
+            .zip(deliveries.iter())
+            .enumerate()
+            .map(|(index, (pickup, delivery))| {
+                let mut dimens = create_dimens_with_id("mlt", &index.to_string());
+                dimens.set_max_ride_duration(header.max_ride_duration);
+
+                let (pickup_tw, delivery_tw) = tighten_time_windows(pickup, delivery, header.max_ride_duration);
+
+                Job::Multi(Multi::new_shared(
+                    vec![
+                        self.create_single_job(pickup, pickup_tw, true),
+                        self.create_single_job(delivery, delivery_tw, false),
+                    ],
+                    dimens,
+                ))
+            })
+            .collect();
+
+        self.header = Some(header);
+
+        Ok((jobs, fleet))
+    }
+
+    fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, GenericError> {
+        create_euclidean_transport(self.locations.as_slice(), is_rounded)
+    }
+
+    fn create_extras(&self) -> Extras {
+        // NOTE coord index keeps integer coordinates, so they are used only for approximate spatial analysis
+        let locations = self.locations.iter().map(|&(x, y)| (x.round() as i32, y.round() as i32)).collect();
+
+        get_extras(CoordIndex { locations })
+    }
+}
+
+impl<R: Read> DarpReader<R> {
+    fn read_header(&mut self) -> Result<HeaderLine, GenericError> {
+        read_line(&mut self.reader, &mut self.buffer)?;
+        let (vehicles, _, max_route_duration, capacity, max_ride_duration) = self
+            .buffer
+            .split_whitespace()
+            .map(|value| value.parse::<f64>())
+            .try_collect_tuple()
+            .ok_or_else(|| "cannot read header line".to_string())?;
+
+        let parse_err = |err: std::num::ParseFloatError| format!("cannot parse header: {err}");
+
+        Ok(HeaderLine {
+            vehicles: vehicles.map_err(parse_err)? as usize,
+            max_route_duration: max_route_duration.map_err(parse_err)?,
+            capacity: capacity.map_err(parse_err)? as usize,
+            max_ride_duration: max_ride_duration.map_err(parse_err)?,
+        })
+    }
+
+    fn read_nodes(&mut self) -> Result<Vec<NodeLine>, GenericError> {
+        let mut nodes = vec![];
+
+        while read_line(&mut self.reader, &mut self.buffer)? > 0 {
+            if self.buffer.trim().is_empty() {
+                continue;
+            }
+
+            let (id, x, y, service, demand, start, end) = self
+                .buffer
+                .split_whitespace()
+                .map(|value| value.parse::<f64>().map_err(|err| format!("cannot parse node line: {err}")))
+                .try_collect_tuple()
+                .ok_or_else(|| format!("cannot read node line: '{}'", self.buffer.trim()))?;
+
+            nodes.push(NodeLine {
+                id: id? as usize,
+                location: (x?, y?),
+                service: service?,
+                demand: demand? as i32,
+                tw: TimeWindow::new(start?, end?),
+            });
+        }
+
+        Ok(nodes)
+    }
+
+    fn collect_location(&mut self, location: (f64, f64)) -> Location {
+        self.locations.push(location);
+        self.locations.len() - 1
+    }
+
+    fn create_single_job(&mut self, node: &NodeLine, tw: TimeWindow, is_pickup: bool) -> Arc<Single> {
+        let demand = SingleDimLoad::new(node.demand.abs());
+        let mut dimens = create_dimens_with_id("c", &node.id.to_string());
+        dimens.set_demand(if is_pickup {
+            Demand::<SingleDimLoad> { pickup: (SingleDimLoad::default(), demand), delivery: Default::default() }
+        } else {
+            Demand::<SingleDimLoad> { pickup: Default::default(), delivery: (SingleDimLoad::default(), demand) }
+        });
+
+        Arc::new(Single {
+            places: vec![Place {
+                location: Some(self.collect_location(node.location)),
+                duration: node.service,
+                times: vec![TimeSpan::Window(tw)],
+            }],
+            dimens,
+        })
+    }
+}
+
+/// Tightens time windows of the request using max ride duration. As the vehicle departs as early as possible,
+/// this makes it wait at pickup instead of waiting with passengers on board when delivery has a narrow time window.
+fn tighten_time_windows(
+    pickup: &NodeLine,
+    delivery: &NodeLine,
+    max_ride_duration: Duration,
+) -> (TimeWindow, TimeWindow) {
+    let pickup_start = pickup.tw.start.max(delivery.tw.start - max_ride_duration - pickup.service);
+    let delivery_end = delivery.tw.end.min(pickup.tw.end + pickup.service + max_ride_duration);
+
+    (
+        TimeWindow::new(pickup_start.min(pickup.tw.end), pickup.tw.end),
+        TimeWindow::new(delivery.tw.start, delivery_end.max(delivery.tw.start)),
+    )
+}
@@ -0,0 +1,17 @@
+use crate::common::write_text_solution;
+use std::borrow::Borrow;
+use std::io::{BufWriter, Write};
+use vrp_core::prelude::*;
+
+/// A trait to write dial-a-ride solution.
+pub trait DarpSolution<W: Write> {
+    /// Writes dial-a-ride solution.
+    fn write_darp(&self, writer: &mut BufWriter<W>) -> Result<(), GenericError>;
+}
+
+impl<W: Write, B: Borrow<Solution>> DarpSolution<W> for B {
+    fn write_darp(&self, writer: &mut BufWriter<W>) -> Result<(), GenericError> {
+        write_text_solution(self.borrow(), writer).map_err(|err| err.to_string())?;
+        Ok(())
+    }
+}
@@ -6,7 +6,8 @@
 //!
 //! - **solomon**: see [Solomon benchmark](https://www.sintef.no/projectweb/top/vrptw/solomon-benchmark)
 //! - **lilim**: see [Li&Lim benchmark](https://www.sintef.no/projectweb/top/pdptw/li-lim-benchmark)
-//! - **tsplib** subset of TSPLIB95 format
+//! - **tsplib** subset of TSPLIB95 format: CVRP, TSP and ATSP problem types
+//! - **darp**: dial-a-ride problem in Cordeau's benchmark format
 
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
@@ -23,6 +24,7 @@ mod known_problems_test;
 pub use vrp_core as core;
 
 pub mod common;
+pub mod darp;
 pub mod lilim;
 pub mod solomon;
 pub mod tsplib;
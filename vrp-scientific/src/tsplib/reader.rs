@@ -6,12 +6,15 @@ use crate::common::*;
 use std::collections::HashMap;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use vrp_core::construction::features::create_travel_limit_feature;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 use vrp_core::models::*;
 use vrp_core::prelude::GenericError;
 
-/// A trait to read tsplib95 problem. Please note that it is very basic implementation of the format specification.
+/// A trait to read tsplib95 problem. Supports `CVRP`, `TSP` and `ATSP` problem types with coordinate based
+/// (`EUC_2D`, `CEIL_2D`, `MAN_2D`, `MAX_2D`, `GEO`, `ATT`) or `EXPLICIT` edge weights. Specification keys and
+/// data sections are read in any order, but keys used by a section have to precede it.
 pub trait TsplibProblem {
     /// Reads tsplib95 problem.
     fn read_tsplib(self, is_rounded: bool) -> Result<Problem, GenericError>;
@@ -29,12 +32,48 @@ impl TsplibProblem for String {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ProblemType {
+    Cvrp,
+    Tsp,
+    Atsp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EdgeWeightType {
+    Euclidean,
+    Ceil,
+    Manhattan,
+    Maximum,
+    Geo,
+    Att,
+    Explicit,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EdgeWeightFormat {
+    FullMatrix,
+    UpperRow,
+    LowerRow,
+    UpperDiagRow,
+    LowerDiagRow,
+}
+
 struct TsplibReader<R: Read> {
     buffer: String,
     reader: BufReader<R>,
+    problem_type: Option<ProblemType>,
     dimension: Option<usize>,
     vehicle_capacity: Option<usize>,
-    coord_index: CoordIndex,
+    vehicles: Option<usize>,
+    max_distance: Option<Distance>,
+    service_time: Option<Duration>,
+    edge_weight_type: Option<EdgeWeightType>,
+    edge_weight_format: Option<EdgeWeightFormat>,
+    locations: Vec<(f64, f64)>,
+    demands: Option<HashMap<usize, i32>>,
+    depots: Vec<usize>,
+    weights: Vec<f64>,
 }
 
 impl<R: Read> TextReader for TsplibReader<R> {
@@ -43,171 +82,311 @@ impl<R: Read> TextReader for TsplibReader<R> {
         activity: Arc<SimpleActivityCost>,
         transport: Arc<dyn TransportCost + Send + Sync>,
     ) -> Result<GoalContext, GenericError> {
-        create_goal_context_distance_only(activity, transport)
+        // NOTE route length of distance constrained instances includes service time, so it is
+        // modeled as a duration limit: durations are the same as distances
+        let features = self
+            .max_distance
+            .map(|max_distance| {
+                create_travel_limit_feature(
+                    "max_distance",
+                    transport.clone(),
+                    Arc::new(|_| None),
+                    Arc::new(move |_| Some(max_distance)),
+                    3,
+                    4,
+                )
+            })
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        create_goal_context_distance_only_with_features(activity, transport, features)
     }
 
     fn read_definitions(&mut self) -> Result<(Vec<Job>, Fleet), GenericError> {
-        self.read_meta()?;
-
-        let (coordinates, demands) = self.read_customer_data()?;
-        let depot_id = self.read_depot_data()?;
-        self.read_expected_line("EOF")?;
-
-        let dimension = self.dimension.unwrap();
-
-        let jobs = coordinates.iter().filter(|(id, _)| **id != depot_id).try_fold::<_, _, Result<_, GenericError>>(
-            Vec::with_capacity(dimension),
-            |mut jobs, (id, (x, y))| {
-                let demand = demands.get(id).cloned().ok_or_else(|| format!("cannot find demand for id: '{id}'"))?;
-
-                jobs.push(self.create_job(&(*id - 1).to_string(), (*x, *y), demand));
-
-                Ok(jobs)
-            },
-        )?;
-
-        let depot_coord =
-            *coordinates.get(&depot_id).ok_or_else(|| format!("cannot find coordinate for depot id: '{depot_id}'"))?;
-
-        let fleet = create_fleet_with_distance_costs(
-            dimension,
-            self.vehicle_capacity.unwrap(),
-            self.coord_index.collect(depot_coord),
-            TimeWindow::max(),
-        );
+        self.read_data()?;
+
+        let problem_type = self.problem_type.ok_or("TYPE is not specified")?;
+        let dimension = self.dimension.ok_or("DIMENSION is not specified")?;
+        let depot = match self.depots.as_slice() {
+            [] => 0,
+            [depot] => *depot,
+            _ => return Err("multiple depots are not supported".into()),
+        };
+
+        let (vehicles, capacity) = match problem_type {
+            ProblemType::Cvrp => {
+                if self.demands.is_none() {
+                    return Err("DEMAND_SECTION is not specified".into());
+                }
+                (self.vehicles.unwrap_or(dimension), self.vehicle_capacity.ok_or("CAPACITY is not specified")?)
+            }
+            ProblemType::Tsp | ProblemType::Atsp => (1, self.vehicle_capacity.unwrap_or_default()),
+        };
+
+        // NOTE customers are numbered in the order of nodes skipping the depot
+        let jobs = (0..dimension)
+            .filter(|&idx| idx != depot)
+            .zip(1..)
+            .map(|(idx, id)| {
+                let demand = match self.demands.as_ref() {
+                    Some(demands) => {
+                        *demands.get(&idx).ok_or_else(|| format!("cannot find demand for id: '{}'", idx + 1))?
+                    }
+                    None => 0,
+                };
+
+                Ok(self.create_job(&id.to_string(), idx, demand))
+            })
+            .collect::<Result<Vec<_>, GenericError>>()?;
+
+        let fleet = create_fleet_with_distance_costs(vehicles, capacity, depot, TimeWindow::max());
 
         Ok((jobs, fleet))
     }
 
     fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, GenericError> {
-        self.coord_index.create_transport(is_rounded)
+        let edge_weight_type = self.edge_weight_type.ok_or("EDGE_WEIGHT_TYPE is not specified")?;
+
+        let matrix_values = match edge_weight_type {
+            EdgeWeightType::Explicit if self.weights.is_empty() => {
+                return Err("EDGE_WEIGHT_SECTION is not specified".into());
+            }
+            EdgeWeightType::Explicit => self.weights.clone(),
+            _ if self.locations.is_empty() => return Err("NODE_COORD_SECTION is not specified".into()),
+            _ => self
+                .locations
+                .iter()
+                .enumerate()
+                .flat_map(|(from_idx, &from)| {
+                    self.locations.iter().enumerate().map(move |(to_idx, &to)| {
+                        if from_idx == to_idx {
+                            0.
+                        } else {
+                            edge_weight_type.distance(from, to, is_rounded)
+                        }
+                    })
+                })
+                .collect(),
+        };
+
+        let matrix_data = MatrixData::new(0, None, matrix_values.clone(), matrix_values);
+
+        create_matrix_transport_cost(vec![matrix_data])
     }
 
     fn create_extras(&self) -> Extras {
-        get_extras(self.coord_index.clone())
+        // NOTE coord index keeps integer coordinates, so they are used only for approximate spatial analysis
+        let locations = self.locations.iter().map(|&(x, y)| (x.round() as i32, y.round() as i32)).collect();
+
+        get_extras(CoordIndex { locations })
     }
 }
 
-type ProblemData = (HashMap<i32, (i32, i32)>, HashMap<i32, i32>);
-
 impl<R: Read> TsplibReader<R> {
     fn new(reader: BufReader<R>) -> Self {
         Self {
             buffer: String::new(),
             reader,
+            problem_type: None,
             dimension: None,
             vehicle_capacity: None,
-            coord_index: CoordIndex::default(),
+            vehicles: None,
+            max_distance: None,
+            service_time: None,
+            edge_weight_type: None,
+            edge_weight_format: None,
+            locations: vec![],
+            demands: None,
+            depots: vec![],
+            weights: vec![],
         }
     }
 
-    fn read_meta(&mut self) -> Result<(), GenericError> {
-        self.skip_lines(2)?;
+    fn read_data(&mut self) -> Result<(), GenericError> {
+        while read_line(&mut self.reader, &mut self.buffer)? > 0 {
+            let line = self.buffer.trim();
+            if line.is_empty() {
+                continue;
+            }
 
-        let problem_type = self.read_key_value("TYPE")?;
-        if problem_type != "CVRP" {
-            return Err(format!("expecting 'CVRP' as TYPE, got '{problem_type}'").into());
-        }
+            if line == "EOF" {
+                break;
+            }
 
-        self.dimension = Some(
-            self.read_key_value("DIMENSION")
-                .and_then(|dimen| parse_int(&dimen, "cannot parse DIMENSION").map(|v| v as usize))?,
-        );
+            let (key, value) = line
+                .split_once(':')
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                .unwrap_or_else(|| (line.to_string(), String::new()));
 
-        let edge_type = self.read_key_value("EDGE_WEIGHT_TYPE")?;
-        if edge_type != "EUC_2D" {
-            return Err(format!("expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got '{edge_type}'").into());
+            if key.ends_with("_SECTION") {
+                self.read_section(key.as_str())?;
+            } else {
+                self.read_specification(key.as_str(), value.as_str())?;
+            }
         }
 
-        self.vehicle_capacity = Some(
-            self.read_key_value("CAPACITY")
-                .and_then(|capacity| parse_int(&capacity, "cannot parse CAPACITY").map(|v| v as usize))?,
-        );
+        Ok(())
+    }
+
+    fn read_specification(&mut self, key: &str, value: &str) -> Result<(), GenericError> {
+        match key {
+            "TYPE" => {
+                self.problem_type = Some(match value {
+                    "CVRP" | "DCVRP" => ProblemType::Cvrp,
+                    "TSP" => ProblemType::Tsp,
+                    "ATSP" => ProblemType::Atsp,
+                    _ => return Err(format!("expecting 'CVRP', 'TSP' or 'ATSP' as TYPE, got '{value}'").into()),
+                })
+            }
+            "DIMENSION" => self.dimension = Some(parse_int(value, "cannot parse DIMENSION")? as usize),
+            "CAPACITY" => self.vehicle_capacity = Some(parse_int(value, "cannot parse CAPACITY")? as usize),
+            "VEHICLES" => self.vehicles = Some(parse_int(value, "cannot parse VEHICLES")? as usize),
+            "DISTANCE" => self.max_distance = Some(parse_float(value, "cannot parse DISTANCE")?),
+            "SERVICE_TIME" => self.service_time = Some(parse_float(value, "cannot parse SERVICE_TIME")?),
+            "EDGE_WEIGHT_TYPE" => {
+                self.edge_weight_type = Some(match value {
+                    "EUC_2D" => EdgeWeightType::Euclidean,
+                    "CEIL_2D" => EdgeWeightType::Ceil,
+                    "MAN_2D" => EdgeWeightType::Manhattan,
+                    "MAX_2D" => EdgeWeightType::Maximum,
+                    "GEO" => EdgeWeightType::Geo,
+                    "ATT" => EdgeWeightType::Att,
+                    "EXPLICIT" => EdgeWeightType::Explicit,
+                    _ => return Err(format!("unsupported EDGE_WEIGHT_TYPE: '{value}'").into()),
+                })
+            }
+            "EDGE_WEIGHT_FORMAT" => {
+                // NOTE column-wise formats are the same as row-wise ones of the opposite triangle
+                self.edge_weight_format = Some(match value {
+                    "FULL_MATRIX" => EdgeWeightFormat::FullMatrix,
+                    "UPPER_ROW" | "LOWER_COL" => EdgeWeightFormat::UpperRow,
+                    "LOWER_ROW" | "UPPER_COL" => EdgeWeightFormat::LowerRow,
+                    "UPPER_DIAG_ROW" | "LOWER_DIAG_COL" => EdgeWeightFormat::UpperDiagRow,
+                    "LOWER_DIAG_ROW" | "UPPER_DIAG_COL" => EdgeWeightFormat::LowerDiagRow,
+                    _ => return Err(format!("unsupported EDGE_WEIGHT_FORMAT: '{value}'").into()),
+                })
+            }
+            "NODE_COORD_TYPE" if value != "TWOD_COORDS" && value != "NO_COORDS" => {
+                return Err(format!("unsupported NODE_COORD_TYPE: '{value}'").into());
+            }
+            // NOTE other keys, such as NAME or COMMENT, do not affect the problem
+            _ => {}
+        }
 
         Ok(())
     }
 
-    fn read_customer_data(&mut self) -> Result<ProblemData, GenericError> {
-        let dimension = self.dimension.unwrap();
+    fn read_section(&mut self, name: &str) -> Result<(), GenericError> {
+        let dimension = self.dimension.ok_or_else(|| format!("DIMENSION should be specified before {name}"))?;
 
-        // read coordinates
-        self.read_expected_line("NODE_COORD_SECTION")?;
+        match name {
+            "NODE_COORD_SECTION" => self.locations = self.read_coordinates(dimension, name)?,
+            "DISPLAY_DATA_SECTION" => {
+                let locations = self.read_coordinates(dimension, name)?;
+                if self.locations.is_empty() {
+                    self.locations = locations;
+                }
+            }
+            "DEMAND_SECTION" => self.demands = Some(self.read_demands(dimension)?),
+            "DEPOT_SECTION" => self.depots = self.read_node_list(dimension)?,
+            "EDGE_WEIGHT_SECTION" => self.weights = self.read_edge_weights(dimension)?,
+            "FIXED_EDGES_SECTION" => {
+                self.read_node_list(dimension)?;
+            }
+            _ => return Err(format!("unsupported section: '{name}'").into()),
+        }
 
-        let mut coordinates = HashMap::with_capacity(self.dimension.unwrap());
+        Ok(())
+    }
+
+    fn read_coordinates(&mut self, dimension: usize, name: &str) -> Result<Vec<(f64, f64)>, GenericError> {
+        let mut coordinates = vec![(0., 0.); dimension];
         for _ in 0..dimension {
-            let line = self.read_line()?.trim();
+            let line = self.read_data_line(name)?;
             let data = line.split_whitespace().collect::<Vec<_>>();
 
             if data.len() != 3 {
                 return Err(format!("unexpected coord data: '{line}'").into());
             }
 
-            let coord = (parse_int(data[1], "cannot parse coord.0")?, parse_int(data[2], "cannot parse coord.1")?);
-
-            coordinates.insert(parse_int(data[0], "cannot parse id")?, coord);
+            let node = parse_node(data[0], dimension)?;
+            coordinates[node] =
+                (parse_float(data[1], "cannot parse coord.0")?, parse_float(data[2], "cannot parse coord.1")?);
         }
 
-        // read demand
-        self.read_expected_line("DEMAND_SECTION")?;
+        Ok(coordinates)
+    }
 
-        let mut demands = HashMap::with_capacity(self.dimension.unwrap());
+    fn read_demands(&mut self, dimension: usize) -> Result<HashMap<usize, i32>, GenericError> {
+        let mut demands = HashMap::with_capacity(dimension);
         for _ in 0..dimension {
-            let line = self.read_line()?.trim();
+            let line = self.read_data_line("DEMAND_SECTION")?;
             let data = line.split_whitespace().collect::<Vec<_>>();
 
             if data.len() != 2 {
                 return Err(format!("unexpected demand data: '{line}'").into());
             }
 
-            demands.insert(parse_int(data[0], "cannot parse id")?, parse_int(data[1], "cannot parse demand")?);
+            demands.insert(parse_node(data[0], dimension)?, parse_int(data[1], "cannot parse demand")?);
         }
 
-        Ok((coordinates, demands))
+        Ok(demands)
     }
 
-    fn read_depot_data(&mut self) -> Result<i32, GenericError> {
-        self.read_expected_line("DEPOT_SECTION")?;
-        let depot_id = parse_int(self.read_line()?.trim(), "cannot parse depot id")?;
-        self.read_expected_line("-1")?;
-
-        Ok(depot_id)
+    fn read_node_list(&mut self, dimension: usize) -> Result<Vec<usize>, GenericError> {
+        let mut nodes = vec![];
+        loop {
+            let line = self.read_data_line("node list")?;
+            for value in line.split_whitespace() {
+                if value == "-1" {
+                    return Ok(nodes);
+                }
+                nodes.push(parse_node(value, dimension)?);
+            }
+        }
     }
 
-    fn read_key_value(&mut self, expected_key: &str) -> Result<String, GenericError> {
-        let line = self.read_line()?;
-        let key_value = line.split(':').map(|v| v.to_string()).collect::<Vec<_>>();
+    fn read_edge_weights(&mut self, dimension: usize) -> Result<Vec<f64>, GenericError> {
+        let format = self.edge_weight_format.unwrap_or(EdgeWeightFormat::FullMatrix);
+        let indices = format.get_indices(dimension);
 
-        if key_value.len() != 2 {
-            return Err(format!("expected colon separated string, got: '{line}'").into());
+        let mut values = Vec::with_capacity(indices.len());
+        while values.len() < indices.len() {
+            let line = self.read_data_line("EDGE_WEIGHT_SECTION")?;
+            for value in line.split_whitespace() {
+                values.push(parse_float(value, "cannot parse edge weight")?);
+            }
         }
 
-        let actual_key = key_value[0].trim();
-        if actual_key.trim() != expected_key {
-            return Err(format!("unexpected key, expecting: '{expected_key}', got: '{actual_key}'").into());
+        if values.len() != indices.len() {
+            return Err(format!("expecting {} edge weights, got {}", indices.len(), values.len()).into());
         }
 
-        Ok(key_value[1].trim().to_string())
-    }
+        let mut weights = vec![0.; dimension * dimension];
+        indices.into_iter().zip(values).filter(|((from, to), _)| from != to).for_each(|((from, to), value)| {
+            weights[from * dimension + to] = value;
+            if format != EdgeWeightFormat::FullMatrix {
+                weights[to * dimension + from] = value;
+            }
+        });
 
-    fn read_expected_line(&mut self, expected: &str) -> Result<(), GenericError> {
-        let line = self.read_line()?.trim();
-        if line != expected {
-            Err(format!("expecting {expected}, got: '{line}'").into())
-        } else {
-            Ok(())
-        }
+        Ok(weights)
     }
 
-    fn read_line(&mut self) -> Result<&String, GenericError> {
-        read_line(&mut self.reader, &mut self.buffer)?;
-        Ok(&self.buffer)
-    }
+    fn read_data_line(&mut self, name: &str) -> Result<String, GenericError> {
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Err(format!("unexpected end of {name}").into());
+            }
 
-    fn skip_lines(&mut self, count: usize) -> Result<(), GenericError> {
-        skip_lines(count, &mut self.reader, &mut self.buffer)
+            let line = self.buffer.trim();
+            if !line.is_empty() {
+                return Ok(line.to_string());
+            }
+        }
     }
 
-    fn create_job(&mut self, id: &str, location: (i32, i32), demand: i32) -> Job {
+    fn create_job(&self, id: &str, location: Location, demand: i32) -> Job {
         let mut dimens = create_dimens_with_id("", id);
         dimens.set_demand(Demand::<SingleDimLoad> {
             pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
@@ -215,8 +394,8 @@ impl<R: Read> TsplibReader<R> {
         });
         Job::Single(Arc::new(Single {
             places: vec![Place {
-                location: Some(self.coord_index.collect(location)),
-                duration: 0.,
+                location: Some(location),
+                duration: self.service_time.unwrap_or_default(),
                 times: vec![TimeSpan::Window(TimeWindow::max())],
             }],
             dimens,
@@ -224,9 +403,103 @@ impl<R: Read> TsplibReader<R> {
     }
 }
 
+impl EdgeWeightType {
+    /// Calculates distance between two nodes. When rounding is requested, the function mandated by
+    /// TSPLIB95 specification for the given metric is applied.
+    fn distance(&self, from: (f64, f64), to: (f64, f64), is_rounded: bool) -> f64 {
+        let (dx, dy) = (from.0 - to.0, from.1 - to.1);
+        let nint = |value: f64| if is_rounded { value.round() } else { value };
+
+        match self {
+            EdgeWeightType::Euclidean => nint((dx * dx + dy * dy).sqrt()),
+            EdgeWeightType::Ceil => {
+                let value = (dx * dx + dy * dy).sqrt();
+                if is_rounded {
+                    value.ceil()
+                } else {
+                    value
+                }
+            }
+            EdgeWeightType::Manhattan => nint(dx.abs() + dy.abs()),
+            EdgeWeightType::Maximum => nint(dx.abs()).max(nint(dy.abs())),
+            EdgeWeightType::Att => {
+                let value = ((dx * dx + dy * dy) / 10.).sqrt();
+                let rounded = value.round();
+                match (is_rounded, rounded < value) {
+                    (true, true) => rounded + 1.,
+                    (true, false) => rounded,
+                    _ => value,
+                }
+            }
+            EdgeWeightType::Geo => {
+                let value = get_geo_distance(from, to);
+                if is_rounded {
+                    (value + 1.).trunc()
+                } else {
+                    value
+                }
+            }
+            EdgeWeightType::Explicit => unreachable!("explicit weights are not calculated from coordinates"),
+        }
+    }
+}
+
+impl EdgeWeightFormat {
+    /// Returns node index pairs in the order of weights in the edge weight section.
+    fn get_indices(&self, dimension: usize) -> Vec<(usize, usize)> {
+        let pairs = (0..dimension).flat_map(move |i| (0..dimension).map(move |j| (i, j)));
+
+        match self {
+            EdgeWeightFormat::FullMatrix => pairs.collect(),
+            EdgeWeightFormat::UpperRow => pairs.filter(|(i, j)| j > i).collect(),
+            EdgeWeightFormat::LowerRow => pairs.filter(|(i, j)| j < i).collect(),
+            EdgeWeightFormat::UpperDiagRow => pairs.filter(|(i, j)| j >= i).collect(),
+            EdgeWeightFormat::LowerDiagRow => pairs.filter(|(i, j)| j <= i).collect(),
+        }
+    }
+}
+
+/// Calculates geographical distance as specified by TSPLIB95: coordinates are latitude and longitude
+/// in `DDD.MM` format, where `MM` are minutes.
+fn get_geo_distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+    // NOTE truncated value is mandated by the specification
+    #[allow(clippy::approx_constant)]
+    const PI: f64 = 3.141592;
+    const RRR: f64 = 6378.388;
+
+    // NOTE specification uses nint for degrees, but reference solutions are computed with truncation
+    let to_radians = |value: f64| {
+        let degrees = value.trunc();
+        PI * (degrees + 5. * (value - degrees) / 3.) / 180.
+    };
+
+    let (from_lat, from_lon) = (to_radians(from.0), to_radians(from.1));
+    let (to_lat, to_lon) = (to_radians(to.0), to_radians(to.1));
+
+    let q1 = (from_lon - to_lon).cos();
+    let q2 = (from_lat - to_lat).cos();
+    let q3 = (from_lat + to_lat).cos();
+
+    RRR * (0.5 * ((1. + q1) * q2 - (1. - q1) * q3)).clamp(-1., 1.).acos()
+}
+
+fn parse_node(data: &str, dimension: usize) -> Result<usize, GenericError> {
+    let id = parse_int(data, "cannot parse id")?;
+
+    if id < 1 || id as usize > dimension {
+        Err(format!("node id is out of range: '{id}'").into())
+    } else {
+        Ok(id as usize - 1)
+    }
+}
+
 fn parse_int(data: &str, err_msg: &str) -> Result<i32, GenericError> {
     data.parse::<f64>()
         // NOTE observed that some input files might have coordinates like 28.00000
         .map(|value| value.round() as i32)
         .map_err(|err| format!("{err_msg}: '{err}'").into())
 }
+
+fn parse_float(data: &str, err_msg: &str) -> Result<f64, GenericError> {
+    data.parse::<f64>().map_err(|err| format!("{err_msg}: '{err}'").into())
+}
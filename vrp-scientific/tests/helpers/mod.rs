@@ -12,6 +12,7 @@ pub use self::solomon::SolomonBuilder;
 mod lilim;
 pub use self::lilim::LilimBuilder;
 
+use crate::darp::DarpProblem;
 use crate::lilim::LilimProblem;
 use crate::solomon::SolomonProblem;
 use std::fs::File;
@@ -43,3 +44,10 @@ pub fn create_lc101_problem() -> Problem {
         .read_lilim(false)
         .unwrap()
 }
+
+/// Creates a small dial-a-ride sample problem used by tests.
+pub fn create_darp_sample_problem() -> Problem {
+    BufReader::new(get_test_resource("../../examples/data/scientific/darp/sample.txt").unwrap())
+        .read_darp(false)
+        .unwrap()
+}
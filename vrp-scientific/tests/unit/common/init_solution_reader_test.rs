@@ -1,5 +1,7 @@
 use super::*;
-use crate::helpers::{create_c101_100_problem, get_test_resource};
+use crate::helpers::{create_c101_100_problem, get_test_resource, LilimBuilder};
+use crate::lilim::LilimProblem;
+use crate::tsplib::TsplibProblem;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::utils::Environment;
 
@@ -16,3 +18,55 @@ pub fn can_read_init_solution() {
     let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
     assert_eq!(insertion_ctx.solution.get_total_cost().round(), 828.936f64.round());
 }
+
+#[test]
+pub fn can_read_cvrplib_solution() {
+    let environment = Arc::new(Environment::default());
+    let problem = BufReader::new(get_test_resource("../../examples/data/scientific/tsplib/A-n32-k5.vrp").unwrap())
+        .read_tsplib(true)
+        .map(Arc::new)
+        .expect("cannot read problem");
+    let file = get_test_resource("../../examples/data/scientific/tsplib/A-n32-k5.sol").unwrap();
+
+    let solution = read_init_solution(BufReader::new(file), problem.clone(), environment.random.clone())
+        .expect("cannot read solution");
+    assert_eq!(solution.routes.len(), 5);
+
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(insertion_ctx.solution.get_total_cost(), 784.);
+}
+
+#[test]
+pub fn can_read_lilim_solution() {
+    let environment = Arc::new(Environment::default());
+    let problem = LilimBuilder::default()
+        .set_vehicle((2, 10))
+        .add_customer((0, 0, 0, 0, 0, 1000, 0, 0, 0))
+        .add_customer((1, 1, 0, 2, 0, 1000, 0, 0, 2))
+        .add_customer((2, 2, 0, -2, 0, 1000, 0, 1, 0))
+        .build()
+        .read_lilim(false)
+        .map(Arc::new)
+        .expect("cannot read problem");
+    let content = "Instance name : test\nAuthors : unknown\nSolution\nRoute 1 : 1 2\n";
+
+    let solution = read_init_solution(BufReader::new(content.as_bytes()), problem, environment.random.clone())
+        .expect("cannot read solution");
+
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.routes[0].tour.job_count(), 1);
+    assert_eq!(solution.routes[0].tour.job_activity_count(), 2);
+}
+
+#[test]
+pub fn can_return_error_when_job_is_unknown() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_c101_100_problem());
+
+    let result =
+        read_init_solution(BufReader::new("Route #1: 1 2 101\n".as_bytes()), problem, environment.random.clone())
+            .map(|_| ());
+
+    assert_eq!(result, Err("cannot find job with id: '101'".into()));
+}
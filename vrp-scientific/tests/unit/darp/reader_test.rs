@@ -0,0 +1,31 @@
+use super::*;
+use crate::helpers::{create_darp_sample_problem, get_job_ids, get_vehicle_capacity};
+
+#[test]
+fn can_read_darp_format_from_test_file() {
+    let problem = create_darp_sample_problem();
+
+    assert_eq!(get_job_ids(&problem), (0..4).map(|i| format!("mlt{i}")).collect::<Vec<String>>());
+    assert_eq!(problem.fleet.vehicles.len(), 2);
+    assert_eq!(get_vehicle_capacity(&problem), 3);
+    assert!(problem
+        .jobs
+        .all()
+        .all(|job| job.as_multi().and_then(|multi| multi.dimens.get_max_ride_duration()) == Some(30.)));
+}
+
+#[test]
+fn can_read_darp_format_without_end_depot() {
+    let problem =
+        "1 2 480 3 30\n0 0 0 0 0 0 480\n1 1 0 3 1 0 480\n2 2 0 3 -1 0 480\n".to_string().read_darp(false).unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["mlt0".to_string()]);
+    assert_eq!(problem.fleet.vehicles.len(), 1);
+}
+
+#[test]
+fn can_return_error_on_invalid_node_line() {
+    let result = "1 2 480 3 30\n0 0 0 0 0 0 480\n1 1 0 3\n".to_string().read_darp(false);
+
+    assert!(result.is_err());
+}
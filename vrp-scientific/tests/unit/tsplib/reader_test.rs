@@ -1,8 +1,11 @@
+use super::*;
 use crate::helpers::get_test_resource;
-use crate::tsplib::reader::TsplibReader;
 use crate::tsplib::TsplibProblem;
+use crate::tsplib::TsplibSolution;
 use std::fs::File;
+use std::io::BufWriter;
 use std::io::{BufReader, Read};
+use vrp_core::prelude::Environment;
 
 fn get_example_problem_string() -> String {
     let mut buffer = "".to_string();
@@ -19,51 +22,60 @@ fn get_example_problem_reader() -> BufReader<File> {
     BufReader::new(get_test_resource("../../examples/data/scientific/tsplib/example.txt").expect("cannot open file"))
 }
 
+fn create_problem_string(problem_type: &str, specification: &str, sections: &str) -> String {
+    format!("NAME : test\nTYPE : {problem_type}\nDIMENSION : 4\n{specification}\n{sections}\nEOF\n")
+}
+
+fn get_distances(problem: &Problem) -> Vec<Vec<f64>> {
+    let profile = Profile::default();
+    let size = problem.jobs.size() + 1;
+
+    (0..size)
+        .map(|from| (0..size).map(|to| problem.transport.distance_approx(&profile, from, to)).collect::<Vec<_>>())
+        .collect()
+}
+
 #[test]
-fn can_read_meta_errors() {
+fn can_read_specification_errors() {
     for &(from, to, error) in &[
-        ("CVRP", "ASD", "expecting 'CVRP' as TYPE, got 'ASD'"),
+        ("CVRP", "ASD", "expecting 'CVRP', 'TSP' or 'ATSP' as TYPE, got 'ASD'"),
         ("DIMENSION : 6", "DIMENSION : asd", "cannot parse DIMENSION: 'invalid float literal'"),
-        ("EUC_2D", "ASD", "expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got 'ASD'"),
+        ("EUC_2D", "ASD", "unsupported EDGE_WEIGHT_TYPE: 'ASD'"),
         ("CAPACITY : 30", "CAPACITY : asd", "cannot parse CAPACITY: 'invalid float literal'"),
+        ("DIMENSION : 6", "", "DIMENSION should be specified before NODE_COORD_SECTION"),
+        ("DEPOT_SECTION\n1\n", "DEPOT_SECTION\n1\n2\n", "multiple depots are not supported"),
+        ("DEMAND_SECTION\n1 0\n", "DEMAND_SECTION\n7 0\n", "node id is out of range: '7'"),
     ] {
         let content = get_example_problem_string().replace(from, to);
-        let mut reader = TsplibReader::new(BufReader::new(content.as_bytes()));
 
-        let result = reader.read_meta();
+        let result = content.read_tsplib(false).map(|_| ());
 
         assert_eq!(result, Err(error.into()));
     }
 }
 
 #[test]
-fn can_read_meta_capacity_and_dimension() {
+fn can_read_specification_capacity_and_dimension() {
     let mut reader = TsplibReader::new(get_example_problem_reader());
 
-    reader.read_meta().expect("cannot read meta");
+    reader.read_data().expect("cannot read data");
 
+    assert_eq!(reader.problem_type, Some(ProblemType::Cvrp));
     assert_eq!(reader.dimension, Some(6));
     assert_eq!(reader.vehicle_capacity, Some(30));
+    assert_eq!(reader.edge_weight_type, Some(EdgeWeightType::Euclidean));
 }
 
 #[test]
-fn can_read_customer_data() {
+fn can_read_customer_and_depot_data() {
     let mut reader = TsplibReader::new(get_example_problem_reader());
-    reader.read_meta().expect("cannot read meta");
 
-    let (coordinates, demands) = reader.read_customer_data().expect("cannot read coordinates");
+    reader.read_data().expect("cannot read data");
 
-    assert_eq!(coordinates.len(), 6);
-    assert_eq!(demands.len(), 6);
-}
-
-#[test]
-fn can_read_depot_data() {
-    let mut reader = TsplibReader::new(get_example_problem_reader());
-    reader.read_meta().expect("cannot read meta");
-    reader.read_customer_data().expect("cannot read customer data");
-
-    assert_eq!(reader.read_depot_data(), Ok(1));
+    assert_eq!(reader.locations.len(), 6);
+    assert_eq!(reader.locations[2], (96., 42.));
+    assert_eq!(reader.demands.as_ref().map(|demands| demands.len()), Some(6));
+    assert_eq!(reader.depots, vec![0]);
 }
 
 #[test]
@@ -75,3 +87,122 @@ fn can_read_problem() {
     assert_eq!(problem.jobs.size(), 5);
     assert_eq!(problem.fleet.actors.len(), 6);
 }
+
+#[test]
+fn can_read_sections_in_any_order() {
+    let content = create_problem_string(
+        "CVRP",
+        "COMMENT: sections are shuffled: demands go first\nCAPACITY:10\nEDGE_WEIGHT_TYPE: EUC_2D\nVEHICLES : 2",
+        "DEPOT_SECTION\n 2\n -1\nDEMAND_SECTION\n1 1\n2 0\n3 2\n4 3\nNODE_COORD_SECTION\n1 0 0\n2 3 4\n3 3.0 0\n4 0 4",
+    );
+
+    let problem = content.read_tsplib(false).expect("cannot read problem");
+
+    assert_eq!(problem.jobs.size(), 3);
+    assert_eq!(problem.fleet.actors.len(), 2);
+    assert_eq!(
+        get_distances(&problem),
+        vec![vec![0., 5., 3., 4.], vec![5., 0., 4., 3.], vec![3., 4., 0., 5.], vec![4., 3., 5., 0.]]
+    );
+}
+
+parameterized_test! {can_read_edge_weights, (specification, section, expected), {
+    can_read_edge_weights_impl(specification, section, expected);
+}}
+
+can_read_edge_weights! {
+    case01_full_matrix: ("EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : FULL_MATRIX",
+        "0 1 2 3\n1 0 4 5\n2 4 0 6\n3 5 6 0", None),
+    case02_upper_row: ("EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : UPPER_ROW", "1 2 3\n4 5\n6", None),
+    case03_lower_row: ("EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : LOWER_ROW", "1\n2 4\n3 5 6", None),
+    case04_upper_diag_row: ("EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : UPPER_DIAG_ROW",
+        "0 1 2 3 0 4 5 0 6 0", None),
+    case05_lower_diag_row: ("EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : LOWER_DIAG_ROW",
+        "0\n1 0\n2 4 0\n3 5 6 0", None),
+    case06_upper_col: ("EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : UPPER_COL", "1 2 4 3 5 6", None),
+    case07_asymmetric: ("EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : FULL_MATRIX",
+        "9999 1 2 3\n7 9999 4 5\n8 9 9999 6\n9 9 9 9999",
+        Some(vec![vec![0., 1., 2., 3.], vec![7., 0., 4., 5.], vec![8., 9., 0., 6.], vec![9., 9., 9., 0.]])),
+}
+
+fn can_read_edge_weights_impl(specification: &str, section: &str, expected: Option<Vec<Vec<f64>>>) {
+    let content = create_problem_string("TSP", specification, format!("EDGE_WEIGHT_SECTION\n{section}").as_str());
+    let expected = expected.unwrap_or_else(|| {
+        vec![vec![0., 1., 2., 3.], vec![1., 0., 4., 5.], vec![2., 4., 0., 6.], vec![3., 5., 6., 0.]]
+    });
+
+    let problem = content.read_tsplib(false).expect("cannot read problem");
+
+    assert_eq!(problem.jobs.size(), 3);
+    assert_eq!(problem.fleet.actors.len(), 1);
+    assert_eq!(get_distances(&problem), expected);
+}
+
+parameterized_test! {can_use_edge_weight_type_metric, (edge_weight_type, coords, is_rounded, expected), {
+    can_use_edge_weight_type_metric_impl(edge_weight_type, coords, is_rounded, expected);
+}}
+
+can_use_edge_weight_type_metric! {
+    case01_euc_2d_rounded: (EdgeWeightType::Euclidean, ((0., 0.), (1., 1.)), true, 1.),
+    case02_euc_2d_not_rounded: (EdgeWeightType::Euclidean, ((0., 0.), (3., 4.)), false, 5.),
+    case03_ceil_2d: (EdgeWeightType::Ceil, ((0., 0.), (1., 1.)), true, 2.),
+    case04_man_2d: (EdgeWeightType::Manhattan, ((0., 0.), (1.2, 2.4)), true, 4.),
+    case05_max_2d: (EdgeWeightType::Maximum, ((0., 0.), (1.2, 2.4)), true, 2.),
+    case06_att_round_up: (EdgeWeightType::Att, ((0., 0.), (10., 0.)), true, 4.),
+    case07_att_exact: (EdgeWeightType::Att, ((0., 0.), (30., 10.)), true, 10.),
+    case08_geo_degree: (EdgeWeightType::Geo, ((0., 0.), (1., 0.)), true, 112.),
+    case09_geo_minutes: (EdgeWeightType::Geo, ((0., 0.), (0.30, 0.)), true, 56.),
+}
+
+fn can_use_edge_weight_type_metric_impl(
+    edge_weight_type: EdgeWeightType,
+    coords: ((f64, f64), (f64, f64)),
+    is_rounded: bool,
+    expected: f64,
+) {
+    let result = edge_weight_type.distance(coords.0, coords.1, is_rounded);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_read_distance_constrained_problem() {
+    let create_content = |specification: &str| {
+        create_problem_string(
+            "CVRP",
+            format!("CAPACITY : 10\nEDGE_WEIGHT_TYPE : EUC_2D\n{specification}").as_str(),
+            "NODE_COORD_SECTION\n1 0 0\n2 3 4\n3 3 0\n4 0 4\nDEMAND_SECTION\n1 0\n2 1\n3 1\n4 1\nDEPOT_SECTION\n1\n-1",
+        )
+    };
+    let unconstrained = create_content("").read_tsplib(false).expect("cannot read problem");
+
+    let problem = create_content("DISTANCE : 25\nSERVICE_TIME : 5").read_tsplib(false).expect("cannot read problem");
+
+    assert!(problem.jobs.all().all(|job| job.to_single().places[0].duration == 5.));
+    assert_eq!(problem.goal.constraints().count(), unconstrained.goal.constraints().count() + 1);
+}
+
+#[test]
+fn can_write_solution_to_be_read_back() {
+    let content = create_problem_string(
+        "ATSP",
+        "EDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : FULL_MATRIX",
+        "EDGE_WEIGHT_SECTION\n0 1 9 9\n9 0 1 9\n9 9 0 1\n1 9 9 0",
+    );
+    let problem = Arc::new(content.read_tsplib(false).expect("cannot read problem"));
+    let write_solution = |content: &str| {
+        let random = Environment::default().random;
+        let solution = read_init_solution(BufReader::new(content.as_bytes()), problem.clone(), random)
+            .expect("cannot read solution");
+        let mut writer = BufWriter::new(Vec::new());
+        solution.write_tsplib(&mut writer).expect("cannot write solution");
+
+        String::from_utf8(writer.into_inner().expect("cannot get buffer")).unwrap()
+    };
+
+    let result = write_solution("Route 1: 1 2 3\n");
+    assert_eq!(result, "Route 1: 1 2 3\nCost 4.00");
+
+    let result_after_read = write_solution(result.as_str());
+    assert_eq!(result_after_read, result);
+}